@@ -131,12 +131,33 @@ fn preprocess_assets() {
         "FAIRCAMP_EMBEDS_JS"
     );
 
+    preprocess_js(
+        "navigation.js",
+        Some("FAIRCAMP_NAVIGATION_JS_HASH"),
+        include_str!("src/assets/navigation.js"),
+        "FAIRCAMP_NAVIGATION_JS"
+    );
+
+    preprocess_js(
+        "offline.js",
+        Some("FAIRCAMP_OFFLINE_JS_HASH"),
+        include_str!("src/assets/offline.js"),
+        "FAIRCAMP_OFFLINE_JS"
+    );
+
     preprocess_js(
         "player.js",
         None,
         include_str!("src/assets/player.js"),
         "FAIRCAMP_PLAYER_JS"
     );
+
+    preprocess_js(
+        "service_worker.js",
+        None,
+        include_str!("src/assets/service_worker.js"),
+        "FAIRCAMP_SERVICE_WORKER_JS"
+    );
 }
 
 fn preprocess_css(
@@ -0,0 +1,337 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Generates a static, read-only ActivityPub presence for the catalog: A
+//! WebFinger document (so that e.g. @band@example.com can be looked up), an
+//! actor document describing the catalog, and an outbox containing one
+//! `Create` activity per public release (each with its cover attached).
+//! Fediverse servers can discover and display these, but because the site
+//! is static, follow requests sent to the inbox are not processed (the
+//! preview server offers a stand-in inbox for local testing though). Known
+//! limitation: The actor has no `publicKey` and nothing is signed with HTTP
+//! Signatures, so servers that require these won't show the catalog.
+//!
+//! Specifications for reference:
+//! - https://www.w3.org/TR/activitypub/
+//! - https://www.w3.org/TR/activitystreams-vocabulary/
+//! - https://datatracker.ietf.org/doc/html/rfc7033 (WebFinger)
+
+use std::fs;
+
+use indoc::formatdoc;
+use url::Url;
+
+use crate::{
+    Build,
+    Catalog,
+    FeedImageAsset,
+    Link,
+    Release,
+    SiteUrl
+};
+use crate::util::{
+    html_escape_inside_attribute,
+    html_escape_outside_attribute,
+    json_escape_inside_string
+};
+
+pub const ACTIVITYPUB_DIR: &str = "activitypub";
+pub const ACTOR_FILENAME: &str = "actor.json";
+pub const INBOX_FILENAME: &str = "inbox";
+pub const OUTBOX_FILENAME: &str = "outbox.json";
+pub const RELEASES_DIR: &str = "releases";
+pub const WELL_KNOWN_DIR: &str = ".well-known";
+pub const WEBFINGER_FILENAME: &str = "webfinger";
+
+const ACTIVITYSTREAMS_CONTEXT: &str = "https://www.w3.org/ns/activitystreams";
+const PUBLIC_COLLECTION: &str = "https://www.w3.org/ns/activitystreams#Public";
+
+/// Generate WebFinger, actor, outbox and per-release note documents,
+/// writing them to the build directory.
+pub fn generate(build: &mut Build, catalog: &Catalog, username: &str) {
+    let base_url_prefix = build.base_url_unchecked().prefix().to_string();
+
+    let host = match Url::parse(&base_url_prefix).ok().and_then(|url| {
+        url.host_str().map(|host| match url.port() {
+            Some(port) => format!("{host}:{port}"),
+            None => host.to_string()
+        })
+    }) {
+        Some(host) => host,
+        None => {
+            let message = format!("ActivityPub documents could not be generated because the base_url '{base_url_prefix}' has no host");
+            build.warning(&message);
+            return;
+        }
+    };
+
+    if build.base_url_unchecked().without_scheme_and_trailing_slash() != host {
+        let message = format!("The base_url '{base_url_prefix}' contains a path, however fediverse servers only look up WebFinger documents at the root of a domain (https://{host}/.well-known/webfinger), so you will need to place a copy or redirect there yourself for your catalog to be discoverable as @{username}@{host}");
+        build.warning(&message);
+    }
+
+    let base_url = build.base_url_unchecked();
+    let actor_url = base_url.join_file(format!("{ACTIVITYPUB_DIR}/{ACTOR_FILENAME}"));
+
+    let webfinger_json = webfinger(base_url, build, &actor_url, &host, username);
+    let well_known_dir = build.build_dir.join(WELL_KNOWN_DIR);
+    fs::create_dir_all(&well_known_dir).unwrap();
+    fs::write(well_known_dir.join(WEBFINGER_FILENAME), webfinger_json).unwrap();
+
+    let activitypub_dir = build.build_dir.join(ACTIVITYPUB_DIR);
+    let releases_dir = activitypub_dir.join(RELEASES_DIR);
+    fs::create_dir_all(&releases_dir).unwrap();
+
+    let actor_json = actor(base_url, build, catalog, &actor_url, username);
+    fs::write(activitypub_dir.join(ACTOR_FILENAME), actor_json).unwrap();
+
+    let mut releases_desc_by_date = catalog.published_releases();
+    releases_desc_by_date.sort_by_key(|release| release.borrow().date);
+
+    let mut activities = Vec::new();
+
+    for release in releases_desc_by_date.iter().rev() {
+        let release_ref = release.borrow();
+
+        let note_json = note(base_url, build, catalog, &actor_url, &release_ref);
+        let note_filename = format!("{}.json", release_ref.permalink.slug);
+        fs::write(releases_dir.join(note_filename), &note_json).unwrap();
+
+        activities.push(create_activity(base_url, &actor_url, &note_json, &release_ref));
+    }
+
+    let outbox_json = outbox(base_url, &activities);
+    fs::write(activitypub_dir.join(OUTBOX_FILENAME), outbox_json).unwrap();
+
+    build.reserve_filename(ACTIVITYPUB_DIR);
+    build.reserve_filename(WELL_KNOWN_DIR);
+}
+
+fn actor(
+    base_url: &SiteUrl,
+    build: &Build,
+    catalog: &Catalog,
+    actor_url: &str,
+    username: &str
+) -> String {
+    let mut props = Vec::new();
+
+    props.push(format!(r#""@context":"{ACTIVITYSTREAMS_CONTEXT}""#));
+    props.push(format!(r#""id":"{}""#, json_escape_inside_string(actor_url)));
+
+    // In label mode the catalog represents a collective of artists, which
+    // ActivityStreams models as an Organization, otherwise we present the
+    // catalog as the artist themselves.
+    let actor_type = if catalog.label_mode { "Organization" } else { "Person" };
+    props.push(format!(r#""type":"{actor_type}""#));
+
+    props.push(format!(r#""preferredUsername":"{}""#, json_escape_inside_string(username)));
+
+    let name = if catalog.label_mode {
+        catalog.title()
+    } else if let Some(artist) = &catalog.artist {
+        artist.borrow().name.clone()
+    } else {
+        catalog.title()
+    };
+    props.push(format!(r#""name":"{}""#, json_escape_inside_string(&name)));
+
+    let summary = if let Some(synopsis) = &catalog.synopsis {
+        Some(html_escape_outside_attribute(synopsis))
+    } else {
+        catalog.more.as_ref().map(|html_and_stripped| html_and_stripped.html.clone())
+    };
+
+    if let Some(summary) = summary {
+        props.push(format!(r#""summary":"{}""#, json_escape_inside_string(&summary)));
+    }
+
+    props.push(format!(r#""url":"{}""#, json_escape_inside_string(&base_url.index(build))));
+
+    let inbox_url = base_url.join_file(format!("{ACTIVITYPUB_DIR}/{INBOX_FILENAME}"));
+    props.push(format!(r#""inbox":"{}""#, json_escape_inside_string(&inbox_url)));
+
+    let outbox_url = base_url.join_file(format!("{ACTIVITYPUB_DIR}/{OUTBOX_FILENAME}"));
+    props.push(format!(r#""outbox":"{}""#, json_escape_inside_string(&outbox_url)));
+
+    if let Some(home_image) = &catalog.home_image {
+        let image_ref = home_image.borrow();
+        let hash = image_ref.hash.as_url_safe_base64();
+        let filename = FeedImageAsset::TARGET_FILENAME;
+        let url_escaped = json_escape_inside_string(&base_url.join_file(format!("{filename}?{hash}")));
+
+        let name = match &home_image.description {
+            Some(description) => format!(r#","name":"{}""#, json_escape_inside_string(description)),
+            None => String::new()
+        };
+
+        props.push(format!(r#""icon":{{"type":"Image","mediaType":"image/jpeg","url":"{url_escaped}"{name}}}"#));
+    }
+
+    // Visible links become profile metadata fields, as commonly displayed
+    // by fediverse software (anchor links only make sense on the site itself).
+    let attachments = catalog.links
+        .iter()
+        .filter_map(|link| {
+            match link {
+                Link::Anchor { .. } => None,
+                Link::Full { hidden: true, .. } => None,
+                Link::Full { label, rel_me, url, .. } => {
+                    let rel = if *rel_me { "me nofollow noopener noreferrer" } else { "nofollow noopener noreferrer" };
+                    let value = format!(
+                        r#"<a href="{url_escaped}" rel="{rel}" target="_blank">{label_escaped}</a>"#,
+                        label_escaped = html_escape_outside_attribute(label),
+                        url_escaped = html_escape_inside_attribute(url)
+                    );
+
+                    Some(format!(
+                        r#"{{"type":"PropertyValue","name":"{}","value":"{}"}}"#,
+                        json_escape_inside_string(label),
+                        json_escape_inside_string(&value)
+                    ))
+                }
+            }
+        })
+        .collect::<Vec<String>>();
+
+    if !attachments.is_empty() {
+        props.push(format!(r#""attachment":[{}]"#, attachments.join(",")));
+    }
+
+    format!("{{{}}}", props.join(","))
+}
+
+fn create_activity(
+    base_url: &SiteUrl,
+    actor_url: &str,
+    note_json: &str,
+    release: &Release
+) -> String {
+    let actor_url_escaped = json_escape_inside_string(actor_url);
+    let note_url_escaped = json_escape_inside_string(&note_url(base_url, release));
+    let published_property = published(release);
+
+    formatdoc!(r#"
+        {{"id":"{note_url_escaped}#create","type":"Create","actor":"{actor_url_escaped}"{published_property},"to":["{PUBLIC_COLLECTION}"],"object":{note_json}}}
+    "#).trim_end().to_string()
+}
+
+fn note(
+    base_url: &SiteUrl,
+    build: &Build,
+    catalog: &Catalog,
+    actor_url: &str,
+    release: &Release
+) -> String {
+    let release_slug = &release.permalink.slug;
+    let release_url = base_url.join_index(build, release_slug);
+
+    let main_artists = release.main_artists
+        .iter()
+        .map(|artist| artist.borrow().name.clone())
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let artists_list = if catalog.show_support_artists && !release.support_artists.is_empty() {
+        let support_artists = release.support_artists
+            .iter()
+            .map(|artist| artist.borrow().name.clone())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        format!("{main_artists}, {support_artists}")
+    } else {
+        main_artists
+    };
+
+    let artists_and_title = format!("{artists_list} – {}", release.title);
+
+    let mut content = format!(
+        r#"<p><a href="{}">{}</a></p>"#,
+        html_escape_inside_attribute(&release_url),
+        html_escape_outside_attribute(&artists_and_title)
+    );
+
+    if let Some(synopsis) = &release.synopsis {
+        content.push_str(&format!("<p>{}</p>", html_escape_outside_attribute(synopsis)));
+    }
+
+    let attachment = if let Some(described_image) = &release.cover {
+        let image_ref = described_image.borrow();
+        let opengraph_image = image_ref.cover_opengraph_image_unchecked(&base_url.join_prefix(release_slug));
+
+        let name = match &described_image.description {
+            Some(description) => format!(r#","name":"{}""#, json_escape_inside_string(description)),
+            None => String::new()
+        };
+
+        format!(
+            r#"{{"type":"Image","mediaType":"image/jpeg","url":"{url_escaped}","width":{width},"height":{height}{name}}}"#,
+            height = opengraph_image.height,
+            url_escaped = json_escape_inside_string(&opengraph_image.url),
+            width = opengraph_image.width
+        )
+    } else {
+        let filename = release.procedural_cover_720_filename_unchecked();
+        let url_escaped = json_escape_inside_string(&base_url.join_file(format!("{release_slug}/{filename}")));
+        let t_auto_generated_cover = json_escape_inside_string(*build.locale.translations.auto_generated_cover);
+
+        format!(r#"{{"type":"Image","mediaType":"image/png","url":"{url_escaped}","name":"{t_auto_generated_cover}"}}"#)
+    };
+
+    let actor_url_escaped = json_escape_inside_string(actor_url);
+    let content_escaped = json_escape_inside_string(&content);
+    let id_escaped = json_escape_inside_string(&note_url(base_url, release));
+    let name_escaped = json_escape_inside_string(&artists_and_title);
+    let published_property = published(release);
+    let release_url_escaped = json_escape_inside_string(&release_url);
+
+    formatdoc!(r#"
+        {{"@context":"{ACTIVITYSTREAMS_CONTEXT}","id":"{id_escaped}","type":"Note","attributedTo":"{actor_url_escaped}","name":"{name_escaped}","content":"{content_escaped}","url":"{release_url_escaped}"{published_property},"to":["{PUBLIC_COLLECTION}"],"attachment":[{attachment}]}}
+    "#).trim_end().to_string()
+}
+
+fn note_url(base_url: &SiteUrl, release: &Release) -> String {
+    let release_slug = &release.permalink.slug;
+    base_url.join_file(format!("{ACTIVITYPUB_DIR}/{RELEASES_DIR}/{release_slug}.json"))
+}
+
+fn outbox(base_url: &SiteUrl, activities: &[String]) -> String {
+    let outbox_url_escaped = json_escape_inside_string(
+        &base_url.join_file(format!("{ACTIVITYPUB_DIR}/{OUTBOX_FILENAME}"))
+    );
+    let total_items = activities.len();
+    let ordered_items = activities.join(",");
+
+    formatdoc!(r#"
+        {{"@context":"{ACTIVITYSTREAMS_CONTEXT}","id":"{outbox_url_escaped}","type":"OrderedCollection","totalItems":{total_items},"orderedItems":[{ordered_items}]}}
+    "#)
+}
+
+/// Returns the "published" property (including its leading comma) for
+/// activities and notes of a release. Releases without a date have no known
+/// publication time, so we omit the property for them - using the build
+/// time instead would make the release look newly published on every build.
+fn published(release: &Release) -> String {
+    match release.date {
+        Some(date) => format!(r#","published":"{}T00:00:00Z""#, date.format("%Y-%m-%d")),
+        None => String::new()
+    }
+}
+
+fn webfinger(
+    base_url: &SiteUrl,
+    build: &Build,
+    actor_url: &str,
+    host: &str,
+    username: &str
+) -> String {
+    let actor_url_escaped = json_escape_inside_string(actor_url);
+    let host_escaped = json_escape_inside_string(host);
+    let site_url_escaped = json_escape_inside_string(&base_url.index(build));
+    let username_escaped = json_escape_inside_string(username);
+
+    formatdoc!(r#"
+        {{"subject":"acct:{username_escaped}@{host_escaped}","aliases":["{actor_url_escaped}"],"links":[{{"rel":"self","type":"application/activity+json","href":"{actor_url_escaped}"}},{{"rel":"http://webfinger.net/rel/profile-page","type":"text/html","href":"{site_url_escaped}"}}]}}
+    "#)
+}
@@ -1,24 +1,66 @@
-// SPDX-FileCopyrightText: 2024 Simon Repp
+// SPDX-FileCopyrightText: 2024-2025 Simon Repp
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
 use std::cell::{Ref, RefCell, RefMut};
-use std::fs;
+use std::fs::{self, File};
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde_derive::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use tar::{Builder, EntryType, Header};
+use zip::{CompressionMethod, ZipWriter};
+use zip::write::SimpleFileOptions;
+use zstd::Encoder;
 
-use crate::{Asset, DownloadFormat};
+use crate::{Asset, Build, DownloadFormat};
 use crate::util::url_safe_base64;
 
+/// Compression level for tar.zst archives, 1 (fastest) - 22 (smallest). The
+/// audio in the archives is mostly compressed already, so higher levels
+/// barely pay off.
+const ZSTD_LEVEL: i32 = 3;
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Archive {
     pub asset: Asset,
+    /// Hex-encoded SHA-256 checksum of the archive file, only computed
+    /// (and then cached) once checksums are requested for the release.
+    pub checksum: Option<String>,
     pub format: DownloadFormat
 }
 
-/// Downloadable zip archives for a release, including cover, tracks
+/// The container (and compression) used for release archives.
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum ArchiveFormat {
+    /// Uncompressed tar archive
+    Tar,
+    /// Tar archive with zstd compression
+    TarZst,
+    /// Zip archive with deflate compression
+    Zip,
+    /// Zip archive without compression - audio is already compressed (apart
+    /// from lossless formats), so this saves time on creating and opening
+    /// the archive, at the cost of a (usually) marginally larger file.
+    ZipStored
+}
+
+/// Writes files into a release archive, abstracting over the different
+/// archive formats we support.
+pub enum ArchiveWriter {
+    Tar {
+        builder: Builder<TarSink>,
+        mtime: u64
+    },
+    Zip {
+        options: SimpleFileOptions,
+        writer: ZipWriter<File>
+    }
+}
+
+/// Downloadable archives for a release, including cover, tracks
 /// and extras such as liner notes, graphics, etc.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Archives {
@@ -34,23 +76,180 @@ pub struct ArchivesRc {
     archives: Rc<RefCell<Archives>>,
 }
 
+/// The file that a tar archive is written to, either directly or through
+/// a zstd encoder.
+pub enum TarSink {
+    Plain(File),
+    Zstd(Encoder<'static, File>)
+}
+
 impl Archive {
+    /// Returns the SHA-256 checksum of the archive, computing it first if
+    /// that hasn't happened yet.
+    pub fn checksum(&mut self, build: &Build) -> &str {
+        if self.checksum.is_none() {
+            let mut file = File::open(build.cache_dir.join(&self.asset.filename)).unwrap();
+            let mut hasher = Sha256::new();
+            io::copy(&mut file, &mut hasher).unwrap();
+            self.checksum = Some(format!("{:x}", hasher.finalize()));
+        }
+
+        self.checksum.as_ref().unwrap()
+    }
+
     pub fn new(
         asset: Asset,
         format: DownloadFormat
     ) -> Archive {
         Archive {
             asset,
+            checksum: None,
             format
         }
     }
 }
 
+impl ArchiveFormat {
+    pub const ALL_MANIFEST_KEYS: [&'static str; 4] = ["tar", "tar_zst", "zip", "zip_stored"];
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Tar => "tar",
+            ArchiveFormat::TarZst => "tar.zst",
+            ArchiveFormat::Zip |
+            ArchiveFormat::ZipStored => "zip"
+        }
+    }
+
+    pub fn from_manifest_key(key: &str) -> Option<ArchiveFormat> {
+        match key {
+            "tar" => Some(ArchiveFormat::Tar),
+            "tar_zst" => Some(ArchiveFormat::TarZst),
+            "zip" => Some(ArchiveFormat::Zip),
+            "zip_stored" => Some(ArchiveFormat::ZipStored),
+            _ => None
+        }
+    }
+}
+
+impl ArchiveWriter {
+    pub fn add_bytes(&mut self, name: &str, bytes: &[u8]) {
+        match self {
+            ArchiveWriter::Tar { builder, mtime } => {
+                let mut header = Header::new_gnu();
+                header.set_mode(0o644);
+                header.set_mtime(*mtime);
+                header.set_size(bytes.len() as u64);
+                builder.append_data(&mut header, name, bytes).unwrap();
+            }
+            ArchiveWriter::Zip { options, writer } => {
+                writer.start_file(name, *options).unwrap();
+                writer.write_all(bytes).unwrap();
+            }
+        }
+    }
+
+    pub fn add_directory(&mut self, name: &str) {
+        match self {
+            ArchiveWriter::Tar { builder, mtime } => {
+                let mut header = Header::new_gnu();
+                header.set_entry_type(EntryType::Directory);
+                header.set_mode(0o755);
+                header.set_mtime(*mtime);
+                header.set_size(0);
+                builder.append_data(&mut header, name, io::empty()).unwrap();
+            }
+            ArchiveWriter::Zip { options, writer } => {
+                writer.add_directory(name, *options).unwrap();
+            }
+        }
+    }
+
+    /// Copies the file at source_path into the archive (under the given name).
+    pub fn add_file(&mut self, name: &str, source_path: &Path) {
+        let mut source_file = File::open(source_path).unwrap();
+
+        match self {
+            ArchiveWriter::Tar { builder, mtime } => {
+                let mut header = Header::new_gnu();
+                header.set_mode(0o644);
+                header.set_mtime(*mtime);
+                header.set_size(source_file.metadata().unwrap().len());
+                builder.append_data(&mut header, name, source_file).unwrap();
+            }
+            ArchiveWriter::Zip { options, writer } => {
+                writer.start_file(name, *options).unwrap();
+                io::copy(&mut source_file, writer).unwrap();
+            }
+        }
+    }
+
+    pub fn finish(self) -> Result<(), String> {
+        match self {
+            ArchiveWriter::Tar { builder, .. } => builder
+                .into_inner()
+                .and_then(|tar_sink| tar_sink.finish())
+                .map_err(|err| err.to_string()),
+            ArchiveWriter::Zip { writer, .. } => writer
+                .finish()
+                .map(|_| ())
+                .map_err(|err| err.to_string())
+        }
+    }
+
+    /// For tar archives the release date (if there is one) is written as
+    /// modification time of all entries, otherwise the unix epoch, so that
+    /// the same input always produces the same archive.
+    pub fn new(
+        archive_format: ArchiveFormat,
+        path: &Path,
+        release_date: Option<NaiveDate>
+    ) -> ArchiveWriter {
+        let file = File::create(path).unwrap();
+
+        match archive_format {
+            ArchiveFormat::Tar |
+            ArchiveFormat::TarZst => {
+                let mtime = release_date
+                    .map(|date| date.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp())
+                    .map(|timestamp| timestamp.max(0) as u64)
+                    .unwrap_or(0);
+
+                let tar_sink = match archive_format {
+                    ArchiveFormat::TarZst => TarSink::Zstd(Encoder::new(file, ZSTD_LEVEL).unwrap()),
+                    _ => TarSink::Plain(file)
+                };
+
+                ArchiveWriter::Tar {
+                    builder: Builder::new(tar_sink),
+                    mtime
+                }
+            }
+            ArchiveFormat::Zip |
+            ArchiveFormat::ZipStored => {
+                let compression_method = match archive_format {
+                    ArchiveFormat::ZipStored => CompressionMethod::Stored,
+                    _ => CompressionMethod::Deflated
+                };
+
+                let options = SimpleFileOptions::default()
+                    .compression_method(compression_method)
+                    .unix_permissions(0o755);
+
+                ArchiveWriter::Zip {
+                    options,
+                    writer: ZipWriter::new(file)
+                }
+            }
+        }
+    }
+}
+
 impl Archives {
     /// Increase version on each change to the data layout of [Archive].
     /// This automatically informs the cache not to try to deserialize
     /// manifests that hold old, incompatible data.
-    pub const CACHE_SERIALIZATION_KEY: &'static str = "archives1";
+    pub const CACHE_SERIALIZATION_KEY: &'static str = "archives2";
 
     pub fn deserialize_cached(path: &Path) -> Option<Archives> {
         match fs::read(path) {
@@ -118,4 +317,31 @@ impl ArchivesRc {
             archives: Rc::new(RefCell::new(archives))
         }
     }
-}
\ No newline at end of file
+}
+
+impl TarSink {
+    /// Writes the end of the zstd frame (if compressing) - without this the
+    /// archive would be truncated.
+    fn finish(self) -> io::Result<()> {
+        match self {
+            TarSink::Plain(_) => Ok(()),
+            TarSink::Zstd(encoder) => encoder.finish().map(|_| ())
+        }
+    }
+}
+
+impl Write for TarSink {
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            TarSink::Plain(file) => file.flush(),
+            TarSink::Zstd(encoder) => encoder.flush()
+        }
+    }
+
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            TarSink::Plain(file) => file.write(buf),
+            TarSink::Zstd(encoder) => encoder.write(buf)
+        }
+    }
+}
@@ -2,10 +2,12 @@
 // SPDX-FileCopyrightText: 2025 Sandro Santilli
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use std::net::IpAddr;
 use std::path::PathBuf;
 
+use crate::util::parse_bytes;
+
 #[derive(Debug, Parser)]
 #[clap(version = concat!(env!("FAIRCAMP_VERSION_DETAILED"), " (", env!("FAIRCAMP_REVISION"), ") (", env!("FAIRCAMP_FEATURES"), ")"))]
 pub struct Args {
@@ -25,6 +27,9 @@ pub struct Args {
     #[clap(long = "catalog-dir")]
     pub catalog_dir: Option<PathBuf>,
 
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+
     /// Print debug information for the catalog (no build is performed)
     #[clap(long = "debug")]
     pub debug: bool,
@@ -84,6 +89,14 @@ pub struct Args {
     #[clap(long = "preview-port")]
     pub preview_port: Option<u16>,
 
+    /// A cache shared between several machines or catalogs, given as a directory (e.g. a network share) or an http(s) url (e.g. a server started with the shared-cache-server subcommand). Transcodes are retrieved from there if available and stored there after computing them locally
+    #[clap(long = "shared-cache")]
+    pub shared_cache: Option<String>,
+
+    /// The token required by a shared cache server (see --shared-cache) for storing transcodes there. Without it, transcodes are only retrieved from a shared cache given by url, but not stored to it
+    #[clap(long = "shared-cache-token")]
+    pub shared_cache_token: Option<String>,
+
     /// Injects a small widget into the page which allows you to interactively explore different theme color configurations
     #[clap(long = "theming-widget")]
     pub theming_widget: bool,
@@ -104,3 +117,68 @@ pub struct Args {
     #[clap(long = "wipe-cache")]
     pub wipe_cache: bool
 }
+
+#[derive(Debug, Subcommand)]
+pub enum CacheCommand {
+    /// Evicts the least recently used cached assets until the cache fits within the given size budget (assets used by the last build are never evicted)
+    Gc {
+        /// The maximum size of the cache directory, e.g. "500MB" or "20GB"
+        #[clap(long = "budget", value_parser = parse_bytes)]
+        budget: u64
+    },
+    /// Lists all cached transcodes, images, archives and procedural covers by source file and format, with their sizes
+    List,
+    /// Checks the cache for missing or corrupted files, orphaned files and unreadable manifests, without modifying anything (exits with a non-zero code if problems are found)
+    Verify
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Inspects and maintains the cache (no build is performed)
+    #[clap(subcommand)]
+    Cache(CacheCommand),
+    /// Reads the catalog like a build does and reports the problems found, without decoding audio or touching the cache (no build is performed)
+    Check {
+        /// Prints the report as JSON (e.g. for use in a pre-commit hook)
+        #[clap(long = "json")]
+        json: bool
+    },
+    /// Prints a JSON description of all manifest options (supported keys per manifest type, their forms, value types and allowed values), e.g. for use in editor integrations (no build is performed)
+    ManifestSchema,
+    /// Serves a directory over http, so that other machines can use it as their shared cache (see --shared-cache) (no build is performed)
+    SharedCacheServer {
+        /// The directory in which the shared cache is stored (created if it does not exist yet)
+        #[clap(long = "dir")]
+        dir: PathBuf,
+        /// The ip the server listens on (default is 127.0.0.1)
+        #[clap(long = "ip")]
+        ip: Option<IpAddr>,
+        /// The port the server listens on (default is 8090)
+        #[clap(long = "port")]
+        port: Option<u16>,
+        /// A secret that other machines need to pass with --shared-cache-token in order to store transcodes on this server. Without it the server is read-only and only serves the transcodes already in the directory
+        #[clap(long = "token")]
+        token: Option<String>
+    },
+    /// Prints a report of the play and download counts collected by a stats server (no build is performed)
+    Stats {
+        /// The file in which the stats server stores the counts
+        #[clap(long = "stats-file")]
+        stats_file: PathBuf
+    },
+    /// Runs a small http server that receives play and download counts from your site (see the stats_endpoint option) and aggregates them into a local file (no build is performed)
+    StatsServer {
+        /// The ip the server listens on (default is 127.0.0.1, e.g. for running behind a reverse proxy)
+        #[clap(long = "ip")]
+        ip: Option<IpAddr>,
+        /// The stats_paths.txt file of the deployed site (written to the build directory when stats_endpoint is set), only counts for the paths listed in it are accepted. The file is read again whenever it changes
+        #[clap(long = "paths-file")]
+        paths_file: PathBuf,
+        /// The port the server listens on (default is 8091)
+        #[clap(long = "port")]
+        port: Option<u16>,
+        /// The file in which the counts are stored (created if it does not exist yet)
+        #[clap(long = "stats-file")]
+        stats_file: PathBuf
+    }
+}
@@ -7,6 +7,7 @@ use std::rc::Rc;
 use crate::{
     Catalog,
     DescribedImage,
+    Event,
     HtmlAndStripped,
     Link,
     Permalink,
@@ -18,6 +19,8 @@ use crate::{
 pub struct Artist {
     pub aliases: Vec<String>,
     pub copy_link: bool,
+    /// Concert/tour dates, sorted by date (earliest first)
+    pub events: Vec<Event>,
     /// This is only set when an external_page option is specified for an
     /// artist in a manifest. Its presence indicates that we don't generate
     /// an internal (featured) artist page, but instead link to the artist on
@@ -59,6 +62,7 @@ impl Artist {
         Artist {
             aliases: Vec::new(),
             copy_link: catalog.copy_link,
+            events: Vec::new(),
             external_page: None,
             featured: false,
             image: None,
@@ -80,6 +84,7 @@ impl Artist {
     pub fn new_manual(
         aliases: Vec<String>,
         copy_link: bool,
+        mut events: Vec<Event>,
         external_page: Option<String>,
         image: Option<DescribedImage>,
         links: Vec<Link>,
@@ -93,9 +98,12 @@ impl Artist {
     ) -> Artist {
         let permalink = permalink.unwrap_or_else(|| Permalink::generate(name));
 
+        events.sort_by(|a, b| a.date.cmp(&b.date));
+
         Artist {
             aliases,
             copy_link,
+            events,
             external_page,
             featured: false,
             image,
@@ -133,6 +141,7 @@ impl Artist {
         Artist {
             aliases,
             copy_link: false,
+            events: Vec::new(),
             external_page,
             featured: false,
             image: None,
@@ -1,6 +1,6 @@
 const browser = document.querySelector('#browser');
-const browseButtonFooter = document.querySelector('footer button.browse');
-const browseButtonHeader = document.querySelector('header button.browse');
+let browseButtonFooter = document.querySelector('footer button.browse');
+let browseButtonHeader = document.querySelector('header button.browse');
 
 const browseResults = browser.querySelector('#results');
 const closeButton = browser.querySelector('button');
@@ -227,6 +227,22 @@ browser.addEventListener('keydown', event => {
 browseButtonFooter.addEventListener('click', () => showBrowser(browseButtonFooter));
 browseButtonHeader.addEventListener('click', () => showBrowser(browseButtonHeader));
 
+// With soft navigation (see navigation.js) header and footer are replaced
+// along with the page content. If the browser is open at that point one of
+// its results was just followed, so we close it, then we wire up the browse
+// buttons that came with the new page.
+document.addEventListener('faircamp:afterswap', () => {
+    if (browser.classList.contains('active')) {
+        hideBrowser();
+    }
+
+    browseButtonFooter = document.querySelector('footer button.browse');
+    browseButtonHeader = document.querySelector('header button.browse');
+
+    browseButtonFooter.addEventListener('click', () => showBrowser(browseButtonFooter));
+    browseButtonHeader.addEventListener('click', () => showBrowser(browseButtonHeader));
+});
+
 closeButton.addEventListener('click', hideBrowser);
 
 searchField.addEventListener('input', () => {
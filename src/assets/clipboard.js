@@ -32,22 +32,30 @@ function copyToClipboard(button) {
         .catch(_err => copyFeedback(content, failedIcon, iconContainer));
 };
 
-if (navigator.clipboard) {
-    for (const button of document.querySelectorAll('[data-copy]')) {
-        if (button.dataset.dynamicUrl !== undefined) {
-            const thisPageUrl = window.location.href.split('#')[0]; // discard hash if present
-
-            if (button.dataset.dynamicUrl === '') {
-                button.dataset.content = thisPageUrl;
-            } else {
-                button.dataset.content = thisPageUrl + (thisPageUrl.endsWith('/') ? '' : '/') + button.dataset.dynamicUrl;
+function initCopyButtons() {
+    if (navigator.clipboard) {
+        for (const button of document.querySelectorAll('[data-copy]')) {
+            if (button.dataset.dynamicUrl !== undefined) {
+                const thisPageUrl = window.location.href.split('#')[0]; // discard hash if present
+
+                if (button.dataset.dynamicUrl === '') {
+                    button.dataset.content = thisPageUrl;
+                } else {
+                    button.dataset.content = thisPageUrl + (thisPageUrl.endsWith('/') ? '' : '/') + button.dataset.dynamicUrl;
+                }
             }
-        }
 
-        button.addEventListener('click', () => copyToClipboard(button));
-    }
-} else {
-    for (const button of document.querySelectorAll('[data-copy]')) {
-        button.remove();
+            button.addEventListener('click', () => copyToClipboard(button));
+        }
+    } else {
+        for (const button of document.querySelectorAll('[data-copy]')) {
+            button.remove();
+        }
     }
 }
+
+initCopyButtons();
+
+// With soft navigation (see navigation.js), pages visited after this script
+// was loaded bring their own copy buttons.
+document.addEventListener('faircamp:afterswap', initCopyButtons);
@@ -0,0 +1,332 @@
+// Soft navigation: When a link to another page of the site is followed, we
+// fetch that page in the background and swap its content into the current
+// document, instead of letting the browser load it from scratch. This keeps
+// the docked player around, so playback continues uninterrupted while the
+// visitor browses the site. Other scripts hook into this through the
+// 'faircamp:beforeswap' and 'faircamp:afterswap' events, both of which are
+// dispatched on the document.
+//
+// Additionally this script manages the listener's queue - tracks lined up
+// through the "Play next" and "Add to queue" buttons on track rows and
+// release tiles - which is persisted in localStorage and from there played
+// back by player.js once the currently playing track has ended.
+
+const QUEUE_STORAGE_KEY = 'faircampQueue';
+
+// Pages can only be fetched when the site is served over http(s), when it
+// is viewed straight from disk (file://) we stick to regular navigation.
+const softNavigationAvailable = location.protocol === 'http:' || location.protocol === 'https:';
+
+// The path of the page that is currently displayed, which allows us to tell
+// history changes between pages apart from those within a page (e.g. when
+// going back after having jumped to #more).
+let currentPathname = location.pathname;
+
+// Incremented with each navigation, so that a response arriving after the
+// visitor has already navigated elsewhere in the meantime can be discarded.
+let navigationCounter = 0;
+
+// Relative urls in the part of the document that outlives a page swap would
+// resolve against the wrong location after the swap, therefore we pin them
+// to the location they currently resolve to.
+function absolutizeUrls(root) {
+    for (const link of root.querySelectorAll('a[href]')) {
+        link.href = link.href;
+    }
+
+    for (const element of root.querySelectorAll('img[src], source[src]')) {
+        element.src = element.src;
+    }
+}
+
+// Puts the given items at the front ('next') or the end ('add') of the queue
+function enqueue(items, mode) {
+    const queue = readQueue();
+
+    if (mode === 'next') {
+        queue.unshift(...items);
+    } else {
+        queue.push(...items);
+    }
+
+    localStorage.setItem(QUEUE_STORAGE_KEY, JSON.stringify(queue));
+    document.dispatchEvent(new CustomEvent('faircamp:queuechange'));
+}
+
+// Returns true if the given url points to a page (as opposed to e.g. an
+// archive, an image or a playlist file). This covers both clean urls
+// (example.com/release/) and urls in sites built with --no-clean-urls
+// (example.com/release/index.html).
+function isPageUrl(url) {
+    return url.pathname.endsWith('/') || url.pathname.endsWith('.html');
+}
+
+async function navigate(url, pushHistory, restoreScrollY = 0) {
+    const navigation = ++navigationCounter;
+
+    let newDocument = null;
+
+    try {
+        const response = await fetch(url);
+
+        if (response.ok) {
+            const html = await response.text();
+            newDocument = new DOMParser().parseFromString(html, 'text/html');
+        }
+    } catch (_err) {
+        // Handled below by falling back to regular navigation
+    }
+
+    if (navigation !== navigationCounter) return;
+
+    // If the page can't be fetched or it is not a regular page of the site
+    // (e.g. an embed), we let the browser take over.
+    if (newDocument === null || newDocument.querySelector('.layout') === null) {
+        if (pushHistory) {
+            location.assign(url);
+        } else {
+            location.reload();
+        }
+        return;
+    }
+
+    // Stylesheets the new page requires are loaded before the swap, so the
+    // new content doesn't flash up unstyled.
+    const stylesheetUrls = Array.from(
+        newDocument.head.querySelectorAll('link[rel="stylesheet"]'),
+        link => new URL(link.getAttribute('href'), url).href
+    );
+    const currentStylesheetUrls = Array.from(
+        document.head.querySelectorAll('link[rel="stylesheet"]'),
+        link => link.href
+    );
+    const stylesheetsLoaded = stylesheetUrls
+        .filter(href => !currentStylesheetUrls.includes(href))
+        .map(href => new Promise(resolve => {
+            const link = document.createElement('link');
+            link.addEventListener('error', resolve);
+            link.addEventListener('load', resolve);
+            link.href = href;
+            link.rel = 'stylesheet';
+            document.head.append(link);
+        }));
+
+    await Promise.all(stylesheetsLoaded);
+
+    if (navigation !== navigationCounter) return;
+
+    document.dispatchEvent(new CustomEvent('faircamp:beforeswap'));
+
+    absolutizeUrls(document.querySelector('#browser'));
+
+    if (pushHistory) {
+        history.replaceState({ scrollY: window.scrollY }, '');
+        history.pushState(null, '', url);
+    }
+
+    currentPathname = location.pathname;
+
+    swap(newDocument, stylesheetUrls, url);
+
+    const target = url.hash.length > 1 ? document.getElementById(decodeURIComponent(url.hash.substring(1))) : null;
+
+    if (target) {
+        target.scrollIntoView({ behavior: 'instant' });
+    } else {
+        window.scrollTo({ behavior: 'instant', top: restoreScrollY });
+    }
+
+    // Move focus to the new content so that keyboard and screenreader
+    // users continue from the start of the page they navigated to.
+    const main = document.querySelector('main');
+    main.tabIndex = -1;
+    main.focus({ preventScroll: true });
+
+    document.dispatchEvent(new CustomEvent('faircamp:afterswap'));
+}
+
+function readQueue() {
+    try {
+        const queue = JSON.parse(localStorage.getItem(QUEUE_STORAGE_KEY));
+        return Array.isArray(queue) ? queue : [];
+    } catch (_err) {
+        return [];
+    }
+}
+
+// Fetches a release page and returns queue items for all of its tracks
+// (except locked ones).
+async function releaseQueueItems(releaseUrl) {
+    const response = await fetch(releaseUrl);
+
+    if (!response.ok) {
+        throw new Error(`${releaseUrl} could not be fetched (${response.status})`);
+    }
+
+    const html = await response.text();
+    const releaseDocument = new DOMParser().parseFromString(html, 'text/html');
+
+    return Array.from(
+        releaseDocument.querySelectorAll('.track:not(.locked)'),
+        container => trackQueueItem(container, releaseUrl)
+    );
+}
+
+// Replaces header, content and footer of the current page with those of
+// the new document, and brings the head (title, meta tags, stylesheets and
+// scripts) and the templates at the end of the body up to date.
+function swap(newDocument, stylesheetUrls, url) {
+    for (const element of Array.from(document.head.children)) {
+        if (element.matches('link[rel="stylesheet"]')) {
+            if (!stylesheetUrls.includes(element.href)) {
+                element.remove();
+            }
+        } else if (!element.matches('script')) {
+            element.remove();
+        }
+    }
+
+    for (const element of newDocument.head.children) {
+        if (!element.matches('link[rel="stylesheet"], script')) {
+            document.head.append(document.importNode(element, true));
+        }
+    }
+
+    const layout = document.importNode(newDocument.querySelector('.layout'), true);
+    document.querySelector('.layout').replaceWith(layout);
+
+    // Inline scripts that arrive with the new content don't run by
+    // themselves. We re-create them so they do, wrapping them in a block
+    // so their top-level declarations don't collide with those of pages
+    // visited before.
+    for (const script of layout.querySelectorAll('script:not([src])')) {
+        const runnable = document.createElement('script');
+        runnable.textContent = `{${script.textContent}}`;
+        script.replaceWith(runnable);
+    }
+
+    for (const template of newDocument.body.querySelectorAll(':scope > template[id]')) {
+        if (!document.getElementById(template.id)) {
+            document.body.append(document.importNode(template, true));
+        }
+    }
+
+    const newBrowser = newDocument.querySelector('#browser');
+    if (newBrowser) {
+        document.querySelector('#browser').dataset.rootPrefix = newBrowser.dataset.rootPrefix;
+    }
+
+    // Scripts that this page requires but that have not been loaded so
+    // far (e.g. player.js when coming from a page without tracks) are
+    // loaded now, after the content they operate on is in place.
+    const loadedScriptPaths = Array.from(document.scripts, script => script.src ? new URL(script.src).pathname : null);
+
+    for (const script of newDocument.head.querySelectorAll('script[src]')) {
+        const src = new URL(script.getAttribute('src'), url);
+
+        if (!loadedScriptPaths.includes(src.pathname)) {
+            const loader = document.createElement('script');
+            loader.src = src.href;
+            document.head.append(loader);
+        }
+    }
+}
+
+// Extracts everything player.js needs to play back a track from its row
+// (`.track` container) on a release or playlist page. Relative urls are
+// resolved against baseUrl, the url of the page the row stems from.
+function trackQueueItem(container, baseUrl) {
+    const artists = container.querySelector('.artists');
+    const title = container.querySelector('.title');
+
+    // The artwork (see media_session_attributes in render.rs) is in srcset
+    // syntax, each of its comma-separated candidates starts with a url.
+    const artwork = (container.dataset.artwork ?? '')
+        .split(',')
+        .filter(candidate => candidate.trim() !== '')
+        .map(candidate => {
+            const [src, descriptor] = candidate.trim().split(' ');
+            return `${new URL(src, baseUrl).href} ${descriptor}`;
+        })
+        .join(',');
+
+    return {
+        album: container.dataset.album ?? null,
+        artist: container.dataset.artist ?? null,
+        artists: artists ? artists.textContent.trim().replace(/\s+/g, ' ') : null,
+        artwork,
+        duration: parseFloat(container.dataset.duration),
+        href: new URL(title.getAttribute('href'), baseUrl).href,
+        sources: Array.from(
+            container.querySelectorAll('audio source'),
+            source => ({
+                src: new URL(source.getAttribute('src'), baseUrl).href,
+                type: source.type
+            })
+        ),
+        start: parseFloat(container.dataset.segmentStart ?? '0'),
+        title: title.textContent.trim()
+    };
+}
+
+document.addEventListener('click', async event => {
+    const button = event.target.closest('button[data-queue]');
+
+    if (!button) return;
+
+    const trackContainer = button.closest('.track');
+
+    let items;
+    if (trackContainer) {
+        items = [trackQueueItem(trackContainer, location.href)];
+    } else {
+        const releaseLink = button.closest('.release').querySelector(':scope > a[href]');
+
+        try {
+            items = await releaseQueueItems(new URL(releaseLink.href));
+        } catch (_err) {
+            return;
+        }
+    }
+
+    enqueue(items, button.dataset.queue);
+
+    // Brief visual confirmation that the tracks were queued
+    button.classList.add('queued');
+    setTimeout(() => button.classList.remove('queued'), 1500);
+});
+
+if (softNavigationAvailable) {
+    // We restore scroll positions ourselves, as the browser would attempt
+    // to do so before we have swapped in the content of the page.
+    history.scrollRestoration = 'manual';
+
+    document.addEventListener('click', event => {
+        if (event.button !== 0 ||
+            event.defaultPrevented ||
+            event.altKey ||
+            event.ctrlKey ||
+            event.metaKey ||
+            event.shiftKey) return;
+
+        const link = event.target.closest('a[href]');
+
+        if (!link || link.target || link.hasAttribute('download')) return;
+
+        const url = new URL(link.href);
+
+        if (url.origin !== location.origin || !isPageUrl(url)) return;
+
+        // Jumps within the same page (e.g. to #more) are left to the browser
+        if (url.pathname === location.pathname && url.hash) return;
+
+        event.preventDefault();
+        navigate(url, true);
+    });
+
+    window.addEventListener('popstate', event => {
+        if (location.pathname === currentPathname) return;
+
+        navigate(new URL(location.href), false, event.state?.scrollY ?? 0);
+    });
+}
@@ -0,0 +1,91 @@
+// Registers the service worker (which lets the site work offline) and lets
+// listeners save releases for offline listening. A saved release is its page
+// plus the images and the streaming audio the page references, all stored in
+// a dedicated cache that the service worker serves from when there is no
+// network connection.
+
+const OFFLINE_CACHE_NAME = 'faircamp-offline';
+
+// The service worker lies next to this script, at the root of the site,
+// which makes all of the site its scope.
+const serviceWorkerUrl = new URL('service_worker.js', document.currentScript.src);
+
+// Returns the urls of everything that makes up the release page in
+// the current document. For the audio of each track we pick the first
+// source the browser can play, which is the one the player would choose.
+function offlineUrls() {
+    const urls = [offlinePageUrl()];
+
+    for (const audio of document.querySelectorAll('.track:not(.locked) audio')) {
+        const source = Array.from(audio.querySelectorAll('source'))
+            .find(source => audio.canPlayType(source.type) !== '');
+
+        if (source) {
+            urls.push(source.src);
+        }
+    }
+
+    for (const img of document.querySelectorAll('main img')) {
+        if (img.currentSrc || img.src) {
+            urls.push(img.currentSrc || img.src);
+        }
+    }
+
+    return Array.from(new Set(urls));
+}
+
+function offlinePageUrl() {
+    const url = new URL(location.href);
+    url.hash = '';
+    return url.href;
+}
+
+// Brings the save button (if there is one on the page) in line with
+// whether the release is currently saved.
+async function updateOfflineButton() {
+    const button = document.querySelector('button.save_offline');
+
+    if (!button) return;
+
+    const cache = await caches.open(OFFLINE_CACHE_NAME);
+    const saved = await cache.match(offlinePageUrl()) !== undefined;
+
+    button.dataset.saved = saved;
+    button.querySelector('.label').textContent = saved ? button.dataset.removeLabel : button.dataset.saveLabel;
+    button.hidden = false;
+}
+
+if ('serviceWorker' in navigator && 'caches' in window && window.isSecureContext) {
+    navigator.serviceWorker.register(serviceWorkerUrl);
+
+    updateOfflineButton();
+
+    document.addEventListener('click', async event => {
+        const button = event.target.closest('button.save_offline');
+
+        if (!button || button.disabled) return;
+
+        button.disabled = true;
+        button.setAttribute('aria-busy', 'true');
+
+        const cache = await caches.open(OFFLINE_CACHE_NAME);
+
+        try {
+            if (button.dataset.saved === 'true') {
+                await Promise.all(offlineUrls().map(url => cache.delete(url)));
+            } else {
+                await cache.addAll(offlineUrls());
+            }
+        } catch (_err) {
+            // Whatever could not be stored (e.g. because the storage quota
+            // is exhausted) is simply not available offline.
+        }
+
+        button.disabled = false;
+        button.removeAttribute('aria-busy');
+
+        await updateOfflineButton();
+    });
+
+    document.addEventListener('faircamp:afterswap', updateOfflineButton);
+}
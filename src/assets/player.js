@@ -6,13 +6,20 @@ const WAVEFORM_HEIGHT = TRACK_HEIGHT_EM - WAVEFORM_PADDING_EM * 2.0;
 const WAVEFORM_WIDTH_PADDING_REM = 5;
 const WAVEFORM_WIDTH_TOLERANCE_REM = 2.5;
 
+// With listening statistics enabled (see the stats_endpoint catalog option)
+// a play is only counted once a track has been listened to for this long,
+// so that briefly skimming through tracks doesn't count.
+const STATS_PLAY_THRESHOLD_MS = 10000;
+
 const loadingIcon = document.querySelector('#loading_icon').content;
 const pauseIcon = document.querySelector('#pause_icon').content;
 const playIcon = document.querySelector('#play_icon').content;
 
-const listenButton = document.querySelector('button.listen');
-const listenButtonIcon = document.querySelector('button.listen .icon');
-const listenButtonLabel = document.querySelector('button.listen .label');
+// With soft navigation (see navigation.js) these are looked up anew on
+// every page, and on some pages there is no listen button at all.
+let listenButton;
+let listenButtonIcon;
+let listenButtonLabel;
 
 // There is always an active track (so this is guaranteed to be available
 // after the respective intialization routines have completed). "active"
@@ -107,6 +114,8 @@ function announcePlayhead(track) {
     if (track.waveform) {
         track.waveform.input.setAttribute('aria-valuetext', valueText);
     }
+
+    updateMediaPositionState(track);
 }
 
 // Decodes a sequence of peaks that is encoded using a custom base64 alphabet
@@ -132,6 +141,37 @@ function decode(string) {
     return peaks;
 }
 
+// Removes the first item from the listener's queue (see navigation.js) and
+// returns a track to play it with, or null if the queue is empty.
+function dequeue() {
+    const queue = queuedItems();
+    const item = queue.shift();
+
+    if (item === undefined) return null;
+
+    localStorage.setItem('faircampQueue', JSON.stringify(queue));
+
+    return queuedTrack(item);
+}
+
+// Called when a track has played to its end
+function finish(track) {
+    stopPlayCount(track);
+    delete track.playCounted;
+    delete track.playCountElapsed;
+
+    if (!playFollowing(track)) {
+        reset(track);
+        // Hide docked player
+        document.body.classList.remove('player_active');
+        dockedPlayer.status.setAttribute('aria-label', PLAYER_JS_T.playerClosed);
+
+        if (tracks.length > 0) {
+            setActive(tracks[0]);
+        }
+    }
+}
+
 function formatTime(seconds) {
     if (seconds < 60) {
         return `0:${Math.floor(seconds).toString().padStart(2, '0')}`;
@@ -158,6 +198,34 @@ function formatTimeWrittenOut(seconds) {
     }
 }
 
+// Tracks split from a continuous recording (along a CUE sheet) share a
+// single audio element that plays the entire recording. When playback
+// crosses from one such track into the next, we hand over the playing audio
+// to the next track (instead of resetting the one and starting the other),
+// which keeps playback gapless.
+function handOver(track) {
+    const { nextTrack } = track;
+
+    stopPlayCount(track);
+    delete track.solicitedPlayback;
+    track.container.classList.remove('active', 'playing');
+    track.playbackButtonIcon.replaceChildren(playIcon.cloneNode(true));
+    updatePlayhead(track, true);
+    track.open = false;
+
+    setActive(nextTrack);
+    nextTrack.solicitedPlayback = true;
+    open(nextTrack);
+    nextTrack.container.classList.add('playing');
+    nextTrack.playbackButtonIcon.replaceChildren(pauseIcon.cloneNode(true));
+
+    clearInterval(globalUpdatePlayHeadInterval);
+    globalUpdatePlayHeadInterval = setInterval(() => updatePlayhead(nextTrack), 1000 / 24);
+    updatePlayhead(nextTrack);
+    announcePlayhead(nextTrack);
+    startPlayCount(nextTrack);
+}
+
 // In most cases, hover capability remains constant during runtime, however
 // during testing in desktop browsers with device simulation and possibly in
 // some edge cases in real usage, hover capability might change at runtime,
@@ -166,6 +234,123 @@ function hoverAvailable() {
     return window.matchMedia('(hover: hover)').matches;
 }
 
+// Set active track (and optionally set seekTo and/or open player)
+function initActiveTrack() {
+    const params = parseHashParams();
+    if (params) {
+        setActive(params.track);
+
+        if (params.time !== undefined) {
+            params.track.seekTo = params.time;
+        }
+
+        open(params.track);
+
+        // Announce to screenreaders that the docked player is present
+        dockedPlayer.status.setAttribute('aria-label', PLAYER_JS_T.playerOpenWithXxx(params.track.title.textContent));
+    } else if (tracks.length > 0) {
+        setActive(tracks[0]);
+    }
+}
+
+function initListenButton() {
+    listenButton = document.querySelector('button.listen');
+
+    if (!listenButton) return;
+
+    listenButtonIcon = listenButton.querySelector('.icon');
+    listenButtonLabel = listenButton.querySelector('.label');
+
+    listenButton.addEventListener('click', () => {
+        // The active track might be from a page visited before (see
+        // navigation.js), in which case the button starts this page's tracks.
+        requestPlaybackChange(tracks.includes(activeTrack) ? activeTrack : tracks[0]);
+    });
+}
+
+// Keeps the interface in sync with the playback state of a track's audio
+function observeAudio(track) {
+    const { audio } = track;
+
+    audio.addEventListener('ended', event => {
+        if (track.sharedAudio && track !== activeTrack) { return; }
+
+        finish(track);
+    });
+
+    audio.addEventListener('pause', event => {
+        stopPlayCount(track);
+
+        if (!track.solicitedPlayback) { return; }
+
+        delete track.solicitedPlayback;
+        track.audio.muted = true;
+
+        clearInterval(globalUpdatePlayHeadInterval);
+
+        if (navigator.mediaSession) {
+            navigator.mediaSession.playbackState = 'paused';
+        }
+
+        track.container.classList.remove('playing');
+        dockedPlayer.playbackButton.replaceChildren(playIcon.cloneNode(true));
+        updateListenButton(track, playIcon, PLAYER_JS_T.listen);
+        track.playbackButtonIcon.replaceChildren(playIcon.cloneNode(true));
+
+        if (track.onPause) {
+            track.onPause();
+            delete track.onPause;
+        } else {
+            updatePlayhead(track);
+            announcePlayhead(track);
+        }
+    });
+
+    audio.addEventListener('play', event => {
+        if (track.sharedAudio && track !== activeTrack) { return; }
+
+        if (!track.solicitedPlayback) {
+            // Unsolicited playback triggered by Apple/Safari (see comment
+            // elsewhere regarding track.solicitedPlayback), we cancel it
+            // immediately.
+            audio.pause();
+            return;
+        }
+
+        track.container.classList.add('playing');
+        dockedPlayer.playbackButton.replaceChildren(pauseIcon.cloneNode(true));
+        updateListenButton(track, pauseIcon, PLAYER_JS_T.pause);
+        track.playbackButtonIcon.replaceChildren(pauseIcon.cloneNode(true));
+
+        if (navigator.mediaSession) {
+            navigator.mediaSession.playbackState = 'playing';
+        }
+
+        globalUpdatePlayHeadInterval = setInterval(() => updatePlayhead(track), 1000 / 24);
+        updatePlayhead(track);
+        announcePlayhead(track);
+        startPlayCount(track);
+    });
+
+    audio.addEventListener('playing', event => {
+        if (!track.solicitedPlayback) { return; }
+
+        dockedPlayer.playbackButton.replaceChildren(pauseIcon.cloneNode(true));
+        updateListenButton(track, pauseIcon, PLAYER_JS_T.pause);
+        track.playbackButtonIcon.replaceChildren(pauseIcon.cloneNode(true));
+    });
+
+    audio.addEventListener('waiting', event => {
+        if (!track.solicitedPlayback) { return; }
+
+        // TODO: Eventually we could augment various screenreader labels here to
+        //       indicate the loading state too
+        dockedPlayer.playbackButton.replaceChildren(loadingIcon.cloneNode(true));
+        updateListenButton(track, loadingIcon, PLAYER_JS_T.pause);
+        track.playbackButtonIcon.replaceChildren(loadingIcon.cloneNode(true));
+    });
+}
+
 // Open the docked player and update its various subelements to display the
 // given track. If track.seekTo is set a seek is indicated by advancing both
 // the track's own progress indicator and the docked player progress bar to
@@ -175,10 +360,12 @@ function open(track) {
     // Unhide docked player
     document.body.classList.add('player_active');
 
-    dockedPlayer.currentTime.textContent = formatTime(track.seekTo ?? track.audio.currentTime);
+    dockedPlayer.currentTime.textContent = formatTime(track.seekTo ?? position(track));
     dockedPlayer.totalTime.textContent = formatTime(track.duration);
     dockedPlayer.timelineInput.max = track.container.dataset.duration;
 
+    updateMediaMetadata(track);
+
     if (track.artists) {
         dockedPlayer.titleWrapper.replaceChildren(track.title.cloneNode(true), track.artists.cloneNode(true));
     } else {
@@ -187,7 +374,7 @@ function open(track) {
 
     // Not available on a track player
     if (dockedPlayer.number) {
-        dockedPlayer.nextTrackButton.toggleAttribute('disabled', !track.nextTrack);
+        dockedPlayer.nextTrackButton.toggleAttribute('disabled', !track.nextTrack && queueLength() === 0);
         dockedPlayer.number.textContent = track.number.textContent;
     }
 
@@ -208,6 +395,26 @@ function open(track) {
     track.open = true;
 }
 
+// Turns the artwork of a track (see media_session_attributes in render.rs),
+// which is given in srcset syntax, into images for the Media Session API.
+// Covers are square, so the width descriptor gives us both dimensions.
+function parseArtwork(srcset) {
+    if (!srcset) return [];
+
+    return srcset
+        .split(',')
+        .filter(candidate => candidate.trim() !== '')
+        .map(candidate => {
+            const [src, descriptor] = candidate.trim().split(' ');
+            const edgeSize = parseInt(descriptor);
+
+            return {
+                sizes: `${edgeSize}x${edgeSize}`,
+                src: new URL(src, location.href).href
+            };
+        });
+}
+
 // Parses (and validates) track/time parameters from the current url
 // (e.g. https://example.com/#track=3&time=4m12s) and returns them as an
 // object (e.g. { time: 252, track: [reference to track] }). Track can be
@@ -287,8 +494,7 @@ function play(track) {
     // if there doesn't end up to be any loading required.
     track.playbackButtonIcon.replaceChildren(loadingIcon.cloneNode(true));
     dockedPlayer.playbackButton.replaceChildren(loadingIcon.cloneNode(true));
-    listenButtonIcon.replaceChildren(loadingIcon.cloneNode(true));
-    listenButtonLabel.textContent = PLAYER_JS_T.pause;
+    updateListenButton(track, loadingIcon, PLAYER_JS_T.pause);
 
     const playCallback = () => {
         // On apple devices and browsers (e.g. Safari in macOS 15.1) there is
@@ -310,6 +516,15 @@ function play(track) {
         track.audio.play();
     }
 
+    // A shared audio element (see handOver) might be positioned within
+    // another track, in which case we first seek to the start of this one.
+    if (track.seekTo === undefined && track.sharedAudio) {
+        const time = position(track);
+        if (time < 0 || time >= track.duration) {
+            track.seekTo = 0;
+        }
+    }
+
     if (track.seekTo === undefined) {
         playCallback();
     } else {
@@ -317,6 +532,117 @@ function play(track) {
     }
 }
 
+// Starts playback of whatever follows the given track: Tracks that the
+// listener queued up come first, after them playback continues with the
+// track that followed in the list the given track was played from. Returns
+// false if nothing follows.
+function playFollowing(track) {
+    const queuedTrack = dequeue();
+
+    if (queuedTrack) {
+        queuedTrack.nextTrack = track.nextTrack;
+        requestPlaybackChange(queuedTrack);
+        return true;
+    }
+
+    if (track.nextTrack) {
+        requestPlaybackChange(track.nextTrack);
+        return true;
+    }
+
+    return false;
+}
+
+// Returns to the start of the given track, or, if we're already close to its
+// start, plays the track that precedes it on the page (if there is one).
+function playPrevious(track) {
+    const index = tracks.indexOf(track);
+
+    if (position(track) > 3 || index < 1) {
+        requestSeek(track, 0);
+    } else {
+        requestPlaybackChange(tracks[index - 1]);
+    }
+}
+
+// The playback position within the track, which (for tracks that share an
+// audio element, see handOver) is offset from the audio's own position.
+function position(track) {
+    return track.audio.currentTime - track.start;
+}
+
+// The items in the listener's queue, which navigation.js persists in
+// localStorage (see trackQueueItem there for their structure).
+function queuedItems() {
+    try {
+        const queue = JSON.parse(localStorage.getItem('faircampQueue'));
+        return Array.isArray(queue) ? queue : [];
+    } catch (_err) {
+        return [];
+    }
+}
+
+// Builds a track for an item from the listener's queue. Its elements are
+// never attached to the page, they only exist for the docked player to
+// display and for the playback routines to operate on.
+function queuedTrack(item) {
+    const audio = document.createElement('audio');
+    audio.muted = true;
+    audio.preload = 'none';
+
+    for (const { src, type } of item.sources) {
+        const source = document.createElement('source');
+        source.src = src;
+        source.type = type;
+        audio.append(source);
+    }
+
+    let artists = null;
+    if (item.artists) {
+        artists = document.createElement('div');
+        artists.classList.add('artists');
+        artists.textContent = item.artists;
+    }
+
+    const container = document.createElement('div');
+    container.dataset.duration = item.duration;
+
+    if (item.album) { container.dataset.album = item.album; }
+    if (item.artist) { container.dataset.artist = item.artist; }
+
+    const title = document.createElement('a');
+    title.classList.add('title');
+    title.href = item.href;
+    title.textContent = item.title;
+
+    // Tracks split from a continuous recording (see handOver) start
+    // somewhere within the audio, which we treat just like shared audio.
+    const start = item.start ?? 0;
+
+    const track = {
+        artists,
+        artwork: parseArtwork(item.artwork),
+        audio,
+        container,
+        duration: item.duration,
+        number: document.createElement('span'),
+        playbackButton: document.createElement('button'),
+        playbackButtonIcon: document.createElement('span'),
+        queued: true,
+        sharedAudio: start > 0,
+        start,
+        title
+    };
+
+    observeAudio(track);
+
+    return track;
+}
+
+function queueLength() {
+    return queuedItems().length;
+}
+
 // One of the following:
 // - Request to play the active track
 // - Request to cancel seeking/loading the active track
@@ -354,7 +680,7 @@ function requestSeek(track, seekTo) {
             track.seekTo = seekTo;
             play(track);
         } else /* track is playing */ {
-            track.audio.currentTime = seekTo;
+            setPosition(track, seekTo);
             updatePlayhead(track);
             announcePlayhead(track);
         }
@@ -379,7 +705,7 @@ function reset(track, onComplete = null) {
 
         if (track.open) {
             // Reset "playback heads" back to the beginning
-            track.audio.currentTime = 0;
+            setPosition(track, 0);
             updatePlayhead(track, true);
             announcePlayhead(track);
             track.open = false;
@@ -408,6 +734,28 @@ function reset(track, onComplete = null) {
     }
 }
 
+// Returns numSamples values sampled at regular intervals from values,
+// linearly interpolating between neighboring values.
+function sampleWaveform(values, numSamples) {
+    const sampled = [values[0]];
+
+    for (let sample = 1; sample < numSamples; sample += 1) {
+        const factor = sample / (numSamples - 1);
+        const floatIndex = factor * (values.length - 1);
+        const previousIndex = Math.floor(floatIndex);
+        const nextIndex = Math.ceil(floatIndex);
+
+        if (previousIndex === nextIndex) {
+            sampled.push(values[previousIndex]);
+        } else {
+            const interValueBias = floatIndex - previousIndex;
+            sampled.push(values[previousIndex] * (1 - interValueBias) + values[nextIndex] * interValueBias);
+        }
+    }
+
+    return sampled;
+}
+
 function seek(track, onComplete = null) {
     const seeking = { onComplete };
 
@@ -416,10 +764,11 @@ function seek(track, onComplete = null) {
     function tryFinishSeeking() {
         let closestAvailableSeek = 0;
         const { seekable } = track.audio;
+        const seekToAbsolute = track.start + track.seekTo;
         for (let index = 0; index < seekable.length; index++) {
-            if (seekable.start(index) <= track.seekTo) {
-                if (seekable.end(index) >= track.seekTo) {
-                    track.audio.currentTime = track.seekTo;
+            if (seekable.start(index) <= seekToAbsolute) {
+                if (seekable.end(index) >= seekToAbsolute) {
+                    setPosition(track, track.seekTo);
 
                     const { onComplete } = track.seeking;
 
@@ -456,8 +805,7 @@ function seek(track, onComplete = null) {
         dockedPlayer.playbackButton.replaceChildren(playIcon.cloneNode(true));
         track.container.classList.remove('active');
         track.playbackButtonIcon.replaceChildren(playIcon.cloneNode(true));
-        listenButtonIcon.replaceChildren(playIcon.cloneNode(true));
-        listenButtonLabel.textContent = PLAYER_JS_T.listen;
+        updateListenButton(track, playIcon, PLAYER_JS_T.listen);
     };
 
     // We expose `cancel` and `onComplete` on the seeking object (and `seekTo`
@@ -476,6 +824,10 @@ function setActive(track) {
     track.container.classList.add('active');
 }
 
+function setPosition(track, time) {
+    track.audio.currentTime = track.start + time;
+}
+
 function setSpeed(track) {
     // Our internal speed representation is in percent so we translate to a
     // multiplication factor here
@@ -491,6 +843,39 @@ function setVolume(track) {
     }
 }
 
+// Maps a spectral brightness value (0-1) to a color: Dark sounds are warm
+// (red), bright sounds are cool (blue). Mirrors spectral_rgb in waveform.rs.
+function spectralColor(brightness) {
+    return `hsl(${(15 + brightness * 240).toFixed(0)}, 70%, 55%)`;
+}
+
+// Times how long a track is listened to (across pauses) and reports a play
+// to the stats endpoint once STATS_PLAY_THRESHOLD_MS is reached. Until the
+// track has played to its end it is only ever counted once.
+function startPlayCount(track) {
+    const endpoint = document.querySelector('meta[name="faircamp-stats-endpoint"]')?.content;
+
+    if (!endpoint || track.playCounted || track.playCountTimeout) return;
+
+    // On track pages the title is not a link, the page itself is the track's.
+    const path = new URL(track.title.href ?? location.href).pathname;
+
+    track.playCountStarted = Date.now();
+    track.playCountTimeout = setTimeout(() => {
+        delete track.playCountTimeout;
+        track.playCounted = true;
+        navigator.sendBeacon(new URL('play', endpoint), path);
+    }, STATS_PLAY_THRESHOLD_MS - (track.playCountElapsed ?? 0));
+}
+
+function stopPlayCount(track) {
+    if (!track.playCountTimeout) return;
+
+    clearTimeout(track.playCountTimeout);
+    delete track.playCountTimeout;
+    track.playCountElapsed = (track.playCountElapsed ?? 0) + Date.now() - track.playCountStarted;
+}
+
 function toggleMute() {
     if (volume.level > 0) {
         volume.restoreLevel = volume.level;
@@ -504,18 +889,71 @@ function toggleMute() {
     updateVolume();
 }
 
+// The listen button reflects the state of the active track, as long as that
+// track is on the current page (see navigation.js regarding tracks from
+// pages visited before).
+function updateListenButton(track, icon, label) {
+    if (listenButton && tracks.includes(track)) {
+        listenButtonIcon.replaceChildren(icon.cloneNode(true));
+        listenButtonLabel.textContent = label;
+    }
+}
+
+// Shows what's playing in the operating system's media controls (e.g. on
+// the lock screen of a phone or in a desktop's media overlay).
+function updateMediaMetadata(track) {
+    if (!navigator.mediaSession) return;
+
+    const { album, artist } = track.container.dataset;
+
+    navigator.mediaSession.metadata = new MediaMetadata({
+        album: album ?? '',
+        artist: artist ?? track.artists?.textContent.trim() ?? '',
+        artwork: track.artwork ?? [],
+        title: track.title.textContent.trim()
+    });
+}
+
+function updateMediaPositionState(track) {
+    if (!navigator.mediaSession?.setPositionState) return;
+
+    navigator.mediaSession.setPositionState({
+        duration: track.duration,
+        playbackRate: speed / 100,
+        position: Math.min(Math.max(0, position(track)), track.duration)
+    });
+}
+
 function updatePlayhead(track, reset = false) {
-    const { audio } = track;
-    const factor = reset ? 0 : audio.currentTime / track.duration;
+    const time = reset ? 0 : position(track);
+    const factor = time / track.duration;
 
     dockedPlayer.progress.style.setProperty('width', `${factor * 100}%`);
-    dockedPlayer.currentTime.textContent = formatTime(audio.currentTime);
-    dockedPlayer.timelineInput.value = audio.currentTime;
+    dockedPlayer.currentTime.textContent = formatTime(time);
+    dockedPlayer.timelineInput.value = time;
 
     if (track.waveform) {
         track.waveform.svg.querySelector('linearGradient.playback stop:nth-child(1)').setAttribute('offset', factor);
         track.waveform.svg.querySelector('linearGradient.playback stop:nth-child(2)').setAttribute('offset', factor + 0.0001);
-        track.waveform.input.value = audio.currentTime;
+        track.waveform.input.value = time;
+    }
+
+    if (!reset &&
+        !track.audio.paused &&
+        track.sharedAudio &&
+        time >= track.duration) {
+        if (track.nextTrack &&
+            track.nextTrack.audio === track.audio &&
+            !track.queued &&
+            queueLength() === 0) {
+            handOver(track);
+        } else {
+            // Either the listener has queued up tracks, which take precedence
+            // over the next track of the recording, or this is a queued track
+            // itself, which must not play on into the rest of the recording.
+            clearInterval(globalUpdatePlayHeadInterval);
+            finish(track);
+        }
     }
 }
 
@@ -615,11 +1053,11 @@ dockedPlayer.container.addEventListener('keydown', event => {
 
     if (event.key === 'ArrowLeft') {
         event.preventDefault();
-        const seekTo = Math.max(0, activeTrack.audio.currentTime - 5);
+        const seekTo = Math.max(0, position(activeTrack) - 5);
         requestSeek(activeTrack, seekTo);
     } else if (event.key === 'ArrowRight') {
         event.preventDefault();
-        const seekTo = Math.min(activeTrack.duration - 1, activeTrack.audio.currentTime + 5);
+        const seekTo = Math.min(activeTrack.duration - 1, position(activeTrack) + 5);
         requestSeek(activeTrack, seekTo);
     }
 });
@@ -631,9 +1069,7 @@ dockedPlayer.playbackButton.addEventListener('click', () => {
 // Not available on a track player
 if (dockedPlayer.nextTrackButton) {
     dockedPlayer.nextTrackButton.addEventListener('click', () => {
-        if (activeTrack.nextTrack) {
-            requestPlaybackChange(activeTrack.nextTrack);
-        }
+        playFollowing(activeTrack);
     });
 }
 
@@ -897,261 +1333,262 @@ dockedPlayer.volume.sliderInput.addEventListener('keydown', event => {
 // hence we disable the default behavior and let the event bubble up to our own handler
 dockedPlayer.volume.sliderInput.addEventListener('wheel', event => event.preventDefault());
 
-listenButton.addEventListener('click', () => {
-    requestPlaybackChange(activeTrack);
-});
-
 if (navigator.mediaSession) {
-    navigator.mediaSession.setActionHandler('play', () => {
-        requestPlaybackChange(activeTrack);
+    navigator.mediaSession.setActionHandler('nexttrack', () => {
+        playFollowing(activeTrack);
     });
 
     navigator.mediaSession.setActionHandler('pause', () => {
-        requestPlaybackChange(activeTrack);
-    });
-}
-
-const resizeObserver = new ResizeObserver(entries => {
-    const minWidth = entries.reduce(
-        (minWidth, entry) => Math.min(entry.contentRect.width, minWidth),
-        Infinity
-    );
-
-    waveforms(minWidth);
-});
-
-let previousTrack = null;
-let trackIndex = 0;
-for (const container of document.querySelectorAll('.track')) {
-    const artists = container.querySelector('.artists');
-    const audio = container.querySelector('audio');
-    const number = container.querySelector('.number');
-    const playbackButton = container.querySelector('.track_playback');
-    const playbackButtonIcon = container.querySelector('.track_playback .icon');
-    const title = container.querySelector('.title');
-
-    const duration = parseFloat(container.dataset.duration);
-
-    const track = {
-        artists,
-        audio,
-        container,
-        duration,
-        number,
-        playbackButton,
-        playbackButtonIcon,
-        title
-    };
-
-    // We only unmute tracks right before they play, muting them again at any
-    // pause event. We do this because a bug in browsers on apple systems can
-    // trigger sporadic, unsolicited playback of tracks in certain conditions
-    // (see comment elsewhere on track.solicitedPlayback), and although we
-    // cancel this unsolicited playback right away, it would be sometimes
-    // audible for a brief moment (if we didn't keep tracks muted).
-    audio.muted = true;
-
-    // Playback buttons start off with tabindex="-1" because if the visitor
-    // has JavaScript disabled the element should not be interacted with at
-    // all. When JavaScript is available we revert to making the button
-    // reachable by keyboard.
-    playbackButton.tabIndex = 0;
-
-    if (previousTrack !== null) {
-        previousTrack.nextTrack = track;
-    }
-
-    previousTrack = track;
-
-    audio.addEventListener('ended', event => {
-        if (track.nextTrack) {
-            requestPlaybackChange(track.nextTrack);
-        } else {
-            reset(track);
-            // Hide docked player
-            document.body.classList.remove('player_active');
-            dockedPlayer.status.setAttribute('aria-label', PLAYER_JS_T.playerClosed);
-            setActive(tracks[0]);
+        if (!activeTrack.audio.paused || activeTrack.seeking) {
+            requestPlaybackChange(activeTrack);
         }
     });
 
-    audio.addEventListener('pause', event => {
-        if (!track.solicitedPlayback) { return; }
-
-        delete track.solicitedPlayback;
-        track.audio.muted = true;
-
-        clearInterval(globalUpdatePlayHeadInterval);
-
-        container.classList.remove('playing');
-        dockedPlayer.playbackButton.replaceChildren(playIcon.cloneNode(true));
-        listenButtonIcon.replaceChildren(playIcon.cloneNode(true));
-        listenButtonLabel.textContent = PLAYER_JS_T.listen;
-        track.playbackButtonIcon.replaceChildren(playIcon.cloneNode(true));
-
-        if (track.onPause) {
-            track.onPause();
-            delete track.onPause;
-        } else {
-            updatePlayhead(track);
-            announcePlayhead(track);
+    navigator.mediaSession.setActionHandler('play', () => {
+        if (activeTrack.audio.paused && !activeTrack.seeking) {
+            requestPlaybackChange(activeTrack);
         }
     });
 
-    audio.addEventListener('play', event => {
-        if (!track.solicitedPlayback) {
-            // Unsolicited playback triggered by Apple/Safari (see comment
-            // elsewhere regarding track.solicitedPlayback), we cancel it
-            // immediately.
-            audio.pause();
-            return;
-        }
-
-        container.classList.add('playing');
-        dockedPlayer.playbackButton.replaceChildren(pauseIcon.cloneNode(true));
-        listenButtonIcon.replaceChildren(pauseIcon.cloneNode(true));
-        listenButtonLabel.textContent = PLAYER_JS_T.pause;
-        track.playbackButtonIcon.replaceChildren(pauseIcon.cloneNode(true));
-
-        globalUpdatePlayHeadInterval = setInterval(() => updatePlayhead(track), 1000 / 24);
-        updatePlayhead(track);
-        announcePlayhead(track);
+    navigator.mediaSession.setActionHandler('previoustrack', () => {
+        playPrevious(activeTrack);
     });
 
-    audio.addEventListener('playing', event => {
-        if (!track.solicitedPlayback) { return; }
-
-        dockedPlayer.playbackButton.replaceChildren(pauseIcon.cloneNode(true));
-        listenButtonIcon.replaceChildren(pauseIcon.cloneNode(true));
-        listenButtonLabel.textContent = PLAYER_JS_T.pause;
-        track.playbackButtonIcon.replaceChildren(pauseIcon.cloneNode(true));
+    navigator.mediaSession.setActionHandler('seekbackward', details => {
+        const seekTo = Math.max(0, position(activeTrack) - (details.seekOffset ?? 10));
+        requestSeek(activeTrack, seekTo);
     });
 
-    audio.addEventListener('waiting', event => {
-        if (!track.solicitedPlayback) { return; }
-
-        // TODO: Eventually we could augment various screenreader labels here to
-        //       indicate the loading state too
-        dockedPlayer.playbackButton.replaceChildren(loadingIcon.cloneNode(true));
-        listenButtonIcon.replaceChildren(loadingIcon.cloneNode(true));
-        listenButtonLabel.textContent = PLAYER_JS_T.pause;
-        track.playbackButtonIcon.replaceChildren(loadingIcon.cloneNode(true));
+    navigator.mediaSession.setActionHandler('seekforward', details => {
+        const seekTo = Math.min(activeTrack.duration - 1, position(activeTrack) + (details.seekOffset ?? 10));
+        requestSeek(activeTrack, seekTo);
     });
 
-    track.playbackButton.addEventListener('click', event => {
-        event.preventDefault();
-        requestPlaybackChange(track);
+    navigator.mediaSession.setActionHandler('seekto', details => {
+        requestSeek(activeTrack, Math.min(details.seekTime, activeTrack.duration - 1));
     });
+}
 
-    container.addEventListener('keydown', event => {
-        if (event.key === 'ArrowLeft') {
-            event.preventDefault();
-            const seekTo = Math.max(0, track.audio.currentTime - 5);
-            requestSeek(track, seekTo);
-        } else if (event.key === 'ArrowRight') {
-            event.preventDefault();
-            const seekTo = Math.min(track.duration - 1, track.audio.currentTime + 5);
-            requestSeek(track, seekTo);
-        }
-    });
+const resizeObserver = new ResizeObserver(entries => {
+    const minWidth = entries.reduce(
+        (minWidth, entry) => Math.min(entry.contentRect.width, minWidth),
+        Infinity
+    );
 
-    const waveformContainer = container.querySelector('.waveform');
-    if (waveformContainer) {
-        const input = waveformContainer.querySelector('.waveform input');
-        const svg = waveformContainer.querySelector('.waveform svg');
+    waveforms(minWidth);
+});
 
-        const peaks = decode(svg.dataset.peaks).map(peak => peak / 63);
+// Sets up all (playable) tracks on the page
+function initTracks() {
+    let previousTrack = null;
+    let trackIndex = 0;
+    for (const container of document.querySelectorAll('.track:not(.locked)')) {
+        const artists = container.querySelector('.artists');
+        let audio = container.querySelector('audio');
+        const number = container.querySelector('.number');
+        const playbackButton = container.querySelector('.track_playback');
+        const playbackButtonIcon = container.querySelector('.track_playback .icon');
+        const title = container.querySelector('.title');
+
+        const duration = parseFloat(container.dataset.duration);
+
+        // Tracks split from a continuous recording all play back from the same
+        // audio element, which is the one of the first of them (see handOver).
+        const sharedAudio = container.dataset.segmentStart !== undefined;
+        const start = sharedAudio ? parseFloat(container.dataset.segmentStart) : 0;
+
+        if (sharedAudio && previousTrack !== null && previousTrack.sharedAudio) {
+            audio = previousTrack.audio;
+        }
 
-        track.waveform = {
-            container: waveformContainer,
-            input,
-            peaks,
-            svg
+        const track = {
+            artists,
+            // Resolved right away, as the track may outlive its page (see
+            // the faircamp:beforeswap handler).
+            artwork: parseArtwork(container.dataset.artwork),
+            audio,
+            container,
+            duration,
+            number,
+            playbackButton,
+            playbackButtonIcon,
+            sharedAudio,
+            start,
+            title
         };
 
-        track.waveform.container.addEventListener('click', event => {
-            const factor = (event.clientX - track.waveform.input.getBoundingClientRect().x) / track.waveform.input.getBoundingClientRect().width;
-            const seekTo = factor * track.waveform.input.max
-            requestSeek(track, seekTo);
-            track.waveform.input.classList.add('focus_from_click');
-            track.waveform.input.focus();
-        });
-
-        track.waveform.container.addEventListener('mouseenter', event => {
-            track.waveform.container.classList.add('seek');
-        });
-
-        track.waveform.container.addEventListener('mousemove', event => {
-            const factor = (event.clientX - track.waveform.container.getBoundingClientRect().x) / track.waveform.container.getBoundingClientRect().width;
-            // TODO: Pre-store the two querySelector results
-            track.waveform.svg.querySelector('linearGradient.seek stop:nth-child(1)').setAttribute('offset', factor);
-            track.waveform.svg.querySelector('linearGradient.seek stop:nth-child(2)').setAttribute('offset', factor + 0.0001);
-        });
+        // We only unmute tracks right before they play, muting them again at any
+        // pause event. We do this because a bug in browsers on apple systems can
+        // trigger sporadic, unsolicited playback of tracks in certain conditions
+        // (see comment elsewhere on track.solicitedPlayback), and although we
+        // cancel this unsolicited playback right away, it would be sometimes
+        // audible for a brief moment (if we didn't keep tracks muted).
+        audio.muted = true;
+
+        // Playback buttons start off with tabindex="-1" because if the visitor
+        // has JavaScript disabled the element should not be interacted with at
+        // all. When JavaScript is available we revert to making the button
+        // reachable by keyboard.
+        playbackButton.tabIndex = 0;
+
+        if (previousTrack !== null) {
+            previousTrack.nextTrack = track;
+        }
 
-        track.waveform.container.addEventListener('mouseout', event => {
-            track.waveform.container.classList.remove('seek');
-        });
+        previousTrack = track;
 
-        track.waveform.input.addEventListener('blur', () => {
-            track.waveform.input.classList.remove('focus_from_click');
-        });
+        observeAudio(track);
 
-        track.waveform.input.addEventListener('focus', () => {
-            announcePlayhead(track);
+        track.playbackButton.addEventListener('click', event => {
+            event.preventDefault();
+            requestPlaybackChange(track);
         });
 
-        track.waveform.input.addEventListener('keydown', event => {
-            if (event.key === ' ' || event.key === 'Enter') {
+        container.addEventListener('keydown', event => {
+            if (event.key === 'ArrowLeft') {
                 event.preventDefault();
-                requestPlaybackChange(track);
+                const seekTo = Math.max(0, position(track) - 5);
+                requestSeek(track, seekTo);
+            } else if (event.key === 'ArrowRight') {
+                event.preventDefault();
+                const seekTo = Math.min(track.duration - 1, position(track) + 5);
+                requestSeek(track, seekTo);
             }
         });
 
-        // Initialize playback/seek gradients
-        const SVG_XMLNS = 'http://www.w3.org/2000/svg';
-
-        svg.setAttribute('xmlns', SVG_XMLNS);
-        svg.setAttribute('height', `${TRACK_HEIGHT_EM}em`);
-
-        const defs = document.createElementNS(SVG_XMLNS, 'defs');
-
-        const playbackGradient = document.createElementNS(SVG_XMLNS, 'linearGradient');
-        playbackGradient.classList.add('playback');
-        playbackGradient.id = `gradient_playback_${trackIndex}`;
-        const playbackGradientStop1 = document.createElementNS(SVG_XMLNS, 'stop');
-        playbackGradientStop1.setAttribute('offset', '0');
-        playbackGradientStop1.setAttribute('stop-color', 'var(--fg-1)');
-        const playbackGradientStop2 = document.createElementNS(SVG_XMLNS, 'stop');
-        playbackGradientStop2.setAttribute('offset', '0.000001');
-        playbackGradientStop2.setAttribute('stop-color', 'hsla(0, 0%, 0%, 0)');
-        playbackGradient.append(playbackGradientStop1, playbackGradientStop2);
-
-        const seekGradient = document.createElementNS(SVG_XMLNS, 'linearGradient');
-        seekGradient.classList.add('seek');
-        seekGradient.id = `gradient_seek_${trackIndex}`;
-        const seekGradientStop1 = document.createElementNS(SVG_XMLNS, 'stop');
-        seekGradientStop1.setAttribute('offset', '0');
-        seekGradientStop1.setAttribute('stop-color', 'var(--fg-3)');
-        const seekGradientStop2 = document.createElementNS(SVG_XMLNS, 'stop');
-        seekGradientStop2.setAttribute('offset', '0.000001');
-        seekGradientStop2.setAttribute('stop-color', 'hsla(0, 0%, 0%, 0)');
-        seekGradient.append(seekGradientStop1, seekGradientStop2);
-
-        defs.append(playbackGradient);
-        defs.append(seekGradient);
-        svg.prepend(defs);
-
-        svg.querySelector('path.playback').setAttribute('stroke', `url(#gradient_playback_${trackIndex})`);
-        svg.querySelector('path.seek').setAttribute('stroke', `url(#gradient_seek_${trackIndex})`);
-
-        // Trigger waveform recomputation on resize (this also triggers the
-        // first draw after the initial page load).
-        const waveformParent = track.waveform.container.parentElement;
-        resizeObserver.observe(waveformParent);
-    }
+        const waveformContainer = container.querySelector('.waveform');
+        if (waveformContainer) {
+            const input = waveformContainer.querySelector('.waveform input');
+            const svg = waveformContainer.querySelector('.waveform svg');
+
+            // The line style only uses peaks, the mirrored and stereo styles
+            // additionally use rms levels (and for stereo a separate lower lane)
+            const style = svg.dataset.style ?? 'line';
+            const peaks = decode(svg.dataset.peaks).map(peak => peak / 63);
+            const rms = svg.dataset.rms ? decode(svg.dataset.rms).map(level => level / 63) : null;
+            const peaksLower = svg.dataset.peaksLower ? decode(svg.dataset.peaksLower).map(peak => peak / 63) : peaks;
+            const rmsLower = svg.dataset.rmsLower ? decode(svg.dataset.rmsLower).map(level => level / 63) : rms;
+
+            track.waveform = {
+                container: waveformContainer,
+                input,
+                peaks,
+                peaksLower,
+                rms,
+                rmsLower,
+                style,
+                svg
+            };
+
+            track.waveform.container.addEventListener('click', event => {
+                const factor = (event.clientX - track.waveform.input.getBoundingClientRect().x) / track.waveform.input.getBoundingClientRect().width;
+                const seekTo = factor * track.waveform.input.max
+                requestSeek(track, seekTo);
+                track.waveform.input.classList.add('focus_from_click');
+                track.waveform.input.focus();
+            });
+
+            track.waveform.container.addEventListener('mouseenter', event => {
+                track.waveform.container.classList.add('seek');
+            });
+
+            track.waveform.container.addEventListener('mousemove', event => {
+                const factor = (event.clientX - track.waveform.container.getBoundingClientRect().x) / track.waveform.container.getBoundingClientRect().width;
+                // TODO: Pre-store the two querySelector results
+                track.waveform.svg.querySelector('linearGradient.seek stop:nth-child(1)').setAttribute('offset', factor);
+                track.waveform.svg.querySelector('linearGradient.seek stop:nth-child(2)').setAttribute('offset', factor + 0.0001);
+            });
+
+            track.waveform.container.addEventListener('mouseout', event => {
+                track.waveform.container.classList.remove('seek');
+            });
+
+            track.waveform.input.addEventListener('blur', () => {
+                track.waveform.input.classList.remove('focus_from_click');
+            });
+
+            track.waveform.input.addEventListener('focus', () => {
+                announcePlayhead(track);
+            });
+
+            track.waveform.input.addEventListener('keydown', event => {
+                if (event.key === ' ' || event.key === 'Enter') {
+                    event.preventDefault();
+                    requestPlaybackChange(track);
+                }
+            });
+
+            // Initialize playback/seek gradients
+            const SVG_XMLNS = 'http://www.w3.org/2000/svg';
+
+            svg.setAttribute('xmlns', SVG_XMLNS);
+            svg.setAttribute('height', `${TRACK_HEIGHT_EM}em`);
+
+            const defs = document.createElementNS(SVG_XMLNS, 'defs');
+
+            const playbackGradient = document.createElementNS(SVG_XMLNS, 'linearGradient');
+            playbackGradient.classList.add('playback');
+            playbackGradient.id = `gradient_playback_${trackIndex}`;
+            const playbackGradientStop1 = document.createElementNS(SVG_XMLNS, 'stop');
+            playbackGradientStop1.setAttribute('offset', '0');
+            playbackGradientStop1.setAttribute('stop-color', 'var(--fg-1)');
+            const playbackGradientStop2 = document.createElementNS(SVG_XMLNS, 'stop');
+            playbackGradientStop2.setAttribute('offset', '0.000001');
+            playbackGradientStop2.setAttribute('stop-color', 'hsla(0, 0%, 0%, 0)');
+            playbackGradient.append(playbackGradientStop1, playbackGradientStop2);
+
+            const seekGradient = document.createElementNS(SVG_XMLNS, 'linearGradient');
+            seekGradient.classList.add('seek');
+            seekGradient.id = `gradient_seek_${trackIndex}`;
+            const seekGradientStop1 = document.createElementNS(SVG_XMLNS, 'stop');
+            seekGradientStop1.setAttribute('offset', '0');
+            seekGradientStop1.setAttribute('stop-color', 'var(--fg-3)');
+            const seekGradientStop2 = document.createElementNS(SVG_XMLNS, 'stop');
+            seekGradientStop2.setAttribute('offset', '0.000001');
+            seekGradientStop2.setAttribute('stop-color', 'hsla(0, 0%, 0%, 0)');
+            seekGradient.append(seekGradientStop1, seekGradientStop2);
+
+            defs.append(playbackGradient);
+            defs.append(seekGradient);
+
+            // The line style is drawn with strokes, the others with fills
+            const paintAttribute = style === 'line' ? 'stroke' : 'fill';
+
+            // Color the waveform along its spectral brightness if requested
+            if (svg.dataset.brightness) {
+                const brightness = decode(svg.dataset.brightness).map(value => value / 63);
+
+                const spectralGradient = document.createElementNS(SVG_XMLNS, 'linearGradient');
+                spectralGradient.id = `gradient_spectral_${trackIndex}`;
+
+                brightness.forEach((value, index) => {
+                    const stop = document.createElementNS(SVG_XMLNS, 'stop');
+                    stop.setAttribute('offset', index / Math.max(1, brightness.length - 1));
+                    stop.setAttribute('stop-color', spectralColor(value));
+                    spectralGradient.append(stop);
+                });
+
+                defs.append(spectralGradient);
+
+                for (const path of svg.querySelectorAll('path.base, path.rms')) {
+                    path.style[paintAttribute] = `url(#gradient_spectral_${trackIndex})`;
+                }
+            }
 
-    trackIndex++;
-    tracks.push(track);
+            svg.prepend(defs);
+
+            svg.querySelector('path.playback').setAttribute(paintAttribute, `url(#gradient_playback_${trackIndex})`);
+            svg.querySelector('path.seek').setAttribute(paintAttribute, `url(#gradient_seek_${trackIndex})`);
+
+            // Trigger waveform recomputation on resize (this also triggers the
+            // first draw after the initial page load).
+            const waveformParent = track.waveform.container.parentElement;
+            resizeObserver.observe(waveformParent);
+        }
+
+        trackIndex++;
+        tracks.push(track);
+    }
 }
 
 window.addEventListener('hashchange', event => {
@@ -1168,33 +1605,111 @@ window.addEventListener('hashchange', event => {
     }
 });
 
+// Global keyboard shortcuts, which are ignored while the visitor types
+// into a text field. Apart from m (mute), they can be turned off through the
+// keyboard_shortcuts catalog option, which the manual also documents them for.
 window.addEventListener('keydown', event => {
+    if (event.altKey || event.ctrlKey || event.metaKey) return;
+
+    const { target } = event;
+
+    if (target.isContentEditable ||
+        target.tagName === 'TEXTAREA' ||
+        (target.tagName === 'INPUT' && (target.type === 'search' || target.type === 'text'))) return;
+
     if (event.key === 'm') {
-        const { target } = event;
+        toggleMute();
+        event.preventDefault();
+        return;
+    }
 
-        if (target.tagName !== 'INPUT' || (target.type !== 'search' && target.type !== 'text')) {
-            toggleMute();
-            event.preventDefault();
+    if (!PLAYER_JS_KEYBOARD_SHORTCUTS || !activeTrack) return;
+
+    if (event.key === ' ' || event.key === 'k') {
+        // Space keeps activating whatever button, link or control has focus
+        if (event.key === ' ' && target.closest('a, button, input, select, summary')) return;
+
+        requestPlaybackChange(activeTrack);
+    } else if (event.key === 'j') {
+        requestSeek(activeTrack, Math.max(0, position(activeTrack) - 10));
+    } else if (event.key === 'l') {
+        requestSeek(activeTrack, Math.min(activeTrack.duration - 1, position(activeTrack) + 10));
+    } else if (/^[0-9]$/.test(event.key)) {
+        // 1-9 play the first nine tracks on the page, 0 plays the tenth
+        const track = tracks[event.key === '0' ? 9 : parseInt(event.key) - 1];
+
+        if (!track) return;
+
+        if (track !== activeTrack || activeTrack.audio.paused) {
+            requestPlaybackChange(track);
         }
+    } else {
+        return;
     }
+
+    event.preventDefault();
 });
 
-// Set active track (and optionally set seekTo and/or open player)
-const params = parseHashParams();
-if (params) {
-    setActive(params.track);
+// With soft navigation (see navigation.js) the page content is replaced
+// when the visitor navigates, but the docked player lives on: We move it out
+// of the page content into the body, along with the audio element of the
+// active track, which thereby continues playing.
+document.addEventListener('faircamp:beforeswap', () => {
+    document.body.append(dockedPlayer.container, dockedPlayer.status);
+
+    if (activeTrack) {
+        dockedPlayer.container.append(activeTrack.audio);
+    }
+
+    // Tracks of the page we're leaving might still be played and displayed
+    // later on (e.g. when one of them is up next), so their links and audio
+    // sources are pinned to the urls they currently resolve to.
+    for (const track of tracks) {
+        for (const link of track.container.querySelectorAll('a[href]')) {
+            link.href = link.href;
+        }
 
-    if (params.time !== undefined) {
-        params.track.seekTo = params.time;
+        for (const source of track.audio.querySelectorAll('source')) {
+            source.src = source.src;
+        }
     }
 
-    open(params.track);
+    for (const link of dockedPlayer.titleWrapper.querySelectorAll('a[href]')) {
+        link.href = link.href;
+    }
+});
 
-    // Announce to screenreaders that the docked player is present
-    dockedPlayer.status.setAttribute('aria-label', PLAYER_JS_T.playerOpenWithXxx(params.track.title.textContent));
-} else {
-    setActive(tracks[0]);
-}
+document.addEventListener('faircamp:afterswap', () => {
+    // The new page brings its own docked player, which makes way for ours
+    for (const element of document.querySelectorAll('main .docked_player, main .docked_player_status')) {
+        element.remove();
+    }
+
+    resizeObserver.disconnect();
+    waveformRenderState.widthRem = 0;
+    tracks.length = 0;
+
+    initListenButton();
+    initTracks();
+
+    // A track that is open in the docked player stays active, even though
+    // it may be from a page the visitor has left.
+    if (!activeTrack || !activeTrack.open) {
+        initActiveTrack();
+    }
+});
+
+// Queueing tracks (see navigation.js) might give the active track something
+// to be followed by.
+document.addEventListener('faircamp:queuechange', () => {
+    if (activeTrack && activeTrack.open && dockedPlayer.nextTrackButton) {
+        dockedPlayer.nextTrackButton.toggleAttribute('disabled', !activeTrack.nextTrack && queueLength() === 0);
+    }
+});
+
+initListenButton();
+initTracks();
+initActiveTrack();
 
 const waveformRenderState = { widthRem: 0 };
 
@@ -1231,35 +1746,40 @@ function waveforms(minWidth) {
         const preferredNumSamples = Math.round(0.75 * waveformWidthRem * baseFontSizePx);
         const numSamples = Math.min(preferredNumSamples, peaks.length);
 
-        const prevY = WAVEFORM_PADDING_EM + (1 - peaks[0]) * WAVEFORM_HEIGHT;
-        let d = `M 0,${prevY.toFixed(2)}`;
+        let d;
+        let dRms = null;
 
-        let yChangeOccured = false;
-        for (let sample = 1; sample < numSamples; sample += 1) {
-            const factor = sample / (numSamples - 1);
-            const floatIndex = factor * (peaks.length - 1);
-            const previousIndex = Math.floor(floatIndex);
-            const nextIndex = Math.ceil(floatIndex);
+        if (track.waveform.style === 'line') {
+            const sampledPeaks = sampleWaveform(peaks, numSamples);
 
-            let peak;
-            if (previousIndex === nextIndex) {
-                peak = peaks[previousIndex];
-            } else {
-                const interPeakBias = floatIndex - previousIndex;
-                peak = peaks[previousIndex] * (1 - interPeakBias) + peaks[nextIndex] * interPeakBias;
-            }
+            const prevY = WAVEFORM_PADDING_EM + (1 - sampledPeaks[0]) * WAVEFORM_HEIGHT;
+            d = `M 0,${prevY.toFixed(2)}`;
 
-            const x = factor * waveformWidthRem;
-            const y = WAVEFORM_PADDING_EM + (1 - peak) * WAVEFORM_HEIGHT;
+            for (let sample = 1; sample < numSamples; sample += 1) {
+                const factor = sample / (numSamples - 1);
+                const x = factor * waveformWidthRem;
+                const y = WAVEFORM_PADDING_EM + (1 - sampledPeaks[sample]) * WAVEFORM_HEIGHT;
 
-            // If the y coordinate is always exactly the same on all points, the linear
-            // gradient applied to the .playback path does not show up at all (firefox).
-            // This only happens when the track is perfectly silent/same level all the
-            // way through, which currently is the case when with the disable_waveforms option.
-            // We counter this here by introducing minimal jitter on the y dimension.
-            const yJitter = (y === prevY ? '1' : '');
+                // If the y coordinate is always exactly the same on all points, the linear
+                // gradient applied to the .playback path does not show up at all (firefox).
+                // This only happens when the track is perfectly silent/same level all the
+                // way through, which currently is the case when with the disable_waveforms option.
+                // We counter this here by introducing minimal jitter on the y dimension.
+                const yJitter = (y === prevY ? '1' : '');
 
-            d += ` L ${x.toFixed(2)},${y.toFixed(2)}${yJitter}`;
+                d += ` L ${x.toFixed(2)},${y.toFixed(2)}${yJitter}`;
+            }
+        } else {
+            d = waveformOutline(
+                sampleWaveform(peaks, numSamples),
+                sampleWaveform(track.waveform.peaksLower, numSamples),
+                waveformWidthRem
+            );
+            dRms = waveformOutline(
+                sampleWaveform(track.waveform.rms, numSamples),
+                sampleWaveform(track.waveform.rmsLower, numSamples),
+                waveformWidthRem
+            );
         }
 
         const svg = track.waveform.svg;
@@ -1269,7 +1789,32 @@ function waveforms(minWidth) {
         svg.querySelector('path.base').setAttribute('d', d);
         svg.querySelector('path.playback').setAttribute('d', d);
         svg.querySelector('path.seek').setAttribute('d', d);
+
+        if (dRms) {
+            svg.querySelector('path.rms').setAttribute('d', dRms);
+        }
     }
 
     waveformRenderState.widthRem = maxWaveformWidthRem;
 }
+
+// Returns the path for a filled shape that traces the upper values above
+// the vertical center (left to right) and the lower values below the
+// vertical center (right to left).
+function waveformOutline(upper, lower, waveformWidthRem) {
+    const center = TRACK_HEIGHT_EM / 2;
+    const amplitude = WAVEFORM_HEIGHT / 2;
+    const x = index => (index / Math.max(1, upper.length - 1) * waveformWidthRem).toFixed(2);
+
+    let d = `M 0,${center.toFixed(2)}`;
+
+    for (let index = 0; index < upper.length; index += 1) {
+        d += ` L ${x(index)},${(center - upper[index] * amplitude).toFixed(3)}`;
+    }
+
+    for (let index = lower.length - 1; index >= 0; index -= 1) {
+        d += ` L ${x(index)},${(center + lower[index] * amplitude).toFixed(3)}`;
+    }
+
+    return `${d} Z`;
+}
@@ -0,0 +1,126 @@
+// The service worker of a faircamp site with web_app enabled. During the
+// build, SERVICE_WORKER_CACHE (a cache name that changes whenever the site's
+// assets change) and SERVICE_WORKER_PRECACHE (the urls of the site's
+// homepage and assets) are prepended to this script.
+//
+// Pages are always fetched from the network first, so that listeners see
+// the latest version of the site whenever they are online. Everything else
+// is served from the cache first, which covers the precached assets as well
+// as releases that a listener explicitly saved for offline listening.
+
+const SERVICE_WORKER_OFFLINE_CACHE = 'faircamp-offline';
+const SERVICE_WORKER_RUNTIME_DESTINATIONS = ['font', 'image', 'script', 'style'];
+
+function isPage(url) {
+    return url.pathname.endsWith('/') || url.pathname.endsWith('.html');
+}
+
+async function fromCacheFirst(request) {
+    const cached = await caches.match(request, { ignoreVary: true });
+
+    if (cached) {
+        const range = request.headers.get('range');
+        return range ? rangeResponse(cached, range) : cached;
+    }
+
+    const response = await fetch(request);
+
+    if (response.ok &&
+        response.status === 200 &&
+        SERVICE_WORKER_RUNTIME_DESTINATIONS.includes(request.destination)) {
+        const cache = await caches.open(SERVICE_WORKER_CACHE);
+        cache.put(request, response.clone());
+    }
+
+    return response;
+}
+
+async function fromNetworkFirst(request) {
+    try {
+        return await fetch(request);
+    } catch (err) {
+        const cached = await caches.match(request, { ignoreSearch: true });
+
+        if (cached) return cached;
+
+        throw err;
+    }
+}
+
+// The audio element requests (parts of) audio files through range requests,
+// which a cache cannot answer by itself, so for audio saved for offline
+// listening we cut the requested range out of the full file ourselves.
+async function rangeResponse(response, range) {
+    const match = range.match(/^bytes=(\d*)-(\d*)$/);
+
+    if (!match) return response;
+
+    const blob = await response.blob();
+    const size = blob.size;
+
+    let start = match[1] === '' ? null : parseInt(match[1]);
+    let end = match[2] === '' ? null : parseInt(match[2]);
+
+    if (start === null) {
+        // "bytes=-500" means the last 500 bytes
+        start = Math.max(size - (end ?? 0), 0);
+        end = size - 1;
+    } else if (end === null || end >= size) {
+        end = size - 1;
+    }
+
+    if (start >= size || start > end) {
+        return new Response(null, {
+            headers: { 'Content-Range': `bytes */${size}` },
+            status: 416
+        });
+    }
+
+    return new Response(blob.slice(start, end + 1), {
+        headers: {
+            'Content-Length': String(end + 1 - start),
+            'Content-Range': `bytes ${start}-${end}/${size}`,
+            'Content-Type': response.headers.get('Content-Type') ?? blob.type
+        },
+        status: 206,
+        statusText: 'Partial Content'
+    });
+}
+
+self.addEventListener('install', event => {
+    event.waitUntil(
+        caches.open(SERVICE_WORKER_CACHE)
+            .then(cache => cache.addAll(SERVICE_WORKER_PRECACHE))
+            .then(() => self.skipWaiting())
+    );
+});
+
+// Caches from previous versions of the site are removed, releases saved
+// for offline listening are kept though.
+self.addEventListener('activate', event => {
+    event.waitUntil(
+        caches.keys()
+            .then(keys => Promise.all(
+                keys
+                    .filter(key => key.startsWith('faircamp-shell-') && key !== SERVICE_WORKER_CACHE)
+                    .map(key => caches.delete(key))
+            ))
+            .then(() => self.clients.claim())
+    );
+});
+
+self.addEventListener('fetch', event => {
+    const request = event.request;
+
+    if (request.method !== 'GET') return;
+
+    const url = new URL(request.url);
+
+    if (url.origin !== self.location.origin) return;
+
+    if (request.mode === 'navigate' || isPage(url)) {
+        event.respondWith(fromNetworkFirst(request));
+    } else {
+        event.respondWith(fromCacheFirst(request));
+    }
+});
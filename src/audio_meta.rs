@@ -13,7 +13,7 @@ use std::path::Path;
 
 use serde_derive::{Serialize, Deserialize};
 
-use crate::{AudioFormatFamily, Build};
+use crate::{AudioFormatFamily, Build, Segment, WaveformAnalysis};
 use crate::decode::DecodeResult;
 
 mod aiff;
@@ -46,7 +46,10 @@ pub struct AudioMeta {
     /// The track title as provided by tags
     pub title: Option<String>,
     /// The track number as provided by tags
-    pub track_number: Option<u32>
+    pub track_number: Option<u32>,
+    /// Per-channel peaks, RMS levels and spectral brightness, used for the
+    /// more elaborate waveform styles and static waveform images
+    pub waveform: WaveformAnalysis
 }
 
 impl AudioMeta {
@@ -91,6 +94,61 @@ impl AudioMeta {
             _ => unreachable!()
         }
     }
+
+    /// Stands in for the actual metadata when the catalog is only validated
+    /// (see [Build::validate_only]) and the audio file is therefore never
+    /// decoded or read.
+    pub fn unread(extension: &str) -> AudioMeta {
+        let (format_family, lossless) = match extension {
+            "aac" => (AudioFormatFamily::Aac, false),
+            "aif" |
+            "aifc" |
+            "aiff" => (AudioFormatFamily::Aiff, true),
+            "alac" => (AudioFormatFamily::Alac, true),
+            "flac" => (AudioFormatFamily::Flac, true),
+            "mp3" => (AudioFormatFamily::Mp3, false),
+            "ogg" => (AudioFormatFamily::OggVorbis, false),
+            "opus" => (AudioFormatFamily::Opus, false),
+            "wav" => (AudioFormatFamily::Wav, true),
+            _ => unreachable!()
+        };
+
+        AudioMeta {
+            album: None,
+            album_artists: Vec::new(),
+            artists: Vec::new(),
+            duration_seconds: 0.0,
+            format_family,
+            lossless,
+            peaks: Vec::new(),
+            title: None,
+            track_number: None,
+            waveform: WaveformAnalysis {
+                brightness: Vec::new(),
+                peaks_left: Vec::new(),
+                peaks_right: Vec::new(),
+                rms_left: Vec::new(),
+                rms_right: Vec::new()
+            }
+        }
+    }
+
+    /// Derives the metadata of a segment of a continuous recording (see
+    /// [crate::CueSheet]) from the metadata of the entire recording and its
+    /// decoded audio. Title and track number are left to be given by the
+    /// CUE sheet.
+    pub fn for_segment(&self, decode_result: &DecodeResult, segment: &Segment) -> AudioMeta {
+        let segment_decode_result = decode_result.segment(segment);
+
+        AudioMeta {
+            duration_seconds: segment_decode_result.duration,
+            peaks: compute_peaks(&segment_decode_result, 320),
+            title: None,
+            track_number: None,
+            waveform: WaveformAnalysis::compute(&segment_decode_result, 320),
+            ..self.clone()
+        }
+    }
 }
 
 /// Takes interleaved samples and applies the following processing:
@@ -99,7 +157,7 @@ impl AudioMeta {
 /// - Determine the largest absolute average amplitude among all calculated windows
 /// - For all windows the averaged amplitudes are now upscaled again so that the maximum absolute window amplitude
 ///   is identical to the largest absolute amplitude found in all discrete samples
-fn compute_peaks(decode_result: DecodeResult, points: u32) -> Vec<f32> {
+fn compute_peaks(decode_result: &DecodeResult, points: u32) -> Vec<f32> {
     let window_size = (decode_result.channels as u32 * decode_result.sample_count) / points;
 
     let mut peaks = Vec::with_capacity(points as usize);
@@ -110,7 +168,7 @@ fn compute_peaks(decode_result: DecodeResult, points: u32) -> Vec<f32> {
     let mut sample_abs_max: f32 = 0.0;
     let mut window_abs_max: f32 = 0.0;
 
-    for amplitude in decode_result.samples {
+    for &amplitude in &decode_result.samples {
         sample_abs_max = sample_abs_max.max(amplitude.abs());
 
         if window_samples > window_size {
@@ -3,7 +3,7 @@
 
 use std::path::Path;
 
-use crate::AudioFormatFamily;
+use crate::{AudioFormatFamily, WaveformAnalysis};
 use crate::decode::alac;
 
 use mp4parse::TryString;
@@ -15,10 +15,11 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
     let format_family = AudioFormatFamily::Alac;
     let lossless = true;
 
-    let (duration_seconds, peaks) = match alac::decode(path) {
+    let (duration_seconds, peaks, waveform) = match alac::decode(path) {
         Ok(decode_result) => (
             decode_result.duration,
-            compute_peaks(decode_result, 320)
+            compute_peaks(&decode_result, 320),
+            WaveformAnalysis::compute(&decode_result, 320)
         ),
         Err(err) => return Err(err)
     };
@@ -40,7 +41,8 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
             lossless,
             peaks,
             title,
-            track_number
+            track_number,
+            waveform
         }
     } else {
         AudioMeta {
@@ -52,7 +54,8 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
             lossless,
             peaks,
             title: None,
-            track_number: None
+            track_number: None,
+            waveform
         }
     };
 
@@ -5,7 +5,7 @@ use std::path::Path;
 
 use metaflac::Tag;
 
-use crate::AudioFormatFamily;
+use crate::{AudioFormatFamily, WaveformAnalysis};
 use crate::decode::flac;
 
 use super::{
@@ -19,10 +19,11 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
     let format_family = AudioFormatFamily::Flac;
     let lossless = true;
 
-    let (duration_seconds, peaks) = match flac::decode(path) {
+    let (duration_seconds, peaks, waveform) = match flac::decode(path) {
         Ok(decode_result) => (
             decode_result.duration,
-            compute_peaks(decode_result, 320)
+            compute_peaks(&decode_result, 320),
+            WaveformAnalysis::compute(&decode_result, 320)
         ),
         Err(err) => return Err(err)
     };
@@ -56,7 +57,8 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
             lossless,
             peaks,
             title,
-            track_number
+            track_number,
+            waveform
         }
     } else {
         AudioMeta {
@@ -68,7 +70,8 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
             lossless,
             peaks,
             title: None,
-            track_number: None
+            track_number: None,
+            waveform
         }
     };
 
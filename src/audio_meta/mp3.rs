@@ -5,7 +5,7 @@ use std::path::Path;
 
 use id3::{Tag, TagLike};
 
-use crate::AudioFormatFamily;
+use crate::{AudioFormatFamily, WaveformAnalysis};
 use crate::decode::mp3;
 
 use super::{AudioMeta, compute_peaks, Id3Util};
@@ -14,10 +14,11 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
     let format_family = AudioFormatFamily::Mp3;
     let lossless = false;
 
-    let (duration_seconds, peaks) = match mp3::decode(path) {
+    let (duration_seconds, peaks, waveform) = match mp3::decode(path) {
         Ok(decode_result) => (
             decode_result.duration,
-            compute_peaks(decode_result, 320)
+            compute_peaks(&decode_result, 320),
+            WaveformAnalysis::compute(&decode_result, 320)
         ),
         Err(err) => return Err(err)
     };
@@ -39,7 +40,8 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
             lossless,
             peaks,
             title,
-            track_number: tag.track()
+            track_number: tag.track(),
+            waveform
         }
     } else {
         AudioMeta {
@@ -51,7 +53,8 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
             lossless,
             peaks,
             title: None,
-            track_number: None
+            track_number: None,
+            waveform
         }
     };
 
@@ -3,7 +3,7 @@
 
 use std::path::Path;
 
-use crate::AudioFormatFamily;
+use crate::{AudioFormatFamily, WaveformAnalysis};
 use crate::decode::ogg_vorbis;
 
 use super::{
@@ -17,10 +17,11 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
     let format_family = AudioFormatFamily::OggVorbis;
     let lossless = false;
 
-    let (duration_seconds, peaks, comment_header) = match ogg_vorbis::decode(path) {
+    let (duration_seconds, peaks, waveform, comment_header) = match ogg_vorbis::decode(path) {
         Ok((decode_result, comment_header)) => (
             decode_result.duration,
-            compute_peaks(decode_result, 320),
+            compute_peaks(&decode_result, 320),
+            WaveformAnalysis::compute(&decode_result, 320),
             Some(comment_header)
         ),
         Err(err) => return Err(err)
@@ -64,7 +65,8 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
             lossless,
             peaks,
             title,
-            track_number
+            track_number,
+            waveform
         }
     } else {
         AudioMeta {
@@ -76,7 +78,8 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
             lossless,
             peaks,
             title,
-            track_number
+            track_number,
+            waveform
         }
     };
 
@@ -6,7 +6,7 @@ use std::path::Path;
 
 use opus_headers::parse_from_path;
 
-use crate::AudioFormatFamily;
+use crate::{AudioFormatFamily, WaveformAnalysis};
 use crate::decode::opus;
 
 use super::{
@@ -20,10 +20,11 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
     let format_family = AudioFormatFamily::Opus;
     let lossless = false;
 
-    let (duration_seconds, peaks) = match opus::decode(path) {
+    let (duration_seconds, peaks, waveform) = match opus::decode(path) {
         Ok(decode_result) => (
             decode_result.duration,
-            compute_peaks(decode_result, 320)
+            compute_peaks(&decode_result, 320),
+            WaveformAnalysis::compute(&decode_result, 320)
         ),
         Err(err) => return Err(err)
     };
@@ -50,7 +51,8 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
             lossless,
             peaks,
             title,
-            track_number
+            track_number,
+            waveform
         }
     } else {
         AudioMeta {
@@ -62,7 +64,8 @@ pub fn extract(path: &Path) -> Result<AudioMeta, String> {
             lossless,
             peaks,
             title: None,
-            track_number: None
+            track_number: None,
+            waveform
         }
     };
 
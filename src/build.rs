@@ -15,8 +15,13 @@ use chrono::{DateTime, Utc};
 
 use crate::{
     Args,
+    Diagnostic,
+    ImageFormat,
     ImageProcessor,
     Locale,
+    ManifestMessage,
+    Severity,
+    SharedCache,
     SiteUrl
 };
 use crate::util::format_bytes;
@@ -50,6 +55,10 @@ pub struct Build {
     pub catalog_dir: PathBuf,
     pub clean_urls: bool,
     pub deploy_destination: Option<String>,
+    /// When running the check subcommand, errors and warnings are collected
+    /// here (instead of being printed right away), so they can be reported
+    /// together - and possibly in machine-readable form - at the end.
+    pub diagnostics: Option<Vec<Diagnostic>>,
     /// Whether at least one embed was requested to be generated somewhere.
     /// This lets us know to generate some css/js used in embeds only, and/or
     /// to print a warning in case the base_url is missing and we hence
@@ -58,6 +67,10 @@ pub struct Build {
     /// Counts errors during build
     pub errors: usize,
     pub exclude_patterns: Vec<String>,
+    /// Modern image formats in which artist, cover and background images
+    /// are additionally generated (besides jpeg), kept sorted in order
+    /// of preference. Set through the catalog's image_formats option.
+    pub image_formats: Vec<ImageFormat>,
     pub image_processor: ImageProcessor,
     /// Forces continuation of build even when there are errors in the
     /// manifests or during building in general.
@@ -69,6 +82,9 @@ pub struct Build {
     pub missing_image_descriptions: bool,
     pub post_build_action: PostBuildAction,
     reserved_filenames: HashSet<String>,
+    /// A content-addressed cache shared with other machines (if configured),
+    /// consulted before computing transcodes locally.
+    pub shared_cache: Option<SharedCache>,
     pub stats: Stats,
     pub theming_widget: bool,
     /// Most asset urls contain a deterministically random (=hashed) path
@@ -80,6 +96,10 @@ pub struct Build {
     /// deployment, thereby invalidating all download asset urls on each
     /// deployment.
     pub url_salt: String,
+    /// Set when running the check subcommand: The catalog is read as it
+    /// would be for a build, but audio files are neither decoded nor hashed
+    /// and nothing is written to the cache.
+    pub validate_only: bool,
     pub verbose: bool,
     pub warnings: usize
 }
@@ -111,6 +131,8 @@ impl AssetHashes {
     pub const FAVICON_DARK_PNG: &str = env!("FAIRCAMP_FAVICON_DARK_PNG_HASH");
     pub const FAVICON_LIGHT_PNG: &str = env!("FAIRCAMP_FAVICON_LIGHT_PNG_HASH");
     pub const FAVICON_SVG: &str = env!("FAIRCAMP_FAVICON_SVG_HASH");
+    pub const NAVIGATION_JS: &str = env!("FAIRCAMP_NAVIGATION_JS_HASH");
+    pub const OFFLINE_JS: &str = env!("FAIRCAMP_OFFLINE_JS_HASH");
 
     pub fn new() -> AssetHashes {
         AssetHashes {
@@ -137,7 +159,10 @@ impl Build {
     }
 
     pub fn error(&mut self, error: &str) {
-        error!("{}", error);
+        match &mut self.diagnostics {
+            Some(diagnostics) => diagnostics.push(Diagnostic::new(None, error, Severity::Error)),
+            None => error!("{}", error)
+        }
         self.errors += 1;
     }
 
@@ -166,6 +191,33 @@ impl Build {
         }
     }
 
+    pub fn manifest_error(&mut self, error: &ManifestMessage) {
+        match &mut self.diagnostics {
+            Some(diagnostics) => diagnostics.push(Diagnostic::new(Some(error.location.clone()), &error.text, Severity::Error)),
+            None => error!("{}", error.text)
+        }
+        self.errors += 1;
+    }
+
+    pub fn manifest_warning(&mut self, warning: &ManifestMessage) {
+        match &mut self.diagnostics {
+            Some(diagnostics) => diagnostics.push(Diagnostic::new(Some(warning.location.clone()), &warning.text, Severity::Warning)),
+            None => warn!("{}", warning.text)
+        }
+        self.warnings += 1;
+    }
+
+    /// Missing image descriptions are discouraged but not counted as
+    /// warnings, when we encounter one we also need to remember to render
+    /// the page that explains image descriptions.
+    pub fn missing_image_description(&mut self, message: &str) {
+        match &mut self.diagnostics {
+            Some(diagnostics) => diagnostics.push(Diagnostic::new(None, message, Severity::Warning)),
+            None => warn_discouraged!("{}", message)
+        }
+        self.missing_image_descriptions = true;
+    }
+
     pub fn new(args: &Args) -> Build {
         let catalog_dir = args.catalog_dir
             .as_ref()
@@ -199,21 +251,25 @@ impl Build {
             catalog_dir,
             clean_urls: !args.no_clean_urls,
             deploy_destination: args.deploy_destination.clone(),
+            diagnostics: None,
             embeds_requested: false,
             errors: 0,
             exclude_patterns: args.exclude_patterns.clone(),
             include_patterns: args.include_patterns.clone(),
+            image_formats: Vec::new(),
             image_processor: ImageProcessor::new(),
             ignore_errors: args.ignore_errors,
             locale,
             missing_image_descriptions: false,
             post_build_action,
             reserved_filenames: HashSet::new(),
+            shared_cache: args.shared_cache.as_deref().map(|value| SharedCache::from_arg(value, args.shared_cache_token.clone())),
             stats: Stats::new(),
             theming_widget: args.theming_widget,
             // Changing this can invalidate urls of already deployed faircamp
             // sites, handle with care.
             url_salt: String::from(""),
+            validate_only: false,
             verbose: args.verbose,
             warnings: 0
         }
@@ -245,7 +301,10 @@ impl Build {
     }
 
     pub fn warning(&mut self, warning: &str) {
-        warn!("{}", warning);
+        match &mut self.diagnostics {
+            Some(diagnostics) => diagnostics.push(Diagnostic::new(None, warning, Severity::Warning)),
+            None => warn!("{}", warning)
+        }
         self.warnings += 1;
     }
 }
@@ -24,14 +24,18 @@ use crate::{
     ProceduralCover,
     ProceduralCoverRc,
     Release,
+    Segment,
     SourceHash,
     Transcodes,
     TranscodesRc,
     TranscodesRcView,
     util
 };
+use crate::decode;
 use crate::util::string_from_os;
 
+mod inspection;
+
 /// This is the name of an empty file created by faircamp in the root of the
 /// cache directory. When the entire cache layout (or critical implementation
 /// details) change, the cache version can be updated, prompting a complete cache
@@ -49,6 +53,10 @@ pub struct Cache {
     /// those assets that are used are tagged as such. After cache retrieval
     /// all assets not tagged as used are considered orphaned and removed.
     assets: HashMap<String, bool>,
+    /// When evicting assets to fit the cache within a size budget, all
+    /// assets that were marked stale at or before this point in time are
+    /// considered obsolete (regardless of the optimization strategy).
+    evict_stale_until: Option<DateTime<Utc>>,
     pub images: Vec<ImageRc>,
     /// We register all manifests found in the cache here. Afterwards we iterate
     /// through all of them, using those with a known manifest extension
@@ -128,6 +136,13 @@ fn report_stale_images(
 
     report(&image_ref.background_asset);
 
+    if image_ref.background_asset.as_ref().is_some_and(|asset| asset.is_stale()) {
+        for variant in &image_ref.background_variants {
+            *num_unused += 1;
+            *unused_bytesize += variant.filesize_bytes;
+        }
+    }
+
     if let Some(filesize_bytes) = &image_ref.feed_asset
         .as_ref()
         .filter(|asset| asset.is_stale())
@@ -142,6 +157,11 @@ fn report_stale_images(
         for asset in &assets.all() {
             *num_unused += 1;
             *unused_bytesize += asset.filesize_bytes;
+
+            for variant in &asset.variants {
+                *num_unused += 1;
+                *unused_bytesize += variant.filesize_bytes;
+            }
         }
     }
 
@@ -151,6 +171,25 @@ fn report_stale_images(
         for asset in &assets.all() {
             *num_unused += 1;
             *unused_bytesize += asset.filesize_bytes;
+
+            for variant in &asset.variants {
+                *num_unused += 1;
+                *unused_bytesize += variant.filesize_bytes;
+            }
+        }
+    }
+
+    if let Some(assets) = image_ref.gallery_assets
+        .as_ref()
+        .filter(|assets| assets.is_stale()) {
+        for asset in &assets.all() {
+            *num_unused += 1;
+            *unused_bytesize += asset.filesize_bytes;
+
+            for variant in &asset.variants {
+                *num_unused += 1;
+                *unused_bytesize += variant.filesize_bytes;
+            }
         }
     }
 }
@@ -287,6 +326,9 @@ impl Cache {
             .map(|asset| self.obsolete(build, &asset.marked_stale)) {
             Some(true) => {
                 let _ = fs::remove_file(build.cache_dir.join(image_mut.background_asset.take().unwrap().filename));
+                for variant in image_mut.background_variants.drain(..) {
+                    let _ = fs::remove_file(build.cache_dir.join(variant.filename));
+                }
                 info_cache!("Removed cached background image asset {}.", views_context);
             }
             Some(false) => keep_container = true,
@@ -310,6 +352,9 @@ impl Cache {
             Some(true) => {
                 for asset in image_mut.artist_assets.take().unwrap().all() {
                     let _ = fs::remove_file(build.cache_dir.join(&asset.filename));
+                    for variant in &asset.variants {
+                        let _ = fs::remove_file(build.cache_dir.join(&variant.filename));
+                    }
                     info_cache!(
                         "Removed cached image asset ({}) {} {}x{}.",
                         "artist",
@@ -329,6 +374,9 @@ impl Cache {
             Some(true) => {
                 for asset in image_mut.cover_assets.take().unwrap().all() {
                     let _ = fs::remove_file(build.cache_dir.join(&asset.filename));
+                    for variant in &asset.variants {
+                        let _ = fs::remove_file(build.cache_dir.join(&variant.filename));
+                    }
                     info_cache!(
                         "Removed cached image asset ({}) {} {}x{}.",
                         "cover",
@@ -342,6 +390,28 @@ impl Cache {
             None => ()
         }
 
+        match image_mut.gallery_assets
+            .as_ref()
+            .map(|assets| self.obsolete(build, &assets.marked_stale)) {
+            Some(true) => {
+                for asset in image_mut.gallery_assets.take().unwrap().all() {
+                    let _ = fs::remove_file(build.cache_dir.join(&asset.filename));
+                    for variant in &asset.variants {
+                        let _ = fs::remove_file(build.cache_dir.join(&variant.filename));
+                    }
+                    info_cache!(
+                        "Removed cached image asset ({}) {} {}x{}.",
+                        "gallery",
+                        &views_context,
+                        asset.height,
+                        asset.width
+                    );
+                }
+            }
+            Some(false) => keep_container = true,
+            None => ()
+        }
+
         if keep_container {
             image_mut.persist_to_cache(&build.cache_dir);
         } else {
@@ -437,10 +507,13 @@ impl Cache {
         }
     }
 
-    fn new() -> Cache {
+    /// Returns an empty cache that is not backed by the cache directory (use
+    /// [Cache::retrieve] for that), e.g. for reading manifests during `faircamp check`.
+    pub fn new() -> Cache {
         Cache {
             archives: Vec::new(),
             assets: HashMap::new(),
+            evict_stale_until: None,
             images: Vec::new(),
             manifests: Vec::new(),
             optimization: CacheOptimization::Default,
@@ -456,6 +529,10 @@ impl Cache {
     pub fn obsolete(&self, build: &Build, marked_stale: &Option<DateTime<Utc>>) -> bool {
         match marked_stale {
             Some(date_time) => {
+                if let Some(evict_stale_until) = &self.evict_stale_until {
+                    return date_time <= evict_stale_until;
+                }
+
                 match &self.optimization {
                     CacheOptimization::Default |
                     CacheOptimization::Delayed =>
@@ -645,12 +722,18 @@ impl Cache {
             let mut dead_references_removed = false;
 
             if let Some(artist_assets) = image_mut.artist_assets.as_mut() {
-                let all_assets = artist_assets.all();
-
-                if all_assets.iter().all(|asset| self.assets.contains_key(&asset.filename)) {
+                let all_filenames: Vec<&String> = artist_assets.all()
+                    .into_iter()
+                    .flat_map(|asset| {
+                        std::iter::once(&asset.filename)
+                            .chain(asset.variants.iter().map(|variant| &variant.filename))
+                    })
+                    .collect();
+
+                if all_filenames.iter().all(|filename| self.assets.contains_key(*filename)) {
                     // All asset references have been verified, mark all as used
-                    for asset in all_assets.iter() {
-                        *self.assets.get_mut(&asset.filename).unwrap() = true;
+                    for filename in all_filenames.iter() {
+                        *self.assets.get_mut(*filename).unwrap() = true;
                     }
                 } else {
                     // If a single artist asset is in a corrupt state (cached file missing)
@@ -662,21 +745,36 @@ impl Cache {
             }
 
             if let Some(background_asset) = &image_mut.background_asset {
-                if let Some(used) = self.assets.get_mut(&background_asset.filename) {
-                    *used = true;
+                let all_present = self.assets.contains_key(&background_asset.filename) &&
+                    image_mut.background_variants
+                        .iter()
+                        .all(|variant| self.assets.contains_key(&variant.filename));
+
+                if all_present {
+                    *self.assets.get_mut(&background_asset.filename).unwrap() = true;
+                    for variant in &image_mut.background_variants {
+                        *self.assets.get_mut(&variant.filename).unwrap() = true;
+                    }
                 } else {
                     image_mut.background_asset = None;
+                    image_mut.background_variants.clear();
                     dead_references_removed = true;
                 }
             }
 
             if let Some(cover_assets) = image_mut.cover_assets.as_mut() {
-                let all_assets = cover_assets.all();
-
-                if all_assets.iter().all(|asset| self.assets.contains_key(&asset.filename)) {
+                let all_filenames: Vec<&String> = cover_assets.all()
+                    .into_iter()
+                    .flat_map(|asset| {
+                        std::iter::once(&asset.filename)
+                            .chain(asset.variants.iter().map(|variant| &variant.filename))
+                    })
+                    .collect();
+
+                if all_filenames.iter().all(|filename| self.assets.contains_key(*filename)) {
                     // All asset references have been verified, mark all as used
-                    for asset in all_assets.iter() {
-                        *self.assets.get_mut(&asset.filename).unwrap() = true;
+                    for filename in all_filenames.iter() {
+                        *self.assets.get_mut(*filename).unwrap() = true;
                     }
                 } else {
                     // If a single cover asset is in a corrupt state (cached file missing)
@@ -687,6 +785,29 @@ impl Cache {
                 }
             }
 
+            if let Some(gallery_assets) = image_mut.gallery_assets.as_mut() {
+                let all_filenames: Vec<&String> = gallery_assets.all()
+                    .into_iter()
+                    .flat_map(|asset| {
+                        std::iter::once(&asset.filename)
+                            .chain(asset.variants.iter().map(|variant| &variant.filename))
+                    })
+                    .collect();
+
+                if all_filenames.iter().all(|filename| self.assets.contains_key(*filename)) {
+                    // All asset references have been verified, mark all as used
+                    for filename in all_filenames.iter() {
+                        *self.assets.get_mut(*filename).unwrap() = true;
+                    }
+                } else {
+                    // If a single gallery asset is in a corrupt state (cached file missing)
+                    // we drop all gallery assets, letting them become orphaned so the cache
+                    // removes them afterwards.
+                    image_mut.gallery_assets = None;
+                    dead_references_removed = true;
+                }
+            }
+
             if let Some(feed_asset) = &image_mut.feed_asset {
                 if let Some(used) = self.assets.get_mut(&feed_asset.filename) {
                     *used = true;
@@ -699,7 +820,8 @@ impl Cache {
             if image_mut.artist_assets.is_some() ||
                 image_mut.background_asset.is_some() ||
                 image_mut.cover_assets.is_some() ||
-                image_mut.feed_asset.is_some() {
+                image_mut.feed_asset.is_some() ||
+                image_mut.gallery_assets.is_some() {
                 if dead_references_removed {
                     // Persist corrections so we don't have to re-apply them next time around
                     image_mut.persist_to_cache(&build.cache_dir);
@@ -760,7 +882,10 @@ impl Cache {
             if transcodes_mut.hash.incompatible_version() {
                 match recompute_hash(build, &transcodes_mut.views) {
                     Some(hash) => {
-                        transcodes_mut.hash = hash;
+                        transcodes_mut.hash = match &transcodes_mut.segment {
+                            Some(segment) => hash.for_segment(segment),
+                            None => hash
+                        };
                     }
                     None => {
                         info!(
@@ -907,8 +1032,18 @@ impl Cache {
     ) -> Result<TranscodesRcView, String> {
         let file_meta = FileMeta::new(build, source_path);
 
+        // When only validating the catalog the audio file is neither read
+        // nor decoded, and nothing is kept in the cache.
+        if build.validate_only {
+            let hash = SourceHash::from_file_meta(&file_meta);
+            let transcodes = TranscodesRc::new(file_meta.clone(), hash, None, AudioMeta::unread(extension));
+            return Ok(TranscodesRcView::new(file_meta, transcodes));
+        }
+
+        // Transcodes of segments (see get_or_create_segment_transcodes) have
+        // views on the same file, but are never what we are looking for here.
         for transcodes in &self.transcodes {
-            if transcodes.revive_view(&file_meta) {
+            if transcodes.borrow().segment.is_none() && transcodes.revive_view(&file_meta) {
                 return Ok(TranscodesRcView::new(file_meta, transcodes.clone()));
             }
         }
@@ -927,7 +1062,7 @@ impl Cache {
             Err(err) => return Err(err)
         };
 
-        let transcodes = TranscodesRc::new(file_meta.clone(), hash, source_meta);
+        let transcodes = TranscodesRc::new(file_meta.clone(), hash, None, source_meta);
 
         transcodes.borrow().persist_to_cache(&build.cache_dir);
 
@@ -935,9 +1070,96 @@ impl Cache {
 
         Ok(TranscodesRcView::new(file_meta, transcodes))
     }
+
+    /// Obtain transcodes for each of the given segments of a source file
+    /// (see [crate::CueSheet]), by reviving views or computing new
+    /// transcodes instances. The source meta of the entire file is passed
+    /// in as the basis for the source meta of the segments. The file is
+    /// decoded (at most) once, and only if any segment is not yet cached.
+    pub fn get_or_create_segment_transcodes(
+        &mut self,
+        build: &Build,
+        source_path: &Path,
+        extension: &str,
+        source_meta: &AudioMeta,
+        segments: &[Segment]
+    ) -> Result<Vec<TranscodesRcView>, String> {
+        let file_meta = FileMeta::new(build, source_path);
+
+        if build.validate_only {
+            let hash = SourceHash::from_file_meta(&file_meta);
+
+            let segment_transcodes = segments
+                .iter()
+                .map(|segment| {
+                    let transcodes = TranscodesRc::new(
+                        file_meta.clone(),
+                        hash.for_segment(segment),
+                        Some(segment.clone()),
+                        source_meta.clone()
+                    );
+
+                    TranscodesRcView::new(file_meta.clone(), transcodes)
+                })
+                .collect();
+
+            return Ok(segment_transcodes);
+        }
+
+        let mut decode_result = None;
+        let mut source_hash = None;
+        let mut segment_transcodes = Vec::with_capacity(segments.len());
+
+        'segments: for segment in segments {
+            for transcodes in &self.transcodes {
+                if transcodes.borrow().segment.as_ref() == Some(segment) &&
+                    transcodes.revive_view(&file_meta) {
+                    segment_transcodes.push(TranscodesRcView::new(file_meta.clone(), transcodes.clone()));
+                    continue 'segments;
+                }
+            }
+
+            let hash = source_hash
+                .get_or_insert_with(|| SourceHash::new(&build.catalog_dir.join(source_path)))
+                .for_segment(segment);
+
+            for transcodes in &self.transcodes {
+                if transcodes.matches_hash(&hash) {
+                    transcodes.add_view(&file_meta);
+                    segment_transcodes.push(TranscodesRcView::new(file_meta.clone(), transcodes.clone()));
+                    continue 'segments;
+                }
+            }
+
+            if decode_result.is_none() {
+                info_decoding!("{:?} (Generating waveforms for the tracks of its CUE sheet)", source_path);
+                let absolute_path = build.catalog_dir.join(source_path);
+                decode_result = Some(decode::decode_file(extension, &absolute_path)?);
+            }
+
+            let segment_meta = source_meta.for_segment(decode_result.as_ref().unwrap(), segment);
+
+            let transcodes = TranscodesRc::new(file_meta.clone(), hash, Some(segment.clone()), segment_meta);
+
+            transcodes.borrow().persist_to_cache(&build.cache_dir);
+
+            self.transcodes.push(transcodes.clone());
+
+            segment_transcodes.push(TranscodesRcView::new(file_meta.clone(), transcodes));
+        }
+
+        Ok(segment_transcodes)
+    }
 }
 
 impl CacheOptimization {
+    pub const ALL_MANIFEST_KEYS: [&'static str; 4] = [
+        "delayed",
+        "immediate",
+        "manual",
+        "wipe"
+    ];
+
     pub fn from_manifest_key(key: &str) -> Option<CacheOptimization> {
         match key {
             "delayed" => Some(CacheOptimization::Delayed),
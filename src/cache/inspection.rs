@@ -0,0 +1,467 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Implements the `faircamp cache` subcommands, which list the contents of
+//! the cache, evict least recently used assets to fit the cache within a
+//! size budget, and verify the integrity of the cache.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    Archives,
+    Build,
+    Image,
+    ProceduralCover,
+    Transcodes,
+    View
+};
+use crate::util::{format_bytes, format_time, string_from_os};
+
+use super::{Cache, CACHE_VERSION_MARKER};
+
+/// A group of cached files that are computed, marked stale and removed
+/// together, e.g. a single transcode, or all differently sized versions
+/// of a cover image.
+struct CacheEntry {
+    /// Filenames (in the cache directory) and their recorded size in bytes
+    files: Vec<(String, u64)>,
+    /// E.g. "Opus 128" for a transcode, "Cover" for an image
+    format: String,
+    kind: EntryKind,
+    marked_stale: Option<DateTime<Utc>>,
+    /// The source file(s) the entry was computed from, or a description of
+    /// what it was computed for when there is no single source file (e.g.
+    /// release archives).
+    source: String
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum EntryKind {
+    Archive,
+    Image,
+    ProceduralCover,
+    Transcode
+}
+
+fn archives_entries(archives: &Archives) -> Vec<CacheEntry> {
+    archives.formats
+        .iter()
+        .map(|archive| CacheEntry {
+            files: vec![(archive.asset.filename.clone(), archive.asset.filesize_bytes)],
+            format: archive.format.to_string(),
+            kind: EntryKind::Archive,
+            marked_stale: archive.asset.marked_stale,
+            source: format!("Release archive with signature {}", archives.signature)
+        })
+        .collect()
+}
+
+/// Sums up the size of all files directly inside `dir`
+fn dir_size(dir: &Path) -> u64 {
+    match dir.read_dir() {
+        Ok(dir_entries) => dir_entries
+            .flatten()
+            .filter_map(|dir_entry| dir_entry.metadata().ok())
+            .filter(|metadata| metadata.is_file())
+            .map(|metadata| metadata.len())
+            .sum(),
+        Err(_) => 0
+    }
+}
+
+fn image_entries(image: &Image) -> Vec<CacheEntry> {
+    let source = views_source(&image.views);
+    let mut entries = Vec::new();
+
+    let mut push = |format: &str, files: Vec<(String, u64)>, marked_stale: Option<DateTime<Utc>>| {
+        entries.push(CacheEntry {
+            files,
+            format: format.to_string(),
+            kind: EntryKind::Image,
+            marked_stale,
+            source: source.clone()
+        });
+    };
+
+    if let Some(artist_assets) = &image.artist_assets {
+        let files = artist_assets.all()
+            .into_iter()
+            .flat_map(|asset| {
+                std::iter::once((asset.filename.clone(), asset.filesize_bytes))
+                    .chain(asset.variants.iter().map(|variant| (variant.filename.clone(), variant.filesize_bytes)))
+            })
+            .collect();
+
+        push("Artist", files, artist_assets.marked_stale);
+    }
+
+    if let Some(background_asset) = &image.background_asset {
+        let files = std::iter::once((background_asset.filename.clone(), background_asset.filesize_bytes))
+            .chain(image.background_variants.iter().map(|variant| (variant.filename.clone(), variant.filesize_bytes)))
+            .collect();
+
+        push("Background", files, background_asset.marked_stale);
+    }
+
+    if let Some(cover_assets) = &image.cover_assets {
+        let files = cover_assets.all()
+            .into_iter()
+            .flat_map(|asset| {
+                std::iter::once((asset.filename.clone(), asset.filesize_bytes))
+                    .chain(asset.variants.iter().map(|variant| (variant.filename.clone(), variant.filesize_bytes)))
+            })
+            .collect();
+
+        push("Cover", files, cover_assets.marked_stale);
+    }
+
+    if let Some(feed_asset) = &image.feed_asset {
+        push("Feed", vec![(feed_asset.filename.clone(), feed_asset.filesize_bytes)], feed_asset.marked_stale);
+    }
+
+    if let Some(gallery_assets) = &image.gallery_assets {
+        let files = gallery_assets.all()
+            .into_iter()
+            .flat_map(|asset| {
+                std::iter::once((asset.filename.clone(), asset.filesize_bytes))
+                    .chain(asset.variants.iter().map(|variant| (variant.filename.clone(), variant.filesize_bytes)))
+            })
+            .collect();
+
+        push("Gallery", files, gallery_assets.marked_stale);
+    }
+
+    entries
+}
+
+fn procedural_cover_entry(procedural_cover: &ProceduralCover) -> CacheEntry {
+    let files = [
+        &procedural_cover.asset_120,
+        &procedural_cover.asset_240,
+        &procedural_cover.asset_480,
+        &procedural_cover.asset_720
+    ]
+        .iter()
+        .map(|asset| (asset.filename.clone(), asset.filesize_bytes))
+        .collect();
+
+    CacheEntry {
+        files,
+        format: String::from("Procedural cover"),
+        kind: EntryKind::ProceduralCover,
+        marked_stale: procedural_cover.marked_stale,
+        source: format!("Procedural cover with signature {}", procedural_cover.signature)
+    }
+}
+
+fn transcodes_entries(transcodes: &Transcodes) -> Vec<CacheEntry> {
+    let source = match &transcodes.segment {
+        Some(segment) => format!(
+            "{} (segment starting at {})",
+            views_source(&transcodes.views),
+            format_time(segment.start_seconds())
+        ),
+        None => views_source(&transcodes.views)
+    };
+
+    transcodes.formats
+        .iter()
+        .map(|transcode| CacheEntry {
+            files: vec![(transcode.asset.filename.clone(), transcode.asset.filesize_bytes)],
+            format: transcode.format.to_string(),
+            kind: EntryKind::Transcode,
+            marked_stale: transcode.asset.marked_stale,
+            source: source.clone()
+        })
+        .collect()
+}
+
+fn views_source(views: &[View]) -> String {
+    if views.is_empty() {
+        return String::from("(no known source file)");
+    }
+
+    views
+        .iter()
+        .map(|view| view.file_meta.path.display().to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+impl CacheEntry {
+    fn filesize_bytes(&self) -> u64 {
+        self.files.iter().map(|(_, filesize_bytes)| filesize_bytes).sum()
+    }
+
+    fn status(&self) -> String {
+        match &self.marked_stale {
+            Some(date_time) => format!("unused since {}", date_time.format("%Y-%m-%d %H:%M")),
+            None => String::from("in use")
+        }
+    }
+}
+
+impl EntryKind {
+    const ALL: [EntryKind; 4] = [
+        EntryKind::Transcode,
+        EntryKind::Image,
+        EntryKind::Archive,
+        EntryKind::ProceduralCover
+    ];
+
+    fn heading(&self) -> &'static str {
+        match self {
+            EntryKind::Archive => "Archives",
+            EntryKind::Image => "Images",
+            EntryKind::ProceduralCover => "Procedural covers",
+            EntryKind::Transcode => "Transcodes"
+        }
+    }
+}
+
+impl Cache {
+    fn entries(&self) -> Vec<CacheEntry> {
+        let mut entries = Vec::new();
+
+        for archives in &self.archives {
+            entries.extend(archives_entries(&archives.borrow()));
+        }
+
+        for image in &self.images {
+            entries.extend(image_entries(&image.borrow()));
+        }
+
+        for procedural_cover in &self.procedural_covers {
+            entries.push(procedural_cover_entry(&procedural_cover.borrow()));
+        }
+
+        for transcodes in &self.transcodes {
+            entries.extend(transcodes_entries(&transcodes.borrow()));
+        }
+
+        entries
+    }
+
+    /// Removes the least recently used assets (those that were marked
+    /// stale the longest time ago) until the cache directory fits within
+    /// `budget` bytes. Assets that were used by the last build are never
+    /// removed, as they would only be recomputed by the next build anyway.
+    pub fn evict_to_budget(&mut self, build: &Build, budget: u64) {
+        let total_bytes = dir_size(&build.cache_dir);
+
+        if total_bytes <= budget {
+            info_cache!(
+                "The cache uses {} and fits within the budget of {}, nothing needs to be evicted.",
+                format_bytes(total_bytes),
+                format_bytes(budget)
+            );
+            return;
+        }
+
+        let mut stale_entries: Vec<CacheEntry> = self.entries()
+            .into_iter()
+            .filter(|entry| entry.marked_stale.is_some())
+            .collect();
+
+        stale_entries.sort_by_key(|entry| entry.marked_stale);
+
+        let mut evicted_bytes = 0;
+
+        // Assets are marked stale with the begin time of the build in which
+        // they were first unused, so entries share timestamps build-wise and
+        // we can only evict all entries up to and including a timestamp.
+        for entry in &stale_entries {
+            if total_bytes - evicted_bytes <= budget && self.evict_stale_until != entry.marked_stale {
+                break;
+            }
+
+            evicted_bytes += entry.filesize_bytes();
+            self.evict_stale_until = entry.marked_stale;
+        }
+
+        if self.evict_stale_until.is_some() {
+            self.maintain(build);
+        }
+
+        let remaining_bytes = dir_size(&build.cache_dir);
+
+        if remaining_bytes > budget {
+            warn!(
+                "The cache still uses {} after evicting all unused assets, which exceeds the budget of {}. The remaining assets were used by the last build and are not evicted (they would only be recomputed by the next build).",
+                format_bytes(remaining_bytes),
+                format_bytes(budget)
+            );
+        } else {
+            info_cache!(
+                "Evicted least recently used assets, the cache now uses {} (budget {}).",
+                format_bytes(remaining_bytes),
+                format_bytes(budget)
+            );
+        }
+    }
+
+    /// Prints all cached assets grouped by kind (transcodes, images,
+    /// archives, procedural covers), by source file and format, with their
+    /// sizes and whether they were used by the last build.
+    pub fn list(&self, build: &Build) {
+        let mut entries = self.entries();
+
+        entries.sort_by(|a, b| a.source.cmp(&b.source).then_with(|| a.format.cmp(&b.format)));
+
+        for kind in EntryKind::ALL {
+            let kind_entries: Vec<&CacheEntry> = entries
+                .iter()
+                .filter(|entry| entry.kind == kind)
+                .collect();
+
+            if kind_entries.is_empty() {
+                continue;
+            }
+
+            let kind_bytes: u64 = kind_entries.iter().map(|entry| entry.filesize_bytes()).sum();
+
+            println!("{} ({} entries, {})", kind.heading(), kind_entries.len(), format_bytes(kind_bytes));
+
+            for entry in kind_entries {
+                println!(
+                    "  {} | {} | {} | {}",
+                    entry.source,
+                    entry.format,
+                    format_bytes(entry.filesize_bytes()),
+                    entry.status()
+                );
+            }
+
+            println!();
+        }
+
+        let stale_bytes: u64 = entries
+            .iter()
+            .filter(|entry| entry.marked_stale.is_some())
+            .map(|entry| entry.filesize_bytes())
+            .sum();
+
+        println!(
+            "Total: {} on disk ({} in assets unused by the last build)",
+            format_bytes(dir_size(&build.cache_dir)),
+            format_bytes(stale_bytes)
+        );
+    }
+
+    /// Checks the cache directory for problems without modifying anything:
+    /// Manifests that can not be deserialized (or stem from a different
+    /// faircamp version), referenced files that are missing or whose size
+    /// does not match the recorded size, and files that no manifest refers
+    /// to. Returns true if no problems were found.
+    pub fn verify(build: &Build) -> bool {
+        let cache_dir = &build.cache_dir;
+
+        if !cache_dir.exists() {
+            info_cache!("There is no cache directory at {}, nothing to verify.", cache_dir.display());
+            return true;
+        }
+
+        let mut problems = Vec::new();
+
+        if !cache_dir.join(CACHE_VERSION_MARKER).exists() {
+            problems.push(format!("The cache version marker ({CACHE_VERSION_MARKER}) is missing, the next build will purge and regenerate the entire cache."));
+        }
+
+        let mut asset_files = HashSet::new();
+        let mut manifests = Vec::new();
+
+        let dir_entries = match cache_dir.read_dir() {
+            Ok(dir_entries) => dir_entries,
+            Err(err) => {
+                error!("Could not read the cache directory {} ({})", cache_dir.display(), err);
+                return false;
+            }
+        };
+
+        for dir_entry in dir_entries.flatten() {
+            let file_name = string_from_os(dir_entry.file_name());
+
+            if dir_entry.path().is_dir() {
+                problems.push(format!("Unexpected directory {file_name} (will be removed by the next build)"));
+            } else if file_name.ends_with(".bincode") {
+                manifests.push(file_name);
+            } else if file_name != CACHE_VERSION_MARKER {
+                asset_files.insert(file_name);
+            }
+        }
+
+        manifests.sort();
+
+        let mut referenced_files = HashSet::new();
+
+        for file_name in &manifests {
+            let manifest_path = cache_dir.join(file_name);
+
+            let entries = if file_name.ends_with(&format!(".{}.bincode", Archives::CACHE_SERIALIZATION_KEY)) {
+                Archives::deserialize_cached(&manifest_path).map(|archives| archives_entries(&archives))
+            } else if file_name.ends_with(&format!(".{}.bincode", Image::CACHE_SERIALIZATION_KEY)) {
+                Image::deserialize_cached(&manifest_path).map(|image| image_entries(&image))
+            } else if file_name.ends_with(&format!(".{}.bincode", ProceduralCover::CACHE_SERIALIZATION_KEY)) {
+                ProceduralCover::deserialize_cached(&manifest_path).map(|procedural_cover| vec![procedural_cover_entry(&procedural_cover)])
+            } else if file_name.ends_with(&format!(".{}.bincode", Transcodes::CACHE_SERIALIZATION_KEY)) {
+                Transcodes::deserialize_cached(&manifest_path).map(|transcodes| transcodes_entries(&transcodes))
+            } else {
+                problems.push(format!("Manifest {file_name} has an unknown serialization key, it was probably created with a different version of faircamp (will be removed by the next build)"));
+                continue;
+            };
+
+            let Some(entries) = entries else {
+                problems.push(format!("Manifest {file_name} can not be deserialized, it is corrupted (will be removed by the next build)"));
+                continue;
+            };
+
+            for entry in entries {
+                for (filename, filesize_bytes) in &entry.files {
+                    referenced_files.insert(filename.clone());
+
+                    match fs::metadata(cache_dir.join(filename)) {
+                        Ok(metadata) => {
+                            if metadata.len() != *filesize_bytes {
+                                problems.push(format!(
+                                    "File {filename} ({} for {}) has a size of {} bytes, but {} bytes were recorded in manifest {file_name}, it is possibly corrupted",
+                                    entry.format,
+                                    entry.source,
+                                    metadata.len(),
+                                    filesize_bytes
+                                ));
+                            }
+                        }
+                        Err(_) => problems.push(format!(
+                            "File {filename} ({} for {}) referenced in manifest {file_name} is missing (will be recomputed when needed)",
+                            entry.format,
+                            entry.source
+                        ))
+                    }
+                }
+            }
+        }
+
+        let mut orphaned_files: Vec<&String> = asset_files.difference(&referenced_files).collect();
+        orphaned_files.sort();
+
+        for file_name in orphaned_files {
+            problems.push(format!("File {file_name} is not referenced by any manifest (will be removed by the next build)"));
+        }
+
+        if problems.is_empty() {
+            info_cache!("Verified {} manifests and {} files, no problems found.", manifests.len(), asset_files.len());
+            true
+        } else {
+            for problem in &problems {
+                warn!("{}", problem);
+            }
+
+            error!("Found {} problems in the cache at {}", problems.len(), cache_dir.display());
+            false
+        }
+    }
+}
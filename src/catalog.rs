@@ -16,24 +16,35 @@ use crate::{
     AssetIntent,
     Build,
     Cache,
+    CueSheet,
     DescribedImage,
+    DownloadAccess,
     Extra,
     FairDir,
     Favicon,
     FeedImageAsset,
     Feeds,
     FileMeta,
+    GalleryAssets,
     HeuristicAudioMeta,
     HtmlAndStripped,
     ImageRcView,
     Link,
+    Page,
+    Permalink,
     PermalinkUsage,
+    Playlist,
+    PlaylistEntry,
+    Post,
     ProceduralCover,
     ProceduralCoverAsset,
+    Product,
     Release,
     ReleaseRc,
     SiteAsset,
     SiteMetadata,
+    SiteUrl,
+    TagAgenda,
     TagMapping,
     Theme,
     Track,
@@ -48,6 +59,9 @@ const PERMALINK_CONFLICT_RESOLUTION_HINT: &str = "In order to resolve the confli
 
 #[derive(Debug)]
 pub struct Catalog {
+    /// The preferred username (e.g. "band" in @band@example.com) under which
+    /// a static ActivityPub actor for the catalog is published, if enabled.
+    pub activitypub: Option<String>,
     /// Stores the primary artist for "single artist" catalogs
     pub artist: Option<ArtistRc>,
     /// All artists (main_artists + support_artists)
@@ -62,18 +76,41 @@ pub struct Catalog {
     pub featured_artists: Vec<ArtistRc>,
     pub feeds: Feeds,
     pub home_image: Option<DescribedImage>,
+    /// Whether the player responds to the global keyboard shortcuts
+    /// (space, j/k/l, number keys) documented in the manual.
+    pub keyboard_shortcuts: bool,
     pub label_mode: bool,
     pub links: Vec<Link>,
     /// Whether an m3u playlist should be generated and provided for the entire catalog
     pub m3u: bool,
     pub main_artists: Vec<ArtistRc>,
+    /// Same as news_permalink, but for the merch page, which lists all
+    /// products of the catalog and its (public) releases. None if there
+    /// are no products.
+    pub merch_permalink: Option<String>,
     pub more: Option<HtmlAndStripped>,
     /// Optional custom label for the button that (by default) says "More" on the
     /// catalog homepage and points to additional long-form content for the catalog.
     pub more_label: Option<String>,
+    /// The news page listing all (public) posts competes with all other
+    /// permalinks, so just like for the subscribe page we determine a
+    /// conflict-free permalink for it at run-time. None if there are no
+    /// public posts (in which case there is no news page).
+    pub news_permalink: Option<String>,
     /// Whether to include Open Graph metadata tags on all major pages (pages not intended
     /// for sharing generally don't render Open graph tags)
     pub opengraph: bool,
+    /// Standalone pages (e.g. About, Imprint), each defined through a
+    /// page.eno manifest
+    pub pages: Vec<Page>,
+    /// Curated playlists, each defined through a playlist.eno manifest
+    pub playlists: Vec<Playlist>,
+    /// News/blog posts, each defined through a post.eno manifest, ordered
+    /// from newest to oldest
+    pub posts: Vec<Post>,
+    /// Physical products (merch) not tied to a specific release, defined
+    /// in the catalog manifest
+    pub products: Vec<Product>,
     pub releases: Vec<ReleaseRc>,
     pub show_support_artists: bool,
     /// Files specified through the site_assets option that are meant to be
@@ -84,6 +121,13 @@ pub struct Catalog {
     /// specified through the site_metadata option that is injected into the
     /// <head>…</head> section on all rendered pages.
     pub site_metadata: Option<SiteMetadata>,
+    /// Whether pages are swapped in place (fetching them in the background)
+    /// when following internal links, which keeps the docked player (and
+    /// with it playback) alive across pages and enables the listening queue.
+    pub soft_navigation: bool,
+    /// Where the site reports plays and downloads to (usually a server
+    /// started with `faircamp stats-server`), if anywhere.
+    pub stats_endpoint: Option<SiteUrl>,
     /// The page presenting subscription choices for the catalog competes with all
     /// artist+release permalinks, therefore we do a run-time computation to
     /// determine a conflict-free permalink for it (which starts with our
@@ -93,7 +137,24 @@ pub struct Catalog {
     pub support_artists: Vec<ArtistRc>,
     pub synopsis: Option<String>,
     pub theme: Theme,
-    title: Option<String>
+    title: Option<String>,
+    /// Whether static waveform images (SVG/PNG) should be generated for each
+    /// release and track, which are then also used for Open Graph previews
+    /// (where there's no cover) and in the media rss feed.
+    pub waveform_images: bool,
+    /// Whether the site can be installed as a web app (through a web app
+    /// manifest) and releases can be saved for offline listening (through a
+    /// service worker).
+    pub web_app: bool
+}
+
+/// The kinds of directories that hold a manifest but no audio, see
+/// [Catalog::read_content_dir].
+#[derive(Clone, Copy)]
+enum ContentKind {
+    Page,
+    Playlist,
+    Post
 }
 
 /// Gets passed the images found in a release directory. Checks against a few
@@ -122,7 +183,31 @@ fn pick_best_cover_image(images: &[ImageRcView]) -> Option<DescribedImage> {
     }
 
     cover_candidate_option
-        .map(|cover_candidate| DescribedImage::new(None, cover_candidate.1.clone()))
+        .map(|cover_candidate| DescribedImage::new(None, None, cover_candidate.1.clone()))
+}
+
+/// Marks the permalink as used by the given release, playlist, page or post,
+/// unless it is already used by something else, in which case the conflict
+/// is reported as an error. Returns whether the permalink was free.
+fn register_permalink<'a>(
+    build: &mut Build,
+    used_permalinks: &mut HashMap<String, PermalinkUsage<'a>>,
+    permalink: &Permalink,
+    usage: PermalinkUsage<'a>
+) -> bool {
+    if let Some(previous_usage) = used_permalinks.get(&permalink.slug) {
+        let generated_or_assigned = permalink.generated_or_assigned_str();
+        let slug = &permalink.slug;
+        let subject = usage.subject();
+        let previous_usage_formatted = previous_usage.as_string();
+        let error = format!("The {generated_or_assigned} permalink '{slug}' of the {subject} conflicts with another one:\n{previous_usage_formatted}\n{PERMALINK_CONFLICT_RESOLUTION_HINT}");
+        build.error(&error);
+        return false;
+    }
+
+    used_permalinks.insert(permalink.slug.to_string(), usage);
+
+    true
 }
 
 // TODO: Optimize this (and also the related mechanism in styles.rs).
@@ -132,12 +217,12 @@ fn pick_best_cover_image(images: &[ImageRcView]) -> Option<DescribedImage> {
 pub fn write_background_image(build: &mut Build, image: &ImageRcView) {
     let mut image_mut = image.borrow_mut();
     let source_path = &image.file_meta.path;
-    let background_asset = image_mut.background_asset(build, source_path);
+    let background_asset = image_mut.background_asset(build, None, source_path);
 
     let hashed_filename = format!("background-{}.jpg", url_safe_hash_base64(&background_asset.filename));
     let hashed_path = build.build_dir.join(&hashed_filename);
 
-    build.reserve_filename(hashed_filename);
+    build.reserve_filename(hashed_filename.clone());
 
     if !hashed_path.exists() {
         util::hard_link_or_copy(
@@ -147,10 +232,55 @@ pub fn write_background_image(build: &mut Build, image: &ImageRcView) {
 
         build.stats.add_image(background_asset.filesize_bytes);
 
+        for variant in &image_mut.background_variants {
+            let variant_filename = variant.target_filename(&hashed_filename);
+
+            util::hard_link_or_copy(
+                build.cache_dir.join(&variant.filename),
+                build.build_dir.join(&variant_filename)
+            );
+
+            build.reserve_filename(variant_filename);
+            build.stats.add_image(variant.filesize_bytes);
+        }
+
         image_mut.persist_to_cache(&build.cache_dir);
     }
 }
 
+/// Writes the images of the given products (if they have one) to the given
+/// directory, numbered by the position of the product (see
+/// [GalleryAssets::product_target_filename]).
+fn write_product_images(build: &mut Build, dir: &Path, products: &[Product]) {
+    for (product, number) in products.iter().zip(1..) {
+        if let Some(described_image) = &product.image {
+            let mut image_mut = described_image.borrow_mut();
+            let source_path = &described_image.file_meta.path;
+            let gallery_assets = image_mut.gallery_assets(build, described_image.crop, source_path);
+            let asset = &gallery_assets.thumbnail;
+            let target_filename = GalleryAssets::product_target_filename(number);
+
+            util::hard_link_or_copy(
+                build.cache_dir.join(&asset.filename),
+                dir.join(&target_filename)
+            );
+
+            build.stats.add_image(asset.filesize_bytes);
+
+            for variant in &asset.variants {
+                util::hard_link_or_copy(
+                    build.cache_dir.join(&variant.filename),
+                    dir.join(variant.target_filename(&target_filename))
+                );
+
+                build.stats.add_image(variant.filesize_bytes);
+            }
+
+            image_mut.persist_to_cache(&build.cache_dir);
+        }
+    }
+}
+
 impl Catalog {
     /// Use the metadata we gathered for tracks and releases to compute
     /// the folder and file names we are going to create in our build
@@ -197,26 +327,77 @@ impl Catalog {
         }
     }
 
-    /// If the subscribe page permalink we have in our translations collides with
-    /// any of the artist or release permalinks, we prepend underscores to it
+    /// Same as compute_subscribe_permalink, but for the merch page, which is
+    /// only created if there are products (in the catalog or on public
+    /// releases).
+    fn compute_merch_permalink(&mut self, build: &Build) {
+        if !self.has_merch() {
+            return;
+        }
+
+        let mut merch_slug = build.locale.translations.merch_permalink.to_string();
+
+        while self.permalink_taken(&merch_slug) ||
+            self.news_permalink.as_ref().is_some_and(|news_slug| *news_slug == merch_slug) {
+            merch_slug = format!("_{merch_slug}");
+        }
+
+        self.merch_permalink = Some(merch_slug);
+    }
+
+    /// Same as compute_subscribe_permalink, but for the news page, which is
+    /// only created if there are public posts.
+    fn compute_news_permalink(&mut self, build: &Build) {
+        if self.public_posts().is_empty() {
+            return;
+        }
+
+        let mut news_slug = build.locale.translations.news_permalink.to_string();
+
+        while self.permalink_taken(&news_slug) {
+            news_slug = format!("_{news_slug}");
+        }
+
+        self.news_permalink = Some(news_slug);
+    }
+
+    /// If the subscribe page permalink we have in our translations collides
+    /// with any of the artist, page, playlist, post or release permalinks (or
+    /// the merch and news page permalinks), we prepend underscores to it
     /// until there is no collision anymore.
     fn compute_subscribe_permalink(&mut self, build: &Build) {
         let mut subscribe_slug = build.locale.translations.subscribe_permalink.to_string();
 
-        while self.featured_artists.iter().any(|artist| artist.borrow().permalink.slug == subscribe_slug) ||
-            self.releases.iter().any(|release| release.borrow().permalink.slug == subscribe_slug) {
+        while self.permalink_taken(&subscribe_slug) ||
+            self.merch_permalink.as_ref().is_some_and(|merch_slug| *merch_slug == subscribe_slug) ||
+            self.news_permalink.as_ref().is_some_and(|news_slug| *news_slug == subscribe_slug) {
             subscribe_slug = format!("_{subscribe_slug}");
         }
 
         self.subscribe_permalink = Some(subscribe_slug);
     }
 
-    pub fn get_or_create_release_archives(&mut self, cache: &mut Cache) {
+    /// Public artists that have events, these are aggregated on the
+    /// homepage and in the catalog's calendar file.
+    pub fn event_artists(&self) -> Vec<ArtistRc> {
+        self.public_artists()
+            .into_iter()
+            .filter(|artist| !artist.borrow().events.is_empty())
+            .collect()
+    }
+
+    pub fn get_or_create_release_archives(&mut self, build: &Build, cache: &mut Cache) {
         for release in self.releases.iter_mut() {
-            release.borrow_mut().get_or_create_release_archives(cache);
+            release.borrow_mut().get_or_create_release_archives(build, cache);
         }
     }
 
+    /// Whether there are any products to show on the merch page
+    pub fn has_merch(&self) -> bool {
+        !self.products.is_empty() ||
+        self.public_releases().iter().any(|release| !release.borrow().products.is_empty())
+    }
+
     /// For each release goes through the following mappings:
     /// - main_artists_to_map
     /// - support_artists_to_map
@@ -341,8 +522,69 @@ impl Catalog {
         }
     }
 
+    /// Resolves the track references of all playlists (which are only
+    /// names at the time the playlist manifests are read) to the actual
+    /// tracks of releases in the catalog. References that can not be
+    /// resolved are reported and left out of the playlist.
+    fn map_playlists(&mut self, build: &mut Build) {
+        for playlist in self.playlists.iter_mut() {
+            for track_reference in playlist.track_references.drain(..) {
+                let release_slug = &track_reference.release_slug;
+                let track_number = track_reference.track_number;
+
+                let Some(release) = self.releases
+                    .iter()
+                    .find(|release| release.borrow().permalink.slug == *release_slug) else {
+                    let error = format!("The playlist '{}' references the track '{release_slug}/{track_number}', but there is no release with the permalink '{release_slug}' in the catalog - the track is left out of the playlist", playlist.title);
+                    build.error(&error);
+                    continue;
+                };
+
+                let release_ref = release.borrow();
+
+                match release_ref.tracks.get(track_number - 1) {
+                    Some(track) if release_ref.track_locked(track) => {
+                        let warning = format!("The playlist '{}' references the track '{release_slug}/{track_number}', which is not yet published - the track is left out of the playlist until the release is out", playlist.title);
+                        build.warning(&warning);
+                    }
+                    Some(_) => {
+                        playlist.entries.push(PlaylistEntry {
+                            release: release.clone(),
+                            track_number
+                        });
+                    }
+                    None => {
+                        let error = format!("The playlist '{}' references the track '{release_slug}/{track_number}', but the release '{}' only has {} tracks - the track is left out of the playlist", playlist.title, release_ref.title, release_ref.tracks.len());
+                        build.error(&error);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Resolves the release references of all posts (which are only
+    /// permalinks at the time the post manifests are read) to the actual
+    /// releases in the catalog. References that can not be resolved are
+    /// reported and left out.
+    fn map_posts(&mut self, build: &mut Build) {
+        for post in self.posts.iter_mut() {
+            for release_slug in post.release_references.drain(..) {
+                match self.releases
+                    .iter()
+                    .find(|release| release.borrow().permalink.slug == release_slug) {
+                    Some(release) => post.releases.push(release.clone()),
+                    None => {
+                        let error = format!("The post '{}' references the release '{release_slug}', but there is no release with that permalink in the catalog - the reference is left out of the post", post.title);
+                        build.error(&error);
+                    }
+                }
+            }
+        }
+    }
+
     pub fn new() -> Catalog {
         Catalog {
+            activitypub: None,
             artist: None,
             artists: Vec::new(),
             copy_link: true,
@@ -352,25 +594,46 @@ impl Catalog {
             featured_artists: Vec::new(),
             feeds: Feeds::DEFAULT,
             home_image: None,
+            keyboard_shortcuts: true,
             label_mode: false,
             links: Vec::new(),
             m3u: false,
             main_artists: Vec::new(),
+            merch_permalink: None,
             more: None,
             more_label: None,
+            news_permalink: None,
             opengraph: false,
+            pages: Vec::new(),
+            playlists: Vec::new(),
+            posts: Vec::new(),
+            products: Vec::new(),
             releases: Vec::new(),
             show_support_artists: false,
             site_assets: Vec::new(),
             site_metadata: None,
+            soft_navigation: false,
+            stats_endpoint: None,
             subscribe_permalink: None,
             support_artists: Vec::new(),
             synopsis: None,
             theme: Theme::new(),
-            title: None
+            title: None,
+            waveform_images: false,
+            web_app: false
         }
     }
 
+    /// Whether any artist, page, playlist, post or release already uses
+    /// the given permalink slug.
+    fn permalink_taken(&self, slug: &str) -> bool {
+        self.featured_artists.iter().any(|artist| artist.borrow().permalink.slug == slug) ||
+        self.pages.iter().any(|page| page.permalink.slug == slug) ||
+        self.playlists.iter().any(|playlist| playlist.permalink.slug == slug) ||
+        self.posts.iter().any(|post| post.permalink.slug == slug) ||
+        self.releases.iter().any(|release| release.borrow().permalink.slug == slug)
+    }
+
     /// All artists in the catalog that are not unlisted
     pub fn public_artists(&self) -> Vec<ArtistRc> {
         self.artists
@@ -384,6 +647,14 @@ impl Catalog {
             .collect()
     }
 
+    /// All posts that are not unlisted, newest first
+    pub fn public_posts(&self) -> Vec<&Post> {
+        self.posts
+            .iter()
+            .filter(|post| !post.unlisted)
+            .collect()
+    }
+
     /// All releases in the catalog that are not unlisted
     pub fn public_releases(&self) -> Vec<ReleaseRc> {
         self.releases
@@ -397,12 +668,21 @@ impl Catalog {
             .collect()
     }
 
+    /// All public releases that are fully published, i.e. without upcoming
+    /// releases, which are only announced (e.g. in feeds) once they are out.
+    pub fn published_releases(&self) -> Vec<ReleaseRc> {
+        self.public_releases()
+            .into_iter()
+            .filter(|release| release.borrow().upcoming.is_none())
+            .collect()
+    }
+
     pub fn read(build: &mut Build, cache: &mut Cache) -> Result<Catalog, ()> {
         let mut catalog = Catalog::new();
 
         catalog.read_catalog_dir(build, cache);
 
-        if build.errors > 0 && !build.ignore_errors {
+        if build.errors > 0 && !build.ignore_errors && !build.validate_only {
             info!("Build was aborted because {} errors were encountered while reading the catalog.", build.errors);
             info!("You can run faircamp with --ignore-errors if you want to build in spite of errors.");
             return Err(());
@@ -411,16 +691,25 @@ impl Catalog {
         if catalog.releases.iter().any(|release| {
             let release_ref = release.borrow();
             release_ref.embedding || release_ref.tracks.iter().any(|track| track.embedding)
-        }) {
+        }) || catalog.playlists.iter().any(|playlist| playlist.embedding) {
             build.embeds_requested = true;
         }
 
         if catalog.home_image.as_ref().is_some_and(|described_image| described_image.description.is_none()) {
-            warn_discouraged!("The catalog home image is missing an image description.");
-            build.missing_image_descriptions = true;
+            build.missing_image_description("The catalog home image is missing an image description.");
         }
 
         catalog.map_artists();
+        catalog.map_playlists(build);
+        catalog.map_posts(build);
+
+        // Directories are read in no particular order, sorting pages by their
+        // directory gives the header menu a stable order that can be
+        // controlled through directory names (e.g. "1 About", "2 Tour").
+        catalog.pages.sort_by(|a, b| a.source_dir.cmp(&b.source_dir));
+
+        // Newest posts first, for the news page and the feeds
+        catalog.posts.sort_by(|a, b| b.date.cmp(&a.date));
 
         if catalog.label_mode {
             for main_artist in &catalog.main_artists {
@@ -448,33 +737,40 @@ impl Catalog {
             for artist in &catalog.featured_artists {
                 let artist_ref = artist.borrow();
                 if artist_ref.image.as_ref().is_some_and(|described_image| described_image.description.is_none()) {
-                    warn_discouraged!("The image for artist '{}' is missing an image description.", artist_ref.name);
-                    build.missing_image_descriptions = true;
+                    let message = format!("The image for artist '{}' is missing an image description.", artist_ref.name);
+                    build.missing_image_description(&message);
                 }
             }
         } else {
             catalog.set_artist();
         }
 
-        catalog.get_or_create_release_archives(cache);
+        catalog.get_or_create_release_archives(build, cache);
 
         if !catalog.validate_permalinks(build) {
-            warn!("The build has been aborted because permalink conflicts were found, this kind of error needs to be resolved and cannot be ignored.");
+            if !build.validate_only {
+                warn!("The build has been aborted because permalink conflicts were found, this kind of error needs to be resolved and cannot be ignored.");
+            }
             return Err(());
         }
 
         if let Some(site_metadata) = &mut catalog.site_metadata {
             if let Err(missing_filenames) = site_metadata.resolve_filename_references(&catalog.site_assets) {
                 for filename in &missing_filenames {
-                    error!("The filename reference {{{}}} inside site_metadata could not be resolved.", filename)
+                    let error = format!("The filename reference {{{filename}}} inside site_metadata could not be resolved.");
+                    build.error(&error);
                 }
 
-                warn!("The build has been aborted because {} filenames in site_metadata could not be resolved, this kind of error needs to be resolved and cannot be ignored.", missing_filenames.len());
+                if !build.validate_only {
+                    warn!("The build has been aborted because {} filenames in site_metadata could not be resolved, this kind of error needs to be resolved and cannot be ignored.", missing_filenames.len());
+                }
                 return Err(());
             }
         }
 
         catalog.compute_asset_basenames();
+        catalog.compute_news_permalink(build);
+        catalog.compute_merch_permalink(build);
         catalog.compute_subscribe_permalink(build);
 
         catalog.unlist_artists();
@@ -531,6 +827,21 @@ impl Catalog {
             build.error(&error);
         }
 
+        if fair_dir.page_manifest.is_some() {
+            let error = format!("A page.eno manifest may not be placed at the root of the catalog directory, however it was found there (at '{}'). Please move it into its own (page) directory", build.catalog_dir.display());
+            build.error(&error);
+        }
+
+        if fair_dir.post_manifest.is_some() {
+            let error = format!("A post.eno manifest may not be placed at the root of the catalog directory, however it was found there (at '{}'). Please move it into its own (post) directory", build.catalog_dir.display());
+            build.error(&error);
+        }
+
+        if fair_dir.playlist_manifest.is_some() {
+            let error = format!("A playlist.eno manifest may not be placed at the root of the catalog directory, however it was found there (at '{}'). Please move it into its own (playlist) directory", build.catalog_dir.display());
+            build.error(&error);
+        }
+
         if fair_dir.track_manifest.is_some() {
             let error = format!("A track.eno manifest may not be placed at the root of the catalog directory, however it was found there (at '{}'). Please move it into its own (track) directory", build.catalog_dir.display());
             build.error(&error);
@@ -579,6 +890,10 @@ impl Catalog {
             self.links = local_options.links;
         }
 
+        if !local_options.products.is_empty() {
+            self.products = local_options.products;
+        }
+
         if local_options.synopsis.is_some() {
             self.synopsis = local_options.synopsis;
         }
@@ -590,6 +905,49 @@ impl Catalog {
         }
     }
 
+    /// Reads a directory with a page.eno, playlist.eno or post.eno manifest.
+    fn read_content_dir(
+        &mut self,
+        build: &mut Build,
+        cache: &mut Cache,
+        fair_dir: FairDir,
+        parent_overrides: &Overrides,
+        kind: ContentKind
+    ) {
+        let label = kind.label();
+
+        if !fair_dir.audio_files.is_empty() {
+            let audio_files_hint = kind.audio_files_hint();
+            let error = format!("Audio files were encountered in the {label} directory '{}' but will be ignored - {audio_files_hint}", fair_dir.path.display());
+            build.error(&error);
+        }
+
+        let manifest_path = kind.manifest_path(&fair_dir).unwrap();
+
+        let mut overrides = parent_overrides.clone();
+
+        if build.verbose {
+            info!("Reading {} manifest {}", label, manifest_path.display());
+        }
+        let read_manifest = match kind {
+            ContentKind::Page => manifest::read_page_manifest,
+            ContentKind::Playlist => manifest::read_playlist_manifest,
+            ContentKind::Post => manifest::read_post_manifest
+        };
+        read_manifest(
+            build,
+            cache,
+            self,
+            &fair_dir.path,
+            manifest_path,
+            &mut overrides
+        );
+
+        for dir_path in &fair_dir.dirs {
+            self.read_unknown_dir(build, cache, &overrides, dir_path);
+        }
+    }
+
     fn read_release_dir(
         &mut self,
         build: &mut Build,
@@ -663,7 +1021,41 @@ impl Catalog {
                 continue;
             }
 
-            warn!("Ignoring release subdirectory '{}' - if you meant to make it a track directory it must contain exactly one audio file (plus a track.eno manifest and auxiliary files potentially)", fair_subdir.path.display());
+            let warning = format!("Ignoring release subdirectory '{}' - if you meant to make it a track directory it must contain exactly one audio file (plus a track.eno manifest and auxiliary files potentially)", fair_subdir.path.display());
+            build.warning(&warning);
+        }
+
+        // A single audio file accompanied by a CUE sheet is split into
+        // tracks along the CUE sheet, CUE sheets in any other constellation
+        // are just treated as extras.
+        let mut cue_extra_files = Vec::new();
+        let mut cue_sheet = None;
+
+        match (fair_dir.audio_files.as_slice(), fair_dir.cue_files.as_slice()) {
+            (_, []) => (),
+            ([audio_path], [cue_path]) => match CueSheet::read(cue_path) {
+                Ok(parsed) => {
+                    if let Some(file) = &parsed.file {
+                        let audio_filename = audio_path.file_name().unwrap().to_string_lossy();
+                        if *file != audio_filename {
+                            let warning = format!("The CUE sheet {} refers to the audio file '{file}', but the audio file next to it is '{audio_filename}' - faircamp assumes the CUE sheet describes '{audio_filename}'", cue_path.display());
+                            build.warning(&warning);
+                        }
+                    }
+
+                    cue_sheet = Some(parsed);
+                }
+                Err(err) => {
+                    let error = format!("The CUE sheet {} could not be read ({err}), the audio file next to it is presented as a single track instead", cue_path.display());
+                    build.error(&error);
+                    cue_extra_files.push(cue_path.clone());
+                }
+            }
+            (_, cue_paths) => {
+                let warning = format!("Not splitting any audio files along the CUE sheet(s) in '{}' - this is only done when the directory contains exactly one CUE sheet and one audio file", fair_dir.path.display());
+                build.warning(&warning);
+                cue_extra_files.extend_from_slice(cue_paths);
+            }
         }
 
         for audio_path in &fair_dir.audio_files {
@@ -683,6 +1075,65 @@ impl Catalog {
                 }
             };
 
+            if let Some(cue_sheet) = &cue_sheet {
+                let duration_ms = (transcodes.borrow().source_meta.duration_seconds * 1000.0) as u64;
+
+                // When only validating, the audio file is not decoded and
+                // its duration therefore unknown.
+                let track_after_end = match build.validate_only {
+                    true => None,
+                    false => cue_sheet.tracks.iter().find(|cue_track| cue_track.start_ms >= duration_ms)
+                };
+
+                if let Some(cue_track) = track_after_end {
+                    let error = format!("Track {} of the CUE sheet for {} starts after the end of the audio file, the audio file is presented as a single track instead", cue_track.number, path_relative_to_catalog.display());
+                    build.error(&error);
+                } else {
+                    let source_meta = transcodes.borrow().source_meta.clone();
+
+                    let segment_transcodes = match cache.get_or_create_segment_transcodes(
+                        build,
+                        path_relative_to_catalog,
+                        &extension,
+                        &source_meta,
+                        &cue_sheet.segments()
+                    ) {
+                        Ok(segment_transcodes) => segment_transcodes,
+                        Err(err) => {
+                            let error = format!("Skipping track {} due to decoding error ({err})", path_relative_to_catalog.display());
+                            build.error(&error);
+                            continue;
+                        }
+                    };
+
+                    for (cue_track, segment_transcodes) in cue_sheet.tracks.iter().zip(segment_transcodes) {
+                        let mut local_options = LocalOptions::new();
+
+                        local_options.title = cue_track.title.clone();
+
+                        let mut track = self.read_track(
+                            None,
+                            Vec::new(),
+                            local_options,
+                            finalized_overrides,
+                            segment_transcodes
+                        );
+
+                        if finalized_overrides.track_artists.is_empty() {
+                            if let Some(performer) = cue_track.performer.as_ref().or(cue_sheet.performer.as_ref()) {
+                                track.artists_to_map = vec![performer.clone()];
+                            }
+                        }
+
+                        track.continuous_source = Some(transcodes.clone());
+
+                        release_tracks.push(track);
+                    }
+
+                    continue;
+                }
+            }
+
             let track = self.read_track(
                 None,
                 Vec::new(),
@@ -709,7 +1160,11 @@ impl Catalog {
                 })
                 .collect();
 
-            HeuristicAudioMeta::compute(&mut release_tracks);
+            // Tracks split along a CUE sheet all share the same file name,
+            // which is therefore of no use for heuristics.
+            if cue_sheet.is_none() {
+                HeuristicAudioMeta::compute(&mut release_tracks);
+            }
 
             // TODO: Print warning if all tracks have track numbers as tags but they don't start at 0/1 and don't increase monotonically
             // TODO: Print warning if only some tracks have track numbers as tags
@@ -741,7 +1196,7 @@ impl Catalog {
             let mut support_artists_to_map: Vec<String> = Vec::new();
 
             // This sets main_artists_to_map and support_artists_to_map in
-            // one of three ways, see comments in branches
+            // one of four ways, see comments in branches
             if !finalized_overrides.release_artists.is_empty() {
                 // Here, main_artists_to_map is set manually through manifest metadata.
                 for artist_name in &finalized_overrides.release_artists {
@@ -751,6 +1206,18 @@ impl Catalog {
                 // All artists that were associated with a track but not
                 // manually set as main_artists_to_map are now added as
                 // support_artists_to_map.
+                for release_track in &release_tracks {
+                    for track_artist_to_map in &release_track.artists_to_map {
+                        if !main_artists_to_map.contains(track_artist_to_map) && !support_artists_to_map.contains(track_artist_to_map) {
+                            support_artists_to_map.push(track_artist_to_map.clone());
+                        }
+                    }
+                }
+            } else if let Some(performer) = cue_sheet.as_ref().and_then(|cue_sheet| cue_sheet.performer.as_ref()) {
+                // Here, main_artists_to_map is set through the PERFORMER
+                // given for the entirety of a CUE sheet (e.g. the DJ of a mix).
+                main_artists_to_map.push(performer.clone());
+
                 for release_track in &release_tracks {
                     for track_artist_to_map in &release_track.artists_to_map {
                         if !main_artists_to_map.contains(track_artist_to_map) && !support_artists_to_map.contains(track_artist_to_map) {
@@ -815,7 +1282,16 @@ impl Catalog {
                 }
             }
 
-            let title = match local_options.title {
+            // When only validating, audio files are not read, so if the
+            // release would take its title (and thereby possibly its
+            // permalink) from their metadata, we can not validate it
+            // any further than up to this point.
+            let title_unknown = build.validate_only &&
+                local_options.permalink.is_none() &&
+                local_options.title.is_none() &&
+                !cue_sheet.as_ref().is_some_and(|cue_sheet| cue_sheet.title.is_some());
+
+            let title = match local_options.title.or_else(|| cue_sheet.as_ref().and_then(|cue_sheet| cue_sheet.title.clone())) {
                 Some(title) => title,
                 None => {
                     // To implicitly obtain the release title we get
@@ -860,12 +1336,33 @@ impl Catalog {
 
             let cover = match &local_options.cover {
                 Some(described_image) => Some(described_image.clone()),
-                None => pick_best_cover_image(&images)
+                None => {
+                    // Images that are explicitly shown in the gallery are
+                    // not considered when we automatically pick a cover.
+                    let cover_candidates: Vec<ImageRcView> = images
+                        .iter()
+                        .filter(|image| {
+                            !local_options.gallery
+                                .iter()
+                                .any(|described_image| described_image.file_meta.path == image.file_meta.path)
+                        })
+                        .cloned()
+                        .collect();
+
+                    pick_best_cover_image(&cover_candidates)
+                }
             };
 
             if cover.as_ref().is_some_and(|described_image| described_image.description.is_none()) {
-                warn_discouraged!("The cover image for release '{}' is missing an image description.", title);
-                build.missing_image_descriptions = true;
+                let message = format!("The cover image for release '{title}' is missing an image description.");
+                build.missing_image_description(&message);
+            }
+
+            for (index, described_image) in local_options.gallery.iter().enumerate() {
+                if described_image.description.is_none() {
+                    let message = format!("Gallery image {} for release '{}' is missing an image description.", index + 1, title);
+                    build.missing_image_description(&message);
+                }
             }
 
             let mut extras = Vec::new();
@@ -882,34 +1379,85 @@ impl Catalog {
                 extras.push(extra);
             }
 
-            for extra_path in fair_dir.extra_files {
+            for extra_path in fair_dir.extra_files.iter().chain(&cue_extra_files) {
                 let path_relative_to_catalog = extra_path.strip_prefix(&build.catalog_dir).unwrap();
                 let file_meta = FileMeta::new(build, path_relative_to_catalog);
                 extras.push(Extra::new(file_meta));
             }
 
-            let download_access = finalized_overrides.release_download_access.assemble(
+            let mut download_access = finalized_overrides.release_download_access.assemble(
                 finalized_overrides,
                 &finalized_overrides.release_price
             );
 
             let release_dir_relative_to_catalog = fair_dir.path.strip_prefix(&build.catalog_dir).unwrap().to_path_buf();
 
+            // A release scheduled for later is left out of the build
+            // entirely, unless some of its tracks are marked as previews, in
+            // which case it is published as an upcoming release (a teaser
+            // with only the preview tracks streamable and no downloads).
+            let mut upcoming = None;
+
+            if let Some(publish_at) = local_options.publish_at {
+                if publish_at.and_utc() > build.build_begin {
+                    let publish_at_formatted = publish_at.format("%Y-%m-%d %H:%M");
+
+                    if !release_tracks.iter().any(|track| track.preview) {
+                        info!(
+                            "Release '{}' is scheduled to be published at {} (UTC) - it is left out of this build until then",
+                            title,
+                            publish_at_formatted
+                        );
+                        return;
+                    }
+
+                    info!(
+                        "Release '{}' is scheduled to be published at {} (UTC) - until then only its preview tracks are published",
+                        title,
+                        publish_at_formatted
+                    );
+
+                    download_access = DownloadAccess::Disabled;
+
+                    for track in release_tracks.iter_mut() {
+                        track.download_access = DownloadAccess::Disabled;
+                    }
+
+                    upcoming = Some(publish_at);
+                }
+            }
+
+            // A scheduled release without an explicit date is dated to
+            // its day of publication.
+            let release_date = local_options.release_date
+                .take()
+                .or(local_options.publish_at.map(|publish_at| publish_at.date()));
+
+            if title_unknown {
+                return;
+            }
+
             let release = Release::new(
+                finalized_overrides.release_archive_checksums,
+                finalized_overrides.release_archive_format,
+                finalized_overrides.release_archive_info,
                 finalized_overrides.copy_link,
                 cover,
-                local_options.release_date.take(),
+                release_date,
                 download_access,
                 finalized_overrides.release_downloads.clone(),
                 finalized_overrides.embedding,
                 finalized_overrides.release_extras.clone(),
                 extras,
+                mem::take(&mut local_options.gallery),
+                finalized_overrides.license.clone(),
                 mem::take(&mut local_options.links),
                 finalized_overrides.m3u_enabled,
                 main_artists_to_map,
                 local_options.more.take(),
                 finalized_overrides.more_label.clone(),
                 local_options.permalink.take(),
+                mem::take(&mut local_options.products),
                 release_dir_relative_to_catalog,
                 finalized_overrides.speed_controls,
                 support_artists_to_map,
@@ -918,7 +1466,8 @@ impl Catalog {
                 title.to_string(),
                 finalized_overrides.track_numbering.clone(),
                 release_tracks,
-                local_options.unlisted_release
+                local_options.unlisted_release,
+                upcoming
             );
 
             self.releases.push(ReleaseRc::new(release));
@@ -948,6 +1497,7 @@ impl Catalog {
 
         Track::new(
             artists_to_map,
+            local_options.chapters,
             overrides.copy_link,
             cover,
             download_access,
@@ -955,6 +1505,7 @@ impl Catalog {
             overrides.embedding,
             overrides.track_extras,
             extras,
+            overrides.license.clone(),
             local_options.links,
             local_options.more.take(),
             // TODO: There is a general design issue here: Overriding (= inheriting across
@@ -966,6 +1517,7 @@ impl Catalog {
             // inheritance towards certain child nodes). But this needs to be carefully considered
             // as to stay manageable/compatible with potential future GUI usage.
             overrides.more_label.clone(),
+            local_options.preview_track,
             overrides.speed_controls,
             overrides.streaming_quality,
             local_options.synopsis.take(),
@@ -1020,6 +1572,32 @@ impl Catalog {
             }
         };
 
+        let duration_seconds = transcodes.borrow().source_meta.duration_seconds;
+        local_options.chapters.retain(|chapter| {
+            if chapter.start_seconds() < duration_seconds {
+                true
+            } else {
+                let warning = format!("Ignoring chapter '{}' of track {} as it starts after the end of the track", chapter.title, path_relative_to_catalog.display());
+                build.warning(&warning);
+                false
+            }
+        });
+
+        // Chapters are embedded through the tag mapping, which only carries
+        // them when tags are written by faircamp itself.
+        if !local_options.chapters.is_empty() {
+            let tags_value = match finalized_overrides.tag_agenda {
+                TagAgenda::Copy => Some("copy"),
+                TagAgenda::Custom { .. } => None,
+                TagAgenda::Remove => Some("remove")
+            };
+
+            if let Some(tags_value) = tags_value {
+                let warning = format!("The chapters of track {} are not embedded in its audio files because of 'tags: {tags_value}' (they are only embedded when faircamp writes the tags itself, e.g. with 'tags: normalize')", path_relative_to_catalog.display());
+                build.warning(&warning);
+            }
+        }
+
         // Process bare image paths into ImageRc representations
         let images: Vec<ImageRcView> = fair_dir.image_files
             .into_iter()
@@ -1057,8 +1635,8 @@ impl Catalog {
         };
 
         if cover.as_ref().is_some_and(|described_image| described_image.description.is_none()) {
-            warn_discouraged!("The cover image for track '{}' is missing an image description.", title);
-            build.missing_image_descriptions = true;
+            let message = format!("The cover image for track '{title}' is missing an image description.");
+            build.missing_image_description(&message);
         }
 
         let mut extras = Vec::new();
@@ -1075,7 +1653,7 @@ impl Catalog {
             extras.push(extra);
         }
 
-        for extra_path in fair_dir.extra_files {
+        for extra_path in fair_dir.extra_files.iter().chain(&fair_dir.cue_files) {
             let path_relative_to_catalog = extra_path.strip_prefix(&build.catalog_dir).unwrap();
             let file_meta = FileMeta::new(build, path_relative_to_catalog);
             extras.push(Extra::new(file_meta));
@@ -1116,11 +1694,14 @@ impl Catalog {
 
         let mutually_exclusive_manifests =
             fair_dir.artist_manifest.is_some() as usize +
+            fair_dir.page_manifest.is_some() as usize +
+            fair_dir.playlist_manifest.is_some() as usize +
+            fair_dir.post_manifest.is_some() as usize +
             fair_dir.release_manifest.is_some() as usize +
             fair_dir.track_manifest.is_some() as usize;
 
         if mutually_exclusive_manifests > 1 {
-            let error = format!("A directory in a faircamp catalog may only ever contain a catalog.eno, release.eno or track.eno manifest (one of them), but the directory '{}' contains {mutually_exclusive_manifests} of these. The directory will be ignored until this is resolved.", path.display());
+            let error = format!("A directory in a faircamp catalog may only ever contain an artist.eno, page.eno, playlist.eno, post.eno, release.eno or track.eno manifest (one of them), but the directory '{}' contains {mutually_exclusive_manifests} of these. The directory will be ignored until this is resolved.", path.display());
             build.error(&error);
             return;
         }
@@ -1135,6 +1716,39 @@ impl Catalog {
             return;
         }
 
+        if fair_dir.page_manifest.is_some() {
+            self.read_content_dir(
+                build,
+                cache,
+                fair_dir,
+                parent_overrides,
+                ContentKind::Page
+            );
+            return;
+        }
+
+        if fair_dir.playlist_manifest.is_some() {
+            self.read_content_dir(
+                build,
+                cache,
+                fair_dir,
+                parent_overrides,
+                ContentKind::Playlist
+            );
+            return;
+        }
+
+        if fair_dir.post_manifest.is_some() {
+            self.read_content_dir(
+                build,
+                cache,
+                fair_dir,
+                parent_overrides,
+                ContentKind::Post
+            );
+            return;
+        }
+
         if fair_dir.release_manifest.is_some() {
             self.read_release_dir(
                 build,
@@ -1236,7 +1850,7 @@ impl Catalog {
     }
 
     /// Checks the (either auto-generated or user-assigned) permalinks of all
-    /// artists and releases in the catalog, printing errors when any two
+    /// artists, pages, playlists, posts and releases in the catalog, printing errors when any two
     /// conflict with each other. Also prints warnings if there are
     /// auto-generated permalinks, as these are not truly permanent and
     /// should be replaced with manually specified ones. Returns whether all
@@ -1252,6 +1866,9 @@ impl Catalog {
             } else {
                 let label = match usage {
                     PermalinkUsage::Artist(artist) => format!("artist '{}'", artist.borrow().name),
+                    PermalinkUsage::Page(page) => format!("page '{}'", page.title),
+                    PermalinkUsage::Playlist(playlist) => format!("playlist '{}'", playlist.title),
+                    PermalinkUsage::Post(post) => format!("post '{}'", post.title),
                     PermalinkUsage::Release(release) => format!("release '{}'", release.borrow().title)
                 };
 
@@ -1267,20 +1884,42 @@ impl Catalog {
 
         for release in &self.releases {
             let release_ref = release.borrow();
+            let usage = PermalinkUsage::Release(release);
 
-            if let Some(previous_usage) = used_permalinks.get(&release_ref.permalink.slug) {
-                let generated_or_assigned = &release_ref.permalink.generated_or_assigned_str();
-                let slug = &release_ref.permalink.slug;
-                let title = &release_ref.title;
-                let previous_usage_formatted = previous_usage.as_string();
-                let release_dir = release_ref.source_dir.display();
-                let error = format!("The {generated_or_assigned} permalink '{slug}' of the release '{title}' from directory '{release_dir}' conflicts with the {previous_usage_formatted}\n{PERMALINK_CONFLICT_RESOLUTION_HINT}");
-                build.error(&error);
+            if register_permalink(build, &mut used_permalinks, &release_ref.permalink, usage) {
+                if release_ref.permalink.generated { add_generated_usage(&usage); }
+            } else {
+                no_conflicts = false;
+            }
+        }
+
+        for playlist in &self.playlists {
+            let usage = PermalinkUsage::Playlist(playlist);
+
+            if register_permalink(build, &mut used_permalinks, &playlist.permalink, usage) {
+                if playlist.permalink.generated { add_generated_usage(&usage); }
+            } else {
                 no_conflicts = false;
+            }
+        }
+
+        for page in &self.pages {
+            let usage = PermalinkUsage::Page(page);
+
+            if register_permalink(build, &mut used_permalinks, &page.permalink, usage) {
+                if page.permalink.generated { add_generated_usage(&usage); }
             } else {
-                let usage = PermalinkUsage::Release(release);
-                if release_ref.permalink.generated { add_generated_usage(&usage); }
-                used_permalinks.insert(release_ref.permalink.slug.to_string(), usage);
+                no_conflicts = false;
+            }
+        }
+
+        for post in &self.posts {
+            let usage = PermalinkUsage::Post(post);
+
+            if register_permalink(build, &mut used_permalinks, &post.permalink, usage) {
+                if post.permalink.generated { add_generated_usage(&usage); }
+            } else {
+                no_conflicts = false;
             }
         }
 
@@ -1325,6 +1964,9 @@ impl Catalog {
                             resolve the conflict.
                         "#)
                     }
+                    PermalinkUsage::Page(_) |
+                    PermalinkUsage::Playlist(_) |
+                    PermalinkUsage::Post(_) |
                     PermalinkUsage::Release(_) => PERMALINK_CONFLICT_RESOLUTION_HINT
                 };
 
@@ -1346,13 +1988,17 @@ impl Catalog {
             }
         }
 
-        match generated_permalinks {
-            (None, None, None, 0) => (),
-            (Some(first), None, None, 0) => warn!("The {} has no user-assigned permalink, it is recommended to assign one.", first),
-            (Some(first), Some(second), None, 0) => warn!("The {} and the {} have no user-assigned permalinks, it is recommended to assign some.", first, second),
-            (Some(first), Some(second), Some(third), 0) => warn!("The {}, the {} and the {} have no user-assigned permalinks, it is recommended to assign some.", first, second, third),
-            (Some(first), Some(second), Some(third), further) => warn!("The {}, the {}, the {} and {} other things have no user-assigned permalinks, it is recommended to assign some.", first, second, third, further),
+        let generated_warning = match generated_permalinks {
+            (None, None, None, 0) => None,
+            (Some(first), None, None, 0) => Some(format!("The {first} has no user-assigned permalink, it is recommended to assign one.")),
+            (Some(first), Some(second), None, 0) => Some(format!("The {first} and the {second} have no user-assigned permalinks, it is recommended to assign some.")),
+            (Some(first), Some(second), Some(third), 0) => Some(format!("The {first}, the {second} and the {third} have no user-assigned permalinks, it is recommended to assign some.")),
+            (Some(first), Some(second), Some(third), further) => Some(format!("The {first}, the {second}, the {third} and {further} other things have no user-assigned permalinks, it is recommended to assign some.")),
             _ => unreachable!()
+        };
+
+        if let Some(warning) = generated_warning {
+            build.warning(&warning);
         }
 
         no_conflicts
@@ -1370,7 +2016,7 @@ impl Catalog {
             let mut image_mut = described_image.borrow_mut();
             let source_path = &described_image.file_meta.path;
             // Write home image as poster image for homepage
-            let poster_assets = image_mut.artist_assets(build, source_path);
+            let poster_assets = image_mut.artist_assets(build, described_image.crop, source_path);
 
             for asset in &poster_assets.all() {
                 let target_filename = asset.target_filename();
@@ -1380,12 +2026,24 @@ impl Catalog {
                     build.build_dir.join(&target_filename)
                 );
 
+                for variant in &asset.variants {
+                    let variant_filename = variant.target_filename(&target_filename);
+
+                    util::hard_link_or_copy(
+                        build.cache_dir.join(&variant.filename),
+                        build.build_dir.join(&variant_filename)
+                    );
+
+                    build.reserve_filename(variant_filename);
+                    build.stats.add_image(variant.filesize_bytes);
+                }
+
                 build.reserve_filename(target_filename);
                 build.stats.add_image(asset.filesize_bytes);
             }
 
             // Write home image to show in index
-            let background_asset = image_mut.background_asset(build, source_path);
+            let background_asset = image_mut.background_asset(build, described_image.crop, source_path);
             util::hard_link_or_copy(
                 build.cache_dir.join(&background_asset.filename),
                 // TODO: Address the ugly __home__ hack soon (maybe hashes are again a solution for these naming questions?)
@@ -1393,8 +2051,8 @@ impl Catalog {
             );
             build.stats.add_image(background_asset.filesize_bytes);
 
-            // Write home image as feed image
-            if build.base_url.is_some() && self.feeds.any_requested() {
+            // Write home image as feed image (also used as the ActivityPub actor icon)
+            if build.base_url.is_some() && (self.feeds.any_requested() || self.activitypub.is_some()) {
                 let source_path = &described_image.file_meta.path;
                 let feed_image_asset = image_mut.feed_asset(build, source_path);
 
@@ -1423,15 +2081,26 @@ impl Catalog {
 
                 let mut image_mut = described_image.borrow_mut();
                 let source_path = &described_image.file_meta.path;
-                let poster_assets = image_mut.artist_assets(build, source_path);
+                let poster_assets = image_mut.artist_assets(build, described_image.crop, source_path);
 
                 for asset in &poster_assets.all() {
+                    let target_filename = asset.target_filename();
+
                     util::hard_link_or_copy(
                         build.cache_dir.join(&asset.filename),
-                        artist_dir.join(asset.target_filename())
+                        artist_dir.join(&target_filename)
                     );
 
                     build.stats.add_image(asset.filesize_bytes);
+
+                    for variant in &asset.variants {
+                        util::hard_link_or_copy(
+                            build.cache_dir.join(&variant.filename),
+                            artist_dir.join(variant.target_filename(&target_filename))
+                        );
+
+                        build.stats.add_image(variant.filesize_bytes);
+                    }
                 }
 
                 image_mut.persist_to_cache(&build.cache_dir);
@@ -1443,6 +2112,34 @@ impl Catalog {
             }
         }
 
+        // Write page theme background images
+        for page in &self.pages {
+            if let Some(image) = &page.theme.background_image {
+                write_background_image(build, image);
+            }
+        }
+
+        // Write playlist theme background images
+        for playlist in &self.playlists {
+            if let Some(image) = &playlist.theme.background_image {
+                write_background_image(build, image);
+            }
+        }
+
+        // Write post theme background images
+        for post in &self.posts {
+            if let Some(image) = &post.theme.background_image {
+                write_background_image(build, image);
+            }
+        }
+
+        // Write images of catalog products (these are shown on the merch page)
+        if let Some(merch_permalink) = &self.merch_permalink {
+            let merch_dir = build.build_dir.join(merch_permalink);
+            util::ensure_dir_all(&merch_dir);
+            write_product_images(build, &merch_dir, &self.products);
+        }
+
         let max_tracks_in_release = self.releases
             .iter()
             .map(|release| release.borrow().tracks.len())
@@ -1466,15 +2163,26 @@ impl Catalog {
             if let Some(described_image) = &release_mut.cover {
                 let mut image_mut = described_image.borrow_mut();
                 let source_path = &described_image.file_meta.path;
-                let cover_assets = image_mut.cover_assets(build, source_path);
+                let cover_assets = image_mut.cover_assets(build, described_image.crop, source_path);
 
                 for asset in &cover_assets.all() {
+                    let target_filename = asset.target_filename();
+
                     util::hard_link_or_copy(
                         build.cache_dir.join(&asset.filename),
-                        release_dir.join(asset.target_filename())
+                        release_dir.join(&target_filename)
                     );
 
                     build.stats.add_image(asset.filesize_bytes);
+
+                    for variant in &asset.variants {
+                        util::hard_link_or_copy(
+                            build.cache_dir.join(&variant.filename),
+                            release_dir.join(variant.target_filename(&target_filename))
+                        );
+
+                        build.stats.add_image(variant.filesize_bytes);
+                    }
                 }
 
                 image_mut.persist_to_cache(&build.cache_dir);
@@ -1508,18 +2216,60 @@ impl Catalog {
                 release_mut.procedural_cover = Some(procedural_cover);
             }
 
+            // Write release gallery images
+            for (described_image, number) in release_mut.gallery.iter().zip(1..) {
+                let mut image_mut = described_image.borrow_mut();
+                let source_path = &described_image.file_meta.path;
+                let gallery_assets = image_mut.gallery_assets(build, described_image.crop, source_path);
+
+                let targets = [
+                    (&gallery_assets.thumbnail, GalleryAssets::thumbnail_target_filename(number)),
+                    (&gallery_assets.full, GalleryAssets::full_target_filename(number))
+                ];
+
+                for (asset, target_filename) in targets {
+                    util::hard_link_or_copy(
+                        build.cache_dir.join(&asset.filename),
+                        release_dir.join(&target_filename)
+                    );
+
+                    build.stats.add_image(asset.filesize_bytes);
+
+                    for variant in &asset.variants {
+                        util::hard_link_or_copy(
+                            build.cache_dir.join(&variant.filename),
+                            release_dir.join(variant.target_filename(&target_filename))
+                        );
+
+                        build.stats.add_image(variant.filesize_bytes);
+                    }
+                }
+
+                image_mut.persist_to_cache(&build.cache_dir);
+            }
+
+            // Write release product images
+            write_product_images(build, &release_dir, &release_mut.products);
+
             // Prepare release cover image for optional embed usage
             let release_cover_path = release_mut.cover
                 .as_ref()
                 .map(|described_image| build.catalog_dir.join(&described_image.file_meta.path));
 
             let release_slug = release_mut.permalink.slug.clone();
+            let release_upcoming = release_mut.upcoming.is_some();
 
             let tag_mappings: Vec<TagMapping> = release_mut.tracks.iter().zip(TRACK_NUMBERS)
                 .map(|(track, track_number)| TagMapping::new(&release_mut, track, track_number))
                 .collect();
 
             for ((track, tag_mapping), track_number) in release_mut.tracks.iter_mut().zip(tag_mappings.iter()).zip(TRACK_NUMBERS) {
+                // Locked tracks of an upcoming release get no assets at all,
+                // so that they can not be reached ahead of publication.
+                if release_upcoming && !track.preview {
+                    continue;
+                }
+
                 let track_dir = release_dir.join(track_number.to_string());
 
                 util::ensure_dir_all(&track_dir);
@@ -1533,15 +2283,26 @@ impl Catalog {
                 if let Some(described_image) = &track.cover {
                     let mut image_mut = described_image.borrow_mut();
                     let source_path = &described_image.file_meta.path;
-                    let cover_assets = image_mut.cover_assets(build, source_path);
+                    let cover_assets = image_mut.cover_assets(build, described_image.crop, source_path);
 
                     for asset in &cover_assets.all() {
+                        let target_filename = asset.target_filename();
+
                         util::hard_link_or_copy(
                             build.cache_dir.join(&asset.filename),
-                            track_dir.join(asset.target_filename())
+                            track_dir.join(&target_filename)
                         );
 
                         build.stats.add_image(asset.filesize_bytes);
+
+                        for variant in &asset.variants {
+                            util::hard_link_or_copy(
+                                build.cache_dir.join(&variant.filename),
+                                track_dir.join(variant.target_filename(&target_filename))
+                            );
+
+                            build.stats.add_image(variant.filesize_bytes);
+                        }
                     }
 
                     image_mut.persist_to_cache(&build.cache_dir);
@@ -1597,6 +2358,39 @@ impl Catalog {
                 }
             }
 
+            // Write the continuous recording that the tracks were split from
+            // (if they were split along a CUE sheet), which the release page
+            // streams for gapless playback across the tracks.
+            if let Some(continuous_source) = release_mut.continuous_source() {
+                let tag_mapping = TagMapping::Remove;
+
+                for streaming_format in release_mut.tracks[0].streaming_quality.formats() {
+                    continuous_source.transcode_as(
+                        streaming_format,
+                        build,
+                        AssetIntent::Deliverable,
+                        &tag_mapping,
+                        None
+                    );
+
+                    let stream_path = release_dir.join(release_mut.continuous_stream_path(build, streaming_format));
+
+                    util::ensure_dir_all(stream_path.parent().unwrap());
+
+                    let transcodes_ref = continuous_source.borrow();
+                    let streaming_transcode = transcodes_ref.get_unchecked(streaming_format, generic_hash(&tag_mapping));
+
+                    util::hard_link_or_copy(
+                        build.cache_dir.join(&streaming_transcode.asset.filename),
+                        stream_path
+                    );
+
+                    build.stats.add_track(streaming_transcode.asset.filesize_bytes);
+                }
+
+                continuous_source.borrow().persist_to_cache(&build.cache_dir);
+            }
+
             release_mut.write_downloadable_files(build);
         }
     }
@@ -1625,3 +2419,30 @@ impl Catalog {
         }
     }
 }
+
+impl ContentKind {
+    /// Explains why audio files in a directory of this kind are ignored.
+    fn audio_files_hint(&self) -> &'static str {
+        match self {
+            ContentKind::Page => "if you meant to create a release, move these audio files to a separate directory",
+            ContentKind::Playlist => "playlists only reference tracks of releases in the catalog, if you meant to create a release, move these audio files to a separate directory",
+            ContentKind::Post => "posts can only feature releases of the catalog (see the 'releases' option), if you meant to create a release, move these audio files to a separate directory"
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            ContentKind::Page => "page",
+            ContentKind::Playlist => "playlist",
+            ContentKind::Post => "post"
+        }
+    }
+
+    fn manifest_path<'a>(&self, fair_dir: &'a FairDir) -> Option<&'a Path> {
+        match self {
+            ContentKind::Page => fair_dir.page_manifest.as_deref(),
+            ContentKind::Playlist => fair_dir.playlist_manifest.as_deref(),
+            ContentKind::Post => fair_dir.post_manifest.as_deref()
+        }
+    }
+}
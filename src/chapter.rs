@@ -0,0 +1,197 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Chapters (also known as cues) are labeled points in time within a track,
+//! e.g. the tracklist of a DJ mix or the topics of a podcast episode. They
+//! are specified through the `chapters` option in a track manifest, shown
+//! as markers on the waveform and as a clickable list on the site, exported
+//! as Podcasting 2.0 chapters and CUE sheets, and written into downloadable
+//! files as chapter metadata.
+
+use crate::util::json_escape_inside_string;
+
+/// The filename of the generated CUE sheet placed next to a track page
+pub const CUE_SHEET_FILENAME: &str = "chapters.cue";
+
+/// The filename of the generated Podcasting 2.0 chapters file placed next
+/// to a track page.
+/// See https://github.com/Podcastindex-org/podcast-namespace/blob/main/docs/examples/chapters/jsonChapters.md
+pub const PODCAST_CHAPTERS_FILENAME: &str = "chapters.json";
+
+#[derive(Clone, Debug, Hash)]
+pub struct Chapter {
+    /// Position in the track at which the chapter starts, in milliseconds
+    pub start_ms: u64,
+    pub title: String
+}
+
+/// Escapes characters that carry special meaning in ffmpeg's metadata
+/// file format.
+fn ffmetadata_escape(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+
+    for character in string.chars() {
+        match character {
+            '=' | ';' | '#' | '\\' | '\n' => {
+                escaped.push('\\');
+                escaped.push(character);
+            }
+            _ => escaped.push(character)
+        }
+    }
+
+    escaped
+}
+
+/// Parses "ss", "m:ss" or "h:mm:ss", each optionally with a fractional
+/// part for the seconds ("1:23.5"), into milliseconds.
+fn parse_timestamp(timestamp: &str) -> Option<u64> {
+    let mut components = timestamp.split(':').rev();
+
+    let seconds: f64 = components.next()?.parse().ok().filter(|seconds| *seconds >= 0.0)?;
+    let minutes: u64 = match components.next() {
+        Some(minutes) => minutes.parse().ok()?,
+        None => 0
+    };
+    let hours: u64 = match components.next() {
+        Some(hours) => hours.parse().ok()?,
+        None => 0
+    };
+
+    if components.next().is_some() {
+        return None;
+    }
+
+    Some((hours * 3600 + minutes * 60) * 1000 + (seconds * 1000.0).round() as u64)
+}
+
+impl Chapter {
+    /// Renders a CUE sheet that describes each chapter as a track within
+    /// the given (downloadable) file.
+    pub fn cue_sheet(
+        chapters: &[Chapter],
+        performer: Option<&str>,
+        title: &str,
+        filename: &str
+    ) -> String {
+        // Double quotes can not be escaped in CUE sheets
+        let quote = |string: &str| format!("\"{}\"", string.replace('"', "'"));
+
+        let file_type = match filename.rsplit('.').next().map(|extension| extension.to_lowercase()).as_deref() {
+            Some("aif" | "aiff") => "AIFF",
+            Some("mp3") => "MP3",
+            _ => "WAVE"
+        };
+
+        let mut lines = Vec::new();
+
+        if let Some(performer) = performer {
+            lines.push(format!("PERFORMER {}", quote(performer)));
+        }
+
+        lines.push(format!("TITLE {}", quote(title)));
+        lines.push(format!("FILE {} {file_type}", quote(filename)));
+
+        for (index, chapter) in chapters.iter().enumerate() {
+            // CUE sheet timestamps are given as mm:ss:ff with 75 frames per second
+            let minutes = chapter.start_ms / 60_000;
+            let seconds = (chapter.start_ms % 60_000) / 1000;
+            let frames = (chapter.start_ms % 1000) * 75 / 1000;
+
+            lines.push(format!("  TRACK {:02} AUDIO", index + 1));
+            lines.push(format!("    TITLE {}", quote(&chapter.title)));
+            lines.push(format!("    INDEX 01 {minutes:02}:{seconds:02}:{frames:02}"));
+        }
+
+        lines.push(String::new());
+        lines.join("\r\n")
+    }
+
+    /// Renders an ffmpeg metadata file (see
+    /// https://ffmpeg.org/ffmpeg-formats.html#Metadata-2) that declares the
+    /// chapters. Each chapter ends where the next one starts, the last one
+    /// at the end of the track.
+    pub fn ffmetadata(chapters: &[Chapter], duration_seconds: f32) -> String {
+        let duration_ms = (duration_seconds * 1000.0) as u64;
+
+        let mut lines = vec![String::from(";FFMETADATA1")];
+
+        for (index, chapter) in chapters.iter().enumerate() {
+            let end_ms = match chapters.get(index + 1) {
+                Some(next_chapter) => next_chapter.start_ms,
+                None => duration_ms.max(chapter.start_ms)
+            };
+
+            lines.push(String::from("[CHAPTER]"));
+            lines.push(String::from("TIMEBASE=1/1000"));
+            lines.push(format!("START={}", chapter.start_ms));
+            lines.push(format!("END={end_ms}"));
+            lines.push(format!("title={}", ffmetadata_escape(&chapter.title)));
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+
+    /// Parses a chapter given as a timestamp followed by the chapter title,
+    /// e.g. "1:02:03 Closing remarks".
+    pub fn from_manifest_value(value: &str) -> Result<Chapter, String> {
+        let value = value.trim();
+
+        let (timestamp, title) = match value.split_once(char::is_whitespace) {
+            Some((timestamp, title)) => (timestamp, title.trim()),
+            None => (value, "")
+        };
+
+        let Some(start_ms) = parse_timestamp(timestamp) else {
+            return Err(format!("'{timestamp}' is not a valid timestamp (supported are e.g. '45', '3:25', '1:02:03' or '3:25.5')"));
+        };
+
+        if title.is_empty() {
+            return Err(format!("The chapter at {timestamp} has no title (write it after the timestamp, e.g. '{timestamp} Intro')"));
+        }
+
+        let chapter = Chapter {
+            start_ms,
+            title: title.to_string()
+        };
+
+        Ok(chapter)
+    }
+
+    /// Renders the chapters in the JSON chapters format of the podcast
+    /// namespace (Podcasting 2.0).
+    pub fn podcast_json(chapters: &[Chapter]) -> String {
+        let r_chapters = chapters
+            .iter()
+            .map(|chapter| {
+                let start_time = chapter.start_seconds();
+                let title_escaped = json_escape_inside_string(&chapter.title);
+                format!(r#"{{"startTime":{start_time},"title":"{title_escaped}"}}"#)
+            })
+            .collect::<Vec<String>>()
+            .join(",");
+
+        format!(r#"{{"version":"1.2.0","chapters":[{r_chapters}]}}"#)
+    }
+
+    pub fn start_seconds(&self) -> f32 {
+        self.start_ms as f32 / 1000.0
+    }
+
+    /// The start of the chapter in the format used for linking to a
+    /// timecode (e.g. "1h2m3s"), see the manual topic "Linking to
+    /// timecodes/tracks".
+    pub fn time_param(&self) -> String {
+        let total_seconds = self.start_ms / 1000;
+        let hours = total_seconds / 3600;
+        let minutes = (total_seconds % 3600) / 60;
+        let seconds = total_seconds % 60;
+
+        match (hours, minutes) {
+            (0, 0) => format!("{seconds}s"),
+            (0, _) => format!("{minutes}m{seconds}s"),
+            _ => format!("{hours}h{minutes}m{seconds}s")
+        }
+    }
+}
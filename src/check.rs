@@ -0,0 +1,171 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Implements the `check` subcommand: The catalog is read through the very
+//! same code that reads it during a regular build, but in a "validate only"
+//! mode (see [Build::validate_only]) in which audio files are never decoded
+//! and the cache directory is never touched (images are merely hashed into a
+//! throwaway in-memory cache). Errors and warnings are collected and
+//! reported together at the end, either human-readable or as JSON (for use
+//! in e.g. pre-commit hooks).
+
+use std::path::{Path, PathBuf};
+
+use crate::{Build, Cache, Catalog};
+use crate::util::json_escape_inside_string;
+
+/// An error or warning collected during `faircamp check`.
+#[derive(Debug)]
+pub struct Diagnostic {
+    /// Given for problems that concern a specific line in a manifest
+    pub location: Option<Location>,
+    pub message: String,
+    pub severity: Severity
+}
+
+/// A line in a manifest
+#[derive(Clone, Debug)]
+pub struct Location {
+    pub line: u32,
+    pub path: PathBuf
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning
+}
+
+impl Diagnostic {
+    pub fn new(
+        location: Option<Location>,
+        message: &str,
+        severity: Severity
+    ) -> Diagnostic {
+        Diagnostic {
+            location,
+            message: message.to_string(),
+            severity
+        }
+    }
+}
+
+impl Location {
+    pub fn new(line: u32, path: &Path) -> Location {
+        Location {
+            line,
+            path: path.to_path_buf()
+        }
+    }
+}
+
+impl Severity {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning"
+        }
+    }
+}
+
+/// Run all checks and print the collected diagnostics. Returns whether the
+/// catalog passed without errors.
+pub fn check(build: &mut Build, json: bool) -> bool {
+    if json {
+        crate::message::set_quiet();
+    }
+
+    build.diagnostics = Some(Vec::new());
+    build.validate_only = true;
+
+    let mut cache = Cache::new();
+
+    // Reading fails on errors that would abort a build even with
+    // --ignore-errors (e.g. permalink conflicts), these have been
+    // collected nonetheless.
+    if let Ok(catalog) = Catalog::read(build, &mut cache) {
+        check_unlisted(build, &catalog);
+    }
+
+    let diagnostics = build.diagnostics.take().unwrap();
+
+    if json {
+        println!("{}", diagnostics_json(build, &diagnostics));
+    } else {
+        for diagnostic in &diagnostics {
+            match diagnostic.severity {
+                Severity::Error => error!("{}", diagnostic.message),
+                Severity::Warning => warn!("{}", diagnostic.message)
+            }
+        }
+
+        info!("Check finished with {} errors and {} warnings.", build.errors, build.warnings);
+    }
+
+    build.errors == 0
+}
+
+/// Unlisted releases can only be reached by their address, which is why
+/// we point out where that address is not stable, or where nothing at all
+/// can be reached from the homepage.
+fn check_unlisted(build: &mut Build, catalog: &Catalog) {
+    for release in &catalog.releases {
+        let release_ref = release.borrow();
+
+        if release_ref.unlisted && release_ref.permalink.generated {
+            let warning = format!("The unlisted release '{}' has no user-assigned permalink. As it can only be reached by its address, that address would change (and previously shared links would break) whenever its title changes - it is recommended to assign a permalink.", release_ref.title);
+            build.warning(&warning);
+        }
+    }
+
+    if !catalog.releases.is_empty() && catalog.releases.iter().all(|release| release.borrow().unlisted) {
+        build.warning("All releases in the catalog are unlisted, therefore none of them can be reached from the homepage.");
+    }
+}
+
+fn diagnostics_json(build: &Build, diagnostics: &[Diagnostic]) -> String {
+    let r_diagnostics = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let location = match &diagnostic.location {
+                Some(location) => format!(
+                    r#","file":"{}","line":{}"#,
+                    json_escape_inside_string(&location.path.to_string_lossy()),
+                    location.line
+                ),
+                None => String::new()
+            };
+
+            let message = json_escape_inside_string(&strip_terminal_colors(&diagnostic.message));
+            let severity = diagnostic.severity.as_str();
+
+            format!(r#"{{"severity":"{severity}","message":"{message}"{location}}}"#)
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+
+    format!(
+        r#"{{"errors":{},"warnings":{},"diagnostics":[{r_diagnostics}]}}"#,
+        build.errors,
+        build.warnings
+    )
+}
+
+/// Manifest snippets are printed with terminal color codes (e.g. "\x1b[31m"),
+/// which we remove for machine-readable output.
+fn strip_terminal_colors(string: &str) -> String {
+    let mut stripped = String::with_capacity(string.len());
+    let mut chars = string.chars();
+
+    while let Some(character) = chars.next() {
+        if character == '\x1b' {
+            for sequence_character in chars.by_ref() {
+                if sequence_character.is_ascii_alphabetic() { break; }
+            }
+        } else {
+            stripped.push(character);
+        }
+    }
+
+    stripped
+}
@@ -0,0 +1,234 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! CUE sheets describe how a single, continuous audio file (e.g. a DJ mix or
+//! a live recording) is divided into tracks. When a release directory
+//! contains exactly one audio file and a `.cue` file next to it, faircamp
+//! splits the audio file into virtual tracks along the INDEX points of the
+//! CUE sheet. See https://en.wikipedia.org/wiki/Cue_sheet_(computing) and
+//! https://wyday.com/cuesharp/specification.php as reference.
+
+use std::fs;
+use std::path::Path;
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug)]
+pub struct CueSheet {
+    /// The audio file name referenced through FILE
+    pub file: Option<String>,
+    pub performer: Option<String>,
+    pub title: Option<String>,
+    pub tracks: Vec<CueTrack>
+}
+
+#[derive(Debug)]
+pub struct CueTrack {
+    pub number: u32,
+    pub performer: Option<String>,
+    /// Position of INDEX 01 in the audio file, in milliseconds
+    pub start_ms: u64,
+    pub title: Option<String>
+}
+
+/// A section of a continuous audio file that is presented and transcoded
+/// as a track of its own.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct Segment {
+    /// None means the segment extends until the end of the audio file
+    pub end_ms: Option<u64>,
+    pub start_ms: u64
+}
+
+/// Parses a CUE timestamp in the format mm:ss:ff (minutes, seconds and
+/// frames, with 75 frames per second) into milliseconds. Minutes may exceed
+/// two digits for long recordings.
+fn parse_timestamp(timestamp: &str) -> Option<u64> {
+    let mut components = timestamp.split(':');
+
+    let minutes: u64 = components.next()?.parse().ok()?;
+    let seconds: u64 = components.next()?.parse().ok().filter(|seconds| *seconds < 60)?;
+    let frames: u64 = components.next()?.parse().ok().filter(|frames| *frames < 75)?;
+
+    if components.next().is_some() {
+        return None;
+    }
+
+    Some((minutes * 60 + seconds) * 1000 + frames * 1000 / 75)
+}
+
+/// Splits a line into its command and arguments, where arguments may be
+/// wrapped in double quotes to include whitespace.
+fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = line.trim().chars().peekable();
+
+    while let Some(&character) = chars.peek() {
+        if character.is_whitespace() {
+            chars.next();
+        } else if character == '"' {
+            chars.next();
+            let token: String = chars.by_ref().take_while(|character| *character != '"').collect();
+            tokens.push(token);
+        } else {
+            let mut token = String::new();
+            while let Some(&character) = chars.peek() {
+                if character.is_whitespace() { break; }
+                token.push(character);
+                chars.next();
+            }
+            tokens.push(token);
+        }
+    }
+
+    tokens
+}
+
+impl CueSheet {
+    pub fn parse(content: &str) -> Result<CueSheet, String> {
+        let mut cue_sheet = CueSheet {
+            file: None,
+            performer: None,
+            title: None,
+            tracks: Vec::new()
+        };
+
+        // The track currently being read, along with its start (which is
+        // only known once its INDEX 01 line is encountered)
+        let mut pending_track: Option<(CueTrack, Option<u64>)> = None;
+
+        let content = content.trim_start_matches('\u{feff}');
+
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            let tokens = tokenize(line);
+
+            let Some(command) = tokens.first() else { continue };
+            let argument = tokens.get(1).cloned();
+
+            match command.to_uppercase().as_str() {
+                "FILE" => {
+                    if cue_sheet.file.is_some() {
+                        return Err(format!("Line {line_number} references a second audio file, but only CUE sheets describing a single audio file are supported"));
+                    }
+                    cue_sheet.file = argument;
+                }
+                "INDEX" => {
+                    let Some((_, start_ms)) = &mut pending_track else {
+                        return Err(format!("Line {line_number} contains an INDEX outside of a TRACK"));
+                    };
+
+                    // INDEX 00 marks the pregap, INDEX 01 the actual start
+                    // of the track - subindices beyond that are ignored.
+                    if argument.as_deref().and_then(|number| number.parse::<u32>().ok()) == Some(1) {
+                        let timestamp = tokens.get(2).map(|token| token.as_str()).unwrap_or("");
+                        match parse_timestamp(timestamp) {
+                            Some(parsed) => *start_ms = Some(parsed),
+                            None => return Err(format!("Line {line_number} contains the timestamp '{timestamp}', which is not valid (expected is mm:ss:ff, e.g. '03:25:00')"))
+                        }
+                    }
+                }
+                "PERFORMER" => match &mut pending_track {
+                    Some((track, _)) => track.performer = argument,
+                    None => cue_sheet.performer = argument
+                }
+                "TITLE" => match &mut pending_track {
+                    Some((track, _)) => track.title = argument,
+                    None => cue_sheet.title = argument
+                }
+                "TRACK" => {
+                    if let Some(track) = pending_track.take() {
+                        cue_sheet.push_track(track)?;
+                    }
+
+                    let number = argument
+                        .as_deref()
+                        .and_then(|number| number.parse::<u32>().ok())
+                        .ok_or_else(|| format!("Line {line_number} contains a TRACK without a valid track number"))?;
+
+                    let track = CueTrack {
+                        number,
+                        performer: None,
+                        start_ms: 0,
+                        title: None
+                    };
+
+                    pending_track = Some((track, None));
+                }
+                // CATALOG, CDTEXTFILE, FLAGS, ISRC, POSTGAP, PREGAP,
+                // REM, SONGWRITER, ... carry nothing we make use of
+                _ => ()
+            }
+        }
+
+        if let Some(track) = pending_track.take() {
+            cue_sheet.push_track(track)?;
+        }
+
+        if cue_sheet.tracks.is_empty() {
+            return Err(String::from("The CUE sheet contains no tracks"));
+        }
+
+        Ok(cue_sheet)
+    }
+
+    fn push_track(&mut self, (mut track, start_ms): (CueTrack, Option<u64>)) -> Result<(), String> {
+        let Some(start_ms) = start_ms else {
+            return Err(format!("Track {} has no INDEX 01, its start is unknown", track.number));
+        };
+
+        if let Some(previous_track) = self.tracks.last() {
+            if start_ms <= previous_track.start_ms {
+                return Err(format!("Track {} starts before (or at the same time as) the previous track, tracks need to be listed in chronological order", track.number));
+            }
+        }
+
+        track.start_ms = start_ms;
+        self.tracks.push(track);
+
+        Ok(())
+    }
+
+    pub fn read(path: &Path) -> Result<CueSheet, String> {
+        match fs::read(path) {
+            // CUE sheets in the wild come in all sorts of legacy encodings,
+            // we read them lossily rather than rejecting them outright.
+            Ok(bytes) => CueSheet::parse(&String::from_utf8_lossy(&bytes)),
+            Err(err) => Err(err.to_string())
+        }
+    }
+
+    /// Returns the segments of the audio file that correspond to the
+    /// tracks, each ending where the next one starts.
+    pub fn segments(&self) -> Vec<Segment> {
+        self.tracks
+            .iter()
+            .enumerate()
+            .map(|(index, track)| Segment {
+                end_ms: self.tracks.get(index + 1).map(|next_track| next_track.start_ms),
+                start_ms: track.start_ms
+            })
+            .collect()
+    }
+}
+
+impl Segment {
+    /// The ffmpeg input options that restrict decoding to this segment
+    pub fn ffmpeg_input_args(&self) -> Vec<String> {
+        let mut args = vec![
+            String::from("-ss"),
+            format!("{:.3}", self.start_ms as f64 / 1000.0)
+        ];
+
+        if let Some(end_ms) = self.end_ms {
+            args.push(String::from("-t"));
+            args.push(format!("{:.3}", (end_ms - self.start_ms) as f64 / 1000.0));
+        }
+
+        args
+    }
+
+    pub fn start_seconds(&self) -> f32 {
+        self.start_ms as f32 / 1000.0
+    }
+}
@@ -1,6 +1,10 @@
 // SPDX-FileCopyrightText: 2022-2024 Simon Repp
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
+use std::path::Path;
+
+use crate::Segment;
+
 pub mod aiff;
 pub mod alac;
 pub mod flac;
@@ -24,7 +28,46 @@ pub struct DecodeResult {
     pub samples: Vec<f32>
 }
 
+/// Decodes the audio file at the given path, dispatching by its (lowercase)
+/// extension.
+pub fn decode_file(extension: &str, path: &Path) -> Result<DecodeResult, String> {
+    match extension {
+        "aif" |
+        "aifc" |
+        "aiff" => aiff::decode(path),
+        "alac" => alac::decode(path),
+        "flac" => flac::decode(path),
+        "mp3" => mp3::decode(path),
+        "ogg" => ogg_vorbis::decode(path).map(|(decode_result, _comment_header)| decode_result),
+        "opus" => opus::decode(path),
+        "wav" => wav::decode(path),
+        _ => unreachable!()
+    }
+}
+
 impl DecodeResult {
+    /// Returns the part of the decoded audio that lies within the segment
+    pub fn segment(&self, segment: &Segment) -> DecodeResult {
+        let channels = self.channels.max(1) as usize;
+        let frame_count = self.samples.len() / channels;
+
+        let to_frame = |ms: u64| ((ms as u128 * self.sample_rate as u128 / 1000) as usize).min(frame_count);
+
+        let start_frame = to_frame(segment.start_ms);
+        let end_frame = segment.end_ms.map(to_frame).unwrap_or(frame_count).max(start_frame);
+
+        let samples = self.samples[(start_frame * channels)..(end_frame * channels)].to_vec();
+        let sample_count = (end_frame - start_frame) as u32;
+
+        DecodeResult {
+            channels: self.channels,
+            duration: sample_count as f32 / self.sample_rate as f32,
+            sample_count,
+            sample_rate: self.sample_rate,
+            samples
+        }
+    }
+
     pub fn zero_length_message() -> String {
         "Audio files without samples (zero length) are not supported".to_string()
     }
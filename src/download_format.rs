@@ -26,6 +26,21 @@ pub enum DownloadFormat {
 }
 
 impl DownloadFormat {
+    pub const ALL_MANIFEST_KEYS: [&'static str; 12] = [
+        "aac",
+        "aiff",
+        "alac",
+        "flac",
+        "mp3",
+        "mp3_orig",
+        "ogg_vorbis",
+        "opus",
+        "opus_48",
+        "opus_96",
+        "opus_128",
+        "wav"
+    ];
+
     /// DownloadFormat is a more user-facing abstraction over AudioFormat,
     /// but when we go towards transcoding etc. we "downcast" it into the
     /// more generic, internal AudioFormat representation.
@@ -52,6 +52,10 @@ pub struct Price {
 }
 
 impl DownloadAccessOption {
+    /// The keys accepted by release_download_access and
+    /// track_download_access (besides an external url).
+    pub const ALL_MANIFEST_KEYS: [&'static str; 4] = ["code", "disabled", "free", "paycurtain"];
+
     /// Combines DownloadAccess with payment, price and/or unlock info
     /// in order to form the final DownloadAccess data.
     pub fn assemble(
@@ -76,6 +80,7 @@ impl DownloadAccessOption {
 }
 
 impl ExtraDownloads {
+    pub const ALL_MANIFEST_KEYS: [&'static str; 3] = ["bundled", "disabled", "separate"];
     pub const BUNDLED: ExtraDownloads = ExtraDownloads { bundled: true, separate: false };
     pub const DISABLED: ExtraDownloads = ExtraDownloads { bundled: false, separate: false };
     pub const SEPARATE: ExtraDownloads = ExtraDownloads { bundled: false, separate: true };
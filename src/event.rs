@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use chrono::NaiveDate;
+
+use crate::Build;
+
+/// A concert/tour date of an artist, defined through an event field in
+/// an artist.eno manifest.
+#[derive(Clone, Debug)]
+pub struct Event {
+    pub city: Option<String>,
+    pub date: NaiveDate,
+    /// Url of a page where tickets for the event can be obtained
+    pub tickets: Option<String>,
+    pub venue: String
+}
+
+impl Event {
+    /// Events before the day of the build are considered past, they are
+    /// moved to the archive section on the pages they appear on.
+    pub fn is_past(&self, build: &Build) -> bool {
+        self.date < build.build_begin.date_naive()
+    }
+
+    /// Venue and city (if given) joined for display
+    pub fn location(&self) -> String {
+        match &self.city {
+            Some(city) => format!("{}, {}", self.venue, city),
+            None => self.venue.clone()
+        }
+    }
+
+    pub fn new(
+        city: Option<String>,
+        date: NaiveDate,
+        tickets: Option<String>,
+        venue: String
+    ) -> Event {
+        Event {
+            city,
+            date,
+            tickets,
+            venue
+        }
+    }
+}
@@ -16,10 +16,16 @@ pub struct FairDir {
     pub artist_manifest: Option<PathBuf>,
     pub audio_files: Vec<PathBuf>,
     pub catalog_manifest: Option<PathBuf>,
+    /// CUE sheets (see [crate::CueSheet]), which are only interpreted in
+    /// release directories, otherwise treated as extra files.
+    pub cue_files: Vec<PathBuf>,
     pub dirs: Vec<PathBuf>,
     pub extra_files: Vec<PathBuf>,
     pub image_files: Vec<PathBuf>,
+    pub page_manifest: Option<PathBuf>,
     pub path: PathBuf,
+    pub playlist_manifest: Option<PathBuf>,
+    pub post_manifest: Option<PathBuf>,
     pub release_manifest: Option<PathBuf>,
     pub track_manifest: Option<PathBuf>
 }
@@ -30,10 +36,14 @@ impl FairDir {
             artist_manifest: None,
             audio_files: Vec::new(),
             catalog_manifest: None,
+            cue_files: Vec::new(),
             dirs: Vec::new(),
             extra_files: Vec::new(),
             image_files: Vec::new(),
+            page_manifest: None,
             path: path.to_owned(),
+            playlist_manifest: None,
+            post_manifest: None,
             release_manifest: None,
             track_manifest: None
         }
@@ -118,6 +128,12 @@ impl FairDir {
                                 fair_dir.artist_manifest = Some(path);
                             } else if path.ends_with("catalog.eno") {
                                 fair_dir.catalog_manifest = Some(path);
+                            } else if path.ends_with("page.eno") {
+                                fair_dir.page_manifest = Some(path);
+                            } else if path.ends_with("playlist.eno") {
+                                fair_dir.playlist_manifest = Some(path);
+                            } else if path.ends_with("post.eno") {
+                                fair_dir.post_manifest = Some(path);
                             } else if path.ends_with("release.eno") {
                                 fair_dir.release_manifest = Some(path);
                             } else if path.ends_with("track.eno") {
@@ -130,10 +146,12 @@ impl FairDir {
                                     )
                                 ) {
                                 if extension == "eno" {
-                                    let error = format!("A manifest named '{}' was encountered at '{}', but this name is not recognized (allowed ones are 'artist.eno', 'catalog.eno', 'release.eno', and 'track.eno')", path.file_name().unwrap().to_string_lossy(), path.display());
+                                    let error = format!("A manifest named '{}' was encountered at '{}', but this name is not recognized (allowed ones are 'artist.eno', 'catalog.eno', 'page.eno', 'playlist.eno', 'post.eno', 'release.eno', and 'track.eno')", path.file_name().unwrap().to_string_lossy(), path.display());
                                     build.error(&error);
                                 } else if SUPPORTED_AUDIO_EXTENSIONS.contains(&&extension[..]) {
                                     fair_dir.audio_files.push(path);
+                                } else if extension == "cue" {
+                                    fair_dir.cue_files.push(path);
                                 } else if SUPPORTED_IMAGE_EXTENSIONS.contains(&&extension[..]) {
                                     fair_dir.image_files.push(path);
                                 } else if UNSUPPORTED_AUDIO_EXTENSIONS.contains(&&extension[..]) {
@@ -146,9 +164,11 @@ impl FairDir {
                                 fair_dir.extra_files.push(path);
                             }
                         } else if file_type.is_symlink() {
-                            warn!("Ignoring symlink '{}'", path.display());
+                            let warning = format!("Ignoring symlink '{}'", path.display());
+                            build.warning(&warning);
                         } else {
-                            warn!("Ignoring unsupported file '{}'", path.display());
+                            let warning = format!("Ignoring unsupported file '{}'", path.display());
+                            build.warning(&warning);
                         }
                     }
                 }
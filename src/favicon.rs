@@ -74,6 +74,27 @@ impl Favicon {
         }
     }
 
+    /// The favicon files as (url relative to the site root, mime type, sizes)
+    /// tuples, used as icons for the web app manifest. The sizes of custom
+    /// favicons are unknown to us. Must only be called after the favicon
+    /// was written.
+    pub fn web_app_icons(&self, build: &Build) -> Vec<(String, &'static str, Option<&'static str>)> {
+        match self {
+            Favicon::Custom { extension, .. } => {
+                let favicon_custom_hash = build.asset_hashes.favicon_custom.as_ref().unwrap();
+                let mime_type = if extension == "ico" { "image/x-icon" } else { "image/png" };
+
+                vec![(format!("favicon.{extension}?{favicon_custom_hash}"), mime_type, None)]
+            }
+            Favicon::Default => vec![
+                (format!("{FAVICON_SVG_FILENAME}?{}", AssetHashes::FAVICON_SVG), "image/svg+xml", Some("any")),
+                (format!("{FAVICON_LIGHT_PNG_FILENAME}?{}", AssetHashes::FAVICON_LIGHT_PNG), "image/png", Some("32x32")),
+                (format!("{FAVICON_DARK_PNG_FILENAME}?{}", AssetHashes::FAVICON_DARK_PNG), "image/png", Some("32x32"))
+            ],
+            Favicon::None => Vec::new()
+        }
+    }
+
     pub fn write(&self, build: &mut Build) {
         match self {
             Favicon::Custom { absolute_path, extension } => {
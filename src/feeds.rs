@@ -27,6 +27,15 @@ impl Feeds {
         podcast_rss: true
     };
 
+    pub const ALL_MANIFEST_KEYS: [&'static str; 6] = [
+        "all",
+        "atom",
+        "disabled",
+        "generic_rss",
+        "media_rss",
+        "podcast_rss"
+    ];
+
     pub const ATOM_FILENAME: &str = "feed.atom";
 
     pub const ATOM_ONLY: Feeds = Feeds {
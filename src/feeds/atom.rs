@@ -15,10 +15,12 @@ use crate::{
     Catalog,
     FeedImageAsset,
     GENERATOR_INFO,
+    Playlist,
+    Post,
     Release,
     SiteUrl
 };
-use crate::util::html_escape_outside_attribute;
+use crate::util::{html_escape_inside_attribute, html_escape_outside_attribute};
 
 use super::Feeds;
 
@@ -50,7 +52,7 @@ pub fn atom(build: &Build, catalog: &Catalog) {
     // to avoid statefulness, so this should be carefully weighed off)
     let build_begin = build.build_begin.to_rfc3339();
 
-    let entries = catalog.public_releases()
+    let entries = catalog.published_releases()
         .iter()
         .map(|release| {
             entry(
@@ -63,6 +65,21 @@ pub fn atom(build: &Build, catalog: &Catalog) {
         .collect::<Vec<String>>()
         .join("\n");
 
+    // Public curated playlists are announced alongside the releases
+    let playlist_entries = catalog.playlists
+        .iter()
+        .filter(|playlist| !playlist.unlisted)
+        .map(|playlist| playlist_entry(base_url, build, catalog, playlist))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    // Public posts are announced alongside the releases as well
+    let post_entries = catalog.public_posts()
+        .iter()
+        .map(|post| post_entry(base_url, build, catalog, post))
+        .collect::<Vec<String>>()
+        .join("\n");
+
     // TODO: icon (optional field where we could use a faircamp favicon)
     // "Identifies a small image which provides iconic visual identification for the feed. Icons should be square."
     // <icon>/icon.jpg</icon>
@@ -106,6 +123,8 @@ pub fn atom(build: &Build, catalog: &Catalog) {
             <title>{title_escaped}</title>
             <updated>{build_begin}</updated>
             {entries}
+            {playlist_entries}
+            {post_entries}
         </feed>
     "#);
 
@@ -201,6 +220,14 @@ fn entry(
         String::new()
     };
 
+    let license_link = match &release.license {
+        Some(license) => {
+            let url_escaped = html_escape_inside_attribute(&license.url);
+            format!(r#"<link href="{url_escaped}" rel="license"/>"#)
+        }
+        None => String::new()
+    };
+
     let title_escaped = html_escape_outside_attribute(&artists_and_title);
 
     // TODO: Link can include things like language, type, enclosure (!), etc.,
@@ -215,9 +242,83 @@ fn entry(
             {authors}
             <id>{release_url}</id>
             <link href="{release_url}" rel="alternate"/>
+            {license_link}
             <title>{title_escaped}</title>
             {summary}
             <updated>{build_begin_rfc3339}</updated>
         </entry>
     "#)
 }
+
+fn playlist_entry(
+    base_url: &SiteUrl,
+    build: &Build,
+    catalog: &Catalog,
+    playlist: &Playlist
+) -> String {
+    // See the remarks on the updated field in entry()
+    let build_begin_rfc3339 = build.build_begin.to_rfc3339();
+
+    // Playlists are curated by whoever runs the catalog, hence the catalog
+    // is given as their author (atom requires an author for every entry).
+    let catalog_title_escaped = html_escape_outside_attribute(&catalog.title());
+
+    let playlist_url = base_url.join_index(build, &playlist.permalink.slug);
+
+    let summary = if let Some(synopsis) = &playlist.synopsis {
+        let synopsis_escaped = html_escape_outside_attribute(synopsis);
+        format!("<summary>{synopsis_escaped}</summary>")
+    } else if let Some(html_and_stripped) = &playlist.more {
+        let more_html_escaped = html_escape_outside_attribute(html_and_stripped.html.as_str());
+        format!(r#"<summary type="html">{more_html_escaped}</summary>"#)
+    } else {
+        String::new()
+    };
+
+    let title_escaped = html_escape_outside_attribute(&playlist.title);
+
+    formatdoc!(r#"
+        <entry>
+            <author>
+                <name>{catalog_title_escaped}</name>
+            </author>
+            <id>{playlist_url}</id>
+            <link href="{playlist_url}" rel="alternate"/>
+            <title>{title_escaped}</title>
+            {summary}
+            <updated>{build_begin_rfc3339}</updated>
+        </entry>
+    "#)
+}
+
+fn post_entry(
+    base_url: &SiteUrl,
+    build: &Build,
+    catalog: &Catalog,
+    post: &Post
+) -> String {
+    // Unlike for releases we know when a post was published, it's the date
+    // the post carries.
+    let date_rfc3339 = post.date.and_hms_opt(0, 0, 0).unwrap().and_utc().to_rfc3339();
+
+    // Like playlists, posts are written by whoever runs the catalog
+    let catalog_title_escaped = html_escape_outside_attribute(&catalog.title());
+
+    let post_url = base_url.join_index(build, &post.permalink.slug);
+    let text_html_escaped = html_escape_outside_attribute(&post.text.html);
+    let title_escaped = html_escape_outside_attribute(&post.title);
+
+    formatdoc!(r#"
+        <entry>
+            <author>
+                <name>{catalog_title_escaped}</name>
+            </author>
+            <content type="html">{text_html_escaped}</content>
+            <id>{post_url}</id>
+            <link href="{post_url}" rel="alternate"/>
+            <published>{date_rfc3339}</published>
+            <title>{title_escaped}</title>
+            <updated>{date_rfc3339}</updated>
+        </entry>
+    "#)
+}
@@ -6,12 +6,17 @@
 
 use std::fs;
 
+use indoc::formatdoc;
+
 use crate::{
     Build,
     Catalog,
+    Playlist,
+    Post,
     Release,
     SiteUrl
 };
+use crate::util::{html_double_escape_outside_attribute, html_escape_outside_attribute};
 
 use super::Feeds;
 use super::rss::rss;
@@ -26,12 +31,26 @@ pub fn generic_rss(build: &Build, catalog: &Catalog) {
 
     let extra_namespaces = &[];
 
+    // Public curated playlists and posts are announced alongside the releases
+    let extra_items = catalog.playlists
+        .iter()
+        .filter(|playlist| !playlist.unlisted)
+        .map(|playlist| playlist_item(base_url, build, playlist))
+        .chain(
+            catalog.public_posts()
+                .iter()
+                .map(|post| post_item(base_url, build, post))
+        )
+        .collect::<Vec<String>>()
+        .join("\n");
+
     let xml = rss(
         base_url,
         build,
         catalog,
         channel_extensions,
         extra_namespaces,
+        &extra_items,
         &mut item_extensions,
         &url
     );
@@ -49,3 +68,54 @@ pub fn item_extensions(
     // rss module, adding nothing at all.
     String::new()
 }
+
+fn playlist_item(
+    base_url: &SiteUrl,
+    build: &Build,
+    playlist: &Playlist
+) -> String {
+    let description = match &playlist.synopsis {
+        Some(synopsis) => {
+            let synopsis_escaped = html_double_escape_outside_attribute(synopsis);
+            format!("<description>{synopsis_escaped}</description>")
+        }
+        None => String::new()
+    };
+
+    let link = base_url.join_index(build, &playlist.permalink.slug);
+
+    let title = html_double_escape_outside_attribute(&playlist.title);
+
+    formatdoc!(r#"
+        <item>
+            {description}
+            <guid>{link}</guid>
+            <link>{link}</link>
+            <title>{title}</title>
+        </item>
+    "#)
+}
+
+fn post_item(
+    base_url: &SiteUrl,
+    build: &Build,
+    post: &Post
+) -> String {
+    let description = html_escape_outside_attribute(&post.text.html);
+
+    let link = base_url.join_index(build, &post.permalink.slug);
+
+    let pub_date = post.date.and_hms_opt(0, 0, 0).unwrap().and_utc().to_rfc2822();
+
+    let title = html_double_escape_outside_attribute(&post.title);
+
+    formatdoc!(r#"
+        <item>
+            <description>{description}</description>
+            <guid>{link}</guid>
+            <link>{link}</link>
+            <pubDate>{pub_date}</pubDate>
+            <title>{title}</title>
+        </item>
+    "#)
+}
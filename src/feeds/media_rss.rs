@@ -13,16 +13,28 @@ use crate::{
     SiteUrl
 };
 
+use crate::waveform::{WAVEFORM_IMAGE_HEIGHT, WAVEFORM_IMAGE_WIDTH, WAVEFORM_PNG_FILENAME};
+
 use super::Feeds;
 use super::rss::rss;
 
 pub fn item_extensions(
-    _base_url: &SiteUrl,
+    base_url: &SiteUrl,
     _build: &Build,
-    _release: &Release
+    catalog: &Catalog,
+    release: &Release
 ) -> String {
-    // TODO: Implement
-    String::new()
+    // TODO: Implement the remaining media rss item extensions
+    let mut extensions = Vec::new();
+
+    if catalog.waveform_images && release.has_waveform_image() {
+        let release_slug = &release.permalink.slug;
+        let waveform_url = base_url.join_file(format!("{release_slug}/{WAVEFORM_PNG_FILENAME}"));
+        let thumbnail = format!(r#"<media:thumbnail url="{waveform_url}" height="{WAVEFORM_IMAGE_HEIGHT}" width="{WAVEFORM_IMAGE_WIDTH}"/>"#);
+        extensions.push(thumbnail);
+    }
+
+    extensions.join("\n")
 }
 
 pub fn media_rss(
@@ -37,13 +49,18 @@ pub fn media_rss(
 
     let extra_namespaces = &[r#"xmlns:media="http://search.yahoo.com/mrss/""#];
 
+    // Playlists have no enclosures of their own, hence they are only
+    // included in the generic rss feed.
+    let extra_items = "";
+
     let xml = rss(
         base_url,
         build,
         catalog,
         channel_extensions,
         extra_namespaces,
-        &mut item_extensions,
+        extra_items,
+        &mut |base_url: &SiteUrl, build: &Build, release: &Release| item_extensions(base_url, build, catalog, release),
         &url
     );
 
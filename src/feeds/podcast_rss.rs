@@ -20,6 +20,7 @@ use crate::{
     SiteUrl,
     TagMapping
 };
+use crate::chapter::PODCAST_CHAPTERS_FILENAME;
 use crate::util::{generic_hash, html_escape_outside_attribute};
 
 use super::Feeds;
@@ -110,6 +111,14 @@ pub fn item_extensions(
 
     extensions.push(itunes_image);
 
+    // podcast:chapters
+
+    if !track.chapters.is_empty() {
+        let chapters_url = base_url.join_file(format!("{release_slug}/{track_number}/{PODCAST_CHAPTERS_FILENAME}"));
+        let podcast_chapters = format!(r#"<podcast:chapters type="application/json+chapters" url="{chapters_url}"/>"#);
+        extensions.push(podcast_chapters);
+    }
+
     // TODO: Consider further items for future implementation:
     // https://github.com/Podcast-Standards-Project/PSP-1-Podcast-RSS-Specification?tab=readme-ov-file#required-item-elements
 
@@ -160,12 +169,16 @@ pub fn podcast_rss(build: &Build, catalog: &Catalog) {
         r#"xmlns:podcast="https://podcastindex.org/namespace/1.0""#
     ];
 
+    // Curated playlists can not be represented as episodes.
+    let extra_items = "";
+
     let xml = rss(
         base_url,
         build,
         catalog,
         &channel_extensions,
         extra_namespaces,
+        extra_items,
         &mut item_extensions,
         &url
     );
@@ -22,6 +22,7 @@ use crate::{
 };
 use crate::util::{
     html_double_escape_outside_attribute,
+    html_escape_inside_attribute,
     html_escape_outside_attribute
 };
 
@@ -34,12 +35,15 @@ pub fn rss(
     channel_extensions: &str,
     // E.g. to pass specific media or podcast namespaces
     extra_namespaces: &[&str],
+    // Complete items added by the caller after the release items (e.g.
+    // curated playlists in the generic rss feed).
+    extra_items: &str,
     // Specific item-level extension markup added by the caller (e.g. media
     // rss or podcast rss specific markup).
     item_extensions: &mut impl FnMut(&SiteUrl, &Build, &Release) -> String,
     feed_url: &str
 ) -> String {
-    let items = catalog.public_releases()
+    let items = catalog.published_releases()
         .iter()
         .map(|release| {
             item(
@@ -98,6 +102,16 @@ pub fn rss(
     let last_build_date = build.build_begin.to_rfc2822();
     let language = &build.locale.language;
 
+    let mut extra_namespaces = extra_namespaces.to_vec();
+
+    // Namespaces for declaring the license of items (only included if
+    // any release actually specifies a license)
+    if catalog.published_releases().iter().any(|release| release.borrow().license.is_some()) {
+        extra_namespaces.push(r#"xmlns:cc="http://creativecommons.org/ns#""#);
+        extra_namespaces.push(r#"xmlns:dcterms="http://purl.org/dc/terms/""#);
+        extra_namespaces.push(r#"xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#""#);
+    }
+
     let extra_namespaces = extra_namespaces.join(" ");
 
     // Note that atom:link inside <channel> is not something that points to an
@@ -120,6 +134,7 @@ pub fn rss(
                 <title>{channel_title}</title>
                 {channel_extensions}
                 {items}
+                {extra_items}
             </channel>
         </rss>
     "#)
@@ -166,6 +181,17 @@ fn item(
         String::new()
     };
 
+    let license = match &release.license {
+        Some(license) => {
+            let url_escaped = html_escape_inside_attribute(&license.url);
+            formatdoc!(r#"
+                <cc:license rdf:resource="{url_escaped}"/>
+                <dcterms:license>{url_escaped}</dcterms:license>
+            "#)
+        }
+        None => String::new()
+    };
+
     let link = base_url.join_index(build, release_slug);
 
     let title = html_double_escape_outside_attribute(&artists_and_title);
@@ -178,6 +204,7 @@ fn item(
         <item>
             {description}
             <guid>{link}</guid>
+            {license}
             <link>{link}</link>
             <title>{title}</title>
             {extensions}
@@ -10,6 +10,7 @@ use crate::{
     AudioFormat,
     AudioFormatFamily,
     ImageEmbed,
+    Segment,
     TagMapping
 };
 
@@ -92,15 +93,33 @@ fn apply_tag_write_flags(
     }
 }
 
+/// Whether ffmpeg can write chapter metadata for the given format (ID3v2
+/// CHAP frames for MP3, chapter comments for FLAC/Ogg/Opus, chapter track
+/// for ALAC/MP4). AAC is written as a raw ADTS stream, which has no place
+/// for chapters, and neither do AIFF and WAV.
+fn supports_chapters(format_family: AudioFormatFamily) -> bool {
+    match format_family {
+        AudioFormatFamily::Alac |
+        AudioFormatFamily::Flac |
+        AudioFormatFamily::Mp3 |
+        AudioFormatFamily::OggVorbis |
+        AudioFormatFamily::Opus => true,
+        AudioFormatFamily::Aac |
+        AudioFormatFamily::Aiff |
+        AudioFormatFamily::Wav => false
+    }
+}
+
 pub fn transcode(
     cover_path: Option<&PathBuf>,
     input_file: &Path,
+    input_segment: Option<&Segment>,
     output_file: &Path,
     source_format_family: AudioFormatFamily,
     target_format: AudioFormat,
     tag_mapping: &TagMapping
 ) -> Result<(), String> {
-    if matches!(target_format, AudioFormat::Mp3Orig) {
+    if matches!(target_format, AudioFormat::Mp3Orig) && input_segment.is_none() {
         fs::copy(input_file, output_file)
             .map_err(|e| format!("Failed to copy file from {:?} to {:?}: {e}", input_file, output_file))?;
         return Ok(());
@@ -109,8 +128,18 @@ pub fn transcode(
     let mut command = Command::new(FFMPEG_BINARY);
     
     command.arg("-y");
+
+    // Seeking as an input option restricts decoding to the segment, see
+    // https://trac.ffmpeg.org/wiki/Seeking
+    if let Some(segment) = input_segment {
+        command.args(segment.ffmpeg_input_args());
+    }
+
     command.arg("-i").arg(input_file);
 
+    // Temporary ffmpeg metadata file through which chapters are passed in
+    let mut chapters_file = None;
+
     match tag_mapping {
         TagMapping::Copy => {
             let target_format_family = target_format.family();
@@ -118,9 +147,27 @@ pub fn transcode(
             apply_tag_copy_flags(&mut command, source_format_family, target_format_family);
             apply_tag_write_flags(&mut command, target_format_family);
         }
-        TagMapping::Custom { album, album_artist, artist, image, title, track } => {
-            if let Some(ImageEmbed::Write(_))  = image {
+        TagMapping::Custom { album, album_artist, artist, chapters, copyright, image, title, track } => {
+            // Input 0 is the audio file, the index of further inputs
+            // depends on which of them are present.
+            let mut next_input_index = 1;
+
+            if let Some(ImageEmbed::Write { .. }) = image {
                 command.arg("-i").arg(cover_path.unwrap());
+                next_input_index += 1;
+            }
+
+            if let Some(ffmetadata) = chapters {
+                if supports_chapters(target_format.family()) {
+                    let ffmetadata_path = output_file.with_extension("ffmetadata");
+                    fs::write(&ffmetadata_path, ffmetadata)
+                        .map_err(|err| format!("Failed to write chapter metadata to {:?}: {err}", ffmetadata_path))?;
+
+                    command.arg("-i").arg(&ffmetadata_path);
+                    command.arg("-map_chapters").arg(next_input_index.to_string());
+
+                    chapters_file = Some(ffmetadata_path);
+                }
             }
 
             command.arg("-map_metadata").arg("-1");
@@ -137,12 +184,16 @@ pub fn transcode(
                 command.arg("-metadata").arg(format!("artist={}", artist));
             }
 
+            if let Some(copyright) = copyright {
+                command.arg("-metadata").arg(format!("copyright={}", copyright));
+            }
+
             match image {
                 Some(ImageEmbed::Copy) => {
                     command.arg("-c:v").arg("copy");
                     command.arg("-disposition:v:0").arg("attached_pic");
                 }
-                Some(ImageEmbed::Write(_)) => {
+                Some(ImageEmbed::Write { .. }) => {
                     match target_format.family() {
                         AudioFormatFamily::Aac => {
                             // Found no working example for adding cover art to AAC with ffmpeg so far.
@@ -249,12 +300,22 @@ pub fn transcode(
             command.arg("-b:a").arg("128k");
         }
         AudioFormat::Wav => (),
-        AudioFormat::Mp3Orig => ()
+        AudioFormat::Mp3Orig => {
+            // Only reached for segments, which are cut from the original
+            // mp3 without re-encoding it
+            command.arg("-codec:a").arg("copy");
+        }
     }
     
     command.arg(output_file);
 
-    match command.output() {
+    let result = command.output();
+
+    if let Some(ffmetadata_path) = chapters_file {
+        let _ = fs::remove_file(ffmetadata_path);
+    }
+
+    match result {
         Ok(output) => {
             if output.status.success() {
                 Ok(())
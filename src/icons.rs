@@ -175,6 +175,16 @@ pub fn success(label: &str) -> String {
     "#)
 }
 
+/// A closed lock
+pub fn lock(label: &str) -> String {
+    formatdoc!(r#"
+        <svg width="1em" height="1em" version="1.1" viewBox="0 0 64 64" xmlns="http://www.w3.org/2000/svg">
+            <title>{label}</title>
+            <path fill-rule="evenodd" d="m32.086 9.0391c-7.7328 0-14.078 6.3453-14.078 14.078v6.8906h-0.046874c-2.0885 0-3.7695 1.6092-3.7695 3.6094v17.463c0 2.0002 1.681 3.6113 3.7695 3.6113h28.119c2.0885 0 3.7695-1.6111 3.7695-3.6113v-17.463c0-2.0002-1.681-3.6094-3.7695-3.6094h-0.046874v-6.8906c0-7.7328-6.3453-14.078-14.078-14.078zm0 7.1074c3.8922 0 6.9727 3.0785 6.9727 6.9707v6.8906h-13.945v-6.8906c0-3.8922 3.0805-6.9707 6.9727-6.9707z"/>
+        </svg>
+    "#)
+}
+
 /// A half-open lock
 pub fn unlock(label: &str) -> String {
     formatdoc!(r#"
@@ -0,0 +1,158 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+/// iCalendar format reference:
+/// - https://datatracker.ietf.org/doc/html/rfc5545
+
+use slug::slugify;
+use std::collections::HashMap;
+
+use crate::{
+    Artist,
+    Build,
+    Catalog,
+    Event,
+    GENERATOR_INFO
+};
+
+pub const ICS_CALENDAR_FILENAME: &str = "events.ics";
+
+/// Content lines longer than this (in octets) must be folded
+const MAX_LINE_LENGTH: usize = 75;
+
+/// Escapes a value of a TEXT property
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Splits a content line into multiple lines of at most 75 octets, each
+/// continuation line starting with a space, never splitting a character.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut line_length = 0;
+
+    for character in line.chars() {
+        if line_length + character.len_utf8() > MAX_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            // The leading space of the continuation line counts as well
+            line_length = 1;
+        }
+
+        folded.push(character);
+        line_length += character.len_utf8();
+    }
+
+    folded
+}
+
+fn generate_calendar(name: &str, r_events: &[String]) -> String {
+    let lines = [
+        String::from("BEGIN:VCALENDAR"),
+        String::from("VERSION:2.0"),
+        format!("PRODID:-//{}//EN", escape_text(GENERATOR_INFO)),
+        String::from("CALSCALE:GREGORIAN"),
+        format!("X-WR-CALNAME:{}", escape_text(name))
+    ];
+
+    let mut calendar = lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<String>>()
+        .join("\r\n");
+
+    for r_event in r_events {
+        calendar.push_str("\r\n");
+        calendar.push_str(r_event);
+    }
+
+    calendar.push_str("\r\nEND:VCALENDAR\r\n");
+
+    calendar
+}
+
+/// Generate a single all-day VEVENT for the given event of an artist
+fn generate_event(artist: &Artist, build: &Build, event: &Event, uid: &str) -> String {
+    let artist_slug = &artist.permalink.slug;
+    let date_end = event.date.succ_opt().unwrap_or(event.date);
+
+    let mut lines = vec![
+        String::from("BEGIN:VEVENT"),
+        format!("UID:{uid}"),
+        format!("DTSTAMP:{}", build.build_begin.format("%Y%m%dT%H%M%SZ")),
+        format!("DTSTART;VALUE=DATE:{}", event.date.format("%Y%m%d")),
+        format!("DTEND;VALUE=DATE:{}", date_end.format("%Y%m%d")),
+        format!("SUMMARY:{}", escape_text(&format!("{} – {}", artist.name, event.venue))),
+        format!("LOCATION:{}", escape_text(&event.location()))
+    ];
+
+    if let Some(tickets) = &event.tickets {
+        lines.push(format!("URL:{tickets}"));
+    } else if artist.featured {
+        if let Some(base_url) = &build.base_url {
+            lines.push(format!("URL:{}", base_url.join_index(build, artist_slug)));
+        }
+    }
+
+    lines.push(String::from("END:VEVENT"));
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<String>>()
+        .join("\r\n")
+}
+
+/// Generate the VEVENTs for all events of an artist. The UID of an event is
+/// derived from its date, the artist and the venue, so that it stays the
+/// same when other events are added, removed or edited (calendar clients
+/// would otherwise show the event as new). Should several events of the
+/// artist share these (e.g. an afternoon and an evening show at the same
+/// venue), all but the first get a running number appended.
+fn generate_events(artist: &Artist, build: &Build) -> Vec<String> {
+    let artist_slug = &artist.permalink.slug;
+    let domain = match &build.base_url {
+        Some(base_url) => base_url.without_scheme_and_trailing_slash().to_string(),
+        None => String::from("faircamp")
+    };
+
+    let mut occurrences: HashMap<String, usize> = HashMap::new();
+
+    artist.events
+        .iter()
+        .map(|event| {
+            let venue_slug = slugify(&event.venue);
+            let uid_base = format!("{}-{artist_slug}-{venue_slug}", event.date.format("%Y%m%d"));
+
+            let occurrence = occurrences.entry(uid_base.clone()).or_insert(0);
+            *occurrence += 1;
+
+            let uid = match *occurrence {
+                1 => format!("{uid_base}@{domain}"),
+                number => format!("{uid_base}-{number}@{domain}")
+            };
+
+            generate_event(artist, build, event, &uid)
+        })
+        .collect()
+}
+
+/// Generate a complete iCalendar document with all events of an artist.
+pub fn generate_for_artist(artist: &Artist, build: &Build) -> String {
+    let r_events = generate_events(artist, build);
+
+    generate_calendar(&artist.name, &r_events)
+}
+
+/// Generate a complete iCalendar document with the events of all public
+/// artists in the catalog.
+pub fn generate_for_catalog(build: &Build, catalog: &Catalog) -> String {
+    let r_events = catalog.event_artists()
+        .iter()
+        .flat_map(|artist| generate_events(&artist.borrow(), build))
+        .collect::<Vec<String>>();
+
+    generate_calendar(&catalog.title(), &r_events)
+}
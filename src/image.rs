@@ -23,19 +23,28 @@ use crate::{
 use crate::util::url_safe_base64;
 
 mod artist;
+mod crop;
 mod feed;
+mod gallery;
 mod processor;
 mod release;
+mod variant;
 
 use artist::{ArtistAsset, ArtistAssets};
+use gallery::GalleryAsset;
 use processor::{ImageInMemory, ResizeMode};
 use release::{CoverAsset, CoverAssets};
 
+pub use crop::ImageCrop;
 pub use feed::{FeedImageAsset};
+pub use gallery::GalleryAssets;
 pub use processor::ImageProcessor;
+pub use variant::{ImageFormat, ImageVariant};
 
 const BACKGROUND_MAX_EDGE_SIZE: u32 = 1280;
 const FEED_MAX_EDGE_SIZE: u32 = 920;
+const GALLERY_FULL_MAX_EDGE_SIZE: u32 = 1280;
+const GALLERY_THUMBNAIL_EDGE_SIZE: u32 = 320;
 
 /// Artist/cover images are resized towards certain max widths, e.g. 320, 480, 640.
 /// The minimum width version (in the example 320) is always computed.
@@ -45,9 +54,22 @@ const FEED_MAX_EDGE_SIZE: u32 = 920;
 /// but a 321 pixels wide image will only be resized to 320 pixels width.
 const MIN_OVERSHOOT: f32 = 1.2;
 
-/// Associates an [ImageRcView] with an image description
+/// The width of the image in memory, or of the crop box if one applies.
+/// This is what the MIN_OVERSHOOT calculations are based on.
+fn source_width(image_in_memory: &ImageInMemory, crop: Option<ImageCrop>) -> f32 {
+    let width = image_in_memory.width();
+
+    crop
+        .and_then(|crop| crop.box_pixels(width, image_in_memory.height()))
+        .map(|(_left, _top, box_width, _box_height)| box_width)
+        .unwrap_or(width) as f32
+}
+
+/// Associates an [ImageRcView] with an image description and optionally
+/// a user-supplied focal point or crop box.
 #[derive(Clone, Debug)]
 pub struct DescribedImage {
+    pub crop: Option<ImageCrop>,
     pub description: Option<String>,
     pub image: ImageRcView
 }
@@ -61,8 +83,14 @@ pub struct DescribedImage {
 pub struct Image {
     pub artist_assets: Option<ArtistAssets>,
     pub background_asset: Option<Asset>,
+    /// The crop box (if any) with which the background asset was computed.
+    pub background_crop: Option<ImageCrop>,
+    /// Variants of the background asset in modern formats, these are
+    /// computed, marked stale and removed together with the background asset.
+    pub background_variants: Vec<ImageVariant>,
     pub cover_assets: Option<CoverAssets>,
     pub feed_asset: Option<FeedImageAsset>,
+    pub gallery_assets: Option<GalleryAssets>,
     /// Hash of the file content of the source image, with this we
     /// can uniquely identify and re-associate the computed cache
     /// data, no matter where the source file moves.
@@ -82,13 +110,28 @@ pub struct ImageRcView {
 }
 
 pub struct ImgAttributes {
+    /// Additional srcsets for variants in modern formats (if any), in
+    /// order of preference.
+    pub sources: Vec<ImgSource>,
     pub src: String,
     pub srcset: String
 }
 
+/// A srcset for the variants of an image in a single modern format, for
+/// rendering as a <source> element inside a <picture> element.
+pub struct ImgSource {
+    pub format: ImageFormat,
+    pub srcset: String
+}
+
 impl DescribedImage {
-    pub fn new(description: Option<String>, image: ImageRcView) -> DescribedImage {
+    pub fn new(
+        crop: Option<ImageCrop>,
+        description: Option<String>,
+        image: ImageRcView
+    ) -> DescribedImage {
         DescribedImage {
+            crop,
             description,
             image
         }
@@ -107,21 +150,27 @@ impl Image {
     /// Increase version on each change to the data layout of [Image].
     /// This automatically informs the cache not to try to deserialize
     /// manifests that hold old, incompatible data.
-    pub const CACHE_SERIALIZATION_KEY: &'static str = "image2";
+    pub const CACHE_SERIALIZATION_KEY: &'static str = "image5";
 
     pub fn artist_assets(
         &mut self,
         build: &Build,
+        crop: Option<ImageCrop>,
         source_path: &Path
     ) -> &mut ArtistAssets {
-        if let Some(assets) = self.artist_assets.as_mut() {
+        // If the requested image formats or the crop changed since the
+        // assets were computed, we compute them anew (the former assets
+        // thereby become orphaned and get cleaned up by the cache).
+        if let Some(assets) = self.artist_assets
+            .as_mut()
+            .filter(|assets| assets.formats() == build.image_formats && assets.crop == crop) {
             assets.unmark_stale();
         } else {
             info_resizing!("{:?} for usage as an artist image", &source_path);
 
             let absolute_source_path = build.catalog_dir.join(source_path);
             let image_in_memory = build.image_processor.open_opaque(&absolute_source_path);
-            let source_width = image_in_memory.width() as f32;
+            let source_width = source_width(&image_in_memory, crop);
 
             // Compute fixed sizes.
             // Viewport width < 30rem (480px at 16px font-size) = 100vw/40vw = 2.5
@@ -133,7 +182,7 @@ impl Image {
                 max_width: 320,
                 min_aspect: 2.25
             };
-            let fixed_max_320 = Image::compute_artist_asset(build, "fixed", &image_in_memory, resize_mode_fixed_320);
+            let fixed_max_320 = Image::compute_artist_asset(build, crop, "fixed", &image_in_memory, resize_mode_fixed_320);
 
             let fixed_max_480 = if source_width > 320.0 * MIN_OVERSHOOT {
                 let resize_mode_fixed_480 = ResizeMode::CoverRectangle {
@@ -141,7 +190,7 @@ impl Image {
                     max_width: 480,
                     min_aspect: 2.25
                 };
-                Some(Image::compute_artist_asset(build, "fixed", &image_in_memory, resize_mode_fixed_480))
+                Some(Image::compute_artist_asset(build, crop, "fixed", &image_in_memory, resize_mode_fixed_480))
             } else {
                 None
             };
@@ -152,7 +201,7 @@ impl Image {
                     max_width: 640,
                     min_aspect: 2.25
                 };
-                Some(Image::compute_artist_asset(build, "fixed", &image_in_memory, resize_mode_fixed_640))
+                Some(Image::compute_artist_asset(build, crop, "fixed", &image_in_memory, resize_mode_fixed_640))
             } else {
                 None
             };
@@ -167,7 +216,7 @@ impl Image {
                 max_width: 640,
                 min_aspect: 2.5
             };
-            let fluid_max_640 = Image::compute_artist_asset(build, "fluid", &image_in_memory, resize_mode_fluid_640);
+            let fluid_max_640 = Image::compute_artist_asset(build, crop, "fluid", &image_in_memory, resize_mode_fluid_640);
 
             let fluid_max_960 = if source_width > 640.0 * MIN_OVERSHOOT {
                 let resize_mode_fluid_960 = ResizeMode::CoverRectangle {
@@ -175,7 +224,7 @@ impl Image {
                     max_width: 960,
                     min_aspect: 2.5
                 };
-                Some(Image::compute_artist_asset(build, "fluid", &image_in_memory, resize_mode_fluid_960))
+                Some(Image::compute_artist_asset(build, crop, "fluid", &image_in_memory, resize_mode_fluid_960))
             } else {
                 None
             };
@@ -186,12 +235,13 @@ impl Image {
                     max_width: 1280,
                     min_aspect: 2.5
                 };
-                Some(Image::compute_artist_asset(build, "fluid", &image_in_memory, resize_mode_fluid_1280))
+                Some(Image::compute_artist_asset(build, crop, "fluid", &image_in_memory, resize_mode_fluid_1280))
             } else {
                 None
             };
 
             let artist_assets = ArtistAssets {
+                crop,
                 fixed_max_320,
                 fixed_max_480,
                 fixed_max_640,
@@ -231,9 +281,23 @@ impl Image {
     pub fn background_asset(
         &mut self,
         build: &Build,
+        crop: Option<ImageCrop>,
         source_path: &Path
     ) -> &mut Asset {
-        if let Some(asset) = self.background_asset.as_mut() {
+        let background_formats: Vec<ImageFormat> = self.background_variants
+            .iter()
+            .map(|variant| variant.format)
+            .collect();
+
+        // Background images are never cropped to a different aspect ratio,
+        // therefore only a crop box (but not a focal point) affects them.
+        let crop = crop.filter(|crop| matches!(crop, ImageCrop::Box { .. }));
+
+        let up_to_date = background_formats == build.image_formats && self.background_crop == crop;
+
+        if let Some(asset) = self.background_asset
+            .as_mut()
+            .filter(|_| up_to_date) {
             asset.unmark_stale();
         } else {
             info_resizing!("{:?} for usage as a background image", &source_path);
@@ -242,9 +306,22 @@ impl Image {
             let image_in_memory = build.image_processor.open_opaque(&absolute_source_path);
 
             let resize_mode = ResizeMode::ContainInSquare { max_edge_size: BACKGROUND_MAX_EDGE_SIZE };
-            let (filename, _dimensions) = build.image_processor.resize_opaque(build, &image_in_memory, resize_mode);
+            let resized = build.image_processor.resize_opaque(
+                build,
+                &image_in_memory,
+                resize_mode,
+                crop,
+                &build.image_formats
+            );
 
-            self.background_asset.replace(Asset::new(build, filename, AssetIntent::Deliverable));
+            self.background_crop = crop;
+
+            self.background_variants = resized.variants
+                .into_iter()
+                .map(|(format, filename)| ImageVariant::new(build, filename, format))
+                .collect();
+
+            self.background_asset.replace(Asset::new(build, resized.filename, AssetIntent::Deliverable));
         }
 
         self.background_asset.as_mut().unwrap()
@@ -252,44 +329,92 @@ impl Image {
 
     fn compute_artist_asset(
         build: &Build,
+        crop: Option<ImageCrop>,
         format: &str,
         image_in_memory: &ImageInMemory,
         resize_mode: ResizeMode
     ) -> ArtistAsset {
-        let (filename, dimensions) = build.image_processor.resize_opaque(
+        let resized = build.image_processor.resize_opaque(
             build,
             image_in_memory,
-            resize_mode
+            resize_mode,
+            crop,
+            &build.image_formats
         );
 
-        let metadata = fs::metadata(build.cache_dir.join(&filename)).unwrap();
+        let metadata = fs::metadata(build.cache_dir.join(&resized.filename)).unwrap();
+
+        let variants = resized.variants
+            .into_iter()
+            .map(|(format, filename)| ImageVariant::new(build, filename, format))
+            .collect();
 
         ArtistAsset {
-            filename,
+            filename: resized.filename,
             filesize_bytes: metadata.len(),
             format: format.to_string(),
-            height: dimensions.1,
-            width: dimensions.0
+            height: resized.dimensions.1,
+            variants,
+            width: resized.dimensions.0
         }
     }
 
     fn compute_cover_asset(
         build: &Build,
+        crop: Option<ImageCrop>,
         image_in_memory: &ImageInMemory,
         resize_mode: ResizeMode
     ) -> CoverAsset {
-        let (filename, dimensions) = build.image_processor.resize_opaque(
+        let resized = build.image_processor.resize_opaque(
             build,
             image_in_memory,
-            resize_mode
+            resize_mode,
+            crop,
+            &build.image_formats
         );
 
-        let metadata = fs::metadata(build.cache_dir.join(&filename)).unwrap();
+        let metadata = fs::metadata(build.cache_dir.join(&resized.filename)).unwrap();
+
+        let variants = resized.variants
+            .into_iter()
+            .map(|(format, filename)| ImageVariant::new(build, filename, format))
+            .collect();
 
         CoverAsset {
-            edge_size: dimensions.0,
-            filename,
-            filesize_bytes: metadata.len()
+            edge_size: resized.dimensions.0,
+            filename: resized.filename,
+            filesize_bytes: metadata.len(),
+            variants
+        }
+    }
+
+    fn compute_gallery_asset(
+        build: &Build,
+        crop: Option<ImageCrop>,
+        image_in_memory: &ImageInMemory,
+        resize_mode: ResizeMode
+    ) -> GalleryAsset {
+        let resized = build.image_processor.resize_opaque(
+            build,
+            image_in_memory,
+            resize_mode,
+            crop,
+            &build.image_formats
+        );
+
+        let metadata = fs::metadata(build.cache_dir.join(&resized.filename)).unwrap();
+
+        let variants = resized.variants
+            .into_iter()
+            .map(|(format, filename)| ImageVariant::new(build, filename, format))
+            .collect();
+
+        GalleryAsset {
+            filename: resized.filename,
+            filesize_bytes: metadata.len(),
+            height: resized.dimensions.1,
+            variants,
+            width: resized.dimensions.0
         }
     }
 
@@ -307,49 +432,56 @@ impl Image {
     pub fn cover_assets(
         &mut self,
         build: &Build,
+        crop: Option<ImageCrop>,
         source_path: &Path
     ) -> &mut CoverAssets {
-        if let Some(assets) = self.cover_assets.as_mut() {
+        // If the requested image formats or the crop changed since the
+        // assets were computed, we compute them anew (the former assets
+        // thereby become orphaned and get cleaned up by the cache).
+        if let Some(assets) = self.cover_assets
+            .as_mut()
+            .filter(|assets| assets.formats() == build.image_formats && assets.crop == crop) {
             assets.unmark_stale();
         } else {
             info_resizing!("{:?} for usage as a cover image", source_path);
 
             let absolute_source_path = build.catalog_dir.join(source_path);
             let image_in_memory = build.image_processor.open_opaque(&absolute_source_path);
-            let source_width = image_in_memory.width() as f32;
+            let source_width = source_width(&image_in_memory, crop);
 
             let resize_mode_max_160 = ResizeMode::CoverSquare { edge_size: 160 };
-            let max_160 = Image::compute_cover_asset(build, &image_in_memory, resize_mode_max_160);
+            let max_160 = Image::compute_cover_asset(build, crop, &image_in_memory, resize_mode_max_160);
 
             let max_320 = if source_width > 160.0 * MIN_OVERSHOOT {
                 let resize_mode_max_320 = ResizeMode::CoverSquare { edge_size: 320 };
-                Some(Image::compute_cover_asset(build, &image_in_memory, resize_mode_max_320))
+                Some(Image::compute_cover_asset(build, crop, &image_in_memory, resize_mode_max_320))
             } else {
                 None
             };
 
             let max_480 = if source_width > 320.0 * MIN_OVERSHOOT {
                 let resize_mode_max_480 = ResizeMode::CoverSquare { edge_size: 480 };
-                Some(Image::compute_cover_asset(build, &image_in_memory, resize_mode_max_480))
+                Some(Image::compute_cover_asset(build, crop, &image_in_memory, resize_mode_max_480))
             } else {
                 None
             };
 
             let max_800 = if source_width > 480.0 * MIN_OVERSHOOT {
                 let resize_mode_max_800 = ResizeMode::CoverSquare { edge_size: 800 };
-                Some(Image::compute_cover_asset(build, &image_in_memory, resize_mode_max_800))
+                Some(Image::compute_cover_asset(build, crop, &image_in_memory, resize_mode_max_800))
             } else {
                 None
             };
 
             let max_1280 = if source_width > 800.0 * MIN_OVERSHOOT {
                 let resize_mode_max_1280 = ResizeMode::CoverSquare { edge_size: 1280 };
-                Some(Image::compute_cover_asset(build, &image_in_memory, resize_mode_max_1280))
+                Some(Image::compute_cover_asset(build, crop, &image_in_memory, resize_mode_max_1280))
             } else {
                 None
             };
 
             let cover_assets = CoverAssets {
+                crop,
                 marked_stale: None,
                 max_160,
                 max_320,
@@ -410,15 +542,19 @@ impl Image {
             let absolute_source_path = build.catalog_dir.join(source_path);
             let image_in_memory = build.image_processor.open_opaque(&absolute_source_path);
 
-            let (filename, dimensions) = build.image_processor.resize_opaque(
+            // Feed readers and podcast clients expect jpeg (or png) images,
+            // therefore no variants in modern formats are requested here.
+            let resized = build.image_processor.resize_opaque(
                 build,
                 &image_in_memory,
-                ResizeMode::ContainInSquare { max_edge_size: FEED_MAX_EDGE_SIZE }
+                ResizeMode::ContainInSquare { max_edge_size: FEED_MAX_EDGE_SIZE },
+                None,
+                &[]
             );
 
-            let edge_size = dimensions.0; // square ratio
+            let edge_size = resized.dimensions.0; // square ratio
 
-            let feed_asset = FeedImageAsset::new(build, edge_size, filename);
+            let feed_asset = FeedImageAsset::new(build, edge_size, resized.filename);
 
             self.feed_asset.replace(feed_asset);
         }
@@ -433,6 +569,51 @@ impl Image {
         self.feed_asset.as_ref().unwrap()
     }
 
+    pub fn gallery_assets(
+        &mut self,
+        build: &Build,
+        crop: Option<ImageCrop>,
+        source_path: &Path
+    ) -> &mut GalleryAssets {
+        // If the requested image formats or the crop changed since the
+        // assets were computed, we compute them anew (the former assets
+        // thereby become orphaned and get cleaned up by the cache).
+        if let Some(assets) = self.gallery_assets
+            .as_mut()
+            .filter(|assets| assets.formats() == build.image_formats && assets.crop == crop) {
+            assets.unmark_stale();
+        } else {
+            info_resizing!("{:?} for usage as a gallery image", source_path);
+
+            let absolute_source_path = build.catalog_dir.join(source_path);
+            let image_in_memory = build.image_processor.open_opaque(&absolute_source_path);
+
+            let resize_mode_thumbnail = ResizeMode::CoverSquare { edge_size: GALLERY_THUMBNAIL_EDGE_SIZE };
+            let thumbnail = Image::compute_gallery_asset(build, crop, &image_in_memory, resize_mode_thumbnail);
+
+            let resize_mode_full = ResizeMode::ContainInSquare { max_edge_size: GALLERY_FULL_MAX_EDGE_SIZE };
+            let full = Image::compute_gallery_asset(build, crop, &image_in_memory, resize_mode_full);
+
+            let gallery_assets = GalleryAssets {
+                crop,
+                full,
+                marked_stale: None,
+                thumbnail
+            };
+
+            self.gallery_assets.replace(gallery_assets);
+        }
+
+        self.gallery_assets.as_mut().unwrap()
+    }
+
+    /// Gets the computed gallery assets. Only call(ed) at later points in
+    /// the build process where we already ensured computation of the assets -
+    /// will panic when called before the assets are computed.
+    pub fn gallery_assets_unchecked(&self) -> &GalleryAssets {
+        self.gallery_assets.as_ref().unwrap()
+    }
+
     pub fn manifest_path(&self, cache_dir: &Path) -> PathBuf {
         let manifest_filename = format!("{}.{}.bincode", url_safe_base64(self.hash.value), Image::CACHE_SERIALIZATION_KEY);
         cache_dir.join(manifest_filename)
@@ -443,6 +624,7 @@ impl Image {
         if let Some(asset) = self.background_asset.as_mut() { asset.mark_stale(timestamp); }
         if let Some(asset) = self.cover_assets.as_mut() { asset.mark_stale(timestamp); }
         if let Some(asset) = self.feed_asset.as_mut() { asset.mark_stale(timestamp); }
+        if let Some(asset) = self.gallery_assets.as_mut() { asset.mark_stale(timestamp); }
 
         for view in self.views.iter_mut() {
             view.mark_stale(timestamp);
@@ -453,8 +635,11 @@ impl Image {
         Image {
             artist_assets: None,
             background_asset: None,
+            background_crop: None,
+            background_variants: Vec::new(),
             cover_assets: None,
             feed_asset: None,
+            gallery_assets: None,
             hash,
             views: vec![View::new(file_meta)]
         }
@@ -538,14 +723,11 @@ impl Hash for ImageRcView {
 }
 
 impl ImgAttributes {
-    pub fn new(src: String, srcset: String) -> ImgAttributes {
-        ImgAttributes { src, srcset }
-    }
-
-    /// Assets MUST be passed in ascending size. prefix must point to the
-    /// artist directory.
-    pub fn new_for_artist(
-        assets_ascending_by_size: Vec<&ArtistAsset>,
+    /// Builds the attributes from the target filename (of the jpeg version),
+    /// width and variants of each asset, which MUST be passed in ascending
+    /// size. Each variant format gets a srcset of its own (see [ImgSource]).
+    fn from_assets(
+        assets_ascending_by_size: Vec<(String, u32, &[ImageVariant])>,
         hash: &str,
         prefix: &str
     ) -> ImgAttributes {
@@ -554,10 +736,7 @@ impl ImgAttributes {
 
         let mut asset_peek_iter = assets_ascending_by_size.iter().peekable();
 
-        while let Some(asset) = asset_peek_iter.next() {
-            let filename = asset.target_filename();
-            let width = asset.width;
-
+        while let Some((filename, width, _variants)) = asset_peek_iter.next() {
             srcset.push(format!("{prefix}{filename}?{hash} {width}w"));
 
             if asset_peek_iter.peek().is_none() {
@@ -565,36 +744,91 @@ impl ImgAttributes {
             }
         }
 
+        let formats = assets_ascending_by_size[0].2
+            .iter()
+            .map(|variant| variant.format);
+
+        let sources = formats
+            .map(|format| {
+                let srcset = assets_ascending_by_size
+                    .iter()
+                    .filter_map(|(target_filename, width, variants)| {
+                        variants
+                            .iter()
+                            .find(|variant| variant.format == format)
+                            .map(|variant| {
+                                let filename = variant.target_filename(target_filename);
+                                format!("{prefix}{filename}?{hash} {width}w")
+                            })
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",");
+
+                ImgSource { format, srcset }
+            })
+            .collect();
+
         ImgAttributes {
+            sources,
             src,
             srcset: srcset.join(",")
         }
     }
 
+    pub fn new(src: String, srcset: String) -> ImgAttributes {
+        ImgAttributes {
+            sources: Vec::new(),
+            src,
+            srcset
+        }
+    }
+
+    /// Assets MUST be passed in ascending size. prefix must point to the
+    /// artist directory.
+    pub fn new_for_artist(
+        assets_ascending_by_size: Vec<&ArtistAsset>,
+        hash: &str,
+        prefix: &str
+    ) -> ImgAttributes {
+        let assets = assets_ascending_by_size
+            .iter()
+            .map(|asset| (asset.target_filename(), asset.width, asset.variants.as_slice()))
+            .collect();
+
+        ImgAttributes::from_assets(assets, hash, prefix)
+    }
+
     /// Assets MUST be passed in ascending size
     pub fn new_for_cover(
         assets_ascending_by_size: Vec<&CoverAsset>,
         hash: &str,
         prefix: &str
     ) -> ImgAttributes {
-        let mut src = String::new();
-        let mut srcset = Vec::new();
-
-        let mut asset_peek_iter = assets_ascending_by_size.iter().peekable();
-
-        while let Some(asset) = asset_peek_iter.next() {
-            let edge_size = asset.edge_size;
-            let filename = asset.target_filename();
-            srcset.push(format!("{prefix}{filename}?{hash} {edge_size}w"));
-
-            if asset_peek_iter.peek().is_none() {
-                src = format!("{prefix}{filename}?{hash}");
-            }
-        }
-
-        ImgAttributes {
-            src,
-            srcset: srcset.join(",")
-        }
+        let assets = assets_ascending_by_size
+            .iter()
+            .map(|asset| (asset.target_filename(), asset.edge_size, asset.variants.as_slice()))
+            .collect();
+
+        ImgAttributes::from_assets(assets, hash, prefix)
+    }
+
+    /// Renders a <source> element for each variant format (if any), to be
+    /// placed before the (jpeg) fallback <img> element inside a <picture>
+    /// element. media and sizes are passed on verbatim to each element.
+    pub fn picture_sources(&self, media: Option<&str>, sizes: &str) -> String {
+        let media_attribute = match media {
+            Some(media) => format!(r#"media="{media}" "#),
+            None => String::new()
+        };
+
+        self.sources
+            .iter()
+            .map(|source| {
+                let mime_type = source.format.mime_type();
+                let srcset = &source.srcset;
+                format!(r#"<source {media_attribute}sizes="{sizes}" srcset="{srcset}" type="{mime_type}">"#)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
     }
 }
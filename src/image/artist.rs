@@ -4,7 +4,7 @@
 use chrono::{DateTime, Utc};
 use serde_derive::{Serialize, Deserialize};
 
-use super::ImgAttributes;
+use super::{ImageCrop, ImageFormat, ImageVariant, ImgAttributes};
 
 /// A single, resized version of the artist image.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -15,6 +15,8 @@ pub struct ArtistAsset {
     pub filesize_bytes: u64,
     pub format: String,
     pub height: u32,
+    /// Additional versions of this asset in modern formats (if requested)
+    pub variants: Vec<ImageVariant>,
     pub width: u32
 }
 
@@ -22,6 +24,8 @@ pub struct ArtistAsset {
 /// display on different screen sizes. (Numbers refer to maximum width)
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ArtistAssets {
+    /// The focal point or crop box (if any) these assets were computed with
+    pub crop: Option<ImageCrop>,
     pub fixed_max_320: ArtistAsset,
     pub fixed_max_480: Option<ArtistAsset>,
     pub fixed_max_640: Option<ArtistAsset>,
@@ -32,6 +36,10 @@ pub struct ArtistAssets {
 }
 
 impl ArtistAsset {
+    pub fn formats(&self) -> Vec<ImageFormat> {
+        self.variants.iter().map(|variant| variant.format).collect()
+    }
+
     /// The filename of this asset as we are writing it to the artist
     /// directory at build time, e.g. something like "fixed_480x240.jpg"
     /// or "fluid_640x240.jpg".
@@ -58,6 +66,11 @@ impl ArtistAssets {
         result
     }
 
+    /// The modern formats in which variants of all assets are available.
+    pub fn formats(&self) -> Vec<ImageFormat> {
+        self.fixed_max_320.formats()
+    }
+
     pub fn img_attributes_fixed(
         &self,
         hash: &str,
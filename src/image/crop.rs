@@ -0,0 +1,121 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use serde_derive::{Serialize, Deserialize};
+use std::hash::{Hash, Hasher};
+
+/// User-supplied control over which part of an image is kept when it is
+/// cropped (e.g. square covers, wide artist images). All values are
+/// fractions (0.0-1.0) of the width/height of the source image, so they
+/// remain valid when the source image is replaced by a version of the
+/// same image in a different resolution.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum ImageCrop {
+    /// Only the given region of the image is used at all, further cropping
+    /// (if needed) happens around the center of the region.
+    Box { height: f32, left: f32, top: f32, width: f32 },
+    /// Cropping happens such that this point lies as close to the center
+    /// of the resulting image as possible.
+    FocalPoint { x: f32, y: f32 }
+}
+
+impl ImageCrop {
+    /// Returns the region (left, top, width, height) in pixels that is to be
+    /// cut out of an image with the given dimensions before any further
+    /// processing, or None if the whole image is to be used.
+    pub fn box_pixels(&self, width: u32, height: u32) -> Option<(u32, u32, u32, u32)> {
+        match self {
+            ImageCrop::Box { height: box_height, left, top, width: box_width } => {
+                let left_px = ((left * width as f32).round() as u32).min(width - 1);
+                let top_px = ((top * height as f32).round() as u32).min(height - 1);
+                let width_px = ((box_width * width as f32).round() as u32).clamp(1, width - left_px);
+                let height_px = ((box_height * height as f32).round() as u32).clamp(1, height - top_px);
+
+                Some((left_px, top_px, width_px, height_px))
+            }
+            ImageCrop::FocalPoint { .. } => None
+        }
+    }
+
+    /// The point (x, y as fractions) around which cropping happens, after
+    /// the crop box (if any) has been applied.
+    pub fn focal_point(&self) -> (f32, f32) {
+        match self {
+            ImageCrop::Box { .. } => (0.5, 0.5),
+            ImageCrop::FocalPoint { x, y } => (*x, *y)
+        }
+    }
+
+    /// Parses the value of a crop attribute, that is four percentages
+    /// separated by spaces (left, top, width, height), e.g. "10% 0% 80% 100%".
+    pub fn from_crop_value(value: &str) -> Result<ImageCrop, String> {
+        let percentages = parse_percentages(value)?;
+
+        if let [left, top, width, height] = percentages[..] {
+            if width == 0.0 || height == 0.0 {
+                Err(String::from("the width and height of the crop box can not be 0%"))
+            } else if left + width > 1.0001 || top + height > 1.0001 {
+                // (The tiny tolerance accounts for floating point imprecision)
+                Err(String::from("the crop box extends beyond the image (left + width and top + height can each be at most 100%)"))
+            } else {
+                Ok(ImageCrop::Box { height, left, top, width })
+            }
+        } else {
+            Err(String::from("exactly four percentages (left, top, width, height) are required, e.g. '10% 0% 80% 100%'"))
+        }
+    }
+
+    /// Parses the value of a focus attribute, that is two percentages
+    /// separated by a space (x, y), e.g. "50% 20%".
+    pub fn from_focus_value(value: &str) -> Result<ImageCrop, String> {
+        let percentages = parse_percentages(value)?;
+
+        if let [x, y] = percentages[..] {
+            Ok(ImageCrop::FocalPoint { x, y })
+        } else {
+            Err(String::from("exactly two percentages (horizontal, vertical) are required, e.g. '50% 20%'"))
+        }
+    }
+
+    /// Returns the offset at which a window of target_size pixels should
+    /// start along an edge of full_size pixels, so that the given focus
+    /// (fraction) is as close to the center of the window as possible.
+    pub fn offset(full_size: u32, target_size: u32, focus: f32) -> u32 {
+        let max_offset = full_size.saturating_sub(target_size);
+        let centered_offset = focus * full_size as f32 - target_size as f32 / 2.0;
+
+        (centered_offset.round().max(0.0) as u32).min(max_offset)
+    }
+}
+
+/// Derived Hash is not available because of the f32 fields, hashing their
+/// bit patterns is fine as crops are only ever compared by exact equality.
+impl Hash for ImageCrop {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            ImageCrop::Box { height, left, top, width } => {
+                0u8.hash(state);
+                for value in [height, left, top, width] {
+                    value.to_bits().hash(state);
+                }
+            }
+            ImageCrop::FocalPoint { x, y } => {
+                1u8.hash(state);
+                x.to_bits().hash(state);
+                y.to_bits().hash(state);
+            }
+        }
+    }
+}
+
+fn parse_percentages(value: &str) -> Result<Vec<f32>, String> {
+    value
+        .split_whitespace()
+        .map(|token| {
+            match token.trim_end_matches('%').parse::<f32>() {
+                Ok(percentage) if (0.0..=100.0).contains(&percentage) => Ok(percentage / 100.0),
+                _ => Err(format!("'{token}' is not a percentage between 0% and 100%"))
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use chrono::{DateTime, Utc};
+use serde_derive::{Serialize, Deserialize};
+
+use super::{ImageCrop, ImageFormat, ImageVariant, ImgAttributes, ImgSource};
+
+/// A single, resized version of a gallery image.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GalleryAsset {
+    /// This is the filename in cache, at build time we derive the filename
+    /// from the position of the image in the gallery (see [GalleryAssets]).
+    pub filename: String,
+    pub filesize_bytes: u64,
+    pub height: u32,
+    /// Additional versions of this asset in modern formats (if requested)
+    pub variants: Vec<ImageVariant>,
+    pub width: u32
+}
+
+/// Represents the two versions of an image shown in a release gallery:
+/// A square thumbnail that is shown on the release page, and a version
+/// in the original aspect ratio that is shown when the thumbnail is opened.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GalleryAssets {
+    /// The focal point or crop box (if any) these assets were computed with
+    pub crop: Option<ImageCrop>,
+    pub full: GalleryAsset,
+    pub marked_stale: Option<DateTime<Utc>>,
+    pub thumbnail: GalleryAsset
+}
+
+impl GalleryAsset {
+    pub fn formats(&self) -> Vec<ImageFormat> {
+        self.variants.iter().map(|variant| variant.format).collect()
+    }
+
+    fn img_attributes(&self, hash: &str, prefix: &str, target_filename: &str) -> ImgAttributes {
+        let width = self.width;
+
+        let sources = self.variants
+            .iter()
+            .map(|variant| {
+                let filename = variant.target_filename(target_filename);
+                let srcset = format!("{prefix}{filename}?{hash} {width}w");
+
+                ImgSource { format: variant.format, srcset }
+            })
+            .collect();
+
+        ImgAttributes {
+            sources,
+            src: format!("{prefix}{target_filename}?{hash}"),
+            srcset: format!("{prefix}{target_filename}?{hash} {width}w")
+        }
+    }
+}
+
+impl GalleryAssets {
+    pub fn all(&self) -> Vec<&GalleryAsset> {
+        vec![&self.thumbnail, &self.full]
+    }
+
+    /// The modern formats in which variants of all assets are available.
+    pub fn formats(&self) -> Vec<ImageFormat> {
+        self.thumbnail.formats()
+    }
+
+    pub fn full_img_attributes(&self, hash: &str, number: usize, prefix: &str) -> ImgAttributes {
+        self.full.img_attributes(hash, prefix, &GalleryAssets::full_target_filename(number))
+    }
+
+    /// The filename of the full size asset as we are writing it to the
+    /// release directory at build time, e.g. "gallery_3.jpg" for the third
+    /// image in the gallery.
+    pub fn full_target_filename(number: usize) -> String {
+        format!("gallery_{number}.jpg")
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.marked_stale.is_some()
+    }
+
+    pub fn mark_stale(&mut self, timestamp: &DateTime<Utc>) {
+        if self.marked_stale.is_none() {
+            self.marked_stale = Some(*timestamp);
+        }
+    }
+
+    pub fn product_img_attributes(&self, hash: &str, number: usize, prefix: &str) -> ImgAttributes {
+        self.thumbnail.img_attributes(hash, prefix, &GalleryAssets::product_target_filename(number))
+    }
+
+    /// The filename under which the thumbnail asset is written when the
+    /// image is shown for a (physical) product, e.g. "product_2.jpg" for
+    /// the second product of a release or the catalog.
+    pub fn product_target_filename(number: usize) -> String {
+        format!("product_{number}.jpg")
+    }
+
+    pub fn thumbnail_img_attributes(&self, hash: &str, number: usize, prefix: &str) -> ImgAttributes {
+        self.thumbnail.img_attributes(hash, prefix, &GalleryAssets::thumbnail_target_filename(number))
+    }
+
+    /// The filename of the thumbnail asset as we are writing it to the
+    /// release directory at build time, e.g. "gallery_3_thumbnail.jpg" for
+    /// the third image in the gallery.
+    pub fn thumbnail_target_filename(number: usize) -> String {
+        format!("gallery_{number}_thumbnail.jpg")
+    }
+
+    pub fn unmark_stale(&mut self) {
+        self.marked_stale = None;
+    }
+}
@@ -13,6 +13,8 @@ mod implementation;
 
 pub use implementation::{ImageInMemory, ImageProcessor};
 
+use super::{ImageCrop, ImageFormat};
+
 pub enum ResizeMode {
     /// Resize such that the longer edge of the image does not exceed the maximum edge size.
     ContainInSquare { max_edge_size: u32 },
@@ -22,3 +24,12 @@ pub enum ResizeMode {
     /// Aspect ratio is width / height, e.g. 16/9 = 1.7777777
     CoverRectangle { max_aspect: f32, max_width: u32, min_aspect: f32 }
 }
+
+/// The result of resizing an image: The filename of the jpeg version in the
+/// cache, the filenames of the additionally requested variants in modern
+/// formats (if any), and the dimensions (width, height) of the resized image.
+pub struct ResizedImage {
+    pub dimensions: (u32, u32),
+    pub filename: String,
+    pub variants: Vec<(ImageFormat, String)>
+}
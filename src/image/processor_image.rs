@@ -4,13 +4,20 @@
 use std::fs::File;
 use std::path::Path;
 
-use ::image::{DynamicImage, ImageFormat};
+use ::image::DynamicImage;
+use ::image::codecs::avif::AvifEncoder;
 use ::image::imageops::FilterType;
 
 use crate::Build;
 use crate::util;
 
-use super::ResizeMode;
+use super::{ImageCrop, ImageFormat, ResizeMode, ResizedImage};
+
+/// Encoding speed for avif images, 1 (slowest) - 10 (fastest). Avif encoding
+/// is notoriously slow, we therefore trade a bit of compression for speed.
+const AVIF_SPEED: u8 = 6;
+/// Encoding quality for avif images, 1 (worst) - 100 (best)
+const AVIF_QUALITY: u8 = 60;
 
 pub struct ImageInMemory {
     dynamic_image: DynamicImage
@@ -19,12 +26,23 @@ pub struct ImageInMemory {
 pub struct ImageProcessor;
 
 impl ImageInMemory {
+    pub fn height(&self) -> u32 {
+        self.dynamic_image.height()
+    }
+
     pub fn width(&self) -> u32 {
         self.dynamic_image.width()
     }
 }
 
 impl ImageProcessor {
+    /// The modern formats in which this implementation can generate
+    /// additional variants of images. The image crate only offers lossless
+    /// webp encoding, which produces files that are larger than the jpeg
+    /// version they would replace, therefore webp is only available when
+    /// faircamp is built with libvips.
+    pub const VARIANT_FORMATS: &'static [ImageFormat] = &[ImageFormat::Avif];
+
     pub fn new() -> ImageProcessor {
         ImageProcessor
     }
@@ -58,30 +76,77 @@ impl ImageProcessor {
     //     ImageInMemory { dynamic_image }
     // }
 
-    /// Resizing for opaque images, targeting jpeg as output format. Coincidentally
-    /// this is for all user-supplied images.
+    /// Resizing for opaque images, targeting jpeg as output format (plus
+    /// optional variants in the given modern formats). Coincidentally this
+    /// is for all user-supplied images.
     pub fn resize_opaque(
         &self,
         build: &Build,
         image_in_memory: &ImageInMemory,
-        resize_mode: ResizeMode
-    ) -> (String, (u32, u32)) {
-        let original = &image_in_memory.dynamic_image;
+        resize_mode: ResizeMode,
+        crop: Option<ImageCrop>,
+        formats: &[ImageFormat]
+    ) -> ResizedImage {
+        let boxed;
+        let original = match crop.and_then(|crop| crop.box_pixels(
+            image_in_memory.dynamic_image.width(),
+            image_in_memory.dynamic_image.height()
+        )) {
+            Some((left, top, box_width, box_height)) => {
+                boxed = image_in_memory.dynamic_image.crop_imm(left, top, box_width, box_height);
+                &boxed
+            }
+            None => &image_in_memory.dynamic_image
+        };
+
+        let (focus_x, focus_y) = crop
+            .map(|crop| crop.focal_point())
+            .unwrap_or((0.5, 0.5));
 
         let height = original.height();
         let width = original.width();
 
-        let save = |dynamic_image: &DynamicImage| -> (String, (u32, u32)) {
+        let save = |dynamic_image: &DynamicImage| -> ResizedImage {
             let result_dimensions = (dynamic_image.width(), dynamic_image.height());
 
-            let output_filename = format!("{}.jpg", util::uid());
+            let uid = util::uid();
+
+            let output_filename = format!("{uid}.jpg");
             let output_path = build.cache_dir.join(&output_filename);
 
             let mut output_file = File::create(output_path).unwrap();
 
-            dynamic_image.write_to(&mut output_file, ImageFormat::Jpeg).unwrap();
+            dynamic_image.write_to(&mut output_file, ::image::ImageFormat::Jpeg).unwrap();
+
+            let variants = formats
+                .iter()
+                .map(|format| {
+                    let variant_filename = format!("{uid}.{}", format.extension());
+                    let variant_path = build.cache_dir.join(&variant_filename);
+
+                    let mut variant_file = File::create(variant_path).unwrap();
+
+                    match format {
+                        ImageFormat::Avif => {
+                            let encoder = AvifEncoder::new_with_speed_quality(
+                                &mut variant_file,
+                                AVIF_SPEED,
+                                AVIF_QUALITY
+                            );
+                            dynamic_image.write_with_encoder(encoder).unwrap();
+                        }
+                        ImageFormat::Webp => unreachable!("Not in VARIANT_FORMATS, never requested from this implementation")
+                    }
 
-            (output_filename, result_dimensions)
+                    (*format, variant_filename)
+                })
+                .collect();
+
+            ResizedImage {
+                dimensions: result_dimensions,
+                filename: output_filename,
+                variants
+            }
         };
 
         match resize_mode {
@@ -101,7 +166,7 @@ impl ImageProcessor {
             ResizeMode::CoverSquare { edge_size } => {
                 let smaller_edge = std::cmp::min(height, width);
 
-                let resize = |dynamic_image: &DynamicImage| -> (String, (u32, u32)) {
+                let resize = |dynamic_image: &DynamicImage| -> ResizedImage {
                     if smaller_edge <= edge_size {
                         save(dynamic_image)
                     } else {
@@ -117,10 +182,10 @@ impl ImageProcessor {
                     resize(original)
                 } else {
                     let cropped = if height > width {
-                        let y = (height - width) / 2;
+                        let y = ImageCrop::offset(height, width, focus_y);
                         original.crop_imm(0, y, width, width)
                     } else {
-                        let x = (width - height) / 2;
+                        let x = ImageCrop::offset(width, height, focus_x);
                         original.crop_imm(x, 0, height, height)
                     };
 
@@ -128,7 +193,7 @@ impl ImageProcessor {
                 }
             }
             ResizeMode::CoverRectangle { max_aspect, max_width, min_aspect } => {
-                let resize = |dynamic_image: &DynamicImage| -> (String, (u32, u32)) {
+                let resize = |dynamic_image: &DynamicImage| -> ResizedImage {
                     let cropped_width = dynamic_image.width();
                     if cropped_width > max_width {
                         let resize_factor = max_width as f32 / cropped_width as f32;
@@ -146,13 +211,13 @@ impl ImageProcessor {
                 if found_aspect < min_aspect {
                     // too tall, reduce height
                     let new_height = (width as f32 / min_aspect).floor() as u32;
-                    let y = (height - new_height) / 2;
+                    let y = ImageCrop::offset(height, new_height, focus_y);
                     let cropped = original.crop_imm(0, y, width, new_height);
                     resize(&cropped)
                 } else if found_aspect > max_aspect {
                     // too wide, reduce width
                     let new_width = (max_aspect * height as f32).floor() as u32;
-                    let x = (width - new_width) / 2;
+                    let x = ImageCrop::offset(width, new_width, focus_x);
                     let cropped = original.crop_imm(x, 0, new_width, height);
                     resize(&cropped)
                 } else {
@@ -4,14 +4,12 @@
 use std::path::Path;
 
 use libvips::{VipsApp, VipsImage};
-use libvips::ops::{self, Interesting, SmartcropOptions};
+use libvips::ops;
 
 use crate::Build;
 use crate::util;
 
-use super::ResizeMode;
-
-const CROP_OPTIONS: SmartcropOptions = SmartcropOptions { interesting: Interesting::Centre };
+use super::{ImageCrop, ImageFormat, ResizeMode, ResizedImage};
 
 pub struct ImageInMemory {
     pub vips_image: VipsImage
@@ -22,12 +20,20 @@ pub struct ImageProcessor {
 }
 
 impl ImageInMemory {
+    pub fn height(&self) -> u32 {
+        self.vips_image.get_height() as u32
+    }
+
     pub fn width(&self) -> u32 {
         self.vips_image.get_width() as u32
     }
 }
 
 impl ImageProcessor {
+    /// The modern formats in which this implementation can generate
+    /// additional variants of images.
+    pub const VARIANT_FORMATS: &'static [ImageFormat] = &[ImageFormat::Avif, ImageFormat::Webp];
+
     pub fn new() -> ImageProcessor {
         let vips_app = VipsApp::new("faircamp", false).expect("Cannot initialize libvips");
 
@@ -59,20 +65,55 @@ impl ImageProcessor {
     //     ImageInMemory { vips_image }
     // }
 
-    /// Resizing for opaque images, targeting jpeg as output format. Coincidentally
-    /// this is for all user-supplied images.
+    /// Resizing for opaque images, targeting jpeg as output format (plus
+    /// optional variants in the given modern formats). Coincidentally this
+    /// is for all user-supplied images.
     pub fn resize_opaque(
         &self,
         build: &Build,
         image_in_memory: &ImageInMemory,
-        resize_mode: ResizeMode
-    ) -> (String, (u32, u32)) {
-        let image = &image_in_memory.vips_image;
+        resize_mode: ResizeMode,
+        crop: Option<ImageCrop>,
+        formats: &[ImageFormat]
+    ) -> ResizedImage {
+        let boxed;
+        let image = match crop.and_then(|crop| crop.box_pixels(
+            image_in_memory.vips_image.get_width() as u32,
+            image_in_memory.vips_image.get_height() as u32
+        )) {
+            Some((left, top, box_width, box_height)) => {
+                boxed = ops::extract_area(
+                    &image_in_memory.vips_image,
+                    left as i32,
+                    top as i32,
+                    box_width as i32,
+                    box_height as i32
+                ).unwrap();
+                &boxed
+            }
+            None => &image_in_memory.vips_image
+        };
 
         let height = image.get_height() as u32;
         let width = image.get_width() as u32;
 
-        let save = |vips_image: &VipsImage| -> (String, (u32, u32)) {
+        let (focus_x, focus_y) = crop
+            .map(|crop| crop.focal_point())
+            .unwrap_or((0.5, 0.5));
+
+        // Cuts out a region of the given size, placed as close as possible
+        // around the focal point (by default the center of the image).
+        let extract = |target_width: u32, target_height: u32| -> VipsImage {
+            ops::extract_area(
+                image,
+                ImageCrop::offset(width, target_width, focus_x) as i32,
+                ImageCrop::offset(height, target_height, focus_y) as i32,
+                target_width as i32,
+                target_height as i32
+            ).unwrap()
+        };
+
+        let save = |vips_image: &VipsImage| -> ResizedImage {
             let options = ops::JpegsaveOptions {
                 interlace: true,
                 optimize_coding: true,
@@ -81,7 +122,9 @@ impl ImageProcessor {
                 ..ops::JpegsaveOptions::default()
             };
 
-            let target_filename = format!("{}.jpg", util::uid());
+            let uid = util::uid();
+
+            let target_filename = format!("{uid}.jpg");
 
             match ops::jpegsave_with_opts(
                 vips_image,
@@ -92,12 +135,52 @@ impl ImageProcessor {
                 Err(_) => println!("error: {}", self.vips_app.error_buffer().unwrap())
             }
 
+            let variants = formats
+                .iter()
+                .map(|format| {
+                    let variant_filename = format!("{uid}.{}", format.extension());
+                    let variant_path = build.cache_dir.join(&variant_filename);
+
+                    let result = match format {
+                        ImageFormat::Avif => {
+                            let options = ops::HeifsaveOptions {
+                                compression: ops::ForeignHeifCompression::Av1,
+                                q: 60,
+                                strip: true,
+                                ..ops::HeifsaveOptions::default()
+                            };
+
+                            ops::heifsave_with_opts(vips_image, &variant_path.to_string_lossy(), &options)
+                        }
+                        ImageFormat::Webp => {
+                            let options = ops::WebpsaveOptions {
+                                q: 75,
+                                strip: true,
+                                ..ops::WebpsaveOptions::default()
+                            };
+
+                            ops::webpsave_with_opts(vips_image, &variant_path.to_string_lossy(), &options)
+                        }
+                    };
+
+                    if result.is_err() {
+                        println!("error: {}", self.vips_app.error_buffer().unwrap());
+                    }
+
+                    (*format, variant_filename)
+                })
+                .collect();
+
             let result_dimensions = (
                 vips_image.get_width() as u32,
                 vips_image.get_height() as u32
             );
 
-            (target_filename, result_dimensions)
+            ResizedImage {
+                dimensions: result_dimensions,
+                filename: target_filename,
+                variants
+            }
         };
 
         match resize_mode {
@@ -114,7 +197,7 @@ impl ImageProcessor {
             ResizeMode::CoverSquare { edge_size } => {
                 let smaller_edge = std::cmp::min(height, width);
 
-                let resize = |vips_image: &VipsImage| -> (String, (u32, u32)) {
+                let resize = |vips_image: &VipsImage| -> ResizedImage {
                     if smaller_edge <= edge_size {
                         save(vips_image)
                     } else {
@@ -124,19 +207,14 @@ impl ImageProcessor {
                 };
 
                 if height != width {
-                    let cropped = ops::smartcrop_with_opts(
-                        image,
-                        smaller_edge as i32,
-                        smaller_edge as i32,
-                        &CROP_OPTIONS
-                    ).unwrap();
+                    let cropped = extract(smaller_edge, smaller_edge);
                     resize(&cropped)
                 } else {
                     resize(image)
                 }
             }
             ResizeMode::CoverRectangle { max_aspect, max_width, min_aspect } => {
-                let resize = |vips_image: &VipsImage| -> (String, (u32, u32)) {
+                let resize = |vips_image: &VipsImage| -> ResizedImage {
                     let cropped_width = vips_image.get_width() as u32;
                     if cropped_width > max_width {
                         let resized = ops::resize(vips_image, max_width as f64 / cropped_width as f64).unwrap();
@@ -150,21 +228,11 @@ impl ImageProcessor {
 
                 if found_aspect < min_aspect {
                     // too tall, reduce height
-                    let cropped = ops::smartcrop_with_opts(
-                        image,
-                        width as i32,
-                        (width as f32 / min_aspect).floor() as i32,
-                        &CROP_OPTIONS
-                    ).unwrap();
+                    let cropped = extract(width, (width as f32 / min_aspect).floor() as u32);
                     resize(&cropped)
                 } else if found_aspect > max_aspect {
                     // too wide, reduce width
-                    let cropped = ops::smartcrop_with_opts(
-                        image,
-                        (max_aspect * height as f32).floor() as i32,
-                        height as i32,
-                        &CROP_OPTIONS
-                    ).unwrap();
+                    let cropped = extract((max_aspect * height as f32).floor() as u32, height);
                     resize(&cropped)
                 } else {
                     resize(image)
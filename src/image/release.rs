@@ -4,7 +4,7 @@
 use chrono::{DateTime, Utc};
 use serde_derive::{Serialize, Deserialize};
 
-use super::ImgAttributes;
+use super::{ImageCrop, ImageFormat, ImageVariant, ImgAttributes};
 
 /// A single, resized version of the cover image.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -12,7 +12,9 @@ pub struct CoverAsset {
     /// Represents both height and width (covers have a square aspect ratio)
     pub edge_size: u32,
     pub filename: String,
-    pub filesize_bytes: u64
+    pub filesize_bytes: u64,
+    /// Additional versions of this asset in modern formats (if requested)
+    pub variants: Vec<ImageVariant>
 }
 
 /// Represents multiple, differently sized versions of a cover image, for
@@ -20,6 +22,8 @@ pub struct CoverAsset {
 /// archive. (Numbers refer to the square edge size, both height and width)
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CoverAssets {
+    /// The focal point or crop box (if any) these assets were computed with
+    pub crop: Option<ImageCrop>,
     pub marked_stale: Option<DateTime<Utc>>,
     pub max_160: CoverAsset,
     pub max_320: Option<CoverAsset>,
@@ -29,6 +33,10 @@ pub struct CoverAssets {
 }
 
 impl CoverAsset {
+    pub fn formats(&self) -> Vec<ImageFormat> {
+        self.variants.iter().map(|variant| variant.format).collect()
+    }
+
     /// The filename of this asset as we are writing it to the release/track
     /// directory at build time, e.g. something like "cover_480.jpg"
     /// or "cover_240.jpg".
@@ -52,6 +60,11 @@ impl CoverAssets {
         result
     }
 
+    /// The modern formats in which variants of all assets are available.
+    pub fn formats(&self) -> Vec<ImageFormat> {
+        self.max_160.formats()
+    }
+
     pub fn img_attributes_up_to_320(&self, hash: &str, prefix: &str) -> ImgAttributes {
         let assets = match &self.max_320 {
             Some(max_320) => vec![&self.max_160, max_320],
@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::fs;
+
+use serde_derive::{Serialize, Deserialize};
+
+use crate::Build;
+
+/// Modern image formats that can additionally be generated for artist,
+/// cover and background images. A jpeg version is always generated as
+/// well, it serves as fallback for browsers that don't support these.
+/// The order of the variants is the order of preference (most efficient
+/// first), i.e. the order in which we offer them to the browser.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum ImageFormat {
+    Avif,
+    Webp
+}
+
+/// An additional version of a resized image, in a modern format,
+/// with the same dimensions as the jpeg version it accompanies.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ImageVariant {
+    /// This is the filename in cache, at build time the filename is
+    /// derived from the target filename of the jpeg version.
+    pub filename: String,
+    pub filesize_bytes: u64,
+    pub format: ImageFormat
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Avif => "avif",
+            ImageFormat::Webp => "webp"
+        }
+    }
+
+    pub fn from_manifest_key(key: &str) -> Option<ImageFormat> {
+        match key {
+            "avif" => Some(ImageFormat::Avif),
+            "webp" => Some(ImageFormat::Webp),
+            _ => None
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ImageFormat::Avif => "image/avif",
+            ImageFormat::Webp => "image/webp"
+        }
+    }
+}
+
+impl ImageVariant {
+    pub fn new(build: &Build, filename: String, format: ImageFormat) -> ImageVariant {
+        let metadata = fs::metadata(build.cache_dir.join(&filename)).unwrap();
+
+        ImageVariant {
+            filename,
+            filesize_bytes: metadata.len(),
+            format
+        }
+    }
+
+    /// Replaces the extension of the target filename of the jpeg version
+    /// (e.g. "cover_480.jpg") with the one of this variant's format
+    /// (e.g. "cover_480.avif").
+    pub fn target_filename(&self, jpeg_target_filename: &str) -> String {
+        let stem = jpeg_target_filename.trim_end_matches(".jpg");
+        let extension = self.format.extension();
+
+        format!("{stem}.{extension}")
+    }
+}
@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+/// The versions in which the Creative Commons licenses (apart from CC0)
+/// were published.
+const CC_VERSIONS: &[&str] = &["1.0", "2.0", "2.5", "3.0", "4.0"];
+
+/// The license under which a release or track is published, given
+/// either as SPDX identifier (e.g. "CC-BY-SA-4.0", "MIT") or in the
+/// notation commonly used for Creative Commons licenses (e.g. "CC BY-SA 4.0").
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct License {
+    /// The SPDX identifier, e.g. "CC-BY-SA-4.0"
+    pub identifier: String,
+    /// The human-readable form, e.g. "CC BY-SA 4.0" (for non-CC licenses
+    /// this is the same as the identifier)
+    pub label: String,
+    /// Link to the legal text of the license
+    pub url: String
+}
+
+impl License {
+    /// The current Creative Commons licenses in their common notation, these
+    /// are offered as suggestions to editors (see manifest::schema_json),
+    /// although any license recognized by [License::from_manifest_value] is
+    /// accepted.
+    pub const SUGGESTED_MANIFEST_VALUES: [&'static str; 7] = [
+        "CC BY 4.0",
+        "CC BY-NC 4.0",
+        "CC BY-NC-ND 4.0",
+        "CC BY-NC-SA 4.0",
+        "CC BY-ND 4.0",
+        "CC BY-SA 4.0",
+        "CC0"
+    ];
+
+    /// Parses a license as given in a manifest. Creative Commons licenses
+    /// are recognized in both SPDX and the common CC notation (also
+    /// lowercase), any other valid SPDX identifier is accepted as is and
+    /// linked to its entry in the SPDX license list.
+    pub fn from_manifest_value(value: &str) -> Result<License, String> {
+        let normalized = value
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join("-")
+            .to_uppercase();
+
+        if normalized == "CC0" || normalized == "CC0-1.0" {
+            return Ok(License {
+                identifier: String::from("CC0-1.0"),
+                label: String::from("CC0 1.0"),
+                url: String::from("https://creativecommons.org/publicdomain/zero/1.0/")
+            });
+        }
+
+        if let Some(rest) = normalized.strip_prefix("CC-") {
+            if let Some((elements, version)) = rest.rsplit_once('-') {
+                let elements_valid = matches!(
+                    elements,
+                    "BY" | "BY-SA" | "BY-ND" | "BY-NC" | "BY-NC-SA" | "BY-NC-ND"
+                );
+
+                if elements_valid && CC_VERSIONS.contains(&version) {
+                    let url_elements = elements.to_lowercase();
+
+                    return Ok(License {
+                        identifier: format!("CC-{elements}-{version}"),
+                        label: format!("CC {elements} {version}"),
+                        url: format!("https://creativecommons.org/licenses/{url_elements}/{version}/")
+                    });
+                }
+            }
+
+            return Err(format!("'{value}' is not a recognized Creative Commons license (examples of valid values are 'CC BY 4.0', 'CC-BY-SA-4.0' or 'CC0')"));
+        }
+
+        // Any other SPDX identifier (e.g. "MIT", "GPL-3.0-or-later") is
+        // taken over verbatim (SPDX identifiers are case-sensitive).
+        let identifier = value.trim();
+
+        let identifier_valid = !identifier.is_empty() && identifier
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '+');
+
+        if identifier_valid {
+            Ok(License {
+                identifier: identifier.to_string(),
+                label: identifier.to_string(),
+                url: format!("https://spdx.org/licenses/{identifier}.html")
+            })
+        } else {
+            Err(format!("'{value}' is not a valid SPDX license identifier or Creative Commons license (examples of valid values are 'CC BY-SA 4.0', 'CC0' or 'MIT')"))
+        }
+    }
+}
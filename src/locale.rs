@@ -49,6 +49,34 @@ pub enum TextDirection {
 
 // TODO: Runtime-based mechanism for adding or customizing locales
 impl Locale {
+    /// Language codes for which faircamp ships translations (any other code
+    /// is accepted too, but falls back to english interface texts).
+    pub const BUILTIN_CODES: [&'static str; 23] = [
+        "ca",
+        "da",
+        "de",
+        "en",
+        "es",
+        "fi",
+        "fr",
+        "he",
+        "it",
+        "ja",
+        "lt",
+        "nb",
+        "nl",
+        "pl",
+        "pt-pt",
+        "ru",
+        "sr-cyrl",
+        "sr-latn",
+        "sv",
+        "tr",
+        "uk",
+        "zh-hans-cn",
+        "zh-hant-tw"
+    ];
+
     pub fn default() -> Locale {
         Locale::new("en", EN, TextDirection::Ltr)
     }
@@ -5,14 +5,13 @@
 /// - https://en.wikipedia.org/wiki/M3U
 /// - https://docs.fileformat.com/audio/m3u/
 
-use std::hash::Hash;
-
 use indoc::formatdoc;
 
 use crate::{
     Artist,
     Build,
     Catalog,
+    Playlist,
     Release,
     SiteUrl,
     Track,
@@ -32,7 +31,6 @@ pub fn generate_for_artist(
         .iter()
         .map(|release| {
             let release_ref = release.borrow();
-            let release_slug = &release_ref.permalink.slug;
             let release_title = &release_ref.title;
 
             let r_tracks = generate_tracks(
@@ -42,19 +40,7 @@ pub fn generate_for_artist(
                 &release_ref.tracks
             );
 
-            let release_cover_url = match &release_ref.cover {
-                Some(described_image) => {
-                    let image_ref = described_image.borrow();
-                    let file_name = image_ref.cover_assets_unchecked().playlist_image();
-                    let hash = image_ref.hash.as_url_safe_base64();
-
-                    base_url.join_file(format!("{release_slug}/{file_name}?{hash}"))
-                }
-                None => {
-                    let file_name = release_ref.procedural_cover_480_filename_unchecked();
-                    base_url.join_file(format!("{release_slug}/{file_name}"))
-                }
-            };
+            let release_cover_url = release_ref.playlist_image_url(base_url);
 
             formatdoc!(r#"
                 #EXTIMG:{release_cover_url}
@@ -100,7 +86,6 @@ pub fn generate_for_catalog(build: &Build, catalog: &Catalog) -> String {
         .iter()
         .map(|release| {
             let release_ref = release.borrow();
-            let release_slug = &release_ref.permalink.slug;
             let release_title = &release_ref.title;
 
             let r_tracks = generate_tracks(
@@ -110,19 +95,7 @@ pub fn generate_for_catalog(build: &Build, catalog: &Catalog) -> String {
                 &release_ref.tracks
             );
 
-            let release_cover_url = match &release_ref.cover {
-                Some(described_image) => {
-                    let image_ref = described_image.borrow();
-                    let file_name = image_ref.cover_assets_unchecked().playlist_image();
-                    let hash = image_ref.hash.as_url_safe_base64();
-
-                    base_url.join_file(format!("{release_slug}/{file_name}?{hash}"))
-                }
-                None => {
-                    let file_name = release_ref.procedural_cover_480_filename_unchecked();
-                    base_url.join_file(format!("{release_slug}/{file_name}"))
-                }
-            };
+            let release_cover_url = release_ref.playlist_image_url(base_url);
 
             formatdoc!(r#"
                 #EXTIMG:{release_cover_url}
@@ -156,13 +129,55 @@ pub fn generate_for_catalog(build: &Build, catalog: &Catalog) -> String {
     "#)
 }
 
+/// Generate complete content of an M3U playlist for a curated playlist,
+/// whose tracks may stem from any number of releases.
+pub fn generate_for_playlist(
+    base_url: &SiteUrl,
+    build: &Build,
+    playlist: &Playlist
+) -> String {
+    let r_entries = playlist.entries
+        .iter()
+        .map(|entry| {
+            let release_ref = entry.release.borrow();
+            let release_title = &release_ref.title;
+            let track = &release_ref.tracks[entry.track_number - 1];
+
+            let r_track = generate_track(
+                base_url,
+                build,
+                &release_ref,
+                track,
+                entry.track_number
+            );
+
+            let release_cover_url = release_ref.playlist_image_url(base_url);
+
+            formatdoc!(r#"
+                #EXTIMG:{release_cover_url}
+                #EXTALB:{release_title}
+                {r_track}
+            "#)
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let playlist_title = &playlist.title;
+
+    formatdoc!(r#"
+        #EXTM3U
+        #EXTENC:UTF-8
+        #PLAYLIST:{playlist_title}
+        {r_entries}
+    "#)
+}
+
 /// Generate complete content of an M3U playlist for a release
 pub fn generate_for_release(
     base_url: &SiteUrl,
     build: &Build,
     release: &Release
 ) -> String {
-    let release_slug = &release.permalink.slug;
     let release_title = &release.title;
 
     let r_tracks = generate_tracks(
@@ -172,19 +187,7 @@ pub fn generate_for_release(
         &release.tracks
     );
 
-    let release_cover_url = match &release.cover {
-        Some(described_image) => {
-            let image_ref = described_image.borrow();
-            let file_name = image_ref.cover_assets_unchecked().playlist_image();
-            let hash = image_ref.hash.as_url_safe_base64();
-
-            base_url.join_file(format!("{release_slug}/{file_name}?{hash}"))
-        }
-        None => {
-            let file_name = release.procedural_cover_480_filename_unchecked();
-            base_url.join_file(format!("{release_slug}/{file_name}"))
-        }
-    };
+    let release_cover_url = release.playlist_image_url(base_url);
 
     formatdoc!(r#"
         #EXTM3U
@@ -196,62 +199,51 @@ pub fn generate_for_release(
     "#)
 }
 
-/// Generate M3U playlist content just for the tracks of a release, to be used
-/// as a reusable function for generating either a playlist for an release or
-/// for an entire catalog (multiple releases).
-pub fn generate_tracks(
+/// Generate a single M3U playlist entry (#EXTINF line and url) for a track.
+fn generate_track(
     base_url: &SiteUrl,
     build: &Build,
     release: &Release,
-    tracks: &[Track]
+    track: &Track,
+    track_number: usize
 ) -> String {
-    let release_slug = &release.permalink.slug;
+    let track_number_formatted = release.track_numbering.format(track_number);
 
-    tracks
+    let artists = track.artists
         .iter()
-        .zip(TRACK_NUMBERS)
-        .map(|(track, track_number)| {
-            let track_number_formatted = release.track_numbering.format(track_number);
-
-            let artists = track.artists
-                .iter()
-                .map(|artist| artist.borrow().name.clone())
-                .collect::<Vec<String>>()
-                .join(", ");
-
-            let track_title = track.title();
-            let title = match track_number_formatted.is_empty() {
-                true => format!("{artists} – {track_title}"),
-                false => format!("{artists} – {track_number_formatted} {track_title}")
-            };
-
-            let duration_seconds = track.transcodes.borrow().source_meta.duration_seconds as usize;
+        .map(|artist| artist.borrow().name.clone())
+        .collect::<Vec<String>>()
+        .join(", ");
 
-            let extinf = format!("#EXTINF:{duration_seconds}, {title}");
+    let track_title = track.title();
+    let title = match track_number_formatted.is_empty() {
+        true => format!("{artists} – {track_title}"),
+        false => format!("{artists} – {track_number_formatted} {track_title}")
+    };
 
-            let primary_streaming_format = track.streaming_quality.formats()[0];
-            let format_dir = primary_streaming_format.asset_dirname();
-            let format_extension = primary_streaming_format.extension();
+    let duration_seconds = track.transcodes.borrow().source_meta.duration_seconds as usize;
 
-            let track_filename = format!(
-                "{basename}{format_extension}",
-                basename = track.asset_basename.as_ref().unwrap()
-            );
+    let extinf = format!("#EXTINF:{duration_seconds}, {title}");
 
-            let track_hash = build.hash_with_salt(|hasher| {
-                release_slug.hash(hasher);
-                track_number.hash(hasher);
-                format_dir.hash(hasher);
-                track_filename.hash(hasher);
-            });
+    let file_url = release.track_stream_url(base_url, build, track, track_number);
 
-            let track_filename_urlencoded = urlencoding::encode(&track_filename);
-            let file_url = base_url.join_file(
-                format!("{release_slug}/{track_number}/{format_dir}/{track_hash}/{track_filename_urlencoded}")
-            );
+    format!("{extinf}\n{file_url}")
+}
 
-            format!("{extinf}\n{file_url}")
-        })
+/// Generate M3U playlist content just for the tracks of a release, to be used
+/// as a reusable function for generating either a playlist for an release or
+/// for an entire catalog (multiple releases).
+pub fn generate_tracks(
+    base_url: &SiteUrl,
+    build: &Build,
+    release: &Release,
+    tracks: &[Track]
+) -> String {
+    tracks
+        .iter()
+        .zip(TRACK_NUMBERS)
+        .filter(|(track, _track_number)| !release.track_locked(track))
+        .map(|(track, track_number)| generate_track(base_url, build, release, track, track_number))
         .collect::<Vec<String>>()
         .join("\n")
 }
@@ -11,6 +11,7 @@ use indoc::formatdoc;
 #[macro_use]
 mod message;
 
+mod activitypub;
 mod archives;
 mod args;
 mod artist;
@@ -20,33 +21,46 @@ mod audio_meta;
 mod build;
 mod cache;
 mod catalog;
+mod chapter;
+mod check;
 mod cover_generator;
+mod cue_sheet;
 mod debug;
 mod decode;
 mod deploy;
 mod download_format;
 mod downloads;
+mod event;
 mod fair_dir;
 mod favicon;
 mod feeds;
 mod ffmpeg;
 mod heuristic_audio_meta;
 mod icons;
+mod ics;
 mod image;
+mod jspf;
+mod license;
 mod link;
 mod locale;
 mod m3u;
 mod manifest;
 mod markdown;
 mod opengraph;
+mod page;
 mod permalink;
+mod playlist;
+mod post;
+mod product;
 mod release;
 mod render;
 mod rsync;
 mod server;
+mod shared_cache;
 mod site_metadata;
 mod site_url;
 mod source_file_signature;
+mod stats;
 mod streaming_quality;
 mod scripts;
 mod styles;
@@ -56,9 +70,12 @@ mod track;
 mod track_numbering;
 mod transcodes;
 mod util;
+mod waveform;
+mod web_app;
+mod xspf;
 
-use archives::{Archive, Archives, ArchivesRc};
-use args::Args;
+use archives::{Archive, ArchiveFormat, ArchiveWriter, Archives, ArchivesRc};
+use args::{Args, CacheCommand, Command};
 use artist::{Artist, ArtistRc};
 use asset::{Asset, AssetIntent};
 use audio_format::{AudioFormat, AudioFormatFamily};
@@ -66,31 +83,45 @@ use audio_meta::AudioMeta;
 use build::{AssetHashes, Build, GENERATOR_INFO, PostBuildAction};
 use cache::{Cache, CacheOptimization, View};
 use catalog::Catalog;
+use chapter::Chapter;
+use check::{Diagnostic, Location, Severity};
 use cover_generator::{CoverGenerator, ProceduralCover, ProceduralCoverAsset, ProceduralCoverRc};
+use cue_sheet::{CueSheet, Segment};
 use download_format::DownloadFormat;
 use downloads::{DownloadAccess, DownloadAccessOption, ExtraDownloads, Price};
+use event::Event;
 use fair_dir::FairDir;
 use favicon::Favicon;
 use feeds::Feeds;
 use heuristic_audio_meta::HeuristicAudioMeta;
-use crate::image::{DescribedImage, FeedImageAsset, Image, ImageProcessor, ImageRc, ImageRcView, ImgAttributes};
+use ics::ICS_CALENDAR_FILENAME;
+use crate::image::{DescribedImage, FeedImageAsset, GalleryAssets, Image, ImageCrop, ImageFormat, ImageProcessor, ImageRc, ImageRcView, ImgAttributes};
+use jspf::JSPF_PLAYLIST_FILENAME;
+use license::License;
 use link::Link;
 use locale::Locale;
 use m3u::M3U_PLAYLIST_FILENAME;
-use manifest::{LocalOptions, Overrides};
+use manifest::{LocalOptions, ManifestMessage, Overrides};
 use markdown::HtmlAndStripped;
 use opengraph::{OpenGraphImage, OpenGraphMeta};
+use page::Page;
 use permalink::{Permalink, PermalinkUsage};
+use playlist::{Playlist, PlaylistEntry, TrackReference};
+use post::Post;
+use product::{Product, Stock};
 use release::{Extra, Release, ReleaseRc, TRACK_NUMBERS};
 use site_metadata::{SiteAsset, SiteMetadata};
+use shared_cache::SharedCache;
 use site_url::SiteUrl;
 use source_file_signature::{FileMeta, SourceHash};
 use streaming_quality::StreamingQuality;
-use tags::{ImageEmbed, TagAgenda, TagMapping};
+use tags::{ImageEmbed, TagAction, TagAgenda, TagMapping};
 use theme::{Theme, ThemeBase, ThemeFont, ThemeVarsHsl, ThemeVarsOklch};
 use track::Track;
 use track_numbering::TrackNumbering;
 use transcodes::{Transcode, Transcodes, TranscodesRc, TranscodesRcView};
+use waveform::{WaveformAnalysis, WaveformColor, WaveformStyle};
+use xspf::XSPF_PLAYLIST_FILENAME;
 
 const MANUAL_URL: &str = "https://simonrepp.com/faircamp/manual/";
 
@@ -106,6 +137,36 @@ fn main() -> ExitCode {
         }
     }
 
+    match &args.command {
+        Some(Command::ManifestSchema) => {
+            println!("{}", manifest::schema_json());
+            return ExitCode::SUCCESS;
+        }
+        Some(Command::SharedCacheServer { dir, ip, port, token }) => {
+            server::serve_shared_cache(dir, *ip, *port, token.clone());
+            return ExitCode::SUCCESS;
+        }
+        Some(Command::Stats { stats_file }) => {
+            return match stats::Stats::read(stats_file) {
+                Ok(stats) => {
+                    stats.report();
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    error!("{}", err);
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Some(Command::StatsServer { ip, paths_file, port, stats_file }) => {
+            return match server::serve_stats(stats_file, paths_file, *ip, *port) {
+                true => ExitCode::SUCCESS,
+                false => ExitCode::FAILURE
+            };
+        }
+        _ => ()
+    }
+
     let mut build = Build::new(&args);
 
     if !build.catalog_dir.is_dir() {
@@ -113,6 +174,32 @@ fn main() -> ExitCode {
         return ExitCode::FAILURE;
     }
 
+    if let Some(Command::Check { json }) = &args.command {
+        return match check::check(&mut build, *json) {
+            true => ExitCode::SUCCESS,
+            false => ExitCode::FAILURE
+        };
+    }
+
+    if let Some(Command::Cache(cache_command)) = &args.command {
+        return match cache_command {
+            CacheCommand::Gc { budget } => {
+                let mut cache = Cache::retrieve(&build);
+                cache.evict_to_budget(&build, *budget);
+                ExitCode::SUCCESS
+            }
+            CacheCommand::List => {
+                let cache = Cache::retrieve(&build);
+                cache.list(&build);
+                ExitCode::SUCCESS
+            }
+            CacheCommand::Verify => match Cache::verify(&build) {
+                true => ExitCode::SUCCESS,
+                false => ExitCode::FAILURE
+            }
+        };
+    }
+
     info!("You can safely terminate faircamp at any point (using Ctrl+C) - all progress is continuously saved and new builds always continue where the previous build left off.");
 
     let mut cache = Cache::retrieve(&build);
@@ -167,12 +254,24 @@ fn main() -> ExitCode {
     styles::generate(&mut build, &catalog);
     catalog.favicon.write(&mut build);
 
+    if catalog.web_app {
+        web_app::generate(&mut build, &catalog);
+    }
+
     if build.cdn_url.is_some() {
-        // Render M3U playlist
+        // Render M3U, XSPF and JSPF playlist
         if catalog.m3u {
             let r_m3u = m3u::generate_for_catalog(&build, &catalog);
             fs::write(build.build_dir.join(M3U_PLAYLIST_FILENAME), r_m3u).unwrap();
             build.reserve_filename(M3U_PLAYLIST_FILENAME);
+
+            let r_xspf = xspf::generate_for_catalog(&build, &catalog);
+            fs::write(build.build_dir.join(XSPF_PLAYLIST_FILENAME), r_xspf).unwrap();
+            build.reserve_filename(XSPF_PLAYLIST_FILENAME);
+
+            let r_jspf = jspf::generate_for_catalog(&build, &catalog);
+            fs::write(build.build_dir.join(JSPF_PLAYLIST_FILENAME), r_jspf).unwrap();
+            build.reserve_filename(JSPF_PLAYLIST_FILENAME);
         }
     }
 
@@ -189,6 +288,11 @@ fn main() -> ExitCode {
             fs::write(subscribe_dir.join("index.html"), subscribe_html).unwrap();
             build.reserve_filename(subscribe_permalink);
         }
+
+        // Render static ActivityPub actor, outbox and WebFinger documents
+        if let Some(username) = &catalog.activitypub {
+            activitypub::generate(&mut build, &catalog, username);
+        }
     }
 
     // Render homepage (page for all releases)
@@ -196,6 +300,13 @@ fn main() -> ExitCode {
     fs::write(build.build_dir.join("index.html"), index_html).unwrap();
     build.reserve_filename("index.html");
 
+    // Render calendar file with the events of all artists (when there are any)
+    if !catalog.event_artists().is_empty() {
+        let r_ics = ics::generate_for_catalog(&build, &catalog);
+        fs::write(build.build_dir.join(ICS_CALENDAR_FILENAME), r_ics).unwrap();
+        build.reserve_filename(ICS_CALENDAR_FILENAME);
+    }
+
     // Render pages for each release (including playlists, track pages, embeds, etc.)
     for release in &catalog.releases {
         let release_mut = release.borrow_mut();
@@ -210,19 +321,63 @@ fn main() -> ExitCode {
 
         util::ensure_dir_all(&artist_dir);
 
-        // Render m3u playlist
+        // Render m3u, xspf and jspf playlist
         if let Some(base_url) = &build.cdn_url {
             if artist_ref.m3u {
                 let r_m3u = m3u::generate_for_artist(&artist_ref, base_url, &build);
                 fs::write(artist_dir.join(M3U_PLAYLIST_FILENAME), r_m3u).unwrap();
+
+                let r_xspf = xspf::generate_for_artist(&artist_ref, base_url, &build);
+                fs::write(artist_dir.join(XSPF_PLAYLIST_FILENAME), r_xspf).unwrap();
+
+                let r_jspf = jspf::generate_for_artist(&artist_ref, base_url, &build);
+                fs::write(artist_dir.join(JSPF_PLAYLIST_FILENAME), r_jspf).unwrap();
             }
         }
 
+        if !artist_ref.events.is_empty() {
+            let r_ics = ics::generate_for_artist(&artist_ref, &build);
+            fs::write(artist_dir.join(ICS_CALENDAR_FILENAME), r_ics).unwrap();
+        }
+
         let artist_html = render::artist::artist_html(&artist_ref, &build, &catalog);
         fs::write(artist_dir.join("index.html"), artist_html).unwrap();
         build.reserve_filename(artist_ref.permalink.slug.clone());
     }
 
+    // Render standalone pages
+    for page in &catalog.pages {
+        page.write_page(&mut build, &catalog);
+        build.reserve_filename(page.permalink.slug.clone());
+    }
+
+    // Render news/blog posts and the news page listing them
+    for post in &catalog.posts {
+        post.write_page(&mut build, &catalog);
+        build.reserve_filename(post.permalink.slug.clone());
+    }
+
+    if let Some(news_permalink) = &catalog.news_permalink {
+        let news_dir = build.build_dir.join(news_permalink);
+        let news_html = render::news::news_html(&build, &catalog);
+        util::ensure_dir_all_and_write_index(&news_dir, &news_html);
+        build.reserve_filename(news_permalink);
+    }
+
+    // Render merch page listing the products of the catalog and its releases
+    if let Some(merch_permalink) = &catalog.merch_permalink {
+        let merch_dir = build.build_dir.join(merch_permalink);
+        let merch_html = render::merch::merch_html(&build, &catalog);
+        util::ensure_dir_all_and_write_index(&merch_dir, &merch_html);
+        build.reserve_filename(merch_permalink);
+    }
+
+    // Render pages for curated playlists (including playlist files, embeds, etc.)
+    for playlist in &catalog.playlists {
+        playlist.write_pages_and_playlist_files(&mut build, &catalog);
+        build.reserve_filename(playlist.permalink.slug.clone());
+    }
+
     // Render image descriptions page (when needed)
     if build.missing_image_descriptions {
         let t_image_descriptions_permalink = *build.locale.translations.image_descriptions_permalink;
@@ -233,6 +388,12 @@ fn main() -> ExitCode {
         build.reserve_filename(t_image_descriptions_permalink);
     }
 
+    // Written after everything that can be played or downloaded, as all
+    // paths in the build directory up to this point are listed.
+    if catalog.stats_endpoint.is_some() {
+        stats::write_paths_file(&mut build);
+    }
+
     // Must be the last step because we need to check for collisions against
     // everything we wrote to the build directory ourselves beforehand.
     if let Err(collisions) = catalog.write_user_assets(&mut build) {
@@ -254,13 +415,15 @@ fn main() -> ExitCode {
     if build.base_url.is_none() {
         let mut not_generated = Vec::new();
 
+        if catalog.activitypub.is_some() { not_generated.push("ActivityPub actor"); }
         if build.embeds_requested { not_generated.push("Embeds"); }
         if catalog.opengraph { not_generated.push("Open Graph meta tags"); }
         if catalog.feeds.any_requested() { not_generated.push("Feeds"); }
         if catalog.m3u ||
             catalog.artists.iter().any(|artist| artist.borrow().m3u) ||
+            catalog.playlists.iter().any(|playlist| playlist.m3u) ||
             catalog.releases.iter().any(|release| release.borrow().m3u) {
-            not_generated.push("M3U playlists");
+            not_generated.push("M3U/XSPF/JSPF playlists");
         }
 
         if !not_generated.is_empty() {
@@ -3,19 +3,26 @@
 
 use std::path::Path;
 
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime};
 use enolib::prelude::*;
 use enolib::{Attribute, Item};
 
 use crate::{
+    ArchiveFormat,
+    Build,
+    Chapter,
     DescribedImage,
     DownloadAccessOption,
     DownloadFormat,
     ExtraDownloads,
     HtmlAndStripped,
+    ImageCrop,
+    License,
     Link,
+    Location,
     Permalink,
     Price,
+    Product,
     StreamingQuality,
     TagAgenda,
     Theme,
@@ -31,11 +38,15 @@ mod artist_release;
 mod catalog;
 mod catalog_release;
 mod obsolete;
+mod page;
+mod playlist;
+mod post;
 mod release;
 mod release_track;
+mod schema;
 mod track;
 
-pub use artist::read_artist_manifest;
+pub use artist::{ARTIST_MANIFEST_OPTIONS, read_artist_manifest};
 pub use artist_catalog_release::{
     ARTIST_CATALOG_RELEASE_OPTIONS,
     read_artist_catalog_release_option
@@ -48,31 +59,57 @@ pub use artist_release::{
     ARTIST_RELEASE_OPTIONS,
     read_artist_release_option
 };
-pub use catalog::read_catalog_manifest;
+pub use catalog::{CATALOG_MANIFEST_OPTIONS, read_catalog_manifest};
 pub use catalog_release::{
     CATALOG_RELEASE_OPTIONS,
     read_catalog_release_option
 };
 pub use obsolete::{read_obsolete_option, read_obsolete_theme_attribute};
-pub use release::read_release_manifest;
+pub use page::{PAGE_MANIFEST_OPTIONS, read_page_manifest};
+pub use playlist::{PLAYLIST_MANIFEST_OPTIONS, read_playlist_manifest};
+pub use post::{POST_MANIFEST_OPTIONS, read_post_manifest};
+pub use release::{RELEASE_MANIFEST_OPTIONS, read_release_manifest};
 pub use release_track::{
     RELEASE_TRACK_OPTIONS,
     read_release_track_option
 };
-pub use track::read_track_manifest;
+pub use schema::schema_json;
+pub use track::{TRACK_MANIFEST_OPTIONS, read_track_manifest};
+
+/// An error or warning concerning a specific line in a manifest, which is
+/// printed together with a snippet of the manifest around that line. The
+/// location is kept apart from the text so that `faircamp check` can report
+/// it in machine-readable form.
+pub struct ManifestMessage {
+    pub location: Location,
+    pub text: String
+}
 
 /// Options specified in a manifest that only apply to everything found in the
 /// same folder as the manifest. For instance a permalink can only uniquely
 /// apply to one artist or release, thus it is a local option only.
 #[derive(Clone)]
 pub struct LocalOptions {
+    /// Used by track, labeled points in time (e.g. a DJ mix tracklist)
+    pub chapters: Vec<Chapter>,
     /// Used by release and track
     pub cover: Option<DescribedImage>,
+    /// Used by release, images shown in a gallery on the release page
+    pub gallery: Vec<DescribedImage>,
     pub links: Vec<Link>,
     /// Used by artist, release and track
     pub more: Option<HtmlAndStripped>,
     /// Used by artist and release
     pub permalink: Option<Permalink>,
+    /// Used by track, marks it as streamable while its release is still
+    /// upcoming (see publish_at).
+    pub preview_track: bool,
+    /// Used by catalog and release, physical products (merch) offered
+    /// through external checkout pages.
+    pub products: Vec<Product>,
+    /// Used by release, until this point in time (UTC) the release is left
+    /// out of the build entirely.
+    pub publish_at: Option<NaiveDateTime>,
     pub release_date: Option<NaiveDate>,
     /// Used by artist, release and track
     pub synopsis: Option<String>,
@@ -92,9 +129,13 @@ pub struct Overrides {
     pub copy_link: bool,
     pub download_codes: Vec<String>,
     pub embedding: bool,
+    pub license: Option<License>,
     pub m3u_enabled: bool,
     pub more_label: Option<String>,
     pub payment_info: Option<String>,
+    pub release_archive_checksums: bool,
+    pub release_archive_format: ArchiveFormat,
+    pub release_archive_info: bool,
     pub release_artists: Vec<String>,
     pub release_download_access: DownloadAccessOption,
     pub release_downloads: Vec<DownloadFormat>,
@@ -116,10 +157,15 @@ pub struct Overrides {
 impl LocalOptions {
     pub fn new() -> LocalOptions {
         LocalOptions {
+            chapters: Vec::new(),
             cover: None,
+            gallery: Vec::new(),
             links: Vec::new(),
             more: None,
             permalink: None,
+            preview_track: false,
+            products: Vec::new(),
+            publish_at: None,
             release_date: None,
             synopsis: None,
             title: None,
@@ -128,15 +174,35 @@ impl LocalOptions {
     }
 }
 
+impl ManifestMessage {
+    pub fn new(
+        line: u32,
+        manifest_path: &Path,
+        snippet: &str,
+        message: &str
+    ) -> ManifestMessage {
+        let text = format!("Error in {}:{}:\n\n{}\n\n{}", manifest_path.display(), line, snippet, message);
+
+        ManifestMessage {
+            location: Location::new(line, manifest_path),
+            text
+        }
+    }
+}
+
 impl Overrides {
     pub fn default() -> Overrides {
         Overrides {
             copy_link: true,
             download_codes: Vec::new(),
             embedding: false,
+            license: None,
             m3u_enabled: false,
             more_label: None,
             payment_info: None,
+            release_archive_checksums: false,
+            release_archive_format: ArchiveFormat::Zip,
+            release_archive_info: false,
             release_artists: Vec::new(),
             release_download_access: DownloadAccessOption::Free,
             release_downloads: Vec::new(),
@@ -161,27 +227,24 @@ fn attribute_error_with_snippet(
     attribute: &Attribute,
     manifest_path: &Path,
     error: &str
-) -> String {
-    let snippet = attribute.snippet();
-    format!("Error in {}:{}:\n\n{}\n\n{}", manifest_path.display(), attribute.line_number, snippet, error)
+) -> ManifestMessage {
+    ManifestMessage::new(attribute.line_number, manifest_path, &attribute.snippet(), error)
 }
 
 fn element_error_with_snippet(
     element: &Box<dyn SectionElement>,
     manifest_path: &Path,
     error: &str
-) -> String {
-    let snippet = element.snippet();
-    format!("Error in {}:{}:\n\n{}\n\n{}", manifest_path.display(), element.line_number(), snippet, error)
+) -> ManifestMessage {
+    ManifestMessage::new(element.line_number(), manifest_path, &element.snippet(), error)
 }
 
 fn item_error_with_snippet(
     item: &Item,
     manifest_path: &Path,
     error: &str
-) -> String {
-    let snippet = item.snippet();
-    format!("Error in {}:{}:\n\n{}\n\n{}", manifest_path.display(), item.line_number, snippet, error)
+) -> ManifestMessage {
+    ManifestMessage::new(item.line_number, manifest_path, &item.snippet(), error)
 }
 
 fn not_supported_error(
@@ -215,3 +278,42 @@ fn platform_printer() -> Box<enolib::TerminalPrinter> {
 fn platform_printer() -> Box<enolib::TextPrinter> {
     Box::new(enolib::TextPrinter)
 }
+
+/// Reads the 'crop' or 'focus' attribute of an image field (cover, image,
+/// etc.), if present. Only one of the two may be given. All other attributes
+/// are left for the caller to read.
+fn read_image_crop_attributes(
+    element: &Box<dyn SectionElement>,
+    manifest_path: &Path,
+    build: &mut Build
+) -> Option<ImageCrop> {
+    let attributes = element.as_field().ok()?.attributes().ok()?;
+
+    let mut crop = None;
+
+    for attribute in attributes {
+        let parse = match attribute.key() {
+            "crop" => ImageCrop::from_crop_value,
+            "focus" => ImageCrop::from_focus_value,
+            _ => continue
+        };
+
+        if let Some(value) = attribute.value() {
+            match parse(value) {
+                Ok(parsed) if crop.is_none() => crop = Some(parsed),
+                Ok(_) => {
+                    let message = "Only one of 'crop' and 'focus' can be specified for an image";
+                    let error = attribute_error_with_snippet(attribute, manifest_path, message);
+                    build.manifest_error(&error);
+                }
+                Err(err) => {
+                    let message = format!("Invalid {} setting value '{value}' ({err})", attribute.key());
+                    let error = attribute_error_with_snippet(attribute, manifest_path, &message);
+                    build.manifest_error(&error);
+                }
+            }
+        }
+    }
+
+    crop
+}
@@ -5,6 +5,7 @@ use std::fs;
 use std::mem;
 use std::path::Path;
 
+use chrono::NaiveDate;
 use url::Url;
 
 use crate::{
@@ -14,6 +15,7 @@ use crate::{
     Cache,
     Catalog,
     DescribedImage,
+    Event,
     LocalOptions,
     Overrides
 };
@@ -29,17 +31,27 @@ use super::{
     read_artist_catalog_release_option,
     read_artist_catalog_release_track_option,
     read_artist_release_option,
+    read_image_crop_attributes,
     read_obsolete_option
 };
 
 const ARTIST_OPTIONS: &[&str] = &[
     "alias",
     "aliases",
+    "event",
     "external_page",
     "image",
     "name"
 ];
 
+/// All option groups supported in a artist.eno manifest
+pub const ARTIST_MANIFEST_OPTIONS: &[&[&str]] = &[
+    ARTIST_OPTIONS,
+    ARTIST_CATALOG_RELEASE_OPTIONS,
+    ARTIST_CATALOG_RELEASE_TRACK_OPTIONS,
+    ARTIST_RELEASE_OPTIONS
+];
+
 pub fn read_artist_manifest(
     build: &mut Build,
     cache: &mut Cache,
@@ -69,6 +81,7 @@ pub fn read_artist_manifest(
     let mut local_options = LocalOptions::new();
 
     let mut aliases = Vec::new();
+    let mut events = Vec::new();
     let mut external_page = None;
     // By default we use the folder name as name
     let mut name = dir.file_name().unwrap().to_string_lossy().to_string();
@@ -90,7 +103,7 @@ pub fn read_artist_manifest(
 
                 let message = "alias needs to be provided as a field with a value, e.g.: 'alias: Älice'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "aliases" => 'aliases: {
                 if let Ok(field) = element.as_field() {
@@ -102,7 +115,76 @@ pub fn read_artist_manifest(
 
                 let message = "aliases needs to be provided as a field containing items, e.g.:\n\naliases:\n- Älice\n- Älicë";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
+            }
+            "event" => 'event: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(attributes) = field.attributes() {
+                        let mut city = None;
+                        let mut date = None;
+                        let mut tickets = None;
+                        let mut venue = None;
+
+                        for attribute in attributes {
+                            match attribute.key() {
+                                "city" => {
+                                    if let Some(value) = attribute.value() {
+                                        city = Some(value.to_string());
+                                    }
+                                }
+                                "date" => {
+                                    if let Some(value) = attribute.value() {
+                                        match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+                                            Ok(parsed_date) => date = Some(parsed_date),
+                                            Err(err) => {
+                                                let message = format!("Invalid date value '{value}', it needs to follow the pattern YYYY-MM-DD ({err})");
+                                                let error = attribute_error_with_snippet(attribute, manifest_path, &message);
+                                                build.manifest_error(&error);
+                                            }
+                                        }
+                                    }
+                                }
+                                "tickets" => {
+                                    if let Some(value) = attribute.value() {
+                                        match Url::parse(value) {
+                                            Ok(_) => tickets = Some(value.to_string()),
+                                            Err(err) => {
+                                                let message = format!("The url supplied for tickets seems to be malformed ({err})");
+                                                let error = attribute_error_with_snippet(attribute, manifest_path, &message);
+                                                build.manifest_error(&error);
+                                            }
+                                        }
+                                    }
+                                }
+                                "venue" => {
+                                    if let Some(value) = attribute.value() {
+                                        venue = Some(value.to_string());
+                                    }
+                                }
+                                _ => {
+                                    let message = "The key/name of this attribute was not recognized, only 'city', 'date', 'tickets' and 'venue' are recognized inside an event field";
+                                    let error = attribute_error_with_snippet(attribute, manifest_path, message);
+                                    build.manifest_error(&error);
+                                }
+                            }
+                        }
+
+                        match (date, venue) {
+                            (Some(date), Some(venue)) => events.push(Event::new(city, date, tickets, venue)),
+                            _ => {
+                                let message = "An event needs to provide at least a date and a venue, the event is skipped";
+                                let error = element_error_with_snippet(element, manifest_path, message);
+                                build.manifest_error(&error);
+                            }
+                        }
+
+                        break 'event;
+                    }
+                }
+
+                let message = "event needs to be provided as a field with attributes, e.g.:\n\nevent:\ndate = 2025-09-12\nvenue = Rote Fabrik\ncity = Zürich\ntickets = https://example.com/tickets";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
             }
             "external_page" => 'external_page: {
                 if let Ok(field) = element.as_field() {
@@ -113,7 +195,7 @@ pub fn read_artist_manifest(
                                 Err(err) => {
                                     let message = format!("The url supplied for the external_page option seems to be malformed ({err})");
                                     let error = element_error_with_snippet(element, manifest_path, &message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         }
@@ -124,16 +206,19 @@ pub fn read_artist_manifest(
 
                 let message = "external_page must be provided as a field with a value, e.g. 'external_page: https://example.com'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "image" => 'image: {
                 if let Ok(field) = element.as_field() {
                     if let Ok(attributes) = field.attributes() {
                         let mut path_relative_to_catalog = None;
+                        let crop = read_image_crop_attributes(element, manifest_path, build);
                         let mut description = None;
 
                         for attribute in attributes {
                             match attribute.key() {
+                                // Read through read_image_crop_attributes
+                                "crop" | "focus" => (),
                                 "description" => {
                                     if let Some(value) = attribute.value() {
                                         description = Some(value.to_string());
@@ -148,22 +233,22 @@ pub fn read_artist_manifest(
                                         } else {
                                             let message = format!("The referenced file was not found ({})", absolute_path.display());
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
 
                                     }
                                 }
                                 _ => {
-                                    let message = "The key/name of this attribute was not recognized, only 'description' and 'file' are recognized inside an image field";
+                                    let message = "The key/name of this attribute was not recognized, only 'crop', 'description', 'file' and 'focus' are recognized inside an image field";
                                     let error = element_error_with_snippet(element, manifest_path, message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         }
 
                         if let Some(path) = path_relative_to_catalog {
                             let obtained_image = cache.get_or_create_image(build, &path);
-                            image = Some(DescribedImage::new(description, obtained_image));
+                            image = Some(DescribedImage::new(crop, description, obtained_image));
                         }
 
                         break 'image;
@@ -172,7 +257,7 @@ pub fn read_artist_manifest(
 
                 let message = "image needs to be provided as a field with attributes, e.g.:\n\nimage:\ndescription = Alice, looking amused\nfile = alice.jpg";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "name" => 'name: {
                 if let Ok(field) = element.as_field() {
@@ -186,7 +271,7 @@ pub fn read_artist_manifest(
                 }
                 let message = "name needs to be provided as a field with a value, e.g.: 'name: Alice'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             _ if read_artist_catalog_release_option(build, element, manifest_path, overrides) => (),
             _ if read_artist_catalog_release_track_option(build, cache, element, &mut local_options, manifest_path, overrides) => (),
@@ -195,16 +280,11 @@ pub fn read_artist_manifest(
                 let message = not_supported_error(
                     "artist.eno",
                     other,
-                    &[
-                        ARTIST_OPTIONS,
-                        ARTIST_CATALOG_RELEASE_OPTIONS,
-                        ARTIST_CATALOG_RELEASE_TRACK_OPTIONS,
-                        ARTIST_RELEASE_OPTIONS
-                    ]
+                    ARTIST_MANIFEST_OPTIONS
                 );
 
                 let error = element_error_with_snippet(element, manifest_path, &message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
         }
     }
@@ -212,6 +292,7 @@ pub fn read_artist_manifest(
     let artist = Artist::new_manual(
         aliases,
         overrides.copy_link,
+        events,
         external_page,
         image,
         mem::take(&mut local_options.links),
@@ -7,6 +7,7 @@ use enolib::SectionElement;
 use url::Url;
 
 use crate::{
+    ArchiveFormat,
     Build,
     DownloadAccessOption,
     DownloadFormat,
@@ -22,6 +23,9 @@ use super::{
 };
 
 pub const ARTIST_CATALOG_RELEASE_OPTIONS: &[&str] = &[
+    "release_archive_checksums",
+    "release_archive_format",
+    "release_archive_info",
     "release_download_access",
     "release_downloads",
     "release_extras",
@@ -42,7 +46,7 @@ pub fn read_artist_catalog_release_option(
         "downloads" => 'downloads: {
             let message = "The 'downloads' option was split into two: 'release_download_access' and 'track_download_access'. Depending on whether you have release and/or track downloads configured you should now use one or both of the two - in terms of the value you can provide for each of them it still works exactly the same as 'downloads' though. For the time being 'downloads' will still work, but it won't forever - make sure to update this at some point.";
             let warning = element_error_with_snippet(element, manifest_path, message);
-            build.warning(&warning);
+            build.manifest_warning(&warning);
 
             if let Ok(field) = element.as_field() {
                 if let Ok(result) = field.value() {
@@ -73,14 +77,14 @@ pub fn read_artist_catalog_release_option(
                                     Err(err) => {
                                         let message = format!("This external downloads url is somehow not valid ({err})");
                                         let error = element_error_with_snippet(element, manifest_path, &message);
-                                        build.error(&error);
+                                        build.manifest_error(&error);
                                     }
                                 }
                             }
                             _ => {
                                 let message = "This downloads setting was not recognized (supported values are 'code', 'disabled', 'free', 'paycurtain' or an external url like 'https://example.com')";
                                 let error = element_error_with_snippet(element, manifest_path, message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -91,13 +95,13 @@ pub fn read_artist_catalog_release_option(
 
             let message = "downloads needs to be provided as a field with the value 'code', 'disabled', 'free', 'paycurtain' or an external url like 'https://example.com', e.g.: 'downloads: code'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         // TODO: 'extra_downloads' was deprecated in favor of release_extras in ~february 2025, eventually remove this temporary fallback handling in a future release
         "extra_downloads" => 'extra_downloads: {
             let message = "The 'extra_downloads' option is now called 'release_extras' - it works exactly the same though. For the time being 'extra_downloads' will still work, but it won't forever - make sure to update at some point. Note that in addition to 'release_extras', there is now also a 'track_extras' option!";
             let warning = element_error_with_snippet(element, manifest_path, message);
-            build.warning(&warning);
+            build.manifest_warning(&warning);
 
             if let Ok(field) = element.as_field() {
                 if let Ok(result) = field.value() {
@@ -109,7 +113,7 @@ pub fn read_artist_catalog_release_option(
                             _ => {
                                 let message = format!("The value '{value}' is not supported (allowed are: 'bundled', 'disabled' or 'separate'");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -126,7 +130,7 @@ pub fn read_artist_catalog_release_option(
                             Some(other) => {
                                 let message = format!("The value '{other}' is not supported (allowed are: 'bundled', 'disabled' or 'separate'");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                             None => ()
                         }
@@ -138,13 +142,13 @@ pub fn read_artist_catalog_release_option(
 
             let message = "release_extras needs to be provided either as a field with a value (e.g. 'release_extras: disabled') or as a field with items, e.g.:\n\nrelease_extras:\n- bundled\n- separate\n\n(The available options are 'bundled', 'disabled' and 'separate')";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         // TODO: 'price' was deprecated in favor of release_price in ~february 2025, eventually remove this temporary fallback handling in a future release
         "price" => 'price: {
             let message = "The 'price' option is now called 'release_price' - it works exactly the same though. For the time being 'price' will still work, but it won't forever - make sure to update at some point. Note that in addition to 'release_price', there is now also a 'track_price' option!";
             let warning = element_error_with_snippet(element, manifest_path, message);
-            build.warning(&warning);
+            build.manifest_warning(&warning);
 
             if let Ok(field) = element.as_field() {
                 if let Ok(result) = field.value() {
@@ -154,7 +158,7 @@ pub fn read_artist_catalog_release_option(
                             Err(err) => {
                                 let message = format!("Invalid price value ({err})");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -165,7 +169,75 @@ pub fn read_artist_catalog_release_option(
 
             let message = "release_price needs to be provided as a field with a currency and price (range) value, e.g.: 'release_price: USD 0+', 'release_price: 3.50 GBP', 'release_price: INR 230+' or 'release_price: JPY 400-800'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
+        }
+        "release_archive_checksums" => 'release_archive_checksums: {
+            if let Ok(field) = element.as_field() {
+                if let Ok(result) = field.value() {
+                    if let Some(value) = result {
+                        match value {
+                            "disabled" => overrides.release_archive_checksums = false,
+                            "enabled" => overrides.release_archive_checksums = true,
+                            _ => {
+                                let message = format!("The value '{value}' is not recognized for the release_archive_checksums option, allowed values are 'enabled' and 'disabled'");
+                                let error = element_error_with_snippet(element, manifest_path, &message);
+                                build.manifest_error(&error);
+                            }
+                        }
+                    }
+
+                    break 'release_archive_checksums;
+                }
+            }
+
+            let message = "release_archive_checksums needs to be provided as a field with the value 'enabled' or 'disabled', e.g.: 'release_archive_checksums: enabled'";
+            let error = element_error_with_snippet(element, manifest_path, message);
+            build.manifest_error(&error);
+        }
+        "release_archive_format" => 'release_archive_format: {
+            if let Ok(field) = element.as_field() {
+                if let Ok(result) = field.value() {
+                    if let Some(value) = result {
+                        match ArchiveFormat::from_manifest_key(value) {
+                            Some(archive_format) => overrides.release_archive_format = archive_format,
+                            None => {
+                                let message = format!("The archive format '{value}' is not supported (allowed are: 'tar', 'tar_zst', 'zip' or 'zip_stored')");
+                                let error = element_error_with_snippet(element, manifest_path, &message);
+                                build.manifest_error(&error);
+                            }
+                        }
+                    }
+
+                    break 'release_archive_format;
+                }
+            }
+
+            let message = "release_archive_format needs to be provided as a field with the value 'tar', 'tar_zst', 'zip' or 'zip_stored', e.g.: 'release_archive_format: zip_stored'";
+            let error = element_error_with_snippet(element, manifest_path, message);
+            build.manifest_error(&error);
+        }
+        "release_archive_info" => 'release_archive_info: {
+            if let Ok(field) = element.as_field() {
+                if let Ok(result) = field.value() {
+                    if let Some(value) = result {
+                        match value {
+                            "disabled" => overrides.release_archive_info = false,
+                            "enabled" => overrides.release_archive_info = true,
+                            _ => {
+                                let message = format!("The value '{value}' is not recognized for the release_archive_info option, allowed values are 'enabled' and 'disabled'");
+                                let error = element_error_with_snippet(element, manifest_path, &message);
+                                build.manifest_error(&error);
+                            }
+                        }
+                    }
+
+                    break 'release_archive_info;
+                }
+            }
+
+            let message = "release_archive_info needs to be provided as a field with the value 'enabled' or 'disabled', e.g.: 'release_archive_info: enabled'";
+            let error = element_error_with_snippet(element, manifest_path, message);
+            build.manifest_error(&error);
         }
         "release_download_access" => 'release_download_access: {
             if let Ok(field) = element.as_field() {
@@ -184,14 +256,14 @@ pub fn read_artist_catalog_release_option(
                                     Err(err) => {
                                         let message = format!("This external downloads url is somehow not valid ({err})");
                                         let error = element_error_with_snippet(element, manifest_path, &message);
-                                        build.error(&error);
+                                        build.manifest_error(&error);
                                     }
                                 }
                             }
                             _ => {
                                 let message = "This release_download_access setting was not recognized (supported values are 'code', 'disabled', 'free', 'paycurtain' or an external url like 'https://example.com')";
                                 let error = element_error_with_snippet(element, manifest_path, message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -202,7 +274,7 @@ pub fn read_artist_catalog_release_option(
 
             let message = "release_download_access needs to be provided as a field with the value 'code', 'disabled', 'free', 'paycurtain' or an external url like 'https://example.com', e.g.: 'release_download_access: code'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "release_downloads" => 'release_downloads: {
             if let Ok(field) = element.as_field() {
@@ -214,7 +286,7 @@ pub fn read_artist_catalog_release_option(
                             None => {
                                 let message = format!("The download format '{value}' is not supported (All available formats: 'aac', 'aiff', 'alac', 'flac', 'mp3', 'ogg_vorbis', 'opus', 'opus_48', 'opus_96', 'opus_128' and 'wav')");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -231,7 +303,7 @@ pub fn read_artist_catalog_release_option(
                                         None => {
                                             let message = format!("The download format '{value}' is not supported (All available formats: 'aac', 'aiff', 'alac', 'flac', 'mp3', 'ogg_vorbis', 'opus', 'opus_48', 'opus_96', 'opus_128' and 'wav')");
                                             let error = item_error_with_snippet(item, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                             None
                                         }
                                     }
@@ -247,7 +319,7 @@ pub fn read_artist_catalog_release_option(
 
             let message = "release_downloads needs to be provided either as a field with a value (e.g. 'release_downloads: mp3') or as a field with items, e.g.:\n\nrelease_downloads:\n- mp3\n- flac\n- opus\n\n(All available formats: 'aac', 'aiff', 'alac', 'flac', 'mp3', 'ogg_vorbis', 'opus', 'opus_48', 'opus_96', 'opus_128' and 'wav')";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "release_extras" => 'release_extras: {
             if let Ok(field) = element.as_field() {
@@ -260,7 +332,7 @@ pub fn read_artist_catalog_release_option(
                             _ => {
                                 let message = format!("The value '{value}' is not supported (allowed are: 'bundled', 'disabled' or 'separate'");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -277,7 +349,7 @@ pub fn read_artist_catalog_release_option(
                             Some(other) => {
                                 let message = format!("The value '{other}' is not supported (allowed are: 'bundled', 'disabled' or 'separate'");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                             None => ()
                         }
@@ -289,7 +361,7 @@ pub fn read_artist_catalog_release_option(
 
             let message = "release_extras needs to be provided either as a field with a value (e.g. 'release_extras: disabled') or as a field with items, e.g.:\n\nrelease_extras:\n- bundled\n- separate\n\n(The available options are 'bundled', 'disabled' and 'separate')";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "release_price" => 'release_price: {
             if let Ok(field) = element.as_field() {
@@ -300,7 +372,7 @@ pub fn read_artist_catalog_release_option(
                             Err(err) => {
                                 let message = format!("Invalid price value ({err})");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -311,7 +383,7 @@ pub fn read_artist_catalog_release_option(
 
             let message = "release_price needs to be provided as a field with a currency and price (range) value, e.g.: 'release_price: USD 0+', 'release_price: 3.50 GBP', 'release_price: INR 230+' or 'release_price: JPY 400-800'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "track_numbering" => 'track_numbering: {
             if let Ok(field) = element.as_field() {
@@ -322,7 +394,7 @@ pub fn read_artist_catalog_release_option(
                             None => {
                                 let message = format!("track_numbering value '{value}' was not recognized (supported values are 'arabic', 'arabic-dotted', 'arabic-padded', 'disabled', 'hexadecimal', 'hexadecimal-padded', 'roman' and 'roman-dotted')");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -333,7 +405,7 @@ pub fn read_artist_catalog_release_option(
 
             let message = "track_numbering needs to be provided as a field with a value, e.g.: 'track_numbering: arabic-dotted'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         _ => return false
     }
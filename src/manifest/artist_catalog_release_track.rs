@@ -13,6 +13,7 @@ use crate::{
     CoverGenerator,
     DownloadAccessOption,
     DownloadFormat,
+    License,
     Link,
     LocalOptions,
     Overrides,
@@ -21,7 +22,9 @@ use crate::{
     StreamingQuality,
     TagAgenda,
     ThemeBase,
-    ThemeFont
+    ThemeFont,
+    WaveformColor,
+    WaveformStyle
 };
 use crate::markdown;
 use crate::util::html_escape_outside_attribute;
@@ -39,6 +42,7 @@ pub const ARTIST_CATALOG_RELEASE_TRACK_OPTIONS: &[&str] = &[
     "download_code",
     "download_codes",
     "embedding",
+    "license",
     "link",
     "more",
     "more_label",
@@ -78,7 +82,7 @@ pub fn read_artist_catalog_release_track_option(
                             _ => {
                                 let message = "This copy_link setting was not recognized (supported values are 'enabled' and 'disabled')";
                                 let error = element_error_with_snippet(element, manifest_path, message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -89,7 +93,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "copy_link needs to be provided as a field with a value, e.g.: 'copy_link: disabled'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "download_code" => 'download_code: {
             if let Ok(field) = element.as_field() {
@@ -100,7 +104,7 @@ pub fn read_artist_catalog_release_track_option(
                             Err(err) => {
                                 let message = format!("The download code '{value}' contains non-permitted characters ({err})");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -111,7 +115,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "download_code needs to be provided as a field with a value, e.g.: 'download_code: enterfriend'\n\nFor multiple download_codes specify the download_codes field:\n\ndownload_codes:\n- enterfriend\n- enteralternative";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "download_codes" => 'download_codes: {
             if let Ok(field) = element.as_field() {
@@ -126,7 +130,7 @@ pub fn read_artist_catalog_release_track_option(
                                         Err(err) => {
                                             let message = format!("The download code '{value}' contains non-permitted characters ({err})");
                                             let error = item_error_with_snippet(item, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                             None
                                         }
                                     }
@@ -142,7 +146,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "download_codes needs to be provided as a field with items, e.g.:\n\ndownload_codes:\n- enterfriend\n- enteralternative";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "embedding" => 'embedding: {
             if let Ok(field) = element.as_field() {
@@ -154,7 +158,7 @@ pub fn read_artist_catalog_release_track_option(
                             _ => {
                                 let message = format!("The value '{value}' is not recognized for the embedding option, allowed values are 'enabled' and 'disabled'");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -165,7 +169,32 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "embedding needs to be provided as a field with the value 'enabled' or 'disabled', e.g.: 'embedding: enabled'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
+        }
+        "license" => 'license: {
+            if let Ok(field) = element.as_field() {
+                if let Ok(result) = field.value() {
+                    if let Some(value) = result {
+                        match License::from_manifest_value(value) {
+                            Ok(license) => overrides.license = Some(license),
+                            Err(err) => {
+                                let error = element_error_with_snippet(element, manifest_path, &err);
+                                build.manifest_error(&error);
+                            }
+                        }
+                    } else {
+                        // An empty license field explicitly unsets a license
+                        // inherited from a parent manifest.
+                        overrides.license = None;
+                    }
+
+                    break 'license;
+                }
+            }
+
+            let message = "license needs to be provided as a field with a value (an SPDX identifier or Creative Commons license), e.g.: 'license: CC BY-SA 4.0'";
+            let error = element_error_with_snippet(element, manifest_path, message);
+            build.manifest_error(&error);
         }
         "link" => 'link: {
             if let Ok(field) = element.as_field() {
@@ -180,7 +209,7 @@ pub fn read_artist_catalog_release_track_option(
                                 url = {value}
                             "#);
                             let error = element_error_with_snippet(element, manifest_path, &message);
-                            build.error(&error);
+                            build.manifest_error(&error);
                         } else {
                             match Url::parse(value) {
                                 Ok(_) => {
@@ -193,7 +222,7 @@ pub fn read_artist_catalog_release_track_option(
                                         Full urls (e.g. "https://example.com") and internal references (e.g. "#example") are supported.
                                     "##);
                                     let error = element_error_with_snippet(element, manifest_path, &message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         }
@@ -226,7 +255,7 @@ pub fn read_artist_catalog_release_track_option(
                                                     Full urls (e.g. "https://example.com") and internal references (e.g. "#example") are supported.
                                                 "##);
                                                 let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                                build.error(&error);
+                                                build.manifest_error(&error);
                                             }
                                         }
                                     }
@@ -246,7 +275,7 @@ pub fn read_artist_catalog_release_track_option(
                                         _ => {
                                             let message = format!("The verification attribute value '{value}' is not recognized, allowed are 'rel-me' and 'rel-me-hidden'");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -254,7 +283,7 @@ pub fn read_artist_catalog_release_track_option(
                             other => {
                                 let message = format!("The attribute '{other}' is not recognized here (supported attributes are 'label', 'url' and 'verification'");
                                 let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -264,7 +293,7 @@ pub fn read_artist_catalog_release_track_option(
                             if hidden || rel_me {
                                 let message = format!("For internal, anchor-only links the verification option is not supported, please remove it from your link to '{url}'.");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             } else if label.is_none() {
                                 let message = formatdoc!(r#"
                                     For internal, anchor-only links it is mandatory to provide a label, therefore please provide one for your link to '{url}' like this:
@@ -274,7 +303,7 @@ pub fn read_artist_catalog_release_track_option(
                                     url = {url}
                                 "#);
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             } else {
                                 let link = Link::anchor(url, label.unwrap());
                                 local_options.links.push(link);
@@ -286,7 +315,7 @@ pub fn read_artist_catalog_release_track_option(
                     } else {
                         let message = "The link option must supply an url attribute at least, e.g.:\n\nlink:\nurl = https://example.com";
                         let error = element_error_with_snippet(element, manifest_path, message);
-                        build.error(&error);
+                        build.manifest_error(&error);
                     }
 
                     break 'link;
@@ -301,7 +330,7 @@ pub fn read_artist_catalog_release_track_option(
                 url = https://example.com
             "#);
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "more" => {
             if let Ok(embed) = element.as_embed() {
@@ -313,7 +342,7 @@ pub fn read_artist_catalog_release_track_option(
             } else {
                 let message = "The 'more' option needs to be provided as an embed, e.g.:\n-- more\nA long-form 'more' text\n--more";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
         }
         "more_label" => 'more_label: {
@@ -329,7 +358,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "more_label needs to be provided as a field with a value, e.g.: 'more_label: About'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "payment_info" => {
             if let Ok(embed) = element.as_embed() {
@@ -339,7 +368,7 @@ pub fn read_artist_catalog_release_track_option(
             } else {
                 let message = "payment_info needs to be provided as an embed, e.g.:\n-- payment_info\nThe payment info text\n--payment_info";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
         }
         "speed_controls" => 'speed_controls: {
@@ -352,7 +381,7 @@ pub fn read_artist_catalog_release_track_option(
                             _ => {
                                 let message = format!("The value '{value}' is not supported (allowed are: 'enabled' or 'disabled'");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -363,7 +392,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "speed_controls needs to be provided as a field with the value 'enabled' or 'disabled' (e.g. 'speed_controls: enabled')";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "streaming_quality" => 'streaming_quality: {
             if let Ok(field) = element.as_field() {
@@ -373,7 +402,7 @@ pub fn read_artist_catalog_release_track_option(
                             Ok(streaming_quality) => overrides.streaming_quality = streaming_quality,
                             Err(err) => {
                                 let error = element_error_with_snippet(element, manifest_path, &err);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -384,7 +413,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "streaming_quality needs to be provided as a field with a value, e.g.: 'streaming_quality: frugal'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "synopsis" => {
             if let Ok(embed) = element.as_embed() {
@@ -397,7 +426,7 @@ pub fn read_artist_catalog_release_track_option(
                     } else {
                         let message = format!("Synopsis is too long ({synopsis_chars}/{MAX_SYNOPSIS_CHARS} characters)");
                         let error = element_error_with_snippet(element, manifest_path, &message);
-                        build.error(&error);
+                        build.manifest_error(&error);
                     }
                 } else {
                     local_options.synopsis = None;
@@ -405,7 +434,7 @@ pub fn read_artist_catalog_release_track_option(
             } else {
                 let message = "synopsis needs to be provided as an embed, e.g.:\n-- synopsis\nThis is a synopsis\n--synopsis";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
         }
         "tags" => 'tags: {
@@ -419,7 +448,7 @@ pub fn read_artist_catalog_release_track_option(
                             _ => {
                                 let message = format!("The value '{value}' is not recognized for the tags option, allowed values are 'copy', 'normalize' and 'remove'");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -431,7 +460,7 @@ pub fn read_artist_catalog_release_track_option(
                         if let Some(value) = attribute.value() {
                             if let Err(err) = overrides.tag_agenda.set(attribute.key(), value) {
                                 let error = attribute_error_with_snippet(attribute, manifest_path, &err);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -442,7 +471,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "tags needs to be provided either as a field with a value (allowed are 'copy', 'normalize' and 'remove') - e.g.: 'tags: copy' - or as a field with attributes, e.g.:\n\ntags:\ntitle = copy\nartist = rewrite\nalbum_artist = remove";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "theme" => 'theme: {
             if let Ok(field) = element.as_field() {
@@ -457,7 +486,7 @@ pub fn read_artist_catalog_release_track_option(
                                         None => {
                                             let message = format!("Unsupported value '{value}' for 'accent_brightening' (accepts a percentage in the range 0-100 - without the % sign)");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -469,7 +498,7 @@ pub fn read_artist_catalog_release_track_option(
                                         None => {
                                             let message = format!("Unsupported value '{value}' for 'accent_chroma' (accepts a percentage in the range 0-100 - without the % sign)");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -481,7 +510,7 @@ pub fn read_artist_catalog_release_track_option(
                                         None => {
                                             let message = format!("Unsupported value '{value}' for 'accent_hue' (accepts an amount of degrees in the range 0-360)");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -493,7 +522,7 @@ pub fn read_artist_catalog_release_track_option(
                                         None => {
                                             let message = format!("Unsupported value '{value}' for 'background_alpha' (accepts a percentage in the range 0-100 - without the % sign)");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -508,7 +537,7 @@ pub fn read_artist_catalog_release_track_option(
                                     } else {
                                         let message = format!("Invalid background_image setting value '{path_relative_to_manifest}' (The referenced file was not found)");
                                         let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                        build.error(&error);
+                                        build.manifest_error(&error);
                                     }
                                 }
                             }
@@ -519,7 +548,7 @@ pub fn read_artist_catalog_release_track_option(
                                         None => {
                                             let message = format!("Invalid base setting value '{value}' (supported values are 'dark' and 'light')");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -531,7 +560,7 @@ pub fn read_artist_catalog_release_track_option(
                                         None => {
                                             let message = format!("Unsupported value '{value}' for 'base_chroma' (accepts a percentage in the range 0-100 - without the % sign)");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -543,7 +572,7 @@ pub fn read_artist_catalog_release_track_option(
                                         None => {
                                             let message = format!("Unsupported value '{value}' for 'base_hue' (accepts an amount of degrees in the range 0-360)");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -556,7 +585,7 @@ pub fn read_artist_catalog_release_track_option(
                                             let supported = CoverGenerator::ALL_GENERATORS.map(|key| format!("'{key}'")).join(", ");
                                             let message = format!("Invalid cover_generator setting value '{value}' (supported values are {supported})");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -565,7 +594,7 @@ pub fn read_artist_catalog_release_track_option(
                             "custom_font" => {
                                 let message = "The 'custom_font' option has been superseded by more flexible and generic functionality provided through the 'site_assets' and 'site_metadata' options. For the time being 'custom_font' will still work, but it won't forever - make sure to update at some point.";
                                 let warning = element_error_with_snippet(element, manifest_path, message);
-                                build.warning(&warning);
+                                build.manifest_warning(&warning);
 
                                 if let Some(Ok(relative_path)) = attribute.optional_value::<String>() {
                                     let absolute_path = manifest_path.parent().unwrap().join(&relative_path);
@@ -575,13 +604,13 @@ pub fn read_artist_catalog_release_track_option(
                                             Err(err) => {
                                                 let message = format!("Invalid custom_font setting value '{relative_path}' ({err})");
                                                 let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                                build.error(&error);
+                                                build.manifest_error(&error);
                                             }
                                         }
                                     } else {
                                         let message = format!("Invalid custom_font setting value '{relative_path}' (The referenced file was not found)");
                                         let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                        build.error(&error);
+                                        build.manifest_error(&error);
                                     }
                                 }
                             }
@@ -592,7 +621,7 @@ pub fn read_artist_catalog_release_track_option(
                                         None => {
                                             let message = format!("Unsupported value '{value}' for 'dynamic_range' (accepts a percentage in the range 0-100 - without the % sign)");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -605,7 +634,7 @@ pub fn read_artist_catalog_release_track_option(
                                         _ => {
                                             let message = format!("Ignoring unsupported round_corners setting value '{value}' (supported values are 'disabled' and 'enabled')");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -619,13 +648,37 @@ pub fn read_artist_catalog_release_track_option(
                                         _ => {
                                             let message = "The functionality for using 'system_font' to specify arbitrary system fonts has been superseded by more flexible and generic functionality provided through the 'site_assets' and 'site_metadata' options. For the time being 'system_font' will still work the way you're using it, but it won't forever - make sure to update at some point.";
                                             let warning = element_error_with_snippet(element, manifest_path, message);
-                                            build.warning(&warning);
+                                            build.manifest_warning(&warning);
 
                                             ThemeFont::System(value.to_string())
                                         }
                                     };
                                 }
                             }
+                            "waveform_color" => {
+                                if let Some(value) = attribute.value() {
+                                    match WaveformColor::from_manifest_key(value) {
+                                        Some(waveform_color) => overrides.theme.waveform_color = waveform_color,
+                                        None => {
+                                            let message = format!("Ignoring unsupported waveform_color setting value '{value}' (supported values are 'spectral' and 'theme')");
+                                            let error = attribute_error_with_snippet(attribute, manifest_path, &message);
+                                            build.manifest_error(&error);
+                                        }
+                                    }
+                                }
+                            }
+                            "waveform_style" => {
+                                if let Some(value) = attribute.value() {
+                                    match WaveformStyle::from_manifest_key(value) {
+                                        Some(waveform_style) => overrides.theme.waveform_style = waveform_style,
+                                        None => {
+                                            let message = format!("Ignoring unsupported waveform_style setting value '{value}' (supported values are 'line', 'mirrored' and 'stereo')");
+                                            let error = attribute_error_with_snippet(attribute, manifest_path, &message);
+                                            build.manifest_error(&error);
+                                        }
+                                    }
+                                }
+                            }
                             "waveforms" => {
                                 if let Some(value) = attribute.value() {
                                     match value {
@@ -644,15 +697,15 @@ pub fn read_artist_catalog_release_track_option(
                                         _ => {
                                             let message = format!("Ignoring unsupported waveforms setting value '{value}' (supported values are 'absolute', 'relative' and 'disabled')");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
                             }
                             other => {
-                                let message = format!("The attribute '{other}' is not recognized here (supported attributes are 'accent_brightening', 'accent_chroma', 'accent_hue', 'background_alpha', 'background_image', 'base', 'base_chroma', 'base_hue', 'cover_generator', 'dynamic_range', 'round_corners', 'system_font' and 'waveforms')");
+                                let message = format!("The attribute '{other}' is not recognized here (supported attributes are 'accent_brightening', 'accent_chroma', 'accent_hue', 'background_alpha', 'background_image', 'base', 'base_chroma', 'base_hue', 'cover_generator', 'dynamic_range', 'round_corners', 'system_font', 'waveform_color', 'waveform_style' and 'waveforms')");
                                 let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -663,7 +716,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "theme needs to be provided as a field with attributes, e.g.:\n\ntheme:\nbase = light\nwaveforms = absolute";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "track_artist" => 'track_artist: {
             if let Ok(field) = element.as_field() {
@@ -678,7 +731,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "track_artist needs to be provided as a field with a value, e.g.: 'track_artist: Alice'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "track_artists" => 'track_artists: {
             if let Ok(field) = element.as_field() {
@@ -694,7 +747,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "track_artists needs to be provided as a field with items, e.g.:\n\ntrack_artists:\n- Alice\n- Bob'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "track_download_access" => 'track_download_access: {
             if let Ok(field) = element.as_field() {
@@ -713,14 +766,14 @@ pub fn read_artist_catalog_release_track_option(
                                     Err(err) => {
                                         let message = format!("This external downloads url is somehow not valid ({err})");
                                         let error = element_error_with_snippet(element, manifest_path, &message);
-                                        build.error(&error);
+                                        build.manifest_error(&error);
                                     }
                                 }
                             }
                             _ => {
                                 let message = "This track_download_access setting was not recognized (supported values are 'code', 'disabled', 'free', 'paycurtain' or an external url like 'https://example.com')";
                                 let error = element_error_with_snippet(element, manifest_path, message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -731,7 +784,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "track_download_access needs to be provided as a field with the value 'code', 'disabled', 'free', 'paycurtain' or an external url like 'https://example.com', e.g.: 'track_download_access: code'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "track_downloads" => 'track_downloads: {
             if let Ok(field) = element.as_field() {
@@ -743,7 +796,7 @@ pub fn read_artist_catalog_release_track_option(
                             None => {
                                 let message = format!("The download format '{value}' is not supported (All available formats: 'aac', 'aiff', 'alac', 'flac', 'mp3', 'ogg_vorbis', 'opus', 'opus_48', 'opus_96', 'opus_128' and 'wav')");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -760,7 +813,7 @@ pub fn read_artist_catalog_release_track_option(
                                         None => {
                                             let message = format!("The download format '{value}' is not supported (All available formats: 'aac', 'aiff', 'alac', 'flac', 'mp3', 'ogg_vorbis', 'opus', 'opus_48', 'opus_96', 'opus_128' and 'wav')");
                                             let error = item_error_with_snippet(item, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                             None
                                         }
                                     }
@@ -776,7 +829,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "track_downloads needs to be provided either as a field with a value (e.g. 'track_downloads: mp3') or as a field with items, e.g.:\n\ntrack_downloads:\n- mp3\n- flac\n- opus\n\n(All available formats: 'aac', 'aiff', 'alac', 'flac', 'mp3', 'ogg_vorbis', 'opus', 'opus_48', 'opus_96', 'opus_128' and 'wav')";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "track_extras" => 'track_extras: {
             if let Ok(field) = element.as_field() {
@@ -788,7 +841,7 @@ pub fn read_artist_catalog_release_track_option(
                             _ => {
                                 let message = format!("The value '{value}' is not supported (allowed are: 'disabled' or 'enabled'");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -799,7 +852,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "track_extras needs to be provided as a field with the value 'disabled' or 'enabled' (e.g. 'track_extras: disabled')";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "track_price" => 'track_price: {
             if let Ok(field) = element.as_field() {
@@ -810,7 +863,7 @@ pub fn read_artist_catalog_release_track_option(
                             Err(err) => {
                                 let message = format!("Invalid price value ({err})");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -821,7 +874,7 @@ pub fn read_artist_catalog_release_track_option(
 
             let message = "track_price needs to be provided as a field with a currency and price (range) value, e.g.: 'track_price: USD 0+', 'track_price: 3.50 GBP', 'track_price: INR 230+' or 'track_price: JPY 400-800'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "unlock_info" => {
             if let Ok(embed) = element.as_embed() {
@@ -831,7 +884,7 @@ pub fn read_artist_catalog_release_track_option(
             } else {
                 let message = "unlock_info needs to be provided as an embed, e.g.:\n-- unlock_info\nThe text instructing on how to get a download code\n--unlock_info";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
         }
         _ => return false
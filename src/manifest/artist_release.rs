@@ -31,7 +31,7 @@ pub fn read_artist_release_option(
                             _ => {
                                 let message = format!("The value '{value}' is not recognized for the m3u option, allowed values are 'enabled' and 'disabled'");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -42,7 +42,7 @@ pub fn read_artist_release_option(
 
             let message = "m3u needs to be provided as a field with the value 'enabled' or 'disabled', e.g.: 'm3u: enabled'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         "permalink" => 'permalink: {
             if let Ok(field) = element.as_field() {
@@ -53,7 +53,7 @@ pub fn read_artist_release_option(
                             Err(err) => {
                                 let message = format!("There is a problem with the permalink '{value}': {err}");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -64,7 +64,7 @@ pub fn read_artist_release_option(
 
             let message = "permalink needs to be provided as a field with a value, e.g.: 'permalink: such-perma-wow'";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         _ => return false
     }
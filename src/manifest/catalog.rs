@@ -14,6 +14,8 @@ use crate::{
     DescribedImage,
     Favicon,
     Feeds,
+    ImageFormat,
+    ImageProcessor,
     LocalOptions,
     Locale,
     Overrides,
@@ -35,6 +37,7 @@ use super::{
     read_artist_catalog_release_option,
     read_artist_catalog_release_track_option,
     read_catalog_release_option,
+    read_image_crop_attributes,
     read_obsolete_option
 };
 
@@ -43,14 +46,18 @@ use super::{
 /// as well, are tracked here. This has to correspond 1:1 with the keys that
 /// are matched against in `read_catalog_manifest`.
 const CATALOG_OPTIONS: &[&str] = &[
+    "activitypub",
     "base_url",
     "cache_optimization",
+    "cdn_url",
     "faircamp_signature",
     "favicon",
     "feature_support_artists",
     "feeds",
     "freeze_download_urls",
     "home_image",
+    "image_formats",
+    "keyboard_shortcuts",
     "label_mode",
     "language",
     "m3u",
@@ -59,7 +66,19 @@ const CATALOG_OPTIONS: &[&str] = &[
     "show_support_artists",
     "site_assets",
     "site_metadata",
-    "title"
+    "soft_navigation",
+    "stats_endpoint",
+    "title",
+    "waveform_images",
+    "web_app"
+];
+
+/// All option groups supported in a catalog.eno manifest
+pub const CATALOG_MANIFEST_OPTIONS: &[&[&str]] = &[
+    CATALOG_OPTIONS,
+    ARTIST_CATALOG_RELEASE_OPTIONS,
+    ARTIST_CATALOG_RELEASE_TRACK_OPTIONS,
+    CATALOG_RELEASE_OPTIONS
 ];
 
 pub fn read_catalog_manifest(
@@ -93,6 +112,31 @@ pub fn read_catalog_manifest(
     for element in document.elements() {
         match element.key() {
             _ if read_obsolete_option(build, element, manifest_path) => (),
+            "activitypub" => 'activitypub: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            if value == "disabled" {
+                                catalog.activitypub = None;
+                            } else if value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                                catalog.activitypub = Some(value.to_string());
+                            } else {
+                                let message = format!("The activitypub username '{value}' can only contain the characters a-z, A-Z, 0-9 and _ (use 'disabled' to turn off the ActivityPub actor)");
+                                let error = element_error_with_snippet(element, manifest_path, &message);
+                                build.manifest_error(&error);
+                            }
+                        } else {
+                            catalog.activitypub = None;
+                        }
+
+                        break 'activitypub;
+                    }
+                }
+
+                let message = "activitypub needs to be provided as a field with a username as value (or 'disabled'), e.g.: 'activitypub: myband'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
             "base_url" => 'base_url: {
                 if let Ok(field) = element.as_field() {
                     if let Ok(result) = field.value() {
@@ -102,7 +146,7 @@ pub fn read_catalog_manifest(
                                 Err(err) => {
                                     let message = format!("The base_url setting value '{value}' is not a valid URL: {err}");
                                     let error = element_error_with_snippet(element, manifest_path, &message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         } else {
@@ -115,7 +159,7 @@ pub fn read_catalog_manifest(
 
                 let message = "base_url needs to be provided as a field with a value, e.g.: 'base_url: https://example.com'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "cdn_url" => 'cdn_url: {
                 if let Ok(field) = element.as_field() {
@@ -126,7 +170,7 @@ pub fn read_catalog_manifest(
                                 Err(err) => {
                                     let message = format!("The cdn_url setting value '{value}' is not a valid URL: {err}");
                                     let error = element_error_with_snippet(element, manifest_path, &message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         } else {
@@ -139,7 +183,7 @@ pub fn read_catalog_manifest(
 
                 let message = "cdn_url needs to be provided as a field with a value, e.g.: 'cdn_url: https://cdn.example.com'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "cache_optimization" => 'cache_optimization: {
                 if let Ok(field) = element.as_field() {
@@ -150,7 +194,7 @@ pub fn read_catalog_manifest(
                                 None => {
                                     let message = "This cache_optimization setting was not recognized (supported values are 'delayed', 'immediate', 'manual' and 'wipe')";
                                     let error = element_error_with_snippet(element, manifest_path, message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         }
@@ -161,20 +205,20 @@ pub fn read_catalog_manifest(
 
                 let message = "cache_optimization needs to be provided as a field with the value 'delayed', 'immediate', 'manual' or 'wipe', e.g.: 'cache_optimization: delayed'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             // Deprecated ~April 2025, eventually remove in the future
             "disable_feed" => {
                 let message = "The 'disable_feed' option must now be specified as 'feeds: disabled'. For the time being 'disable_feed' will still work, but it won't forever - make sure to update at some point.";
                 let warning = element_error_with_snippet(element, manifest_path, message);
-                build.warning(&warning);
+                build.manifest_warning(&warning);
 
                 if element.is_flag() {
                     catalog.feeds = Feeds::DISABLED;
                 } else {
                     let message = "disable_feed needs to be provided as a flag, that is, exactly as 'disable_feed' (without colon and without value)";
                     let error = element_error_with_snippet(element, manifest_path, message);
-                    build.error(&error);
+                    build.manifest_error(&error);
                 }
             }
             "faircamp_signature" => 'faircamp_signature: {
@@ -191,7 +235,7 @@ pub fn read_catalog_manifest(
                                 _ => {
                                     let message = "This faircamp_signature setting was not recognized (supported values are 'disabled' and 'enabled)";
                                     let error = element_error_with_snippet(element, manifest_path, message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         }
@@ -202,7 +246,7 @@ pub fn read_catalog_manifest(
 
                 let message = "faircamp_signature needs to be provided as a field with the value 'disabled' or 'enabled', e.g.: 'faircamp_signature: disabled'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "favicon" => 'favicon: {
                 if let Ok(field) = element.as_field() {
@@ -217,13 +261,13 @@ pub fn read_catalog_manifest(
                                         Ok(favicon) => catalog.favicon = favicon,
                                         Err(err) => {
                                             let error = element_error_with_snippet(element, manifest_path, &err);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 } else {
                                     let message = format!("The referenced file {} was not found", absolute_path.display());
                                     let error = element_error_with_snippet(element, manifest_path, &message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         }
@@ -234,7 +278,7 @@ pub fn read_catalog_manifest(
 
                 let message = "favicon needs to be provided as a field with a value (relative path to an .ico/.png file), e.g.: 'favicon: favicon.png'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "feature_support_artists" => {
                 if element.is_flag() {
@@ -242,7 +286,7 @@ pub fn read_catalog_manifest(
                 } else {
                     let message = "feature_support_artists needs to be provided as a flag, that is, exactly as 'feature_support_artists' (without colon and without value)";
                     let error = element_error_with_snippet(element, manifest_path, message);
-                    build.error(&error);
+                    build.manifest_error(&error);
                 }
             }
             "feeds" => 'feeds: {
@@ -271,7 +315,7 @@ pub fn read_catalog_manifest(
                                 _ => {
                                     let message = "This feeds option was not recognized (supported values are 'all', 'atom', 'generic_rss', 'media_rss', 'podcast_rss' and 'disabled')";
                                     let error = element_error_with_snippet(element, manifest_path, message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         }
@@ -308,7 +352,7 @@ pub fn read_catalog_manifest(
                                     _ => {
                                         let message = "This feeds option was not recognized (supported values are 'atom', 'generic_rss', 'media_rss', 'podcast_rss', as well as the additional 'all' and 'disabled' in the 'feeds: all' form of the option)";
                                         let error = item_error_with_snippet(item, manifest_path, message);
-                                        build.error(&error);
+                                        build.manifest_error(&error);
                                     }
                                 }
                             }
@@ -328,7 +372,7 @@ pub fn read_catalog_manifest(
                     (available options here being: 'atom', 'generic_rss', 'media_rss' and 'podcast_rss')
                 ");
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "freeze_download_urls" => 'freeze_download_urls: {
                 if let Ok(field) = element.as_field() {
@@ -343,16 +387,19 @@ pub fn read_catalog_manifest(
 
                 let message = "freeze_download_urls needs to be provided as a field with a value, e.g.: 'freeze_download_urls: April 2024'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "home_image" => 'home_image: {
                 if let Ok(field) = element.as_field() {
                     if let Ok(attributes) = field.attributes() {
                         let mut path_relative_to_catalog = None;
+                        let crop = read_image_crop_attributes(element, manifest_path, build);
                         let mut description = None;
 
                         for attribute in attributes {
                             match attribute.key() {
+                                // Read through read_image_crop_attributes
+                                "crop" | "focus" => (),
                                 "description" => {
                                     if let Some(value) = attribute.value() {
                                         description = Some(value.to_string());
@@ -367,22 +414,22 @@ pub fn read_catalog_manifest(
                                         } else {
                                             let message = format!("The referenced file was not found ({})", absolute_path.display());
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
 
                                     }
                                 }
                                 _ => {
-                                    let message = "The key/name of this attribute was not recognized, only 'description' and 'file' are recognized inside an home_image field";
+                                    let message = "The key/name of this attribute was not recognized, only 'crop', 'description', 'file' and 'focus' are recognized inside an home_image field";
                                     let error = element_error_with_snippet(element, manifest_path, message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         }
 
                         if let Some(path) = path_relative_to_catalog {
                             let image = cache.get_or_create_image(build, &path);
-                            catalog.home_image = Some(DescribedImage::new(description, image));
+                            catalog.home_image = Some(DescribedImage::new(crop, description, image));
                         }
 
                         break 'home_image;
@@ -397,7 +444,98 @@ pub fn read_catalog_manifest(
                     file = alice.jpg
                 ");
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
+            }
+            "image_formats" => 'image_formats: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            if value == "jpeg" {
+                                build.image_formats = Vec::new();
+                            } else if let Some(image_format) = ImageFormat::from_manifest_key(value) {
+                                if ImageProcessor::VARIANT_FORMATS.contains(&image_format) {
+                                    build.image_formats = vec![image_format];
+                                } else {
+                                    let message = unsupported_image_format_warning(image_format);
+                                    let warning = element_error_with_snippet(element, manifest_path, &message);
+                                    build.manifest_warning(&warning);
+                                    build.image_formats = Vec::new();
+                                }
+                            } else {
+                                let message = "This image_formats option was not recognized (supported values are 'avif', 'webp' and 'jpeg')";
+                                let error = element_error_with_snippet(element, manifest_path, message);
+                                build.manifest_error(&error);
+                            }
+                        }
+
+                        break 'image_formats;
+                    } else if let Ok(items) = field.items() {
+                        build.image_formats = Vec::new();
+
+                        for item in items {
+                            if let Some(value) = item.value() {
+                                if value == "jpeg" {
+                                    // jpeg is always generated as fallback
+                                } else if let Some(image_format) = ImageFormat::from_manifest_key(value) {
+                                    if !ImageProcessor::VARIANT_FORMATS.contains(&image_format) {
+                                        let message = unsupported_image_format_warning(image_format);
+                                        let warning = item_error_with_snippet(item, manifest_path, &message);
+                                        build.manifest_warning(&warning);
+                                    } else if !build.image_formats.contains(&image_format) {
+                                        build.image_formats.push(image_format);
+                                    }
+                                } else {
+                                    let message = "This image format was not recognized (supported values are 'avif', 'webp' and 'jpeg')";
+                                    let error = item_error_with_snippet(item, manifest_path, message);
+                                    build.manifest_error(&error);
+                                }
+                            }
+                        }
+
+                        // Browsers pick the first supported format, so we
+                        // always offer the most efficient one first.
+                        build.image_formats.sort();
+
+                        break 'image_formats;
+                    }
+                }
+
+                let message = indoc!("
+                    image_formats needs to be provided either as a field with a value (e.g. 'image_formats: webp', with available options being 'avif', 'webp' and 'jpeg') or as a field with items, e.g.:
+
+                    image_formats:
+                    - avif
+                    - webp
+                ");
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
+            "keyboard_shortcuts" => 'keyboard_shortcuts: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            match value {
+                                "disabled" => {
+                                    catalog.keyboard_shortcuts = false;
+                                }
+                                "enabled" => {
+                                    catalog.keyboard_shortcuts = true;
+                                }
+                                _ => {
+                                    let message = "This keyboard_shortcuts setting was not recognized (supported values are 'disabled' and 'enabled')";
+                                    let error = element_error_with_snippet(element, manifest_path, message);
+                                    build.manifest_error(&error);
+                                }
+                            }
+                        }
+
+                        break 'keyboard_shortcuts;
+                    }
+                }
+
+                let message = "The keyboard_shortcuts option needs to be provided as a field with the value 'disabled' or 'enabled', e.g.: 'keyboard_shortcuts: disabled'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
             }
             "label_mode" => {
                 if element.is_flag() {
@@ -405,7 +543,7 @@ pub fn read_catalog_manifest(
                 } else {
                     let message = "label_mode needs to be provided as a flag, that is, exactly as 'label_mode' (without colon and without value)";
                     let error = element_error_with_snippet(element, manifest_path, message);
-                    build.error(&error);
+                    build.manifest_error(&error);
                 }
             }
             "language" => 'language: {
@@ -421,7 +559,7 @@ pub fn read_catalog_manifest(
 
                 let message = "language needs to be provided as a field with a value, e.g.: 'language: fr'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "m3u" => 'm3u: {
                 if let Ok(field) = element.as_field() {
@@ -447,7 +585,7 @@ pub fn read_catalog_manifest(
                                 _ => {
                                     let message = "This m3u setting was not recognized (supported values are 'catalog', 'disabled', 'enabled' and 'releases')";
                                     let error = element_error_with_snippet(element, manifest_path, message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         }
@@ -458,7 +596,7 @@ pub fn read_catalog_manifest(
 
                 let message = "m3u needs to be provided as a field with the value 'catalog', 'disabled', 'enabled' or 'releases', e.g.: 'm3u: disable'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "opengraph" => 'opengraph: {
                 if let Ok(field) = element.as_field() {
@@ -474,7 +612,7 @@ pub fn read_catalog_manifest(
                                 _ => {
                                     let message = "This opengraph setting was not recognized (supported values are 'disabled' and 'enabled)";
                                     let error = element_error_with_snippet(element, manifest_path, message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         }
@@ -485,7 +623,7 @@ pub fn read_catalog_manifest(
 
                 let message = "The opengraph option needs to be provided as a field with the value 'disabled' or 'enabled', e.g.: 'opengraph: disabled'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "rotate_download_urls" => {
                 // TODO: Would make sense to report if both rotate_download_urls and
@@ -497,7 +635,7 @@ pub fn read_catalog_manifest(
                 } else {
                     let message = "rotate_download_urls needs to be provided as a flag, that is, exactly as 'rotate_download_urls' (without colon and without value)";
                     let error = element_error_with_snippet(element, manifest_path, message);
-                    build.error(&error);
+                    build.manifest_error(&error);
                 }
             }
             "show_support_artists" => {
@@ -506,7 +644,7 @@ pub fn read_catalog_manifest(
                 } else {
                     let message = "show_support_artists needs to be provided as a flag, that is, exactly as 'show_support_artists' (without colon and without value)";
                     let error = element_error_with_snippet(element, manifest_path, message);
-                    build.error(&error);
+                    build.manifest_error(&error);
                 }
             }
             "site_assets" => 'site_assets: {
@@ -520,7 +658,7 @@ pub fn read_catalog_manifest(
                             } else {
                                 let message = format!("The referenced file {} was not found", absolute_path.display());
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
 
@@ -537,7 +675,7 @@ pub fn read_catalog_manifest(
                                 } else {
                                     let message = format!("The referenced file {} was not found", absolute_path.display());
                                     let error = element_error_with_snippet(element, manifest_path, &message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         }
@@ -556,7 +694,7 @@ pub fn read_catalog_manifest(
                     - custom.js
                 "#);
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "site_metadata" => 'site_metadata: {
                 if let Ok(embed) = element.as_embed() {
@@ -568,7 +706,7 @@ pub fn read_catalog_manifest(
                             Err(err) => {
                                 let message = format!("The given site_metadata has (an) issue(s): {err}");
                                 let error = element_error_with_snippet(element, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -584,7 +722,58 @@ pub fn read_catalog_manifest(
                     -- site_metadata
                 "#);
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
+            }
+            "soft_navigation" => 'soft_navigation: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            match value {
+                                "disabled" => {
+                                    catalog.soft_navigation = false;
+                                }
+                                "enabled" => {
+                                    catalog.soft_navigation = true;
+                                }
+                                _ => {
+                                    let message = "This soft_navigation setting was not recognized (supported values are 'disabled' and 'enabled')";
+                                    let error = element_error_with_snippet(element, manifest_path, message);
+                                    build.manifest_error(&error);
+                                }
+                            }
+                        }
+
+                        break 'soft_navigation;
+                    }
+                }
+
+                let message = "The soft_navigation option needs to be provided as a field with the value 'disabled' or 'enabled', e.g.: 'soft_navigation: enabled'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
+            "stats_endpoint" => 'stats_endpoint: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            match SiteUrl::parse(value) {
+                                Ok(site_url) => catalog.stats_endpoint = Some(site_url),
+                                Err(err) => {
+                                    let message = format!("The stats_endpoint setting value '{value}' is not a valid URL: {err}");
+                                    let error = element_error_with_snippet(element, manifest_path, &message);
+                                    build.manifest_error(&error);
+                                }
+                            }
+                        } else {
+                            catalog.stats_endpoint = None;
+                        }
+
+                        break 'stats_endpoint;
+                    }
+                }
+
+                let message = "stats_endpoint needs to be provided as a field with a value, e.g.: 'stats_endpoint: https://stats.example.com'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
             }
             "title" => 'title: {
                 if let Ok(field) = element.as_field() {
@@ -599,25 +788,79 @@ pub fn read_catalog_manifest(
 
                 let message = "title needs to be provided as a field with a value, e.g.: 'title: My music'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
+            }
+            "waveform_images" => 'waveform_images: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            match value {
+                                "disabled" => {
+                                    catalog.waveform_images = false;
+                                }
+                                "enabled" => {
+                                    catalog.waveform_images = true;
+                                }
+                                _ => {
+                                    let message = "This waveform_images setting was not recognized (supported values are 'disabled' and 'enabled')";
+                                    let error = element_error_with_snippet(element, manifest_path, message);
+                                    build.manifest_error(&error);
+                                }
+                            }
+                        }
+
+                        break 'waveform_images;
+                    }
+                }
+
+                let message = "The waveform_images option needs to be provided as a field with the value 'disabled' or 'enabled', e.g.: 'waveform_images: enabled'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
+            "web_app" => 'web_app: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            match value {
+                                "disabled" => {
+                                    catalog.web_app = false;
+                                }
+                                "enabled" => {
+                                    catalog.web_app = true;
+                                }
+                                _ => {
+                                    let message = "This web_app setting was not recognized (supported values are 'disabled' and 'enabled')";
+                                    let error = element_error_with_snippet(element, manifest_path, message);
+                                    build.manifest_error(&error);
+                                }
+                            }
+                        }
+
+                        break 'web_app;
+                    }
+                }
+
+                let message = "The web_app option needs to be provided as a field with the value 'disabled' or 'enabled', e.g.: 'web_app: enabled'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
             }
             _ if read_artist_catalog_release_option(build, element, manifest_path, overrides) => (),
             _ if read_artist_catalog_release_track_option(build, cache, element, local_options, manifest_path, overrides) => (),
-            _ if read_catalog_release_option(build, catalog, element, manifest_path) => (),
+            _ if read_catalog_release_option(build, cache, catalog, element, local_options, manifest_path) => (),
             other => {
                 let message = not_supported_error(
                     "catalog.eno",
                     other,
-                    &[
-                        CATALOG_OPTIONS,
-                        ARTIST_CATALOG_RELEASE_OPTIONS,
-                        ARTIST_CATALOG_RELEASE_TRACK_OPTIONS,
-                        CATALOG_RELEASE_OPTIONS
-                    ]
+                    CATALOG_MANIFEST_OPTIONS
                 );
                 let error = element_error_with_snippet(element, manifest_path, &message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
         }
     }
 }
+
+fn unsupported_image_format_warning(image_format: ImageFormat) -> String {
+    let extension = image_format.extension();
+    format!("This faircamp build can not generate {extension} images that are smaller than the jpeg images they would replace (this requires faircamp to be built with libvips), no {extension} images are generated therefore.")
+}
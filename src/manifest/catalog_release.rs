@@ -1,4 +1,4 @@
-// SPDX-FileCopyrightText: 2024 Simon Repp
+// SPDX-FileCopyrightText: 2024-2025 Simon Repp
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
 use std::path::Path;
@@ -10,8 +10,14 @@ use crate::{
     Artist,
     ArtistRc,
     Build,
+    Cache,
     Catalog,
-    Permalink
+    DescribedImage,
+    LocalOptions,
+    Permalink,
+    Price,
+    Product,
+    Stock
 };
 
 use super::{
@@ -20,15 +26,18 @@ use super::{
 };
 
 pub const CATALOG_RELEASE_OPTIONS: &[&str] = &[
-    "artist"
+    "artist",
+    "product"
 ];
 
 /// Try to read a single option from the passed element. Processes
 /// options that are present in catalog and release manifests.
 pub fn read_catalog_release_option(
     build: &mut Build,
+    cache: &mut Cache,
     catalog: &mut Catalog,
     element: &Box<dyn SectionElement>,
+    local_options: &mut LocalOptions,
     manifest_path: &Path
 ) -> bool {
     match element.key() {
@@ -54,7 +63,7 @@ pub fn read_catalog_release_option(
                                         Err(err) => {
                                             let message = format!("The url supplied for the external_page option seems to be malformed ({err})");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -71,7 +80,7 @@ pub fn read_catalog_release_option(
                                         Err(err) => {
                                             let message = format!("There is a problem with the permalink '{value}': {err}");
                                             let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                            build.error(&error);
+                                            build.manifest_error(&error);
                                         }
                                     }
                                 }
@@ -79,7 +88,7 @@ pub fn read_catalog_release_option(
                             other => {
                                 let message = format!("The attribute '{other}' is not recognized here (supported attributes are 'alias', 'external_page', 'name' and 'permalink')");
                                 let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
@@ -97,7 +106,7 @@ pub fn read_catalog_release_option(
                     } else {
                         let message = "The artist option must supply a name attribute at least, e.g.:\n\nartist:\nname = Alice";
                         let error = element_error_with_snippet(element, manifest_path, message);
-                        build.error(&error);
+                        build.manifest_error(&error);
                     }
 
                     break 'artist;
@@ -106,7 +115,103 @@ pub fn read_catalog_release_option(
 
             let message = "artist must be provided as a field with attributes, e.g.:\n\nartist:\nname = Alice\nlink = https://example.com\nalias = Älice\nalias = Älicë";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
+        }
+        "product" => 'product: {
+            if let Ok(field) = element.as_field() {
+                if let Ok(attributes) = field.attributes() {
+                    let mut checkout = None;
+                    let mut format = None;
+                    let mut image_path = None;
+                    let mut price = None;
+                    let mut stock = Stock::Available;
+
+                    for attribute in attributes {
+                        match attribute.key() {
+                            "checkout" => {
+                                if let Some(value) = attribute.value() {
+                                    match Url::parse(value) {
+                                        Ok(_) => checkout = Some(value.to_string()),
+                                        Err(err) => {
+                                            let message = format!("The url supplied for checkout seems to be malformed ({err})");
+                                            let error = attribute_error_with_snippet(attribute, manifest_path, &message);
+                                            build.manifest_error(&error);
+                                        }
+                                    }
+                                }
+                            }
+                            "format" => {
+                                if let Some(value) = attribute.value() {
+                                    format = Some(value.to_string());
+                                }
+                            }
+                            "image" => {
+                                // image is a path relative to the manifest
+                                if let Some(value) = attribute.value() {
+                                    let absolute_path = manifest_path.parent().unwrap().join(value);
+                                    if absolute_path.exists() {
+                                        image_path = Some(absolute_path.strip_prefix(&build.catalog_dir).unwrap().to_path_buf());
+                                    } else {
+                                        let message = format!("The referenced file was not found ({})", absolute_path.display());
+                                        let error = attribute_error_with_snippet(attribute, manifest_path, &message);
+                                        build.manifest_error(&error);
+                                    }
+                                }
+                            }
+                            "price" => {
+                                if let Some(value) = attribute.value() {
+                                    match Price::new_from_price_string(value) {
+                                        Ok(parsed) => price = Some(parsed),
+                                        Err(err) => {
+                                            let message = format!("Invalid price value '{value}' ({err})");
+                                            let error = attribute_error_with_snippet(attribute, manifest_path, &message);
+                                            build.manifest_error(&error);
+                                        }
+                                    }
+                                }
+                            }
+                            "stock" => {
+                                if let Some(value) = attribute.value() {
+                                    match Stock::from_manifest_key(value) {
+                                        Some(parsed) => stock = parsed,
+                                        None => {
+                                            let message = format!("Unknown stock value '{value}' (supported are 'available', 'low', 'preorder' and 'sold_out')");
+                                            let error = attribute_error_with_snippet(attribute, manifest_path, &message);
+                                            build.manifest_error(&error);
+                                        }
+                                    }
+                                }
+                            }
+                            other => {
+                                let message = format!("The attribute '{other}' is not recognized here (supported attributes are 'checkout', 'format', 'image', 'price' and 'stock')");
+                                let error = attribute_error_with_snippet(attribute, manifest_path, &message);
+                                build.manifest_error(&error);
+                            }
+                        }
+                    }
+
+                    if let (Some(checkout), Some(format)) = (checkout, format) {
+                        // The product's format doubles as description of its image
+                        let image = image_path.map(|path| {
+                            let obtained_image = cache.get_or_create_image(build, &path);
+                            DescribedImage::new(None, Some(format.clone()), obtained_image)
+                        });
+
+                        let product = Product::new(checkout, format, image, price, stock);
+                        local_options.products.push(product);
+                    } else {
+                        let message = "The product option must supply a checkout and a format attribute at least, e.g.:\n\nproduct:\nformat = 12\" Vinyl\ncheckout = https://example.com/shop/vinyl";
+                        let error = element_error_with_snippet(element, manifest_path, message);
+                        build.manifest_error(&error);
+                    }
+
+                    break 'product;
+                }
+            }
+
+            let message = "product must be provided as a field with attributes, e.g.:\n\nproduct:\nformat = 12\" Vinyl\nprice = EUR 25\nstock = available\nimage = vinyl.jpg\ncheckout = https://example.com/shop/vinyl";
+            let error = element_error_with_snippet(element, manifest_path, message);
+            build.manifest_error(&error);
         }
         _ => return false
     }
@@ -0,0 +1,162 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::fs;
+use std::path::Path;
+
+use crate::{
+    Build,
+    Cache,
+    Catalog,
+    LocalOptions,
+    Overrides,
+    Page
+};
+use crate::markdown;
+
+use super::{
+    element_error_with_snippet,
+    not_supported_error,
+    platform_printer,
+    read_artist_catalog_release_track_option,
+    read_artist_release_option,
+    read_obsolete_option
+};
+
+const PAGE_OPTIONS: &[&str] = &[
+    "menu",
+    "permalink",
+    "text",
+    "theme",
+    "title"
+];
+
+/// All option groups supported in a page.eno manifest
+pub const PAGE_MANIFEST_OPTIONS: &[&[&str]] = &[
+    PAGE_OPTIONS
+];
+
+pub fn read_page_manifest(
+    build: &mut Build,
+    cache: &mut Cache,
+    catalog: &mut Catalog,
+    dir: &Path,
+    manifest_path: &Path,
+    overrides: &mut Overrides
+) {
+    let content = match fs::read_to_string(manifest_path) {
+        Ok(content) => content,
+        Err(err) => {
+            let error = format!("Could not read manifest {} ({})", manifest_path.display(), err);
+            build.error(&error);
+            return
+        }
+    };
+
+    let document = match enolib::parse_with_printer(&content, platform_printer()) {
+        Ok(document) => document,
+        Err(err) => {
+            let error = format!("Syntax error in {}:{} ({})", manifest_path.display(), err.line, err);
+            build.error(&error);
+            return
+        }
+    };
+
+    let mut local_options = LocalOptions::new();
+
+    let mut menu = true;
+    let mut text = None;
+    // By default we use the folder name as title
+    let mut title = dir.file_name().unwrap().to_string_lossy().to_string();
+
+    for element in document.elements() {
+        match element.key() {
+            _ if read_obsolete_option(build, element, manifest_path) => (),
+            "menu" => 'menu: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            match value {
+                                "disabled" => menu = false,
+                                "enabled" => menu = true,
+                                _ => {
+                                    let message = format!("The value '{value}' is not recognized for the menu option, allowed values are 'enabled' and 'disabled'");
+                                    let error = element_error_with_snippet(element, manifest_path, &message);
+                                    build.manifest_error(&error);
+                                }
+                            }
+                        }
+
+                        break 'menu;
+                    }
+                }
+
+                let message = "menu needs to be provided as a field with the value 'disabled' or 'enabled', e.g.: 'menu: disabled'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
+            // Permalink and theme are read through the shared option readers,
+            // but only these two keys of their option groups apply to pages.
+            "permalink" => {
+                read_artist_release_option(build, element, &mut local_options, manifest_path, overrides);
+            }
+            "text" => {
+                if let Ok(embed) = element.as_embed() {
+                    text = embed.value().map(|value| markdown::to_html(&build.base_url, value));
+                } else {
+                    let message = "The 'text' option needs to be provided as an embed, e.g.:\n-- text\nThe content of the page\n-- text";
+                    let error = element_error_with_snippet(element, manifest_path, message);
+                    build.manifest_error(&error);
+                }
+            }
+            "theme" => {
+                read_artist_catalog_release_track_option(build, cache, element, &mut local_options, manifest_path, overrides);
+            }
+            "title" => 'title: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            title = value.to_string();
+                        }
+
+                        break 'title;
+                    }
+                }
+
+                let message = "title needs to be provided as a field with a value, e.g.: 'title: About'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
+            other => {
+                let message = not_supported_error(
+                    "page.eno",
+                    other,
+                    PAGE_MANIFEST_OPTIONS
+                );
+
+                let error = element_error_with_snippet(element, manifest_path, &message);
+                build.manifest_error(&error);
+            }
+        }
+    }
+
+    let text = match text {
+        Some(text) => text,
+        None => {
+            let warning = format!("The page manifest '{}' provides no text (use the 'text' option to do so), therefore no page will be created from it", manifest_path.display());
+            build.warning(&warning);
+            return;
+        }
+    };
+
+    let page = Page::new(
+        menu,
+        local_options.permalink.take(),
+        dir.to_path_buf(),
+        text,
+        overrides.theme.clone(),
+        title
+    );
+
+    catalog.pages.push(page);
+}
@@ -0,0 +1,165 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::fs;
+use std::mem;
+use std::path::Path;
+
+use crate::{
+    Build,
+    Cache,
+    Catalog,
+    LocalOptions,
+    Overrides,
+    Playlist,
+    TrackReference
+};
+
+use super::{
+    ARTIST_CATALOG_RELEASE_TRACK_OPTIONS,
+    ARTIST_RELEASE_OPTIONS,
+    element_error_with_snippet,
+    item_error_with_snippet,
+    not_supported_error,
+    platform_printer,
+    read_artist_catalog_release_track_option,
+    read_artist_release_option,
+    read_obsolete_option
+};
+
+const PLAYLIST_OPTIONS: &[&str] = &[
+    "title",
+    "tracks",
+    "unlisted"
+];
+
+/// All option groups supported in a playlist.eno manifest
+pub const PLAYLIST_MANIFEST_OPTIONS: &[&[&str]] = &[
+    PLAYLIST_OPTIONS,
+    ARTIST_CATALOG_RELEASE_TRACK_OPTIONS,
+    ARTIST_RELEASE_OPTIONS
+];
+
+pub fn read_playlist_manifest(
+    build: &mut Build,
+    cache: &mut Cache,
+    catalog: &mut Catalog,
+    dir: &Path,
+    manifest_path: &Path,
+    overrides: &mut Overrides
+) {
+    let content = match fs::read_to_string(manifest_path) {
+        Ok(content) => content,
+        Err(err) => {
+            let error = format!("Could not read manifest {} ({})", manifest_path.display(), err);
+            build.error(&error);
+            return
+        }
+    };
+
+    let document = match enolib::parse_with_printer(&content, platform_printer()) {
+        Ok(document) => document,
+        Err(err) => {
+            let error = format!("Syntax error in {}:{} ({})", manifest_path.display(), err.line, err);
+            build.error(&error);
+            return
+        }
+    };
+
+    let mut local_options = LocalOptions::new();
+
+    // By default we use the folder name as title
+    let mut title = dir.file_name().unwrap().to_string_lossy().to_string();
+    let mut track_references = Vec::new();
+    let mut unlisted = false;
+
+    for element in document.elements() {
+        match element.key() {
+            _ if read_obsolete_option(build, element, manifest_path) => (),
+            "title" => 'title: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            title = value.to_string();
+                        }
+
+                        break 'title;
+                    }
+                }
+
+                let message = "title needs to be provided as a field with a value, e.g.: 'title: Summer Mixtape'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
+            "tracks" => 'tracks: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(items) = field.items() {
+                        for item in items.iter() {
+                            if let Some(value) = item.value() {
+                                match TrackReference::parse(value) {
+                                    Ok(track_reference) => track_references.push(track_reference),
+                                    Err(err) => {
+                                        let error = item_error_with_snippet(item, manifest_path, &err);
+                                        build.manifest_error(&error);
+                                    }
+                                }
+                            }
+                        }
+
+                        break 'tracks;
+                    }
+                }
+
+                let message = "tracks needs to be provided as a field with items, each referencing a track by release permalink and track number, e.g.:\n\ntracks:\n- example-release/3\n- another-release/1";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
+            "unlisted" => {
+                if element.is_flag() {
+                    unlisted = true;
+                } else {
+                    let message = "unlisted needs to be provided as a flag, that is, exactly as 'unlisted' (without colon and without value)";
+                    let error = element_error_with_snippet(element, manifest_path, message);
+                    build.manifest_error(&error);
+                }
+            }
+            _ if read_artist_catalog_release_track_option(build, cache, element, &mut local_options, manifest_path, overrides) => (),
+            _ if read_artist_release_option(build, element, &mut local_options, manifest_path, overrides) => (),
+            other => {
+                let message = not_supported_error(
+                    "playlist.eno",
+                    other,
+                    PLAYLIST_MANIFEST_OPTIONS
+                );
+
+                let error = element_error_with_snippet(element, manifest_path, &message);
+                build.manifest_error(&error);
+            }
+        }
+    }
+
+    if track_references.is_empty() {
+        let warning = format!("The playlist manifest '{}' references no tracks (use the 'tracks' option to do so), therefore no playlist will be created from it", manifest_path.display());
+        build.warning(&warning);
+        return;
+    }
+
+    let playlist = Playlist::new(
+        overrides.copy_link,
+        overrides.embedding,
+        mem::take(&mut local_options.links),
+        overrides.m3u_enabled,
+        local_options.more.take(),
+        overrides.more_label.clone(),
+        local_options.permalink.take(),
+        dir.to_path_buf(),
+        overrides.speed_controls,
+        local_options.synopsis.take(),
+        overrides.theme.clone(),
+        title,
+        track_references,
+        unlisted
+    );
+
+    catalog.playlists.push(playlist);
+}
@@ -0,0 +1,204 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::fs;
+use std::path::Path;
+
+use chrono::NaiveDate;
+
+use crate::{
+    Build,
+    Cache,
+    Catalog,
+    LocalOptions,
+    Overrides,
+    Post
+};
+use crate::markdown;
+
+use super::{
+    element_error_with_snippet,
+    not_supported_error,
+    platform_printer,
+    read_artist_catalog_release_track_option,
+    read_artist_release_option,
+    read_obsolete_option
+};
+
+const POST_OPTIONS: &[&str] = &[
+    "date",
+    "permalink",
+    "releases",
+    "text",
+    "theme",
+    "title",
+    "unlisted"
+];
+
+/// All option groups supported in a post.eno manifest
+pub const POST_MANIFEST_OPTIONS: &[&[&str]] = &[
+    POST_OPTIONS
+];
+
+pub fn read_post_manifest(
+    build: &mut Build,
+    cache: &mut Cache,
+    catalog: &mut Catalog,
+    dir: &Path,
+    manifest_path: &Path,
+    overrides: &mut Overrides
+) {
+    let content = match fs::read_to_string(manifest_path) {
+        Ok(content) => content,
+        Err(err) => {
+            let error = format!("Could not read manifest {} ({})", manifest_path.display(), err);
+            build.error(&error);
+            return
+        }
+    };
+
+    let document = match enolib::parse_with_printer(&content, platform_printer()) {
+        Ok(document) => document,
+        Err(err) => {
+            let error = format!("Syntax error in {}:{} ({})", manifest_path.display(), err.line, err);
+            build.error(&error);
+            return
+        }
+    };
+
+    let mut local_options = LocalOptions::new();
+
+    let mut date = None;
+    let mut release_references = Vec::new();
+    let mut text = None;
+    // By default we use the folder name as title
+    let mut title = dir.file_name().unwrap().to_string_lossy().to_string();
+    let mut unlisted = false;
+
+    for element in document.elements() {
+        match element.key() {
+            _ if read_obsolete_option(build, element, manifest_path) => (),
+            "date" => 'date: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+                                Ok(parsed_date) => date = Some(parsed_date),
+                                Err(err) => {
+                                    let message = format!("Invalid date value '{value}': {err}");
+                                    let error = element_error_with_snippet(element, manifest_path, &message);
+                                    build.manifest_error(&error);
+                                }
+                            }
+                        }
+
+                        break 'date;
+                    }
+                }
+
+                let message = "date needs to be provided as a field with a value following the pattern YYYY-MM-DD, e.g.: 'date: 2025-06-14'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
+            // Permalink and theme are read through the shared option readers,
+            // only these two keys of their option groups apply to posts.
+            "permalink" => {
+                read_artist_release_option(build, element, &mut local_options, manifest_path, overrides);
+            }
+            "releases" => 'releases: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(items) = field.items() {
+                        for item in items.iter() {
+                            if let Some(value) = item.value() {
+                                release_references.push(value.trim().to_string());
+                            }
+                        }
+
+                        break 'releases;
+                    }
+                }
+
+                let message = "releases needs to be provided as a field with items, each referencing a release by its permalink, e.g.:\n\nreleases:\n- example-release\n- another-release";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
+            "text" => {
+                if let Ok(embed) = element.as_embed() {
+                    text = embed.value().map(|value| markdown::to_html_and_stripped(&build.base_url, value));
+                } else {
+                    let message = "The 'text' option needs to be provided as an embed, e.g.:\n-- text\nThe content of the post\n-- text";
+                    let error = element_error_with_snippet(element, manifest_path, message);
+                    build.manifest_error(&error);
+                }
+            }
+            "theme" => {
+                read_artist_catalog_release_track_option(build, cache, element, &mut local_options, manifest_path, overrides);
+            }
+            "title" => 'title: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            title = value.to_string();
+                        }
+
+                        break 'title;
+                    }
+                }
+
+                let message = "title needs to be provided as a field with a value, e.g.: 'title: Summer Tour 2025'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
+            "unlisted" => {
+                if element.is_flag() {
+                    unlisted = true;
+                } else {
+                    let message = "unlisted needs to be provided as a flag, that is, exactly as 'unlisted' (without colon and without value)";
+                    let error = element_error_with_snippet(element, manifest_path, message);
+                    build.manifest_error(&error);
+                }
+            }
+            other => {
+                let message = not_supported_error(
+                    "post.eno",
+                    other,
+                    POST_MANIFEST_OPTIONS
+                );
+
+                let error = element_error_with_snippet(element, manifest_path, &message);
+                build.manifest_error(&error);
+            }
+        }
+    }
+
+    let date = match date {
+        Some(date) => date,
+        None => {
+            let error = format!("The post manifest '{}' provides no date (use the 'date' option to do so, e.g. 'date: 2025-06-14'), therefore no post will be created from it", manifest_path.display());
+            build.error(&error);
+            return;
+        }
+    };
+
+    let text = match text {
+        Some(text) => text,
+        None => {
+            let warning = format!("The post manifest '{}' provides no text (use the 'text' option to do so), therefore no post will be created from it", manifest_path.display());
+            build.warning(&warning);
+            return;
+        }
+    };
+
+    let post = Post::new(
+        date,
+        local_options.permalink.take(),
+        release_references,
+        dir.to_path_buf(),
+        text,
+        overrides.theme.clone(),
+        title,
+        unlisted
+    );
+
+    catalog.posts.push(post);
+}
@@ -4,12 +4,13 @@
 use std::fs;
 use std::path::Path;
 
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 
 use crate::{
     Build,
     Cache,
     Catalog,
+    DescribedImage,
     LocalOptions,
     Overrides
 };
@@ -20,6 +21,7 @@ use super::{
     ARTIST_RELEASE_OPTIONS,
     CATALOG_RELEASE_OPTIONS,
     RELEASE_TRACK_OPTIONS,
+    attribute_error_with_snippet,
     element_error_with_snippet,
     not_supported_error,
     platform_printer,
@@ -27,18 +29,31 @@ use super::{
     read_artist_catalog_release_track_option,
     read_artist_release_option,
     read_catalog_release_option,
+    read_image_crop_attributes,
     read_obsolete_option,
     read_release_track_option
 };
 
 const RELEASE_OPTIONS: &[&str] = &[
     "date",
+    "gallery_image",
+    "publish_at",
     "release_artist",
     "release_artists",
     "title",
     "unlisted"
 ];
 
+/// All option groups supported in a release.eno manifest
+pub const RELEASE_MANIFEST_OPTIONS: &[&[&str]] = &[
+    RELEASE_OPTIONS,
+    ARTIST_CATALOG_RELEASE_OPTIONS,
+    ARTIST_CATALOG_RELEASE_TRACK_OPTIONS,
+    ARTIST_RELEASE_OPTIONS,
+    CATALOG_RELEASE_OPTIONS,
+    RELEASE_TRACK_OPTIONS
+];
+
 pub fn read_release_manifest(
     build: &mut Build,
     cache: &mut Cache,
@@ -78,7 +93,7 @@ pub fn read_release_manifest(
                                 Err(err) => {
                                     let message = format!("Invalid date value '{value}': {err}");
                                     let error = element_error_with_snippet(element, manifest_path, &message);
-                                    build.error(&error);
+                                    build.manifest_error(&error);
                                 }
                             }
                         } else {
@@ -91,7 +106,84 @@ pub fn read_release_manifest(
 
                 let message = "date needs to be provided as a field with a value following the pattern YYYY-MM-DD, e.g.: 'date: 1999-31-12'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
+            }
+            "gallery_image" => 'gallery_image: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(attributes) = field.attributes() {
+                        let mut path_relative_to_catalog = None;
+                        let crop = read_image_crop_attributes(element, manifest_path, build);
+                        let mut description = None;
+
+                        for attribute in attributes {
+                            match attribute.key() {
+                                // Read through read_image_crop_attributes
+                                "crop" | "focus" => (),
+                                "description" => {
+                                    if let Some(value) = attribute.value() {
+                                        description = Some(value.to_string());
+                                    }
+                                }
+                                "file" => {
+                                    // file is a path relative to the manifest
+                                    if let Some(value) = attribute.value() {
+                                        let absolute_path = dir.join(value);
+                                        if absolute_path.exists() {
+                                            path_relative_to_catalog = Some(absolute_path.strip_prefix(&build.catalog_dir).unwrap().to_path_buf());
+                                        } else {
+                                            let message = format!("The referenced file was not found ({})", absolute_path.display());
+                                            let error = attribute_error_with_snippet(attribute, manifest_path, &message);
+                                            build.manifest_error(&error);
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    let message = "The key/name of this attribute was not recognized, only 'crop', 'description', 'file' and 'focus' are recognized inside a gallery_image field";
+                                    let error = element_error_with_snippet(element, manifest_path, message);
+                                    build.manifest_error(&error);
+                                }
+                            }
+                        }
+
+                        if let Some(path) = path_relative_to_catalog {
+                            let image = cache.get_or_create_image(build, &path);
+                            local_options.gallery.push(DescribedImage::new(crop, description, image));
+                        }
+
+                        break 'gallery_image;
+                    }
+                }
+
+                let message = "gallery_image needs to be provided as a field with attributes, e.g.:\n\ngallery_image:\ndescription = The inside of the gatefold sleeve, showing the band on stage\nfile = gatefold.jpg";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
+            }
+            "publish_at" => 'publish_at: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(result) = field.value() {
+                        if let Some(value) = result {
+                            let parsed = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M")
+                                .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d").map(|date| date.and_time(NaiveTime::MIN)));
+
+                            match parsed {
+                                Ok(date_time) => local_options.publish_at = Some(date_time),
+                                Err(err) => {
+                                    let message = format!("Invalid publish_at value '{value}' (supported patterns are YYYY-MM-DD and YYYY-MM-DD HH:MM): {err}");
+                                    let error = element_error_with_snippet(element, manifest_path, &message);
+                                    build.manifest_error(&error);
+                                }
+                            }
+                        } else {
+                            local_options.publish_at = None;
+                        }
+
+                        break 'publish_at;
+                    }
+                }
+
+                let message = "publish_at needs to be provided as a field with a value following the pattern YYYY-MM-DD or YYYY-MM-DD HH:MM (time in UTC), e.g.: 'publish_at: 2025-06-01' or 'publish_at: 2025-06-01 18:00'";
+                let error = element_error_with_snippet(element, manifest_path, message);
+                build.manifest_error(&error);
             }
             "release_artist" => 'release_artist: {
                 if let Ok(field) = element.as_field() {
@@ -106,7 +198,7 @@ pub fn read_release_manifest(
 
                 let message = "release_artist needs to be provided as a field with a value, e.g.: 'release_artist: Alice'\n\nFor multiple artists specify the release_artists field:\n\nrelease_artists:\n- Alice\n- Bob";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "release_artists" => 'release_artists: {
                 if let Ok(field) = element.as_field() {
@@ -122,7 +214,7 @@ pub fn read_release_manifest(
 
                 let message = "release_artists needs to be provided as a field with items, e.g.:\n\nrelease_artists:\n- Alice\n- Bob";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "title" => 'title: {
                 if let Ok(field) = element.as_field() {
@@ -137,7 +229,7 @@ pub fn read_release_manifest(
 
                 let message = "title needs to be provided as a field with a value, e.g.: 'title: Demotape'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             "unlisted" => {
                 if element.is_flag() {
@@ -145,30 +237,23 @@ pub fn read_release_manifest(
                 } else {
                     let message = "unlisted needs to be provided as a flag, that is, exactly as 'unlisted' (without colon and without value)";
                     let error = element_error_with_snippet(element, manifest_path, message);
-                    build.error(&error);
+                    build.manifest_error(&error);
                 }
             }
             _ if read_artist_catalog_release_option(build, element, manifest_path, overrides) => (),
             _ if read_artist_catalog_release_track_option(build, cache, element, local_options, manifest_path, overrides) => (),
             _ if read_artist_release_option(build, element, local_options, manifest_path, overrides) => (),
-            _ if read_catalog_release_option(build, catalog, element, manifest_path) => (),
+            _ if read_catalog_release_option(build, cache, catalog, element, local_options, manifest_path) => (),
             _ if read_release_track_option(build, cache, dir, element, local_options, manifest_path) => (),
             other => {
                 let message = not_supported_error(
                     "release.eno",
                     other,
-                    &[
-                        RELEASE_OPTIONS,
-                        ARTIST_CATALOG_RELEASE_OPTIONS,
-                        ARTIST_CATALOG_RELEASE_TRACK_OPTIONS,
-                        ARTIST_RELEASE_OPTIONS,
-                        CATALOG_RELEASE_OPTIONS,
-                        RELEASE_TRACK_OPTIONS
-                    ]
+                    RELEASE_MANIFEST_OPTIONS
                 );
 
                 let error = element_error_with_snippet(element, manifest_path, &message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
         }
     }
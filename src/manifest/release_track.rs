@@ -14,7 +14,8 @@ use crate::{
 
 use super::{
     attribute_error_with_snippet,
-    element_error_with_snippet
+    element_error_with_snippet,
+    read_image_crop_attributes
 };
 
 pub const RELEASE_TRACK_OPTIONS: &[&str] = &[
@@ -36,10 +37,13 @@ pub fn read_release_track_option(
             if let Ok(field) = element.as_field() {
                 if let Ok(attributes) = field.attributes() {
                     let mut path_relative_to_catalog = None;
+                    let crop = read_image_crop_attributes(element, manifest_path, build);
                     let mut description = None;
 
                     for attribute in attributes {
                         match attribute.key() {
+                            // Read through read_image_crop_attributes
+                            "crop" | "focus" => (),
                             "description" => {
                                 if let Some(value) = attribute.value() {
                                     description = Some(value.to_string());
@@ -54,22 +58,22 @@ pub fn read_release_track_option(
                                     } else {
                                         let message = format!("The referenced file was not found ({})", absolute_path.display());
                                         let error = attribute_error_with_snippet(attribute, manifest_path, &message);
-                                        build.error(&error);
+                                        build.manifest_error(&error);
                                     }
 
                                 }
                             }
                             _ => {
-                                let message = "The key/name of this attribute was not recognized, only 'description' and 'file' are recognized inside a cover field";
+                                let message = "The key/name of this attribute was not recognized, only 'crop', 'description', 'file' and 'focus' are recognized inside a cover field";
                                 let error = element_error_with_snippet(element, manifest_path, message);
-                                build.error(&error);
+                                build.manifest_error(&error);
                             }
                         }
                     }
 
                     if let Some(path) = path_relative_to_catalog {
                         let image = cache.get_or_create_image(build, &path);
-                        local_options.cover = Some(DescribedImage::new(description, image));
+                        local_options.cover = Some(DescribedImage::new(crop, description, image));
                     }
 
                     break 'cover;
@@ -78,7 +82,7 @@ pub fn read_release_track_option(
 
             let message = "cover needs to be provided as a field with attributes, e.g.:\n\ncover:\ndescription = Alice, looking amused\nfile = alice.jpg";
             let error = element_error_with_snippet(element, manifest_path, message);
-            build.error(&error);
+            build.manifest_error(&error);
         }
         _ => return false
     }
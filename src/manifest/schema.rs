@@ -0,0 +1,403 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Machine-readable description of all manifest options, intended for
+//! editor integrations (completion, hover documentation, validation of
+//! values). Manifests are eno documents, not JSON, so this is not a JSON
+//! Schema but a simple format of our own (documented in the manual).
+//!
+//! The keys per manifest type are taken from the very same option groups
+//! that the manifest readers use to report unsupported options, so no
+//! supported key can be missing here. Where a value is parsed into a type
+//! that lists its manifest keys (ALL_MANIFEST_KEYS and similar), the allowed
+//! values are taken from there as well. The forms and value types of each
+//! option however are described by hand (see option_schema) and need to be
+//! updated together with the readers.
+
+use crate::{
+    ArchiveFormat,
+    CacheOptimization,
+    CoverGenerator,
+    DownloadAccessOption,
+    DownloadFormat,
+    ExtraDownloads,
+    Feeds,
+    GENERATOR_INFO,
+    License,
+    Locale,
+    Stock,
+    StreamingQuality,
+    TagAction,
+    TagAgenda,
+    Theme,
+    ThemeBase,
+    TrackNumbering,
+    WaveformColor,
+    WaveformStyle
+};
+use crate::util::json_escape_inside_string;
+
+use super::{
+    ARTIST_MANIFEST_OPTIONS,
+    CATALOG_MANIFEST_OPTIONS,
+    PAGE_MANIFEST_OPTIONS,
+    PLAYLIST_MANIFEST_OPTIONS,
+    POST_MANIFEST_OPTIONS,
+    RELEASE_MANIFEST_OPTIONS,
+    TRACK_MANIFEST_OPTIONS
+};
+
+// Values of options that are not parsed into a type of their own
+const DISABLED_ENABLED: &[&str] = &["disabled", "enabled"];
+const IMAGE_FORMATS: &[&str] = &["avif", "jpeg", "webp"];
+const LINK_VERIFICATION: &[&str] = &["rel-me", "rel-me-hidden"];
+const SYSTEM_FONTS: &[&str] = &["mono", "sans"];
+
+/// The syntactical forms in which an option can be written in a manifest,
+/// as per eno's element types.
+enum Form {
+    /// e.g. "theme:" followed by "base = dark" lines
+    Attributes,
+    /// e.g. "-- more" ... "-- more"
+    Embed,
+    /// e.g. "unlisted" on its own
+    Flag,
+    /// e.g. "release_downloads:" followed by "- mp3" lines
+    Items,
+    /// e.g. "title: Foo"
+    Value
+}
+
+struct OptionSchema {
+    /// Only relevant when the option supports Form::Attributes
+    attributes: Vec<(&'static str, ValueSchema)>,
+    forms: Vec<Form>,
+    /// Applies both to the plain value and to each item (Form::Items)
+    value: Option<ValueSchema>
+}
+
+struct ValueSchema {
+    /// A loose type name such as "text", "url" or "enum"
+    kind: &'static str,
+    /// For "enum" these are the only allowed values, for other kinds these
+    /// are suggestions (e.g. the built-in language codes).
+    values: &'static [&'static str]
+}
+
+impl Form {
+    fn to_key(&self) -> &'static str {
+        match self {
+            Form::Attributes => "attributes",
+            Form::Embed => "embed",
+            Form::Flag => "flag",
+            Form::Items => "items",
+            Form::Value => "value"
+        }
+    }
+}
+
+impl OptionSchema {
+    fn attributes(attributes: Vec<(&'static str, ValueSchema)>) -> OptionSchema {
+        OptionSchema {
+            attributes,
+            forms: vec![Form::Attributes],
+            value: None
+        }
+    }
+
+    fn embed(value: ValueSchema) -> OptionSchema {
+        OptionSchema {
+            attributes: Vec::new(),
+            forms: vec![Form::Embed],
+            value: Some(value)
+        }
+    }
+
+    fn flag() -> OptionSchema {
+        OptionSchema {
+            attributes: Vec::new(),
+            forms: vec![Form::Flag],
+            value: None
+        }
+    }
+
+    fn items(value: ValueSchema) -> OptionSchema {
+        OptionSchema {
+            attributes: Vec::new(),
+            forms: vec![Form::Items],
+            value: Some(value)
+        }
+    }
+
+    fn value(value: ValueSchema) -> OptionSchema {
+        OptionSchema {
+            attributes: Vec::new(),
+            forms: vec![Form::Value],
+            value: Some(value)
+        }
+    }
+
+    fn value_or_attributes(value: ValueSchema, attributes: Vec<(&'static str, ValueSchema)>) -> OptionSchema {
+        OptionSchema {
+            attributes,
+            forms: vec![Form::Value, Form::Attributes],
+            value: Some(value)
+        }
+    }
+
+    fn value_or_items(value: ValueSchema) -> OptionSchema {
+        OptionSchema {
+            attributes: Vec::new(),
+            forms: vec![Form::Value, Form::Items],
+            value: Some(value)
+        }
+    }
+
+    fn to_json(&self) -> String {
+        let r_forms = self.forms
+            .iter()
+            .map(|form| format!(r#""{}""#, form.to_key()))
+            .collect::<Vec<String>>()
+            .join(",");
+
+        let mut json = format!(r#"{{"forms":[{r_forms}]"#);
+
+        if let Some(value) = &self.value {
+            json.push_str(&format!(r#","value":{}"#, value.to_json()));
+        }
+
+        if !self.attributes.is_empty() {
+            let r_attributes = self.attributes
+                .iter()
+                .map(|(key, value)| format!(r#""{key}":{}"#, value.to_json()))
+                .collect::<Vec<String>>()
+                .join(",");
+
+            json.push_str(&format!(r#","attributes":{{{r_attributes}}}"#));
+        }
+
+        json.push('}');
+
+        json
+    }
+}
+
+impl ValueSchema {
+    fn of(kind: &'static str) -> ValueSchema {
+        ValueSchema { kind, values: &[] }
+    }
+
+    fn one_of(values: &'static [&'static str]) -> ValueSchema {
+        ValueSchema { kind: "enum", values }
+    }
+
+    fn suggesting(kind: &'static str, values: &'static [&'static str]) -> ValueSchema {
+        ValueSchema { kind, values }
+    }
+
+    fn to_json(&self) -> String {
+        if self.values.is_empty() {
+            format!(r#"{{"type":"{}"}}"#, self.kind)
+        } else {
+            let r_values = self.values
+                .iter()
+                .map(|value| format!(r#""{}""#, json_escape_inside_string(value)))
+                .collect::<Vec<String>>()
+                .join(",");
+
+            format!(r#"{{"type":"{}","values":[{r_values}]}}"#, self.kind)
+        }
+    }
+}
+
+fn image_attributes() -> Vec<(&'static str, ValueSchema)> {
+    vec![
+        ("crop", ValueSchema::of("percentages")),
+        ("description", ValueSchema::of("text")),
+        ("file", ValueSchema::of("path")),
+        ("focus", ValueSchema::of("percentages"))
+    ]
+}
+
+/// Describes a single option as it is read inside the given manifest. This
+/// needs to be kept in sync with the readers in the sibling modules by hand -
+/// keys that are unknown here are still listed in the schema, only without
+/// further details about their form and values.
+fn option_schema(manifest_name: &str, key: &str) -> Option<OptionSchema> {
+    let degrees = || ValueSchema::of("degrees");
+    let percentage = || ValueSchema::of("percentage");
+    let text = || ValueSchema::of("text");
+    let url = || ValueSchema::of("url");
+
+    let schema = match (manifest_name, key) {
+        // catalog.eno has its own, extended m3u option
+        ("catalog.eno", "m3u") => OptionSchema::value(ValueSchema::one_of(&["catalog", "disabled", "enabled", "releases"])),
+        (_, "m3u") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+
+        (_, "activitypub") => OptionSchema::value(ValueSchema::of("username")),
+        (_, "alias") => OptionSchema::value(text()),
+        (_, "aliases") => OptionSchema::items(text()),
+        (_, "artist") => OptionSchema::attributes(vec![
+            ("alias", text()),
+            ("external_page", url()),
+            ("name", text()),
+            ("permalink", ValueSchema::of("permalink"))
+        ]),
+        (_, "base_url") => OptionSchema::value(url()),
+        (_, "cache_optimization") => OptionSchema::value(ValueSchema::one_of(&CacheOptimization::ALL_MANIFEST_KEYS)),
+        (_, "cdn_url") => OptionSchema::value(url()),
+        (_, "chapters") |
+        (_, "cues") => OptionSchema::items(ValueSchema::of("chapter")),
+        (_, "copy_link") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "cover") => OptionSchema::attributes(image_attributes()),
+        (_, "date") => OptionSchema::value(ValueSchema::of("date")),
+        (_, "download_code") => OptionSchema::value(text()),
+        (_, "download_codes") => OptionSchema::items(text()),
+        (_, "embedding") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "event") => OptionSchema::attributes(vec![
+            ("city", text()),
+            ("date", ValueSchema::of("date")),
+            ("tickets", url()),
+            ("venue", text())
+        ]),
+        (_, "external_page") => OptionSchema::value(url()),
+        (_, "faircamp_signature") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "favicon") => OptionSchema::value(ValueSchema::suggesting("path", &["none"])),
+        (_, "feature_support_artists") => OptionSchema::flag(),
+        (_, "feeds") => OptionSchema::value_or_items(ValueSchema::one_of(&Feeds::ALL_MANIFEST_KEYS)),
+        (_, "freeze_download_urls") => OptionSchema::value(text()),
+        (_, "gallery_image") => OptionSchema::attributes(image_attributes()),
+        (_, "home_image") => OptionSchema::attributes(image_attributes()),
+        (_, "image") => OptionSchema::attributes(image_attributes()),
+        (_, "image_formats") => OptionSchema::value_or_items(ValueSchema::one_of(IMAGE_FORMATS)),
+        (_, "keyboard_shortcuts") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "label_mode") => OptionSchema::flag(),
+        (_, "language") => OptionSchema::value(ValueSchema::suggesting("language", &Locale::BUILTIN_CODES)),
+        (_, "license") => OptionSchema::value(ValueSchema::suggesting("license", &License::SUGGESTED_MANIFEST_VALUES)),
+        (_, "link") => OptionSchema::value_or_attributes(url(), vec![
+            ("label", text()),
+            ("url", url()),
+            ("verification", ValueSchema::one_of(LINK_VERIFICATION))
+        ]),
+        (_, "menu") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "more") => OptionSchema::embed(ValueSchema::of("markdown")),
+        (_, "more_label") => OptionSchema::value(text()),
+        (_, "name") => OptionSchema::value(text()),
+        (_, "opengraph") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "payment_info") => OptionSchema::embed(ValueSchema::of("markdown")),
+        (_, "permalink") => OptionSchema::value(ValueSchema::of("permalink")),
+        (_, "preview") => OptionSchema::flag(),
+        (_, "product") => OptionSchema::attributes(vec![
+            ("checkout", url()),
+            ("format", text()),
+            ("image", ValueSchema::of("path")),
+            ("price", ValueSchema::of("price")),
+            ("stock", ValueSchema::one_of(&Stock::ALL_MANIFEST_KEYS))
+        ]),
+        (_, "publish_at") => OptionSchema::value(ValueSchema::of("datetime")),
+        (_, "release_archive_checksums") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "release_archive_format") => OptionSchema::value(ValueSchema::one_of(&ArchiveFormat::ALL_MANIFEST_KEYS)),
+        (_, "release_archive_info") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "release_artist") => OptionSchema::value(text()),
+        (_, "release_artists") => OptionSchema::items(text()),
+        (_, "release_download_access") => OptionSchema::value(ValueSchema::suggesting("download_access", &DownloadAccessOption::ALL_MANIFEST_KEYS)),
+        (_, "release_downloads") => OptionSchema::value_or_items(ValueSchema::one_of(&DownloadFormat::ALL_MANIFEST_KEYS)),
+        (_, "release_extras") => OptionSchema::value_or_items(ValueSchema::one_of(&ExtraDownloads::ALL_MANIFEST_KEYS)),
+        (_, "release_price") => OptionSchema::value(ValueSchema::of("price")),
+        (_, "releases") => OptionSchema::items(ValueSchema::of("permalink")),
+        (_, "rotate_download_urls") => OptionSchema::flag(),
+        (_, "show_support_artists") => OptionSchema::flag(),
+        (_, "site_assets") => OptionSchema::value_or_items(ValueSchema::of("path")),
+        (_, "site_metadata") => OptionSchema::embed(ValueSchema::of("html")),
+        (_, "soft_navigation") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "speed_controls") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "stats_endpoint") => OptionSchema::value(url()),
+        (_, "streaming_quality") => OptionSchema::value(ValueSchema::one_of(&StreamingQuality::ALL_KEYS)),
+        (_, "synopsis") => OptionSchema::embed(ValueSchema::of("markdown")),
+        (_, "tags") => OptionSchema::value_or_attributes(ValueSchema::one_of(&TagAgenda::ALL_MANIFEST_KEYS), vec![
+            ("album", ValueSchema::one_of(&TagAction::ALL_KEYS)),
+            ("album_artist", ValueSchema::one_of(&TagAction::ALL_KEYS)),
+            ("artist", ValueSchema::one_of(&TagAction::ALL_KEYS)),
+            ("image", ValueSchema::one_of(&TagAction::ALL_KEYS)),
+            ("title", ValueSchema::one_of(&TagAction::ALL_KEYS)),
+            ("track", ValueSchema::one_of(&TagAction::ALL_KEYS))
+        ]),
+        (_, "text") => OptionSchema::embed(ValueSchema::of("markdown")),
+        (_, "theme") => OptionSchema::attributes(vec![
+            ("accent_brightening", percentage()),
+            ("accent_chroma", percentage()),
+            ("accent_hue", degrees()),
+            ("background_alpha", percentage()),
+            ("background_image", ValueSchema::of("path")),
+            ("base", ValueSchema::one_of(&ThemeBase::ALL_MANIFEST_KEYS)),
+            ("base_chroma", percentage()),
+            ("base_hue", degrees()),
+            ("cover_generator", ValueSchema::one_of(&CoverGenerator::ALL_GENERATORS)),
+            ("dynamic_range", percentage()),
+            ("round_corners", ValueSchema::one_of(DISABLED_ENABLED)),
+            ("system_font", ValueSchema::one_of(SYSTEM_FONTS)),
+            ("waveform_color", ValueSchema::one_of(&WaveformColor::ALL_MANIFEST_KEYS)),
+            ("waveform_style", ValueSchema::one_of(&WaveformStyle::ALL_MANIFEST_KEYS)),
+            ("waveforms", ValueSchema::one_of(&Theme::ALL_WAVEFORMS_MANIFEST_KEYS))
+        ]),
+        (_, "title") => OptionSchema::value(text()),
+        (_, "track_artist") => OptionSchema::value(text()),
+        (_, "track_artists") => OptionSchema::items(text()),
+        (_, "track_download_access") => OptionSchema::value(ValueSchema::suggesting("download_access", &DownloadAccessOption::ALL_MANIFEST_KEYS)),
+        (_, "track_downloads") => OptionSchema::value_or_items(ValueSchema::one_of(&DownloadFormat::ALL_MANIFEST_KEYS)),
+        (_, "track_extras") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "track_numbering") => OptionSchema::value(ValueSchema::one_of(&TrackNumbering::ALL_MANIFEST_KEYS)),
+        (_, "track_price") => OptionSchema::value(ValueSchema::of("price")),
+        (_, "tracks") => OptionSchema::items(ValueSchema::of("track_reference")),
+        (_, "unlisted") => OptionSchema::flag(),
+        (_, "unlock_info") => OptionSchema::embed(ValueSchema::of("markdown")),
+        (_, "waveform_images") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        (_, "web_app") => OptionSchema::value(ValueSchema::one_of(DISABLED_ENABLED)),
+        _ => return None
+    };
+
+    Some(schema)
+}
+
+fn manifest_json(manifest_name: &str, option_groups: &[&[&str]]) -> String {
+    let mut keys = option_groups
+        .iter()
+        .flat_map(|group| group.iter().copied())
+        .collect::<Vec<&str>>();
+
+    keys.sort();
+
+    let r_options = keys
+        .iter()
+        .map(|key| {
+            let r_schema = match option_schema(manifest_name, key) {
+                Some(schema) => schema.to_json(),
+                None => String::from("{}")
+            };
+
+            format!(r#""{key}":{r_schema}"#)
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+
+    format!(r#""{manifest_name}":{{{r_options}}}"#)
+}
+
+/// Returns a JSON document that lists, for each manifest type, the
+/// supported option keys, the forms in which they can be written, and their
+/// value types and allowed values.
+pub fn schema_json() -> String {
+    let r_manifests = [
+        manifest_json("artist.eno", ARTIST_MANIFEST_OPTIONS),
+        manifest_json("catalog.eno", CATALOG_MANIFEST_OPTIONS),
+        manifest_json("page.eno", PAGE_MANIFEST_OPTIONS),
+        manifest_json("playlist.eno", PLAYLIST_MANIFEST_OPTIONS),
+        manifest_json("post.eno", POST_MANIFEST_OPTIONS),
+        manifest_json("release.eno", RELEASE_MANIFEST_OPTIONS),
+        manifest_json("track.eno", TRACK_MANIFEST_OPTIONS)
+    ].join(",");
+
+    let generator = json_escape_inside_string(GENERATOR_INFO);
+
+    format!(r#"{{"generator":"{generator}","manifests":{{{r_manifests}}}}}"#)
+}
@@ -7,6 +7,7 @@ use std::path::Path;
 use crate::{
     Build,
     Cache,
+    Chapter,
     LocalOptions,
     Overrides
 };
@@ -15,6 +16,7 @@ use super::{
     ARTIST_CATALOG_RELEASE_TRACK_OPTIONS,
     RELEASE_TRACK_OPTIONS,
     element_error_with_snippet,
+    item_error_with_snippet,
     not_supported_error,
     platform_printer,
     read_artist_catalog_release_track_option,
@@ -23,9 +25,19 @@ use super::{
 };
 
 const TRACK_OPTIONS: &[&str] = &[
+    "chapters",
+    "cues",
+    "preview",
     "title"
 ];
 
+/// All option groups supported in a track.eno manifest
+pub const TRACK_MANIFEST_OPTIONS: &[&[&str]] = &[
+    ARTIST_CATALOG_RELEASE_TRACK_OPTIONS,
+    RELEASE_TRACK_OPTIONS,
+    TRACK_OPTIONS
+];
+
 pub fn read_track_manifest(
     build: &mut Build,
     cache: &mut Cache,
@@ -55,6 +67,53 @@ pub fn read_track_manifest(
     for element in document.elements() {
         match element.key() {
             _ if read_obsolete_option(build, element, manifest_path) => (),
+            // "cues" is accepted as an alias, as chapters are commonly called
+            // that in the context of DJ mixes and CUE sheets.
+            key @ ("chapters" | "cues") => 'chapters: {
+                if let Ok(field) = element.as_field() {
+                    if let Ok(items) = field.items() {
+                        let mut chapters: Vec<Chapter> = items
+                            .iter()
+                            .filter_map(|item| {
+                                match item.value() {
+                                    Some(value) => {
+                                        match Chapter::from_manifest_value(value) {
+                                            Ok(chapter) => Some(chapter),
+                                            Err(err) => {
+                                                let message = format!("Ignoring invalid chapter ({err})");
+                                                let error = item_error_with_snippet(item, manifest_path, &message);
+                                                build.manifest_error(&error);
+                                                None
+                                            }
+                                        }
+                                    }
+                                    None => None
+                                }
+                            })
+                            .collect();
+
+                        chapters.sort_by_key(|chapter| chapter.start_ms);
+                        chapters.dedup_by_key(|chapter| chapter.start_ms);
+
+                        local_options.chapters = chapters;
+
+                        break 'chapters;
+                    }
+                }
+
+                let message = format!("{key} needs to be provided as a field with items, each a timestamp followed by a title, e.g.:\n\n{key}:\n- 0:00 Intro\n- 3:25 Main theme\n- 1:02:03 Outro");
+                let error = element_error_with_snippet(element, manifest_path, &message);
+                build.manifest_error(&error);
+            }
+            "preview" => {
+                if element.is_flag() {
+                    local_options.preview_track = true;
+                } else {
+                    let message = "preview needs to be provided as a flag, that is, exactly as 'preview' (without colon and without value)";
+                    let error = element_error_with_snippet(element, manifest_path, message);
+                    build.manifest_error(&error);
+                }
+            }
             "title" => 'title: {
                 if let Ok(field) = element.as_field() {
                     if let Ok(result) = field.value() {
@@ -68,7 +127,7 @@ pub fn read_track_manifest(
 
                 let message = "title needs to be provided as a field with a value, e.g.: 'title: Interlude'";
                 let error = element_error_with_snippet(element, manifest_path, message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
             _ if read_artist_catalog_release_track_option(build, cache, element, local_options, manifest_path, overrides) => (),
             _ if read_release_track_option(build, cache, dir, element, local_options, manifest_path) => (),
@@ -76,15 +135,11 @@ pub fn read_track_manifest(
                 let message = not_supported_error(
                     "track.eno",
                     other,
-                    &[
-                        ARTIST_CATALOG_RELEASE_TRACK_OPTIONS,
-                        RELEASE_TRACK_OPTIONS,
-                        TRACK_OPTIONS
-                    ]
+                    TRACK_MANIFEST_OPTIONS
                 );
 
                 let error = element_error_with_snippet(element, manifest_path, &message);
-                build.error(&error);
+                build.manifest_error(&error);
             }
         }
     }
@@ -1,6 +1,20 @@
 // SPDX-FileCopyrightText: 2021-2025 Simon Repp
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// When set, none of the messages below are printed, so that stdout only
+/// carries machine-readable output (e.g. with `faircamp check --json`).
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+pub fn quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+pub fn set_quiet() {
+    QUIET.store(true, Ordering::Relaxed);
+}
+
 #[cfg(not(target_os = "windows"))]
 macro_rules! color {
     (blue) => ("\x1b[34m");
@@ -25,99 +39,121 @@ macro_rules! color {
 
 macro_rules! error {
     ($format_str:expr $(,$args:expr)*) => {
-        println!(
-            concat!(color!(red), "[ERROR] ", $format_str, color!(reset))
-            $(,$args)*
-        )
+        if !$crate::message::quiet() {
+            println!(
+                concat!(color!(red), "[ERROR] ", $format_str, color!(reset))
+                $(,$args)*
+            )
+        }
     };
 }
 
 macro_rules! info {
     ($format_str:expr $(,$args:expr)*) => {
-        println!(
-            concat!(color!(blue), "[INFO] ", $format_str, color!(reset))
-            $(,$args)*
-        )
+        if !$crate::message::quiet() {
+            println!(
+                concat!(color!(blue), "[INFO] ", $format_str, color!(reset))
+                $(,$args)*
+            )
+        }
     };
 }
 
 macro_rules! info_cache {
     ($format_str:expr $(,$args:expr)*) => {
-        println!(
-            concat!(color!(magenta), "[CACHE] ", $format_str, color!(reset))
-            $(,$args)*
-        )
+        if !$crate::message::quiet() {
+            println!(
+                concat!(color!(magenta), "[CACHE] ", $format_str, color!(reset))
+                $(,$args)*
+            )
+        }
     };
 }
 
 macro_rules! info_decoding {
     ($format_str:expr $(,$args:expr)*) => {
-        println!(
-            concat!(color!(magenta), "[DECODING] ", $format_str, color!(reset))
-            $(,$args)*
-        )
+        if !$crate::message::quiet() {
+            println!(
+                concat!(color!(magenta), "[DECODING] ", $format_str, color!(reset))
+                $(,$args)*
+            )
+        }
     };
 }
 
 macro_rules! info_generating {
     ($format_str:expr $(,$args:expr)*) => {
-        println!(
-            concat!(color!(blue), "[GENERATING] ", $format_str, color!(reset))
-            $(,$args)*
-        )
+        if !$crate::message::quiet() {
+            println!(
+                concat!(color!(blue), "[GENERATING] ", $format_str, color!(reset))
+                $(,$args)*
+            )
+        }
     };
 }
 
 macro_rules! info_resizing {
     ($format_str:expr $(,$args:expr)*) => {
-        println!(
-            concat!(color!(blue), "[RESIZING] ", $format_str, color!(reset))
-            $(,$args)*
-        )
+        if !$crate::message::quiet() {
+            println!(
+                concat!(color!(blue), "[RESIZING] ", $format_str, color!(reset))
+                $(,$args)*
+            )
+        }
     };
 }
 
 macro_rules! info_stats {
     ($format_str:expr $(,$args:expr)*) => {
-        println!(
-            concat!(color!(cyan), "[STATS] ", $format_str, color!(reset))
-            $(,$args)*
-        )
+        if !$crate::message::quiet() {
+            println!(
+                concat!(color!(cyan), "[STATS] ", $format_str, color!(reset))
+                $(,$args)*
+            )
+        }
     };
 }
 
 macro_rules! info_transcoding {
     ($format_str:expr $(,$args:expr)*) => {
-        println!(
-            concat!(color!(blue), "[TRANSCODING] ", $format_str, color!(reset))
-            $(,$args)*
-        )
+        if !$crate::message::quiet() {
+            println!(
+                concat!(color!(blue), "[TRANSCODING] ", $format_str, color!(reset))
+                $(,$args)*
+            )
+        }
     };
 }
 
 macro_rules! info_zipping {
     ($format_str:expr $(,$args:expr)*) => {
-        println!(
-            concat!(color!(blue), "[ZIPPING] ", $format_str, color!(reset))
-            $(,$args)*
-        )
+        if !$crate::message::quiet() {
+            println!(
+                concat!(color!(blue), "[ZIPPING] ", $format_str, color!(reset))
+                $(,$args)*
+            )
+        }
     };
 }
 
 macro_rules! warn {
     ($format_str:expr $(,$args:expr)*) => {
-        println!(
-            concat!(color!(yellow), "[WARNING] ", $format_str, color!(reset))
-            $(,$args)*
-        )
+        if !$crate::message::quiet() {
+            println!(
+                concat!(color!(yellow), "[WARNING] ", $format_str, color!(reset))
+                $(,$args)*
+            )
+        }
     };
 }
 
 macro_rules! warn_discouraged {
     ($format_str:expr $(,$args:expr)*) => {
-        println!(
-            concat!(color!(yellow), "[DISCOURAGED] ", $format_str, color!(reset))
-            $(,$args)*
-        )
+        if !$crate::message::quiet() {
+            println!(
+                concat!(color!(yellow), "[DISCOURAGED] ", $format_str, color!(reset))
+                $(,$args)*
+            )
+        }
     };
 }
@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::path::PathBuf;
+
+use crate::{Build, Catalog, Permalink, Theme};
+use crate::{render, util};
+
+/// A standalone page of free-form content (e.g. an About, Tour dates or
+/// Imprint page), defined through a page.eno manifest.
+#[derive(Debug)]
+pub struct Page {
+    /// Whether the page is linked to from the header of every page
+    pub menu: bool,
+    pub permalink: Permalink,
+    pub source_dir: PathBuf,
+    /// Rendered from the markdown provided in the manifest
+    pub text: String,
+    pub theme: Theme,
+    pub title: String
+}
+
+impl Page {
+    pub fn new(
+        menu: bool,
+        permalink: Option<Permalink>,
+        source_dir: PathBuf,
+        text: String,
+        theme: Theme,
+        title: String
+    ) -> Page {
+        let permalink = permalink.unwrap_or_else(|| Permalink::generate(&title));
+
+        Page {
+            menu,
+            permalink,
+            source_dir,
+            text,
+            theme,
+            title
+        }
+    }
+
+    pub fn write_page(&self, build: &mut Build, catalog: &Catalog) {
+        let page_dir = build.build_dir.join(&self.permalink.slug);
+        let page_html = render::page::page_html(build, catalog, self);
+        util::ensure_dir_all_and_write_index(&page_dir, &page_html);
+    }
+}
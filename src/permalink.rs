@@ -3,7 +3,7 @@
 
 use slug::slugify;
 
-use crate::{ArtistRc, ReleaseRc};
+use crate::{ArtistRc, Page, Playlist, Post, ReleaseRc};
 use crate::util::uid;
 
 #[derive(Clone, Debug)]
@@ -12,8 +12,12 @@ pub struct Permalink {
     pub slug: String
 }
 
+#[derive(Clone, Copy)]
 pub enum PermalinkUsage<'a> {
     Artist(&'a ArtistRc),
+    Page(&'a Page),
+    Playlist(&'a Playlist),
+    Post(&'a Post),
     Release(&'a ReleaseRc)
 }
 
@@ -63,6 +67,36 @@ impl PermalinkUsage<'_> {
 
                 format!("The artist '{name}' also has the {generated_or_assigned} permalink '{slug}'")
             }
+            PermalinkUsage::Page(page) => {
+                let permalink = &page.permalink;
+
+                let generated_or_assigned = permalink.generated_or_assigned_str();
+                let page_dir = page.source_dir.display();
+                let slug = &permalink.slug;
+                let title = &page.title;
+
+                format!("The page '{title}' from directory '{page_dir}' also has the {generated_or_assigned} permalink '{slug}'")
+            }
+            PermalinkUsage::Playlist(playlist) => {
+                let permalink = &playlist.permalink;
+
+                let generated_or_assigned = permalink.generated_or_assigned_str();
+                let playlist_dir = playlist.source_dir.display();
+                let slug = &permalink.slug;
+                let title = &playlist.title;
+
+                format!("The playlist '{title}' from directory '{playlist_dir}' also has the {generated_or_assigned} permalink '{slug}'")
+            }
+            PermalinkUsage::Post(post) => {
+                let permalink = &post.permalink;
+
+                let generated_or_assigned = permalink.generated_or_assigned_str();
+                let post_dir = post.source_dir.display();
+                let slug = &permalink.slug;
+                let title = &post.title;
+
+                format!("The post '{title}' from directory '{post_dir}' also has the {generated_or_assigned} permalink '{slug}'")
+            }
             PermalinkUsage::Release(release) => {
                 let release_ref = release.borrow();
                 let permalink = &release_ref.permalink;
@@ -76,4 +110,19 @@ impl PermalinkUsage<'_> {
             }
         }
     }
+
+    /// Describes what uses the permalink, e.g. "page 'About' from
+    /// directory 'about'", for use in conflict errors.
+    pub fn subject(&self) -> String {
+        match self {
+            PermalinkUsage::Artist(artist) => format!("artist '{}'", artist.borrow().name),
+            PermalinkUsage::Page(page) => format!("page '{}' from directory '{}'", page.title, page.source_dir.display()),
+            PermalinkUsage::Playlist(playlist) => format!("playlist '{}' from directory '{}'", playlist.title, playlist.source_dir.display()),
+            PermalinkUsage::Post(post) => format!("post '{}' from directory '{}'", post.title, post.source_dir.display()),
+            PermalinkUsage::Release(release) => {
+                let release_ref = release.borrow();
+                format!("release '{}' from directory '{}'", release_ref.title, release_ref.source_dir.display())
+            }
+        }
+    }
 }
@@ -0,0 +1,170 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::{
+    Build,
+    Catalog,
+    HtmlAndStripped,
+    JSPF_PLAYLIST_FILENAME,
+    Link,
+    M3U_PLAYLIST_FILENAME,
+    Permalink,
+    ReleaseRc,
+    Theme,
+    XSPF_PLAYLIST_FILENAME
+};
+use crate::{jspf, m3u, render, util, xspf};
+
+/// A curated selection of tracks drawn from any number of releases in the
+/// catalog (e.g. a themed mixtape), defined through a playlist.eno manifest.
+/// Playlists only reference tracks, they never have audio files of their
+/// own - everything is streamed from the assets of the referenced releases.
+#[derive(Debug)]
+pub struct Playlist {
+    pub copy_link: bool,
+    pub embedding: bool,
+    /// Populated from track_references once all releases in the catalog
+    /// have been read, see [Catalog::map_playlists].
+    pub entries: Vec<PlaylistEntry>,
+    pub links: Vec<Link>,
+    /// Whether M3U/XSPF/JSPF playlist files should be generated and provided
+    pub m3u: bool,
+    pub more: Option<HtmlAndStripped>,
+    /// Optional override label for the button that (by default) says "More"
+    /// on the playlist page and points to additional long-form content.
+    pub more_label: Option<String>,
+    pub permalink: Permalink,
+    pub source_dir: PathBuf,
+    pub speed_controls: bool,
+    pub synopsis: Option<String>,
+    pub theme: Theme,
+    pub title: String,
+    /// The tracks as referenced in the manifest, in playlist order. Releases
+    /// may be read after the playlist, hence these are only resolved to
+    /// entries later on.
+    pub track_references: Vec<TrackReference>,
+    pub unlisted: bool
+}
+
+/// A track of a playlist, addressed through the release it belongs to.
+#[derive(Debug)]
+pub struct PlaylistEntry {
+    pub release: ReleaseRc,
+    /// 1-indexed, as in the track's url (e.g. "example-release/3/")
+    pub track_number: usize
+}
+
+/// A track reference as written in a playlist.eno manifest, e.g.
+/// "example-release/3" for the third track of the release with the
+/// permalink "example-release".
+#[derive(Debug)]
+pub struct TrackReference {
+    pub release_slug: String,
+    pub track_number: usize
+}
+
+impl Playlist {
+    pub fn longest_track_duration(&self) -> f32 {
+        let mut longest_track_duration = 0.0;
+        for entry in &self.entries {
+            let release_ref = entry.release.borrow();
+            let track = &release_ref.tracks[entry.track_number - 1];
+            let duration_seconds = track.transcodes.borrow().source_meta.duration_seconds;
+            if duration_seconds > longest_track_duration {
+                longest_track_duration = duration_seconds;
+            }
+        }
+        longest_track_duration
+    }
+
+    pub fn new(
+        copy_link: bool,
+        embedding: bool,
+        links: Vec<Link>,
+        m3u: bool,
+        more: Option<HtmlAndStripped>,
+        more_label: Option<String>,
+        permalink: Option<Permalink>,
+        source_dir: PathBuf,
+        speed_controls: bool,
+        synopsis: Option<String>,
+        theme: Theme,
+        title: String,
+        track_references: Vec<TrackReference>,
+        unlisted: bool
+    ) -> Playlist {
+        let permalink = permalink.unwrap_or_else(|| Permalink::generate(&title));
+
+        Playlist {
+            copy_link,
+            embedding,
+            entries: Vec::new(),
+            links,
+            m3u,
+            more,
+            more_label,
+            permalink,
+            source_dir,
+            speed_controls,
+            synopsis,
+            theme,
+            title,
+            track_references,
+            unlisted
+        }
+    }
+
+    pub fn write_pages_and_playlist_files(&self, build: &mut Build, catalog: &Catalog) {
+        // Render playlist page
+        let playlist_dir = build.build_dir.join(&self.permalink.slug);
+        let playlist_html = render::playlist::playlist_html(build, catalog, self);
+        util::ensure_dir_all_and_write_index(&playlist_dir, &playlist_html);
+
+        if let Some(cdn_url) = &build.cdn_url {
+            // Render m3u, xspf and jspf playlist
+            if self.m3u {
+                let r_m3u = m3u::generate_for_playlist(cdn_url, build, self);
+                fs::write(playlist_dir.join(M3U_PLAYLIST_FILENAME), r_m3u).unwrap();
+
+                let r_xspf = xspf::generate_for_playlist(cdn_url, build, self);
+                fs::write(playlist_dir.join(XSPF_PLAYLIST_FILENAME), r_xspf).unwrap();
+
+                let r_jspf = jspf::generate_for_playlist(cdn_url, build, self);
+                fs::write(playlist_dir.join(JSPF_PLAYLIST_FILENAME), r_jspf).unwrap();
+            }
+        }
+
+        if let Some(base_url) = &build.base_url {
+            // Render playlist embed pages
+            if self.embedding {
+                let playlist_embed_codes_dir = playlist_dir.join("embed");
+                let playlist_embed_codes_html = render::playlist_embed_codes::playlist_embed_codes_html(base_url, build, catalog, self);
+                util::ensure_dir_all_and_write_index(&playlist_embed_codes_dir, &playlist_embed_codes_html);
+
+                let playlist_embed_dir = playlist_embed_codes_dir.join("all");
+                let playlist_embed_html = render::playlist_embed::playlist_embed_html(base_url, build, self);
+                util::ensure_dir_all_and_write_index(&playlist_embed_dir, &playlist_embed_html);
+            }
+        }
+    }
+}
+
+impl TrackReference {
+    /// Parses a reference of the form "release-permalink/track-number"
+    pub fn parse(value: &str) -> Result<TrackReference, String> {
+        let Some((release_slug, track_number)) = value.trim().rsplit_once('/') else {
+            return Err(format!("The track reference '{value}' is missing the track number, it needs to be given as release permalink and track number, e.g. 'example-release/3'"));
+        };
+
+        match track_number.parse::<usize>() {
+            Ok(track_number) if track_number > 0 => Ok(TrackReference {
+                release_slug: release_slug.to_string(),
+                track_number
+            }),
+            _ => Err(format!("The track number in the track reference '{value}' is not valid, it needs to be a number starting at 1, e.g. 'example-release/3'"))
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+
+use crate::{
+    Build,
+    Catalog,
+    HtmlAndStripped,
+    Permalink,
+    ReleaseRc,
+    Theme
+};
+use crate::{render, util};
+
+/// A dated news/blog post (e.g. tour news or a reissue announcement),
+/// defined through a post.eno manifest. Posts are listed on the news page
+/// and announced in the feeds alongside releases.
+#[derive(Debug)]
+pub struct Post {
+    pub date: NaiveDate,
+    pub permalink: Permalink,
+    /// The permalinks of the releases referenced in the manifest. Releases
+    /// may be read after the post, hence these are only resolved to
+    /// releases later on, see [Catalog::map_posts].
+    pub release_references: Vec<String>,
+    /// Releases featured at the end of the post (with an embedded player
+    /// where possible).
+    pub releases: Vec<ReleaseRc>,
+    pub source_dir: PathBuf,
+    pub text: HtmlAndStripped,
+    pub theme: Theme,
+    pub title: String,
+    /// Unlisted posts are neither listed on the news page nor included in
+    /// the feeds, they can only be reached through their url.
+    pub unlisted: bool
+}
+
+impl Post {
+    pub fn new(
+        date: NaiveDate,
+        permalink: Option<Permalink>,
+        release_references: Vec<String>,
+        source_dir: PathBuf,
+        text: HtmlAndStripped,
+        theme: Theme,
+        title: String,
+        unlisted: bool
+    ) -> Post {
+        let permalink = permalink.unwrap_or_else(|| Permalink::generate(&title));
+
+        Post {
+            date,
+            permalink,
+            release_references,
+            releases: Vec::new(),
+            source_dir,
+            text,
+            theme,
+            title,
+            unlisted
+        }
+    }
+
+    pub fn write_page(&self, build: &mut Build, catalog: &Catalog) {
+        let post_dir = build.build_dir.join(&self.permalink.slug);
+        let post_html = render::post::post_html(build, catalog, self);
+        util::ensure_dir_all_and_write_index(&post_dir, &post_html);
+    }
+}
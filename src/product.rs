@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use translations::Translations;
+
+use crate::{DescribedImage, Price};
+
+/// A physical product (e.g. a vinyl pressing, a cassette or a shirt),
+/// defined through a product field in a catalog or release manifest.
+/// Faircamp itself does not handle the sale, it links to an external
+/// checkout page instead.
+#[derive(Clone, Debug)]
+pub struct Product {
+    /// Url of the external page where the product can be bought
+    pub checkout: String,
+    /// The name under which the product is offered, e.g. "12\" Vinyl"
+    pub format: String,
+    pub image: Option<DescribedImage>,
+    pub price: Option<Price>,
+    pub stock: Stock
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stock {
+    Available,
+    Low,
+    Preorder,
+    SoldOut
+}
+
+impl Product {
+    pub fn new(
+        checkout: String,
+        format: String,
+        image: Option<DescribedImage>,
+        price: Option<Price>,
+        stock: Stock
+    ) -> Product {
+        Product {
+            checkout,
+            format,
+            image,
+            price,
+            stock
+        }
+    }
+}
+
+impl Stock {
+    pub const ALL_MANIFEST_KEYS: [&'static str; 4] = ["available", "low", "preorder", "sold_out"];
+
+    pub fn from_manifest_key(key: &str) -> Option<Stock> {
+        match key {
+            "available" => Some(Stock::Available),
+            "low" => Some(Stock::Low),
+            "preorder" => Some(Stock::Preorder),
+            "sold_out" => Some(Stock::SoldOut),
+            _ => None
+        }
+    }
+
+    pub fn label(&self, translations: &Translations) -> &'static str {
+        match self {
+            Stock::Available => *translations.in_stock,
+            Stock::Low => *translations.low_stock,
+            Stock::Preorder => *translations.preorder,
+            Stock::SoldOut => *translations.sold_out
+        }
+    }
+
+    /// The corresponding schema.org ItemAvailability value
+    pub fn schema_availability(&self) -> &'static str {
+        match self {
+            Stock::Available => "https://schema.org/InStock",
+            Stock::Low => "https://schema.org/LimitedAvailability",
+            Stock::Preorder => "https://schema.org/PreOrder",
+            Stock::SoldOut => "https://schema.org/SoldOut"
+        }
+    }
+}
@@ -5,45 +5,63 @@
 use std::cell::{Ref, RefCell, RefMut};
 use std::collections::HashSet;
 use std::fs;
-use std::fs::File;
 use std::hash::{DefaultHasher, Hash, Hasher};
-use std::io::prelude::*;
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::ops::RangeFrom;
 
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime};
 use sanitize_filename::sanitize;
 use serde_derive::{Serialize, Deserialize};
-use zip::{CompressionMethod, ZipWriter};
-use zip::write::SimpleFileOptions;
 
-use crate::M3U_PLAYLIST_FILENAME;
+use crate::{JSPF_PLAYLIST_FILENAME, M3U_PLAYLIST_FILENAME, XSPF_PLAYLIST_FILENAME};
 use crate::{
     Archive,
+    ArchiveFormat,
+    ArchiveWriter,
     ArchivesRc,
     ArtistRc,
     Asset,
     AssetIntent,
+    AudioFormat,
     Build,
     Cache,
     Catalog,
+    Chapter,
     DescribedImage,
     DownloadAccess,
     DownloadFormat,
     ExtraDownloads,
     FileMeta,
     HtmlAndStripped,
+    License,
     Link,
     Permalink,
     ProceduralCoverRc,
+    Product,
+    SiteUrl,
     TagMapping,
     Theme,
     Track,
-    TrackNumbering
+    TrackNumbering,
+    TranscodesRcView,
+    WaveformAnalysis
 };
-use crate::{m3u, render, util};
-use crate::util::{deduplicate_filename, generic_hash};
+use crate::{jspf, m3u, render, util, xspf};
+use crate::chapter::{CUE_SHEET_FILENAME, PODCAST_CHAPTERS_FILENAME};
+use crate::util::{deduplicate_filename, format_time, generic_hash};
+
+/// The filename of the generated info file included in release archives
+/// (if enabled).
+const ARCHIVE_INFO_FILENAME: &str = "info.txt";
+
+/// The filename of the generated license notice included in release
+/// archives (if a license is specified for the release or its tracks).
+const ARCHIVE_LICENSE_FILENAME: &str = "license.txt";
+
+/// The directory (inside the release directory) in which the continuous
+/// recording of a release split along a CUE sheet is placed.
+const CONTINUOUS_STREAM_DIRNAME: &str = "continuous";
 
 /// An unbounded iterator returning track numbers (1, 2, 3, ..) which
 /// we generally use with ".zip(TRACK_NUMBERS)" to augment an iteration
@@ -75,6 +93,12 @@ pub struct Extra {
 
 #[derive(Debug)]
 pub struct Release {
+    /// Whether a SHA-256 checksum file is published alongside each archive
+    pub archive_checksums: bool,
+    pub archive_format: ArchiveFormat,
+    /// Whether a generated info file (tracklist, credits, etc.) is included
+    /// in the release archives
+    pub archive_info: bool,
     /// This is an option because of delayed initialization - at the point where
     /// we create the [Release] we cannot obtain this yet (we still need to map
     /// the artists and the signature that we need to compute to obtain the right
@@ -94,6 +118,11 @@ pub struct Release {
     /// Additional files that are included in the download archive,
     /// such as additional images, liner notes, etc.
     pub extras: Vec<Extra>,
+    /// Additional images (e.g. booklet pages, photos) that are shown in a
+    /// gallery on the release page.
+    pub gallery: Vec<DescribedImage>,
+    /// The license under which the release is published (if specified)
+    pub license: Option<License>,
     pub links: Vec<Link>,
     /// The artists that are the principal authors of a release ("Album Artist" in tag lingo)
     pub main_artists: Vec<ArtistRc>,
@@ -113,6 +142,9 @@ pub struct Release {
     pub permalink: Permalink,
     /// Lazily generated when there is no regular cover
     pub procedural_cover: Option<ProceduralCoverRc>,
+    /// Physical products (e.g. vinyl, cassettes, shirts) offered for the
+    /// release through external checkout pages.
+    pub products: Vec<Product>,
     /// Relative path of the release directory in the catalog directory.
     /// This is used to augment permalink conflict errors with additional
     /// info for resolving the conflict.
@@ -136,7 +168,13 @@ pub struct Release {
     /// increase in steps of 1 some unexpected or random track ordering and numbering
     /// might happen, but this is somewhat impossible to avoid.
     pub tracks: Vec<Track>,
-    pub unlisted: bool
+    pub unlisted: bool,
+    /// Set when the release is scheduled for publication (publish_at) at a
+    /// later point in time, but already published as a teaser because some
+    /// of its tracks are marked as previews. Until then only the preview
+    /// tracks are streamable, all others are shown as locked placeholders,
+    /// and no downloads are offered.
+    pub upcoming: Option<NaiveDateTime>
 }
 
 #[derive(Clone, Debug)]
@@ -169,6 +207,102 @@ impl Extra {
 }
 
 impl Release {
+    /// Generates the content of the info file that is included in release
+    /// archives (if enabled), a plain text summary of the release with its
+    /// tracklist and credits.
+    pub fn archive_info_text(&self, build: &Build) -> String {
+        let mut lines = vec![self.title.clone()];
+
+        let join_names = |artists: &[ArtistRc]| {
+            artists
+                .iter()
+                .map(|artist| artist.borrow().name.clone())
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        if !self.main_artists.is_empty() {
+            lines.push(join_names(&self.main_artists));
+        }
+
+        if let Some(date) = &self.date {
+            lines.push(date.format("%Y-%m-%d").to_string());
+        }
+
+        if let Some(base_url) = &build.base_url {
+            lines.push(base_url.join_index(build, &self.permalink.slug));
+        }
+
+        if let Some(license) = &self.license {
+            let t_license = &build.locale.translations.license;
+            lines.push(format!("{t_license}: {} ({})", license.label, license.url));
+        }
+
+        if let Some(synopsis) = &self.synopsis {
+            lines.push(String::new());
+            lines.push(synopsis.clone());
+        }
+
+        lines.push(String::new());
+
+        let varying_track_artists = self.varying_track_artists();
+
+        for (track, track_number) in self.tracks.iter().zip(TRACK_NUMBERS) {
+            let number = self.track_numbering.format(track_number);
+            let duration = format_time(track.transcodes.borrow().source_meta.duration_seconds);
+            let title = track.title();
+
+            let line = if varying_track_artists {
+                let track_artists = join_names(&track.artists);
+                format!("{number} {track_artists} - {title} ({duration})")
+            } else {
+                format!("{number} {title} ({duration})")
+            };
+
+            lines.push(line.trim_start().to_string());
+        }
+
+        if let Some(more) = &self.more {
+            lines.push(String::new());
+            lines.push(more.stripped.clone());
+        }
+
+        lines.push(String::new());
+
+        lines.join("\n")
+    }
+
+    /// For a release whose tracks were split from a continuous recording
+    /// along a CUE sheet, returns the transcodes of that recording. Not
+    /// available for upcoming releases, as the recording would reveal
+    /// the locked tracks.
+    pub fn continuous_source(&self) -> Option<TranscodesRcView> {
+        if self.upcoming.is_some() {
+            return None;
+        }
+
+        self.tracks
+            .iter()
+            .find_map(|track| track.continuous_source.clone())
+    }
+
+    /// The path (relative to the release directory) at which the continuous
+    /// recording (see [Release::continuous_source]) is streamed in the
+    /// given format.
+    pub fn continuous_stream_path(&self, build: &Build, format: AudioFormat) -> String {
+        let format_dir = format.asset_dirname();
+        let filename = format!("{}{}", self.permalink.slug, format.extension());
+
+        let hash = build.hash_with_salt(|hasher| {
+            self.permalink.slug.hash(hasher);
+            CONTINUOUS_STREAM_DIRNAME.hash(hasher);
+            format_dir.hash(hasher);
+            filename.hash(hasher);
+        });
+
+        format!("{CONTINUOUS_STREAM_DIRNAME}/{format_dir}/{hash}/{filename}")
+    }
+
     /// Returns - if available - the file name of the release cover,
     /// without any prefixing (i.e. in the context of the release directory)
     pub fn cover_160_filename(&self) -> Option<String> {
@@ -180,7 +314,12 @@ impl Release {
     /// It is critical that every last detail of this hashing implementation
     /// stays the same - unless explicitly needed of course - because this signature
     /// makes or breaks finding cached archives.
-    pub fn get_or_create_release_archives(&mut self, cache: &mut Cache) {
+    pub fn get_or_create_release_archives(&mut self, build: &Build, cache: &mut Cache) {
+        // Upcoming releases offer no downloads until they are fully published
+        if self.upcoming.is_some() {
+            return;
+        }
+
         match &self.download_access {
             DownloadAccess::Code { .. } |
             DownloadAccess::Free |
@@ -210,6 +349,19 @@ impl Release {
 
                     self.title.hash(&mut hasher);
 
+                    self.archive_format.hash(&mut hasher);
+
+                    // Tar archives carry the release date as modification time
+                    if matches!(self.archive_format, ArchiveFormat::Tar | ArchiveFormat::TarZst) {
+                        self.date.hash(&mut hasher);
+                    }
+
+                    if self.archive_info {
+                        self.archive_info_text(build).hash(&mut hasher);
+                    }
+
+                    self.license_text(build).hash(&mut hasher);
+
                     // TODO: TrackNumbering could also be part of signature (how the files are numbered in the filename!)
                     for (track, track_number) in self.tracks.iter().zip(TRACK_NUMBERS) {
                         let tag_mapping = TagMapping::new(self, track, track_number);
@@ -247,6 +399,52 @@ impl Release {
         ((self.extra_downloads.bundled || self.extra_downloads.separate) && !self.extras.is_empty())
     }
 
+    /// Whether a waveform image is written for the release (given that
+    /// waveform images are enabled). Locked tracks are left out of it, so
+    /// while all tracks of an upcoming release are locked there is none.
+    pub fn has_waveform_image(&self) -> bool {
+        self.tracks.iter().any(|track| !self.track_locked(track))
+    }
+
+    /// Generates the content of the license notice that is included in
+    /// release archives, stating the license of the release and of those
+    /// tracks that are published under a different license. Returns None if
+    /// no license is specified for the release or any of its tracks.
+    pub fn license_text(&self, build: &Build) -> Option<String> {
+        let translations = &build.locale.translations;
+        let mut lines = Vec::new();
+
+        if let Some(license) = &self.license {
+            lines.push(translations.xxx_is_licensed_under_xxx(&self.title, &license.label));
+            lines.push(license.url.clone());
+        }
+
+        for (track, track_number) in self.tracks.iter().zip(TRACK_NUMBERS) {
+            if let Some(license) = &track.license {
+                if self.license.as_ref() != Some(license) {
+                    if !lines.is_empty() {
+                        lines.push(String::new());
+                    }
+
+                    let number = self.track_numbering.format(track_number);
+                    let title = track.title();
+                    let t_licensed = translations.xxx_is_licensed_under_xxx(&title, &license.label);
+                    let line = format!("{number} {t_licensed}");
+                    lines.push(line.trim_start().to_string());
+                    lines.push(license.url.clone());
+                }
+            }
+        }
+
+        if lines.is_empty() {
+            return None;
+        }
+
+        lines.push(String::new());
+
+        Some(lines.join("\n"))
+    }
+
     pub fn longest_track_duration(&self) -> f32 {
         let mut longest_track_duration = 0.0;
         for track in &self.tracks {
@@ -259,6 +457,9 @@ impl Release {
     }
 
     pub fn new(
+        archive_checksums: bool,
+        archive_format: ArchiveFormat,
+        archive_info: bool,
         copy_link: bool,
         cover: Option<DescribedImage>,
         date: Option<NaiveDate>,
@@ -267,12 +468,15 @@ impl Release {
         embedding: bool,
         extra_downloads: ExtraDownloads,
         extras: Vec<Extra>,
+        gallery: Vec<DescribedImage>,
+        license: Option<License>,
         links: Vec<Link>,
         m3u: bool,
         main_artists_to_map: Vec<String>,
         more: Option<HtmlAndStripped>,
         more_label: Option<String>,
         permalink: Option<Permalink>,
+        products: Vec<Product>,
         source_dir: PathBuf,
         speed_controls: bool,
         support_artists_to_map: Vec<String>,
@@ -281,11 +485,15 @@ impl Release {
         title: String,
         track_numbering: TrackNumbering,
         tracks: Vec<Track>,
-        unlisted: bool
+        unlisted: bool,
+        upcoming: Option<NaiveDateTime>
     ) -> Release {
         let permalink = permalink.unwrap_or_else(|| Permalink::generate(&title));
 
         Release {
+            archive_checksums,
+            archive_format,
+            archive_info,
             archives: None,
             asset_basename: None,
             copy_link,
@@ -296,6 +504,8 @@ impl Release {
             embedding,
             extra_downloads,
             extras,
+            gallery,
+            license,
             links,
             m3u,
             main_artists: Vec::new(),
@@ -304,6 +514,7 @@ impl Release {
             more_label,
             permalink,
             procedural_cover: None,
+            products,
             source_dir,
             speed_controls,
             support_artists: Vec::new(),
@@ -313,7 +524,28 @@ impl Release {
             title,
             track_numbering,
             tracks,
-            unlisted
+            unlisted,
+            upcoming
+        }
+    }
+
+    /// The absolute url of the cover image (or the procedural cover, if
+    /// there is none) that is referenced from M3U/XSPF/JSPF playlists.
+    pub fn playlist_image_url(&self, base_url: &SiteUrl) -> String {
+        let release_slug = &self.permalink.slug;
+
+        match &self.cover {
+            Some(described_image) => {
+                let image_ref = described_image.borrow();
+                let file_name = image_ref.cover_assets_unchecked().playlist_image();
+                let hash = image_ref.hash.as_url_safe_base64();
+
+                base_url.join_file(format!("{release_slug}/{file_name}?{hash}"))
+            }
+            None => {
+                let file_name = self.procedural_cover_480_filename_unchecked();
+                base_url.join_file(format!("{release_slug}/{file_name}"))
+            }
         }
     }
 
@@ -348,6 +580,47 @@ impl Release {
         self.procedural_cover.as_ref().unwrap()
     }
 
+    /// Locked tracks belong to an upcoming release and are not marked as
+    /// previews - they are only shown as placeholders (without any streaming
+    /// assets, track page or embeds) until the release is fully published.
+    pub fn track_locked(&self, track: &Track) -> bool {
+        self.upcoming.is_some() && !track.preview
+    }
+
+    /// The absolute url of a track's streaming file in its primary
+    /// streaming format, as referenced from M3U/XSPF/JSPF playlists.
+    pub fn track_stream_url(
+        &self,
+        base_url: &SiteUrl,
+        build: &Build,
+        track: &Track,
+        track_number: usize
+    ) -> String {
+        let release_slug = &self.permalink.slug;
+
+        let primary_streaming_format = track.streaming_quality.formats()[0];
+        let format_dir = primary_streaming_format.asset_dirname();
+        let format_extension = primary_streaming_format.extension();
+
+        let track_filename = format!(
+            "{basename}{format_extension}",
+            basename = track.asset_basename.as_ref().unwrap()
+        );
+
+        let track_hash = build.hash_with_salt(|hasher| {
+            release_slug.hash(hasher);
+            track_number.hash(hasher);
+            format_dir.hash(hasher);
+            track_filename.hash(hasher);
+        });
+
+        let track_filename_urlencoded = urlencoding::encode(&track_filename);
+
+        base_url.join_file(
+            format!("{release_slug}/{track_number}/{format_dir}/{track_hash}/{track_filename_urlencoded}")
+        )
+    }
+
     pub fn shortest_track_duration(&self) -> f32 {
         let mut shortest_track_duration = f32::INFINITY;
         for track in &self.tracks {
@@ -377,7 +650,7 @@ impl Release {
         false
     }
 
-    /// Writes release downloads (zip archives including track audio files,
+    /// Writes release downloads (archives including track audio files,
     /// covers and extras as well as release cover and extras) and track
     /// downloads (separate track audio files, covers and extras) to the build
     /// directory.
@@ -507,9 +780,13 @@ impl Release {
                     let archives_ref = self.archives.as_ref().unwrap();
                     let mut archives_mut = archives_ref.borrow_mut();
 
-                    // Write zip archive for required format (to cache) if not yet available
+                    // Write archive for required format (to cache) if not yet available
                     if !archives_mut.has(*download_format) {
-                        let cached_archive_filename = format!("{}.zip", util::uid());
+                        let cached_archive_filename = format!(
+                            "{}.{}",
+                            util::uid(),
+                            self.archive_format.extension()
+                        );
 
                         info_zipping!(
                             "Creating download archive for release '{}' ({})",
@@ -517,13 +794,11 @@ impl Release {
                             download_format.as_audio_format()
                         );
 
-                        let zip_file = File::create(build.cache_dir.join(&cached_archive_filename)).unwrap();
-                        let mut zip_writer = ZipWriter::new(zip_file);
-                        let options = SimpleFileOptions::default()
-                            .compression_method(CompressionMethod::Deflated)
-                            .unix_permissions(0o755);
-
-                        let mut buffer = Vec::new();
+                        let mut archive_writer = ArchiveWriter::new(
+                            self.archive_format,
+                            &build.cache_dir.join(&cached_archive_filename),
+                            self.date
+                        );
 
                         let mut used_filenames_release_level = HashSet::new();
 
@@ -560,17 +835,9 @@ impl Release {
                                 extension = download_format.as_audio_format().extension()
                             );
 
-                            zip_writer.start_file(&*filename, options).unwrap();
+                            archive_writer.add_file(&filename, &build.cache_dir.join(&transcode.asset.filename));
                             used_filenames_release_level.insert(filename);
 
-                            let mut zip_inner_file = File::open(
-                                build.cache_dir.join(&transcode.asset.filename)
-                            ).unwrap();
-
-                            zip_inner_file.read_to_end(&mut buffer).unwrap();
-                            zip_writer.write_all(&buffer).unwrap();
-                            buffer.clear();
-
                             track.transcodes.borrow().persist_to_cache(&build.cache_dir);
 
                             // Write track cover and/or extras to a subdirectory named like the track
@@ -584,7 +851,7 @@ impl Release {
                                     basename = track.asset_basename.as_ref().unwrap()
                                 );
 
-                                zip_writer.add_directory(&extra_dirname, options).unwrap();
+                                archive_writer.add_directory(&extra_dirname);
 
                                 // Write track cover
                                 if let Some(described_image) = &mut track.cover {
@@ -600,22 +867,14 @@ impl Release {
                                     // AssetIntent::Intermediate, i.e. immediately
                                     // beginning its decay in the cache for future
                                     // removal.
-                                    let cover_assets = image_mut.cover_assets(build, source_path);
+                                    let cover_assets = image_mut.cover_assets(build, described_image.crop, source_path);
 
                                     let cover_filename = String::from("cover.jpg");
                                     let cover_path = format!("{extra_dirname}/{cover_filename}");
 
-                                    zip_writer.start_file(cover_path, options).unwrap();
+                                    archive_writer.add_file(&cover_path, &build.cache_dir.join(&cover_assets.largest().filename));
                                     used_filenames_track_level.insert(cover_filename);
 
-                                    let mut zip_inner_file = File::open(
-                                        build.cache_dir.join(&cover_assets.largest().filename)
-                                    ).unwrap();
-
-                                    zip_inner_file.read_to_end(&mut buffer).unwrap();
-                                    zip_writer.write_all(&buffer).unwrap();
-                                    buffer.clear();
-
                                     image_mut.persist_to_cache(&build.cache_dir);
                                 }
 
@@ -629,16 +888,8 @@ impl Release {
 
                                         let extra_path = format!("{extra_dirname}/{extra_filename}");
 
-                                        zip_writer.start_file(extra_path, options).unwrap();
+                                        archive_writer.add_file(&extra_path, &build.catalog_dir.join(&extra.file_meta.path));
                                         used_filenames_track_level.insert(extra_filename);
-
-                                        let mut zip_inner_file = File::open(
-                                            build.catalog_dir.join(&extra.file_meta.path)
-                                        ).unwrap();
-
-                                        zip_inner_file.read_to_end(&mut buffer).unwrap();
-                                        zip_writer.write_all(&buffer).unwrap();
-                                        buffer.clear();
                                     }
                                 }
                             }
@@ -658,21 +909,13 @@ impl Release {
                             // AssetIntent::Intermediate, i.e. immediately
                             // beginning its decay in the cache for future
                             // removal.
-                            let cover_assets = image_mut.cover_assets(build, source_path);
+                            let cover_assets = image_mut.cover_assets(build, described_image.crop, source_path);
 
                             let cover_filename = String::from("cover.jpg");
 
-                            zip_writer.start_file(&*cover_filename, options).unwrap();
+                            archive_writer.add_file(&cover_filename, &build.cache_dir.join(&cover_assets.largest().filename));
                             used_filenames_release_level.insert(cover_filename);
 
-                            let mut zip_inner_file = File::open(
-                                build.cache_dir.join(&cover_assets.largest().filename)
-                            ).unwrap();
-
-                            zip_inner_file.read_to_end(&mut buffer).unwrap();
-                            zip_writer.write_all(&buffer).unwrap();
-                            buffer.clear();
-
                             image_mut.persist_to_cache(&build.cache_dir);
                         }
 
@@ -683,20 +926,30 @@ impl Release {
                                     &used_filenames_release_level
                                 );
 
-                                zip_writer.start_file(&*extra_filename, options).unwrap();
+                                archive_writer.add_file(&extra_filename, &build.catalog_dir.join(&extra.file_meta.path));
                                 used_filenames_release_level.insert(extra_filename);
+                            }
+                        }
 
-                                let mut zip_inner_file = File::open(
-                                    build.catalog_dir.join(&extra.file_meta.path)
-                                ).unwrap();
+                        if self.archive_info {
+                            let info_filename = deduplicate_extra_filename(
+                                ARCHIVE_INFO_FILENAME,
+                                &used_filenames_release_level
+                            );
 
-                                zip_inner_file.read_to_end(&mut buffer).unwrap();
-                                zip_writer.write_all(&buffer).unwrap();
-                                buffer.clear();
-                            }
+                            archive_writer.add_bytes(&info_filename, self.archive_info_text(build).as_bytes());
+                        }
+
+                        if let Some(license_text) = self.license_text(build) {
+                            let license_filename = deduplicate_extra_filename(
+                                ARCHIVE_LICENSE_FILENAME,
+                                &used_filenames_release_level
+                            );
+
+                            archive_writer.add_bytes(&license_filename, license_text.as_bytes());
                         }
 
-                        match zip_writer.finish() {
+                        match archive_writer.finish() {
                             Ok(_) => {
                                 let asset = Asset::new(build, cached_archive_filename, AssetIntent::Deliverable);
                                 archives_mut.formats.push(Archive::new(asset, *download_format));
@@ -705,15 +958,16 @@ impl Release {
                         };
                     }
 
-                    // Copy the zip archive (from cache) to the build
+                    // Copy the archive (from cache) to the build
                     let archive_option = archives_mut.get_mut(*download_format);
                     let archive_mut = archive_option.unwrap();
 
                     archive_mut.asset.unmark_stale();
 
                     let archive_filename = format!(
-                        "{basename}.zip",
-                        basename = self.asset_basename.as_ref().unwrap()
+                        "{basename}.{extension}",
+                        basename = self.asset_basename.as_ref().unwrap(),
+                        extension = self.archive_format.extension()
                     );
 
                     let hash = build.hash_with_salt(|hasher| {
@@ -736,6 +990,14 @@ impl Release {
 
                     build.stats.add_archive(archive_mut.asset.filesize_bytes);
 
+                    // Write a checksum file in the format expected by sha256sum
+                    // (i.e. it can be verified with 'sha256sum -c')
+                    if self.archive_checksums {
+                        let checksum = archive_mut.checksum(build);
+                        let checksum_file = format!("{checksum}  {archive_filename}\n");
+                        fs::write(hash_dir.join(format!("{archive_filename}.sha256")), checksum_file).unwrap();
+                    }
+
                     archives_mut.persist_to_cache(&build.cache_dir);
                 }
 
@@ -777,6 +1039,25 @@ impl Release {
         let release_html = render::release::release_html(build, catalog, self);
         util::ensure_dir_all_and_write_index(&release_dir, &release_html);
 
+        // Locked tracks are left out, their waveforms would reveal the
+        // shape of audio that is not published yet.
+        if catalog.waveform_images && self.has_waveform_image() {
+            let transcodes_refs: Vec<_> = self.tracks
+                .iter()
+                .filter(|track| !self.track_locked(track))
+                .map(|track| track.transcodes.borrow())
+                .collect();
+
+            let analyses: Vec<(&WaveformAnalysis, f32)> = transcodes_refs
+                .iter()
+                .map(|transcodes| (&transcodes.source_meta.waveform, transcodes.source_meta.duration_seconds))
+                .collect();
+
+            // Twice the resolution of a single track's analysis, as a
+            // release usually comprises more than one track
+            WaveformAnalysis::concatenate(&analyses, 640).write_images(&release_dir, &self.theme);
+        }
+
         // Render release download/purchase/unlock page
         if !self.download_formats.is_empty() ||
            ((self.extra_downloads.bundled || self.extra_downloads.separate) && !self.extras.is_empty()) {
@@ -867,10 +1148,16 @@ impl Release {
         }
 
         if let Some(cdn_url) = &build.cdn_url {
-            // Render m3u playlist
+            // Render m3u, xspf and jspf playlist
             if self.m3u {
                 let r_m3u = m3u::generate_for_release(cdn_url, build, self);
                 fs::write(release_dir.join(M3U_PLAYLIST_FILENAME), r_m3u).unwrap();
+
+                let r_xspf = xspf::generate_for_release(cdn_url, build, self);
+                fs::write(release_dir.join(XSPF_PLAYLIST_FILENAME), r_xspf).unwrap();
+
+                let r_jspf = jspf::generate_for_release(cdn_url, build, self);
+                fs::write(release_dir.join(JSPF_PLAYLIST_FILENAME), r_jspf).unwrap();
             }
         }
 
@@ -890,7 +1177,7 @@ impl Release {
 
             // Render track embed pages
             for (track, track_number) in self.tracks.iter().zip(TRACK_NUMBERS) {
-                if track.embedding {
+                if track.embedding && !self.track_locked(track) {
                     let track_embed_codes_dir = release_dir.join(track_number.to_string()).join("embed");
                     let track_embed_codes_html = render::track_embed_codes::track_embed_codes_html(base_url, build, catalog, self, track, track_number);
                     util::ensure_dir_all_and_write_index(&track_embed_codes_dir, &track_embed_codes_html);
@@ -904,11 +1191,57 @@ impl Release {
 
         // Render pages for each track
         for (track, track_number) in self.tracks.iter().zip(TRACK_NUMBERS) {
+            if self.track_locked(track) {
+                continue;
+            }
+
             // Render track page
             let track_dir = release_dir.join(track_number.to_string());
             let track_html = render::track::track_html(build, catalog, self, track, track_number);
             util::ensure_dir_all_and_write_index(&track_dir, &track_html);
 
+            if catalog.waveform_images {
+                track.transcodes
+                    .borrow()
+                    .source_meta
+                    .waveform
+                    .write_images(&track_dir, &track.theme);
+            }
+
+            // Write chapter exports
+            if !track.chapters.is_empty() {
+                let podcast_json = Chapter::podcast_json(&track.chapters);
+                fs::write(track_dir.join(PODCAST_CHAPTERS_FILENAME), podcast_json).unwrap();
+
+                // The CUE sheet refers to the file a listener most likely
+                // has at hand: the first download format, or otherwise the
+                // mp3 streamed on the page.
+                let audio_format = match track.download_formats.first() {
+                    Some(download_format) => download_format.as_audio_format(),
+                    None => track.streaming_quality.mp3_format()
+                };
+
+                let filename = format!(
+                    "{basename}{extension}",
+                    basename = track.asset_basename.as_ref().unwrap(),
+                    extension = audio_format.extension()
+                );
+
+                let performer = match track.artists.is_empty() {
+                    true => None,
+                    false => Some(
+                        track.artists
+                            .iter()
+                            .map(|artist| artist.borrow().name.clone())
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )
+                };
+
+                let cue_sheet = Chapter::cue_sheet(&track.chapters, performer.as_deref(), &track.title(), &filename);
+                fs::write(track_dir.join(CUE_SHEET_FILENAME), cue_sheet).unwrap();
+            }
+
             // Render track download/purchase/unlock page
             if !track.download_formats.is_empty() ||
                (track.extra_downloads && !track.extras.is_empty()) {
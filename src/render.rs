@@ -10,27 +10,46 @@ use indoc::{formatdoc, indoc};
 use translations::Translations;
 
 use crate::{
+    Artist,
     ArtistRc,
     Build,
     Catalog,
     DescribedImage,
+    Event,
+    GalleryAssets,
     ImgAttributes,
+    License,
     Link,
+    Price,
+    Product,
     Release,
     ReleaseRc,
-    Track
+    Stock,
+    Track,
+    WaveformColor,
+    WaveformStyle
 };
+use crate::chapter::{CUE_SHEET_FILENAME, PODCAST_CHAPTERS_FILENAME};
 use crate::icons;
 use crate::util::{
     format_bytes,
+    format_time,
     html_double_escape_inside_attribute,
     html_escape_inside_attribute,
-    html_escape_outside_attribute
+    html_escape_outside_attribute,
+    json_escape_inside_string
 };
 
 pub mod artist;
 pub mod image_descriptions;
 pub mod index;
+pub mod merch;
+pub mod news;
+pub mod page;
+pub mod playlist;
+pub mod playlist_embed;
+pub mod playlist_embed_codes;
+pub mod post;
 pub mod release;
 pub mod release_download;
 pub mod release_embed;
@@ -51,6 +70,21 @@ mod layout;
 use embed_layout::EmbedLayout;
 use layout::Layout;
 
+/// Reports clicks on the download links of a download page to the stats
+/// endpoint advertised by the layout (only rendered when the stats_endpoint
+/// option is set). Nothing is declared at the top level, as with soft
+/// navigation the script runs again on every download page visited.
+const DOWNLOAD_STATS_SCRIPT: &str = indoc!(r#"
+    <script>
+        for (const link of document.querySelectorAll('.download_entry a')) {
+            link.addEventListener('click', () => {
+                const endpoint = document.querySelector('meta[name="faircamp-stats-endpoint"]').content;
+                navigator.sendBeacon(new URL('download', endpoint), new URL(link.href).pathname);
+            });
+        }
+    </script>
+"#);
+
 /// Static reusable markup for a speed button we put into the release, track
 /// and embedded players
 pub const SPEED_CONTROLS: &str = indoc!(r#"
@@ -93,25 +127,37 @@ fn artist_image(
 
     let hash = image_ref.hash.as_url_safe_base64();
 
-    let ImgAttributes { src: src_fixed, srcset: srcset_fixed } = image_ref.artist_assets
+    let img_attributes_fixed = image_ref.artist_assets
         .as_ref()
         .unwrap()
         .img_attributes_fixed(&hash, artist_prefix);
 
-    let ImgAttributes { srcset: srcset_fluid, .. } = image_ref.artist_assets
+    let img_attributes_fluid = image_ref.artist_assets
         .as_ref()
         .unwrap()
         .img_attributes_fluid(&hash, artist_prefix);
 
+    // Sources in modern formats (if any) are each placed before the jpeg
+    // source for the same media condition, as the first matching source wins.
+    let sources_fixed_large = img_attributes_fixed.picture_sources(Some("(min-width: 60rem)"), "27rem");
+    let sources_fluid = img_attributes_fluid.picture_sources(Some("(min-width: 30rem)"), "100vw");
+    let sources_fixed_small = img_attributes_fixed.picture_sources(None, "100vw");
+
+    let ImgAttributes { src: src_fixed, srcset: srcset_fixed, .. } = img_attributes_fixed;
+    let ImgAttributes { srcset: srcset_fluid, .. } = img_attributes_fluid;
+
     let poster = formatdoc!(r#"
         <span class="home_image">
             <picture>
+                {sources_fixed_large}
                 <source media="(min-width: 60rem)"
                         sizes="27rem"
                         srcset="{srcset_fixed}" />
+                {sources_fluid}
                 <source media="(min-width: 30rem)"
                         sizes="100vw"
                         srcset="{srcset_fluid}" />
+                {sources_fixed_small}
                 <img
                     {alt}
                     class="home_image"
@@ -129,6 +175,76 @@ fn artist_image(
     }
 }
 
+/// Renders markers for the start of each chapter of a track, placed on
+/// top of the waveform. Returns an empty string if the track has no
+/// chapters.
+fn chapter_markers(track: &Track) -> String {
+    if track.chapters.is_empty() {
+        return String::new();
+    }
+
+    let duration_seconds = track.transcodes.borrow().source_meta.duration_seconds;
+
+    let r_markers = track.chapters
+        .iter()
+        .filter(|chapter| chapter.start_ms > 0)
+        .map(|chapter| {
+            let percentage = chapter.start_seconds() / duration_seconds * 100.0;
+            format!(r#"<span style="left: {percentage:.3}%;"></span>"#)
+        })
+        .collect::<Vec<String>>()
+        .join("");
+
+    format!(r#"<div aria-hidden="true" class="chapter_markers">{r_markers}</div>"#)
+}
+
+/// Renders the list of chapters of a track, each linking to its timecode,
+/// followed by links to the exported chapter files. On the release page
+/// (where `release_track_number` is given) the list is collapsed and links
+/// address the track by its number, on the track page it is expanded.
+/// Returns an empty string if the track has no chapters.
+fn chapters(build: &Build, track: &Track, release_track_number: Option<usize>) -> String {
+    if track.chapters.is_empty() {
+        return String::new();
+    }
+
+    let (open, hash_prefix, file_prefix) = match release_track_number {
+        Some(track_number) => ("", format!("#track={track_number}&time="), format!("{track_number}/")),
+        None => (" open", String::from("#time="), String::new())
+    };
+
+    let r_chapters = track.chapters
+        .iter()
+        .map(|chapter| {
+            let time_param = chapter.time_param();
+            let time_formatted = format_time(chapter.start_seconds());
+            let title_escaped = html_escape_outside_attribute(&chapter.title);
+
+            formatdoc!(r#"
+                <li>
+                    <a href="{hash_prefix}{time_param}"><span class="time">{time_formatted}</span> {title_escaped}</a>
+                </li>
+            "#)
+        })
+        .collect::<Vec<String>>()
+        .join("");
+
+    let t_chapters = &build.locale.translations.chapters;
+
+    formatdoc!(r#"
+        <details class="chapters"{open}>
+            <summary>{t_chapters}</summary>
+            <ol>
+                {r_chapters}
+            </ol>
+            <div class="chapter_exports">
+                <a download href="{file_prefix}{CUE_SHEET_FILENAME}">CUE</a>
+                <a download href="{file_prefix}{PODCAST_CHAPTERS_FILENAME}">JSON</a>
+            </div>
+        </details>
+    "#)
+}
+
 fn compact_release_identifier(
     build: &Build,
     catalog: &Catalog,
@@ -239,23 +355,25 @@ fn cover_tile_image(
 
             let hash = image_ref.hash.as_url_safe_base64();
 
-            let ImgAttributes { src, srcset } = image_ref.cover_assets_unchecked()
+            let img_attributes = image_ref.cover_assets_unchecked()
                 .img_attributes_up_to_320(&hash, release_prefix);
 
             // TODO: Re-evaluate if the 'sizes' attribute still reflects circumstances of the current layout
+            let sizes = "(min-width: 60rem) 20rem, (min-width: 30rem) calc((100vw - 4rem) * 0.333), (min-width: 15rem) calc((100vw - 3rem) * 0.5), calc(100vw - 2rem)";
+            let sources = img_attributes.picture_sources(None, sizes);
+            let ImgAttributes { src, srcset, .. } = img_attributes;
+
             let thumbnail = formatdoc!(r#"
                 <a href="{href}">
-                    <img
-                        {alt}
-                        loading="lazy"
-                        sizes="
-                            (min-width: 60rem) 20rem,
-                            (min-width: 30rem) calc((100vw - 4rem) * 0.333),
-                            (min-width: 15rem) calc((100vw - 3rem) * 0.5),
-                            calc(100vw - 2rem)
-                        "
-                        src="{src}"
-                        srcset="{srcset}">
+                    <picture>
+                        {sources}
+                        <img
+                            {alt}
+                            loading="lazy"
+                            sizes="{sizes}"
+                            src="{src}"
+                            srcset="{srcset}">
+                    </picture>
                 </a>
             "#);
 
@@ -266,7 +384,7 @@ fn cover_tile_image(
             }
         }
         None => {
-            let ImgAttributes { src, srcset } = release.procedural_cover_unchecked()
+            let ImgAttributes { src, srcset, .. } = release.procedural_cover_unchecked()
                 .borrow()
                 .img_attributes_all_sizes(release_prefix);
 
@@ -335,6 +453,189 @@ fn embed_code(embed_url: &str, title: &str) -> (String, String) {
     (copy_code, display_code)
 }
 
+/// A schema.org MusicEvent object (JSON-LD) describing an event
+fn event_json_ld(artist: &Artist, build: &Build, event: &Event) -> String {
+    let artist_name_escaped = json_escape_inside_string(&artist.name);
+    let date = event.date.format("%Y-%m-%d");
+    let name_escaped = json_escape_inside_string(&format!("{} – {}", artist.name, event.venue));
+    let venue_escaped = json_escape_inside_string(&event.venue);
+
+    let mut location_fields = vec![
+        String::from(r#""@type": "Place""#),
+        format!(r#""name": "{venue_escaped}""#)
+    ];
+
+    if let Some(city) = &event.city {
+        location_fields.push(format!(r#""address": "{}""#, json_escape_inside_string(city)));
+    }
+
+    let mut performer_fields = vec![
+        String::from(r#""@type": "MusicGroup""#),
+        format!(r#""name": "{artist_name_escaped}""#)
+    ];
+
+    if artist.featured {
+        if let Some(base_url) = &build.base_url {
+            let artist_url = base_url.join_index(build, &artist.permalink.slug);
+            performer_fields.push(format!(r#""url": "{}""#, json_escape_inside_string(&artist_url)));
+        }
+    }
+
+    let mut fields = vec![
+        String::from(r#""@type": "MusicEvent""#),
+        format!(r#""name": "{name_escaped}""#),
+        format!(r#""startDate": "{date}""#),
+        format!(r#""location": {{{}}}"#, location_fields.join(", ")),
+        format!(r#""performer": {{{}}}"#, performer_fields.join(", "))
+    ];
+
+    if let Some(tickets) = &event.tickets {
+        let tickets_escaped = json_escape_inside_string(tickets);
+        fields.push(format!(r#""offers": {{"@type": "Offer", "url": "{tickets_escaped}"}}"#));
+    }
+
+    format!("{{{}}}", fields.join(", "))
+}
+
+/// Lists the given events, upcoming ones first (earliest first), past
+/// ones collapsed into an archive below (latest first). Returns a tuple
+/// with the rendered markup and the schema.org MusicEvent objects for the
+/// events (intended for [Layout::add_json_ld]). The artist performing at
+/// an event is only named when show_artists is true (i.e. in a listing
+/// that can contain events of multiple artists).
+fn events(
+    build: &Build,
+    calendar_href: &str,
+    entries: &[(&Artist, &Event)],
+    root_prefix: &str,
+    show_artists: bool
+) -> (String, Vec<String>) {
+    let index_suffix = build.index_suffix();
+    let translations = &build.locale.translations;
+
+    let render_entry = |artist: &Artist, event: &Event| -> String {
+        let date = event.date.format("%Y-%m-%d");
+        let location_escaped = html_escape_outside_attribute(&event.location());
+
+        let r_artist = if show_artists {
+            let name_escaped = html_escape_outside_attribute(&artist.name);
+
+            if let Some(external_page) = &artist.external_page {
+                let external_page_escaped = html_escape_inside_attribute(external_page);
+                format!(r#"<a class="event_artist" href="{external_page_escaped}" target="_blank">{name_escaped}</a>"#)
+            } else if artist.featured {
+                let artist_slug = &artist.permalink.slug;
+                format!(r#"<a class="event_artist" href="{root_prefix}{artist_slug}{index_suffix}">{name_escaped}</a>"#)
+            } else {
+                format!(r#"<span class="event_artist">{name_escaped}</span>"#)
+            }
+        } else {
+            String::new()
+        };
+
+        let r_tickets = match &event.tickets {
+            Some(tickets) if !event.is_past(build) => {
+                let t_tickets = &translations.tickets;
+                let tickets_escaped = html_escape_inside_attribute(tickets);
+                format!(r#"<a class="tickets" href="{tickets_escaped}" target="_blank">{t_tickets}</a>"#)
+            }
+            _ => String::new()
+        };
+
+        formatdoc!(r#"
+            <li>
+                <time datetime="{date}">{date}</time>
+                {r_artist}
+                <span class="event_location">{location_escaped}</span>
+                {r_tickets}
+            </li>
+        "#)
+    };
+
+    let (past, upcoming): (Vec<&(&Artist, &Event)>, Vec<&(&Artist, &Event)>) = entries
+        .iter()
+        .partition(|(_, event)| event.is_past(build));
+
+    let r_upcoming = if upcoming.is_empty() {
+        String::new()
+    } else {
+        let t_upcoming_events = &translations.upcoming_events;
+        let r_entries = upcoming
+            .iter()
+            .map(|&&(artist, event)| render_entry(artist, event))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        formatdoc!(r#"
+            <h2>{t_upcoming_events}</h2>
+            <ul class="events_list">
+                {r_entries}
+            </ul>
+        "#)
+    };
+
+    let r_past = if past.is_empty() {
+        String::new()
+    } else {
+        let t_past_events = &translations.past_events;
+        let r_entries = past
+            .iter()
+            .rev()
+            .map(|&&(artist, event)| render_entry(artist, event))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        formatdoc!(r#"
+            <details class="past_events">
+                <summary>{t_past_events}</summary>
+                <ul class="events_list">
+                    {r_entries}
+                </ul>
+            </details>
+        "#)
+    };
+
+    let calendar_icon = icons::feed(&translations.feed);
+    let t_subscribe_to_calendar = &translations.subscribe_to_calendar;
+
+    let r_events = formatdoc!(r#"
+        <div class="page">
+            <div class="page_center">
+                <div class="events">
+                    {r_upcoming}
+                    {r_past}
+                    <a class="calendar" href="{calendar_href}">
+                        {calendar_icon}
+                        <span>{t_subscribe_to_calendar}</span>
+                    </a>
+                </div>
+            </div>
+        </div>
+    "#);
+
+    let json_ld = entries
+        .iter()
+        .map(|&(artist, event)| event_json_ld(artist, build, event))
+        .collect();
+
+    (r_events, json_ld)
+}
+
+/// Markup for the license badge shown on release and track pages, linking
+/// to the legal text of the license.
+fn license_badge(build: &Build, license: &License) -> String {
+    let t_license = &build.locale.translations.license;
+    let label_escaped = html_escape_outside_attribute(&license.label);
+    let url_escaped = html_escape_inside_attribute(&license.url);
+
+    formatdoc!(r#"
+        <div class="license">
+            <span>{t_license}:</span>
+            <a href="{url_escaped}" rel="license" target="_blank">{label_escaped}</a>
+        </div>
+    "#)
+}
+
 /// Generic link with icon as we render it in the "actions" section on various
 /// pages
 fn link_action(link: &Link, translations: &Translations) -> String {
@@ -528,6 +829,65 @@ fn list_track_artists(
 
 /// These are rendered alongside the release player and provide prepared and translated
 /// icons for the client side script to use.
+/// Attributes for a track's container from which player.js builds the
+/// metadata shown by the operating system's media controls (Media Session
+/// API). The artwork is given in srcset syntax, listing all available sizes
+/// of the track's cover (or the release cover, if the track has none).
+fn media_session_attributes(
+    release: &Release,
+    release_prefix: &str,
+    track: &Track,
+    track_prefix: &str
+) -> String {
+    let album_escaped = html_escape_inside_attribute(&release.title);
+
+    let artist = track.artists
+        .iter()
+        .map(|artist| artist.borrow().name.clone())
+        .collect::<Vec<String>>()
+        .join(", ");
+    let artist_escaped = html_escape_inside_attribute(&artist);
+
+    let cover_srcset = |described_image: &DescribedImage, prefix: &str| {
+        let image_ref = described_image.borrow();
+        let hash = image_ref.hash.as_url_safe_base64();
+
+        image_ref.cover_assets_unchecked().img_attributes_up_to_1280(&hash, prefix).srcset
+    };
+
+    let artwork = if let Some(described_image) = &track.cover {
+        cover_srcset(described_image, track_prefix)
+    } else if let Some(described_image) = &release.cover {
+        cover_srcset(described_image, release_prefix)
+    } else {
+        release.procedural_cover
+            .as_ref()
+            .unwrap()
+            .borrow()
+            .img_attributes_all_sizes(release_prefix)
+            .srcset
+    };
+    let artwork_escaped = html_escape_inside_attribute(&artwork);
+
+    format!(r#"data-album="{album_escaped}" data-artist="{artist_escaped}" data-artwork="{artwork_escaped}""#)
+}
+
+/// Rendered hidden, offline.js reveals the button if the browser supports
+/// service workers, and also keeps its label in line with whether the
+/// release is currently saved.
+fn offline_button(translations: &Translations) -> String {
+    let download_icon = icons::DOWNLOAD;
+    let t_remove_offline_copy = html_escape_inside_attribute(&translations.remove_offline_copy);
+    let t_save_for_offline = html_escape_inside_attribute(&translations.save_for_offline);
+
+    formatdoc!(r#"
+        <button class="save_offline" data-remove-label="{t_remove_offline_copy}" data-save-label="{t_save_for_offline}" hidden>
+            <span class="icon">{download_icon}</span>
+            <span class="label">{t_save_for_offline}</span>
+        </button>
+    "#)
+}
+
 pub fn player_icon_templates(translations: &Translations) -> String {
     let pause_icon = icons::pause(&translations.pause);
     let play_icon = icons::play(&translations.play);
@@ -546,6 +906,151 @@ pub fn player_icon_templates(translations: &Translations) -> String {
     "#)
 }
 
+/// Human-readable price of a product, e.g. "€25 EUR" or "€10+ EUR"
+fn product_price(build: &Build, price: &Price) -> String {
+    let currency_code = price.currency.code();
+    let currency_symbol = price.currency.symbol();
+    let range = &price.range;
+
+    if range.end == f32::INFINITY {
+        let minimum = format!("{currency_symbol}{} {currency_code}", range.start);
+        build.locale.translations.xxx_or_more(&minimum)
+    } else if range.start == range.end {
+        format!("{currency_symbol}{} {currency_code}", range.start)
+    } else {
+        format!("{currency_symbol}{}-{} {currency_code}", range.start, range.end)
+    }
+}
+
+/// Lists physical products with their image (if any), format, price, stock
+/// status and a link to their external checkout page. The images are
+/// expected in the directory dir_slug (relative to the site root), they
+/// are written there by the catalog (see write_product_images). Returns a
+/// tuple with the rendered markup and schema.org Product objects for the
+/// products (intended for [Layout::add_json_ld]), for which name_prefix
+/// (e.g. the release title) is prepended to the format of each product.
+fn products(
+    build: &Build,
+    dir_slug: &str,
+    name_prefix: &str,
+    products: &[Product],
+    root_prefix: &str
+) -> (String, Vec<String>) {
+    let translations = &build.locale.translations;
+
+    let mut json_ld = Vec::with_capacity(products.len());
+    let mut r_products = Vec::with_capacity(products.len());
+
+    for (product, number) in products.iter().zip(1..) {
+        let format_escaped = html_escape_outside_attribute(&product.format);
+        let product_name = format!("{name_prefix} – {}", product.format);
+        let stock_label = product.stock.label(translations);
+
+        let r_image = match &product.image {
+            Some(described_image) => {
+                let image_ref = described_image.borrow();
+                let gallery_assets = image_ref.gallery_assets_unchecked();
+                let hash = image_ref.hash.as_url_safe_base64();
+                let alt = html_escape_inside_attribute(&product.format);
+
+                let img_attributes = gallery_assets.product_img_attributes(&hash, number, &format!("{root_prefix}{dir_slug}/"));
+                let sizes = "10rem";
+                let sources = img_attributes.picture_sources(None, sizes);
+                let ImgAttributes { src, srcset, .. } = img_attributes;
+                let edge_size = gallery_assets.thumbnail.width;
+
+                formatdoc!(r#"
+                    <picture>
+                        {sources}
+                        <img
+                            alt="{alt}"
+                            height="{edge_size}"
+                            loading="lazy"
+                            sizes="{sizes}"
+                            src="{src}"
+                            srcset="{srcset}"
+                            width="{edge_size}">
+                    </picture>
+                "#)
+            }
+            None => String::new()
+        };
+
+        let r_price = match &product.price {
+            Some(price) => {
+                let price_formatted = product_price(build, price);
+                format!(r#"<span class="product_price">{price_formatted}</span>"#)
+            }
+            None => String::new()
+        };
+
+        let r_checkout = if product.stock == Stock::SoldOut {
+            String::new()
+        } else {
+            let checkout_escaped = html_escape_inside_attribute(&product.checkout);
+            let t_buy = &translations.buy;
+            format!(r#"<a class="product_checkout" href="{checkout_escaped}" target="_blank">{t_buy}</a>"#)
+        };
+
+        r_products.push(formatdoc!(r#"
+            <div class="product">
+                {r_image}
+                <div class="product_info">
+                    <span class="product_format">{format_escaped}</span>
+                    {r_price}
+                    <span class="product_stock">{stock_label}</span>
+                    {r_checkout}
+                </div>
+            </div>
+        "#));
+
+        let mut offer_fields = vec![
+            String::from(r#""@type": "Offer""#),
+            format!(r#""availability": "{}""#, product.stock.schema_availability()),
+            format!(r#""url": "{}""#, json_escape_inside_string(&product.checkout))
+        ];
+
+        if let Some(price) = &product.price {
+            offer_fields.push(format!(r#""price": "{}""#, price.range.start));
+            offer_fields.push(format!(r#""priceCurrency": "{}""#, price.currency.code()));
+        }
+
+        let mut fields = vec![
+            String::from(r#""@type": "Product""#),
+            format!(r#""name": "{}""#, json_escape_inside_string(&product_name)),
+            format!(r#""offers": {{{}}}"#, offer_fields.join(", "))
+        ];
+
+        if product.image.is_some() {
+            if let Some(base_url) = &build.base_url {
+                let image_url = base_url.join_file(format!("{dir_slug}/{}", GalleryAssets::product_target_filename(number)));
+                fields.push(format!(r#""image": "{}""#, json_escape_inside_string(&image_url)));
+            }
+        }
+
+        json_ld.push(format!("{{{}}}", fields.join(", ")));
+    }
+
+    let r_products = r_products.join("\n");
+
+    (format!(r#"<div class="products">{r_products}</div>"#), json_ld)
+}
+
+/// With soft navigation enabled, track rows and release tiles carry these
+/// buttons, through which listeners can line up music in their own queue
+/// (managed client-side by navigation.js, played back by player.js).
+fn queue_buttons(translations: &Translations) -> String {
+    let t_add_to_queue = &translations.add_to_queue;
+    let t_play_next = &translations.play_next;
+
+    formatdoc!(r#"
+        <span class="queue">
+            <button data-queue="next">{t_play_next}</button>
+            <button data-queue="add">{t_add_to_queue}</button>
+        </span>
+    "#)
+}
+
 /// Used on release/tracks pages to display a large-size cover for the release
 fn release_cover_image(
     build: &Build,
@@ -564,25 +1069,33 @@ fn release_cover_image(
 
             let hash = image_ref.hash.as_url_safe_base64();
 
-            let ImgAttributes { src: thumb_src, srcset: thumb_srcset } = image_ref.cover_assets
+            let thumb_img_attributes = image_ref.cover_assets
                 .as_ref()
                 .unwrap()
                 .img_attributes_up_to_480(&hash, release_prefix);
 
+            let thumb_sizes = "(min-width: 20rem) 20rem, calc(100vw - 2rem)";
+            let thumb_sources = thumb_img_attributes.picture_sources(None, thumb_sizes);
+            let ImgAttributes { src: thumb_src, srcset: thumb_srcset, .. } = thumb_img_attributes;
+
             let thumbnail = formatdoc!(r#"
                 <a class="image" href="{thumb_src}" target="_blank">
-                    <img
-                        {alt}
-                        sizes="(min-width: 20rem) 20rem, calc(100vw - 2rem)"
-                        src="{thumb_src}"
-                        srcset="{thumb_srcset}">
+                    <picture>
+                        {thumb_sources}
+                        <img
+                            {alt}
+                            sizes="{thumb_sizes}"
+                            src="{thumb_src}"
+                            srcset="{thumb_srcset}">
+                    </picture>
                 </a>
             "#);
 
             let cover_ref = image_ref.cover_assets_unchecked();
 
-            let ImgAttributes { src: overlay_src, srcset: overlay_srcset } = cover_ref
-                .img_attributes_up_to_1280(&hash, release_prefix);
+            let overlay_img_attributes = cover_ref.img_attributes_up_to_1280(&hash, release_prefix);
+            let overlay_sources = overlay_img_attributes.picture_sources(None, "calc(100vmin - 4rem)");
+            let ImgAttributes { src: overlay_src, srcset: overlay_srcset, .. } = overlay_img_attributes;
 
             let largest_edge_size = cover_ref.largest().edge_size;
 
@@ -592,14 +1105,17 @@ fn release_cover_image(
                     <form method="dialog">
                         <button aria-label="{t_close}"></button>
                     </form>
-                    <img
-                        {alt}
-                        height="{largest_edge_size}"
-                        loading="lazy"
-                        sizes="calc(100vmin - 4rem)"
-                        src="{overlay_src}"
-                        srcset="{overlay_srcset}"
-                        width="{largest_edge_size}">
+                    <picture>
+                        {overlay_sources}
+                        <img
+                            {alt}
+                            height="{largest_edge_size}"
+                            loading="lazy"
+                            sizes="calc(100vmin - 4rem)"
+                            src="{overlay_src}"
+                            srcset="{overlay_srcset}"
+                            width="{largest_edge_size}">
+                    </picture>
                 </dialog>
                 <script>
                     const overlay = document.querySelector('dialog#overlay');
@@ -626,7 +1142,7 @@ fn release_cover_image(
             }
         }
         None => {
-            let ImgAttributes { src, srcset } = release.procedural_cover
+            let ImgAttributes { src, srcset, .. } = release.procedural_cover
                 .as_ref()
                 .unwrap()
                 .borrow()
@@ -717,6 +1233,12 @@ fn releases(
             );
             let release_title_escaped = html_escape_outside_attribute(&release_ref.title);
 
+            let r_queue_buttons = if catalog.soft_navigation {
+                queue_buttons(&build.locale.translations)
+            } else {
+                String::new()
+            };
+
             formatdoc!(r#"
                 <div class="release">
                     {cover}
@@ -724,6 +1246,7 @@ fn releases(
                         {release_title_escaped}
                     </a>
                     {artists}
+                    {r_queue_buttons}
                 </div>
             "#)
         })
@@ -747,25 +1270,33 @@ fn track_cover_image(
 
     let hash = image_ref.hash.as_url_safe_base64();
 
-    let ImgAttributes { src: thumb_src, srcset: thumb_srcset } = image_ref.cover_assets
+    let thumb_img_attributes = image_ref.cover_assets
         .as_ref()
         .unwrap()
         .img_attributes_up_to_480(&hash, track_prefix);
 
+    let thumb_sizes = "(min-width: 20rem) 20rem, calc(100vw - 2rem)";
+    let thumb_sources = thumb_img_attributes.picture_sources(None, thumb_sizes);
+    let ImgAttributes { src: thumb_src, srcset: thumb_srcset, .. } = thumb_img_attributes;
+
     let thumbnail = formatdoc!(r#"
         <a class="image" href="{thumb_src}" target="_blank">
-            <img
-                {alt}
-                sizes="(min-width: 20rem) 20rem, calc(100vw - 2rem)"
-                src="{thumb_src}"
-                srcset="{thumb_srcset}">
+            <picture>
+                {thumb_sources}
+                <img
+                    {alt}
+                    sizes="{thumb_sizes}"
+                    src="{thumb_src}"
+                    srcset="{thumb_srcset}">
+            </picture>
         </a>
     "#);
 
     let cover_ref = image_ref.cover_assets_unchecked();
 
-    let ImgAttributes { src: overlay_src, srcset: overlay_srcset } = cover_ref
-        .img_attributes_up_to_1280(&hash, track_prefix);
+    let overlay_img_attributes = cover_ref.img_attributes_up_to_1280(&hash, track_prefix);
+    let overlay_sources = overlay_img_attributes.picture_sources(None, "calc(100vmin - 4rem)");
+    let ImgAttributes { src: overlay_src, srcset: overlay_srcset, .. } = overlay_img_attributes;
 
     let largest_edge_size = cover_ref.largest().edge_size;
 
@@ -775,14 +1306,17 @@ fn track_cover_image(
             <form method="dialog">
                 <button aria-label="{t_close}"></button>
             </form>
-            <img
-                {alt}
-                height="{largest_edge_size}"
-                loading="lazy"
-                sizes="calc(100vmin - 4rem)"
-                src="{overlay_src}"
-                srcset="{overlay_srcset}"
-                width="{largest_edge_size}">
+            <picture>
+                {overlay_sources}
+                <img
+                    {alt}
+                    height="{largest_edge_size}"
+                    loading="lazy"
+                    sizes="calc(100vmin - 4rem)"
+                    src="{overlay_src}"
+                    srcset="{overlay_srcset}"
+                    width="{largest_edge_size}">
+            </picture>
         </dialog>
         <script>
             const overlay = document.querySelector('dialog#overlay');
@@ -941,10 +1475,71 @@ fn volume_controls(translations: &Translations) -> String {
     "#)
 }
 
+/// Renders the svg (without any paths computed yet) and the data from which
+/// player.js draws the waveform, according to the waveform style and color
+/// configured in the track's theme.
 fn waveform(track: &Track) -> String {
-    let peaks_base64 = track.transcodes.borrow().source_meta.peaks
+    let transcodes_ref = track.transcodes.borrow();
+    let source_meta = &transcodes_ref.source_meta;
+
+    let data_brightness = match track.theme.waveform_color {
+        WaveformColor::Spectral => {
+            let brightness_encoded = waveform_encode(&source_meta.waveform.brightness);
+            format!(r#" data-brightness="{brightness_encoded}""#)
+        }
+        WaveformColor::Theme => String::new()
+    };
+
+    match track.theme.waveform_style {
+        WaveformStyle::Line => {
+            let peaks_encoded = waveform_encode(&source_meta.peaks);
+
+            formatdoc!(r#"
+                <svg data-peaks="{peaks_encoded}"{data_brightness}>
+                    <path class="seek"/>
+                    <path class="playback"/>
+                    <path class="base"/>
+                </svg>
+            "#)
+        }
+        WaveformStyle::Mirrored |
+        WaveformStyle::Stereo => {
+            let style = &track.theme.waveform_style;
+            let style_key = style.to_key();
+            let (upper_peaks, upper_rms, lower_peaks, lower_rms) = source_meta.waveform.lanes(style);
+
+            let peaks_encoded = waveform_encode(&upper_peaks);
+            let rms_encoded = waveform_encode(&upper_rms);
+
+            // For the mirrored style the lower lane is identical to the
+            // upper one, player.js falls back to it if it's not provided.
+            let data_lower = match style {
+                WaveformStyle::Stereo => {
+                    let peaks_lower_encoded = waveform_encode(&lower_peaks);
+                    let rms_lower_encoded = waveform_encode(&lower_rms);
+                    format!(r#" data-peaks-lower="{peaks_lower_encoded}" data-rms-lower="{rms_lower_encoded}""#)
+                }
+                _ => String::new()
+            };
+
+            formatdoc!(r#"
+                <svg data-peaks="{peaks_encoded}" data-rms="{rms_encoded}" data-style="{style_key}"{data_lower}{data_brightness}>
+                    <path class="base"/>
+                    <path class="rms"/>
+                    <path class="seek"/>
+                    <path class="playback"/>
+                </svg>
+            "#)
+        }
+    }
+}
+
+/// Encodes a sequence of values in the range 0-1 as a string with one
+/// (custom alphabet) base64 character per value (decoded in player.js)
+fn waveform_encode(values: &[f32]) -> String {
+    values
         .iter()
-        .map(|peak| {
+        .map(|value| {
             // In https://codeberg.org/simonrepp/faircamp/issues/11#issuecomment-858690
             // the "_ => unreachable!()" branch below was hit, probably due to a slight
             // peak overshoot > 1.0 (1.016 already leads to peak64 being assigned 64).
@@ -963,14 +1558,14 @@ fn waveform(track: &Track) -> String {
             //   versions, so all peaks are correctly recalculated for everyone then.
             // - Then also remove this peak_limited correction and rely on the raw
             //   value again.
-            let peak_limited = if *peak > 1.0 { 1.0 } else { *peak };
+            let value_limited = if *value > 1.0 { 1.0 } else { *value };
 
             // Limit range to 0-63
-            let peak64 = ((peak_limited / 1.0) * 63.0) as u8;
-            let base64 = match peak64 {
-                0..=25 => (peak64 + 65) as char, // shift to 65-90 (A-Z)
-                26..=51 => (peak64 + 71) as char, // shift to 97-122 (a-z)
-                52..=61 => (peak64 - 4) as char, // shift to 48-57 (0-9)
+            let value64 = ((value_limited / 1.0) * 63.0) as u8;
+            let base64 = match value64 {
+                0..=25 => (value64 + 65) as char, // shift to 65-90 (A-Z)
+                26..=51 => (value64 + 71) as char, // shift to 97-122 (a-z)
+                52..=61 => (value64 - 4) as char, // shift to 48-57 (0-9)
                 62 => '+', // map to 43 (+)
                 63 => '/', // map to 48 (/)
                 _ => unreachable!()
@@ -978,15 +1573,7 @@ fn waveform(track: &Track) -> String {
             base64.to_string()
         })
         .collect::<Vec<String>>()
-        .join("");
-
-    formatdoc!(r#"
-        <svg data-peaks="{peaks_base64}">
-            <path class="seek"/>
-            <path class="playback"/>
-            <path class="base"/>
-        </svg>
-    "#)
+        .join("")
 }
 
 fn wrap_undescribed_image(
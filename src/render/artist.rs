@@ -7,6 +7,7 @@ use crate::{
     Artist,
     Build,
     Catalog,
+    ICS_CALENDAR_FILENAME,
     OpenGraphMeta
 };
 use crate::icons;
@@ -16,6 +17,7 @@ use super::Layout;
 use super::{
     artist_image,
     copy_button,
+    events,
     link_action,
     releases,
     unlisted_badge
@@ -118,6 +120,27 @@ pub fn artist_html(artist: &Artist, build: &Build, catalog: &Catalog) -> String
         &public_releases
     );
 
+    let r_events = if artist.events.is_empty() {
+        String::new()
+    } else {
+        let entries = artist.events
+            .iter()
+            .map(|event| (artist, event))
+            .collect::<Vec<_>>();
+
+        let (r_events, json_ld) = events(
+            build,
+            ICS_CALENDAR_FILENAME,
+            &entries,
+            root_prefix,
+            false
+        );
+
+        layout.add_json_ld(json_ld);
+
+        r_events
+    };
+
     let synopsis = match &artist.synopsis {
         Some(synopsis) => {
             formatdoc!(r#"
@@ -147,6 +170,7 @@ pub fn artist_html(artist: &Artist, build: &Build, catalog: &Catalog) -> String
                 </div>
             </div>
         </div>
+        {r_events}
         {r_more}
     "##);
 
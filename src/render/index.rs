@@ -3,7 +3,7 @@
 
 use indoc::formatdoc;
 
-use crate::M3U_PLAYLIST_FILENAME;
+use crate::{ICS_CALENDAR_FILENAME, JSPF_PLAYLIST_FILENAME, M3U_PLAYLIST_FILENAME, XSPF_PLAYLIST_FILENAME};
 use crate::{
     Build,
     Catalog,
@@ -15,6 +15,7 @@ use super::Layout;
 use super::{
     artist_image,
     copy_button,
+    events,
     link_action,
     releases
 };
@@ -132,6 +133,26 @@ pub fn index_html(build: &Build, catalog: &Catalog) -> String {
             "#);
 
             actions.push(m3u_playlist_link);
+
+            let t_xspf_playlist = &translations.xspf_playlist;
+            let xspf_playlist_link = formatdoc!(r#"
+                <a href="{XSPF_PLAYLIST_FILENAME}">
+                    {stream_icon}
+                    <span>{t_xspf_playlist}</span>
+                </a>
+            "#);
+
+            actions.push(xspf_playlist_link);
+
+            let t_jspf_playlist = &translations.jspf_playlist;
+            let jspf_playlist_link = formatdoc!(r#"
+                <a href="{JSPF_PLAYLIST_FILENAME}">
+                    {stream_icon}
+                    <span>{t_jspf_playlist}</span>
+                </a>
+            "#);
+
+            actions.push(jspf_playlist_link);
         }
     }
 
@@ -161,6 +182,38 @@ pub fn index_html(build: &Build, catalog: &Catalog) -> String {
         &public_releases
     );
 
+    let event_artists = catalog.event_artists();
+
+    let r_events = if event_artists.is_empty() {
+        String::new()
+    } else {
+        let artist_refs = event_artists
+            .iter()
+            .map(|artist| artist.borrow())
+            .collect::<Vec<_>>();
+
+        let mut entries = artist_refs
+            .iter()
+            .flat_map(|artist_ref| artist_ref.events.iter().map(move |event| (&**artist_ref, event)))
+            .collect::<Vec<_>>();
+
+        entries.sort_by(|a, b| a.1.date.cmp(&b.1.date));
+
+        // In artist mode all events are by the catalog artist, naming
+        // them for each event would be redundant.
+        let (r_events, json_ld) = events(
+            build,
+            ICS_CALENDAR_FILENAME,
+            &entries,
+            root_prefix,
+            catalog.label_mode
+        );
+
+        layout.add_json_ld(json_ld);
+
+        r_events
+    };
+
     let synopsis = match &catalog.synopsis {
         Some(synopsis) => {
             formatdoc!(r#"
@@ -190,6 +243,7 @@ pub fn index_html(build: &Build, catalog: &Catalog) -> String {
                 </div>
             </div>
         </div>
+        {r_events}
         {r_more}
     "#);
 
@@ -11,11 +11,12 @@ use crate::{
     OpenGraphMeta,
     Theme
 };
-use crate::icons;
+use crate::{activitypub, icons};
 use crate::util::{
     html_escape_inside_attribute,
     html_escape_outside_attribute
 };
+use crate::web_app::WEB_APP_MANIFEST_FILENAME;
 
 use super::player_icon_templates;
 
@@ -25,6 +26,12 @@ pub struct Layout {
     /// page and append templates for icons (copy/failed/success) used at
     /// runtime to the end of the page.
     clipboard_script: bool,
+    /// Structured data (schema.org objects in JSON-LD notation) that is
+    /// injected into the head of the page as a single graph.
+    json_ld: Vec<String>,
+    /// If set we link to the legal text of the license that the content on
+    /// the page is published under (rel="license").
+    license_url: Option<String>,
     /// If true we inject noindex/nofollow meta into the head of the page
     no_indexing: bool,
     opengraph_meta: Option<OpenGraphMeta>,
@@ -178,6 +185,14 @@ impl Layout {
         self.clipboard_script = true;
     }
 
+    pub fn add_json_ld(&mut self, json_ld: Vec<String>) {
+        self.json_ld.extend(json_ld);
+    }
+
+    pub fn add_license_link(&mut self, url: &str) {
+        self.license_url = Some(url.to_string());
+    }
+
     pub fn add_opengraph_meta(&mut self, opengraph_meta: OpenGraphMeta) {
         self.opengraph_meta = Some(opengraph_meta);
     }
@@ -190,6 +205,8 @@ impl Layout {
         Layout {
             breadcrumb: None,
             clipboard_script: false,
+            json_ld: Vec::new(),
+            license_url: None,
             no_indexing: false,
             opengraph_meta: None,
             player_script: false
@@ -228,6 +245,11 @@ impl Layout {
             add_extra_meta(&feed_tags);
         }
 
+        if build.base_url.is_some() && catalog.activitypub.is_some() {
+            let actor_path = format!("{}/{}", activitypub::ACTIVITYPUB_DIR, activitypub::ACTOR_FILENAME);
+            add_extra_meta(&format!(r#"<link rel="alternate" type="application/activity+json" href="{root_prefix}{actor_path}">"#));
+        }
+
         let dir_attribute = if build.locale.text_direction.is_rtl() { r#"dir="rtl""# } else { "" };
 
         let faircamp_signature = if catalog.faircamp_signature {
@@ -286,11 +308,53 @@ impl Layout {
             templates.push_str(&player_icon_templates(translations));
         }
 
+        if catalog.soft_navigation {
+            let navigation_js_hash = AssetHashes::NAVIGATION_JS;
+            let navigation_script_tag = format!(r#"<script defer src="{root_prefix}navigation.js?{navigation_js_hash}"></script>"#);
+
+            add_extra_meta(&navigation_script_tag);
+        }
+
+        if catalog.web_app {
+            let offline_js_hash = AssetHashes::OFFLINE_JS;
+            let theme_color = theme.background_hex();
+
+            add_extra_meta(&formatdoc!(r#"
+                <link href="{root_prefix}{WEB_APP_MANIFEST_FILENAME}" rel="manifest">
+                <meta name="theme-color" content="{theme_color}">
+                <script defer src="{root_prefix}offline.js?{offline_js_hash}"></script>
+            "#));
+        }
+
+        if !self.json_ld.is_empty() {
+            // Escaping "</" prevents a "</script>" inside a string from
+            // prematurely closing the script element.
+            let graph = self.json_ld.join(",\n").replace("</", "<\\/");
+
+            add_extra_meta(&formatdoc!(r#"
+                <script type="application/ld+json">
+                    {{"@context": "https://schema.org", "@graph": [{graph}]}}
+                </script>
+            "#));
+        }
+
+        // Picked up by player.js and the download pages to know where
+        // plays and downloads are reported to.
+        if let Some(stats_endpoint) = &catalog.stats_endpoint {
+            let stats_endpoint_escaped = html_escape_inside_attribute(stats_endpoint.prefix());
+            add_extra_meta(&format!(r#"<meta name="faircamp-stats-endpoint" content="{stats_endpoint_escaped}">"#));
+        }
+
         let browse_icon = icons::BROWSE;
         let browser_js_hash = build.asset_hashes.browser_js.as_ref().unwrap();
         let catalog_title = html_escape_outside_attribute(&catalog.title());
 
 
+        if let Some(license_url) = &self.license_url {
+            let url_escaped = html_escape_inside_attribute(license_url);
+            add_extra_meta(&format!(r#"<link href="{url_escaped}" rel="license">"#));
+        }
+
         if self.no_indexing {
             add_extra_meta(r#"<meta name="robots" content="noindex, nofollow">"#);
         }
@@ -332,6 +396,39 @@ impl Layout {
             add_extra_meta(&site_metadata.render(root_prefix));
         }
 
+        let menu_links = catalog.pages
+            .iter()
+            .filter(|page| page.menu)
+            .map(|page| {
+                let page_slug = &page.permalink.slug;
+                let title_escaped = html_escape_outside_attribute(&page.title);
+                format!(r#"<a href="{root_prefix}{page_slug}{index_suffix}">{title_escaped}</a>"#)
+            })
+            .collect::<Vec<String>>();
+
+        let r_menu = if menu_links.is_empty() {
+            String::new()
+        } else {
+            let joined = menu_links.join("\n");
+            format!(r#"<nav class="menu">{joined}</nav>"#)
+        };
+
+        let merch_link = match &catalog.merch_permalink {
+            Some(merch_slug) => {
+                let t_merch = &translations.merch;
+                format!(r#"<a class="merch" href="{root_prefix}{merch_slug}{index_suffix}">{t_merch}</a>"#)
+            }
+            None => String::new()
+        };
+
+        let news_link = match &catalog.news_permalink {
+            Some(news_slug) => {
+                let t_news = &translations.news;
+                format!(r#"<a class="news" href="{root_prefix}{news_slug}{index_suffix}">{t_news}</a>"#)
+            }
+            None => String::new()
+        };
+
         let subscribe_link = if catalog.feeds.any_requested() {
             let t_subscribe = &translations.subscribe;
             let feed_icon = icons::feed(&translations.feed);
@@ -373,6 +470,7 @@ impl Layout {
                                 </a>
                                 {breadcrumb}
                             </div>
+                            {r_menu}
                             <button class="browse">
                                 {t_browse}
                                 {browse_icon}
@@ -385,6 +483,8 @@ impl Layout {
                             <span>
                                 <a href="{root_prefix}">{catalog_title}</a>
                                 <button class="browse">{browse_icon} {t_browse}</button>
+                                {merch_link}
+                                {news_link}
                                 {subscribe_link}
                             </span>
                             {faircamp_signature}
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use indoc::formatdoc;
+
+use crate::{Build, Catalog};
+use crate::util::html_escape_outside_attribute;
+
+use super::Layout;
+use super::products;
+
+/// The merch page, listing the products of the catalog itself, followed by
+/// the products of each (public) release.
+pub fn merch_html(build: &Build, catalog: &Catalog) -> String {
+    let index_suffix = build.index_suffix();
+    let merch_slug = catalog.merch_permalink.as_ref().unwrap();
+    let root_prefix = "../";
+    let translations = &build.locale.translations;
+
+    let mut layout = Layout::new();
+
+    let t_merch = &translations.merch;
+
+    let mut sections = Vec::new();
+
+    if !catalog.products.is_empty() {
+        let (r_products, json_ld) = products(
+            build,
+            merch_slug,
+            &catalog.title(),
+            &catalog.products,
+            root_prefix
+        );
+
+        layout.add_json_ld(json_ld);
+        sections.push(r_products);
+    }
+
+    for release in catalog.public_releases() {
+        let release_ref = release.borrow();
+
+        if release_ref.products.is_empty() { continue; }
+
+        let (r_products, json_ld) = products(
+            build,
+            &release_ref.permalink.slug,
+            &release_ref.title,
+            &release_ref.products,
+            root_prefix
+        );
+
+        layout.add_json_ld(json_ld);
+
+        let release_slug = &release_ref.permalink.slug;
+        let release_title_escaped = html_escape_outside_attribute(&release_ref.title);
+
+        sections.push(formatdoc!(r#"
+            <h2><a href="{root_prefix}{release_slug}{index_suffix}">{release_title_escaped}</a></h2>
+            {r_products}
+        "#));
+    }
+
+    let r_sections = sections.join("\n");
+
+    let body = formatdoc!(r#"
+        <div class="page">
+            <div class="page_center">
+                <section class="merch">
+                    <h1>{t_merch}</h1>
+                    {r_sections}
+                </section>
+            </div>
+        </div>
+    "#);
+
+    layout.render(
+        &body,
+        build,
+        catalog,
+        root_prefix,
+        &catalog.theme,
+        t_merch
+    )
+}
@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use indoc::formatdoc;
+
+use crate::{Build, Catalog};
+use crate::util::html_escape_outside_attribute;
+
+use super::Layout;
+
+/// The news page, listing all public posts from newest to oldest
+pub fn news_html(build: &Build, catalog: &Catalog) -> String {
+    let index_suffix = build.index_suffix();
+    let root_prefix = "../";
+    let translations = &build.locale.translations;
+
+    let layout = Layout::new();
+
+    let t_news = &translations.news;
+
+    let r_posts = catalog.public_posts()
+        .iter()
+        .map(|post| {
+            let date_formatted = post.date.format("%Y-%m-%d");
+            let post_slug = &post.permalink.slug;
+            let title_escaped = html_escape_outside_attribute(&post.title);
+
+            formatdoc!(r#"
+                <li>
+                    <time class="post_date" datetime="{date_formatted}">{date_formatted}</time>
+                    <a href="{root_prefix}{post_slug}{index_suffix}">{title_escaped}</a>
+                </li>
+            "#)
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let body = formatdoc!(r#"
+        <div class="page">
+            <div class="page_center">
+                <div class="page_more">
+                    <h1>{t_news}</h1>
+                    <ul class="posts">
+                        {r_posts}
+                    </ul>
+                </div>
+            </div>
+        </div>
+    "#);
+
+    layout.render(
+        &body,
+        build,
+        catalog,
+        root_prefix,
+        &catalog.theme,
+        t_news
+    )
+}
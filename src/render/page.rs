@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use indoc::formatdoc;
+
+use crate::{
+    Build,
+    Catalog,
+    OpenGraphMeta,
+    Page
+};
+use crate::util::html_escape_outside_attribute;
+
+use super::Layout;
+
+/// A standalone page with free-form content written in markdown
+pub fn page_html(build: &Build, catalog: &Catalog, page: &Page) -> String {
+    let root_prefix = "../";
+
+    let mut layout = Layout::new();
+
+    let page_text = &page.text;
+    let title_escaped = html_escape_outside_attribute(&page.title);
+
+    let body = formatdoc!(r#"
+        <div class="page">
+            <div class="page_center">
+                <div class="page_more">
+                    <h1>{title_escaped}</h1>
+                    <div class="text">{page_text}</div>
+                </div>
+            </div>
+        </div>
+    "#);
+
+    if catalog.opengraph {
+        if let Some(base_url) = &build.base_url {
+            let page_url = base_url.join_index(build, &page.permalink.slug);
+            let meta = OpenGraphMeta::new(page.title.clone(), page_url);
+            layout.add_opengraph_meta(meta);
+        }
+    }
+
+    layout.render(
+        &body,
+        build,
+        catalog,
+        root_prefix,
+        &page.theme,
+        &page.title
+    )
+}
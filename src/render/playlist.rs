@@ -0,0 +1,415 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::hash::Hash;
+
+use indoc::formatdoc;
+
+use crate::{
+    Build,
+    Catalog,
+    JSPF_PLAYLIST_FILENAME,
+    M3U_PLAYLIST_FILENAME,
+    OpenGraphMeta,
+    Playlist,
+    XSPF_PLAYLIST_FILENAME
+};
+use crate::icons;
+use crate::util::{format_time, html_escape_outside_attribute};
+
+use super::SPEED_CONTROLS;
+use super::{Layout, Truncation};
+use super::{
+    chapter_markers,
+    copy_button,
+    link_action,
+    list_track_artists,
+    media_session_attributes,
+    queue_buttons,
+    unlisted_badge,
+    volume_controls,
+    waveform
+};
+
+/// The page of a curated playlist, featuring the track listing (each track
+/// linking back to its release) and the streaming player, along with the
+/// playlist's synopsis, links, playlist files and embeds.
+pub fn playlist_html(build: &Build, catalog: &Catalog, playlist: &Playlist) -> String {
+    let index_suffix = build.index_suffix();
+    let root_prefix = "../";
+    let translations = &build.locale.translations;
+
+    let mut layout = Layout::new();
+
+    layout.add_player_script();
+
+    let longest_track_duration = playlist.longest_track_duration();
+
+    let t_play = &translations.play;
+
+    let more_icon = icons::more(Some(&translations.more));
+    let play_icon = icons::play(t_play);
+
+    let r_queue_buttons = if catalog.soft_navigation {
+        queue_buttons(translations)
+    } else {
+        String::new()
+    };
+
+    let t_playback_position = &translations.playback_position;
+    let r_tracks = playlist.entries
+        .iter()
+        .zip(1..)
+        .map(|(entry, playlist_position)| {
+            let release_ref = entry.release.borrow();
+            let release_slug = &release_ref.permalink.slug;
+            let track_number = entry.track_number;
+            let track = &release_ref.tracks[track_number - 1];
+
+            let audio_sources = track.streaming_quality
+                .formats()
+                .iter()
+                .map(|format| {
+                    let format_dir = format.asset_dirname();
+                    let format_extension = format.extension();
+
+                    let track_filename = format!(
+                        "{basename}{format_extension}",
+                        basename = track.asset_basename.as_ref().unwrap()
+                    );
+
+                    let track_hash = build.hash_with_salt(|hasher| {
+                        release_slug.hash(hasher);
+                        track_number.hash(hasher);
+                        format_dir.hash(hasher);
+                        track_filename.hash(hasher);
+                    });
+
+                    let track_filename_urlencoded = urlencoding::encode(&track_filename);
+                    let track_path = format!("{release_slug}/{track_number}/{format_dir}/{track_hash}/{track_filename_urlencoded}");
+                    let src = if let Some(cdn_url) = &build.cdn_url {
+                        cdn_url.join_file(track_path)
+                    } else {
+                        format!("{root_prefix}{track_path}")
+                    };
+
+                    let source_type = format.source_type();
+                    format!(r#"<source src="{src}" type="{source_type}">"#)
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            let duration_seconds = track.transcodes.borrow().source_meta.duration_seconds;
+            let track_title = track.title();
+
+            let track_duration_formatted = format_time(duration_seconds);
+            let track_title_escaped = html_escape_outside_attribute(&track_title);
+
+            let r_waveform = if playlist.theme.waveforms {
+                let r_chapter_markers = chapter_markers(track);
+                let waveform_svg = waveform(track);
+
+                formatdoc!(r#"
+                    <div class="waveform">
+                        {waveform_svg}
+                        {r_chapter_markers}
+                        <input aria-label="{t_playback_position}" aria-valuetext="" autocomplete="off" max="{duration_seconds}" min="0" step="any" type="range" value="0">
+                        <div class="decoration"></div>
+                    </div>
+                "#)
+            } else {
+                String::new()
+            };
+
+            // Unlike on release pages the artists are always shown, as they
+            // usually vary across the tracks of a playlist, and they are
+            // followed by the release the track stems from.
+            let artists_truncation = Truncation::Truncate {
+                max_chars: 80,
+                others_link: format!("{root_prefix}{release_slug}/{track_number}/")
+            };
+            let artists_truncated = list_track_artists(build, index_suffix, root_prefix, catalog, artists_truncation, track);
+            let release_title_escaped = html_escape_outside_attribute(&release_ref.title);
+            let track_artists = formatdoc!(r#"
+                <div class="artists">
+                    {artists_truncated} – <a href="{root_prefix}{release_slug}{index_suffix}">{release_title_escaped}</a>
+                </div>
+            "#);
+
+            let r_media_session_attributes = media_session_attributes(
+                &release_ref,
+                &format!("{root_prefix}{release_slug}/"),
+                track,
+                &format!("{root_prefix}{release_slug}/{track_number}/")
+            );
+
+            let r_cover_micro = if let Some(filename) = track.cover_160_filename() {
+                format!(r#"<img aria-hidden="true" src="{root_prefix}{release_slug}/{track_number}/{filename}">"#)
+            } else if let Some(filename) = release_ref.cover_160_filename() {
+                format!(r#"<img aria-hidden="true" src="{root_prefix}{release_slug}/{filename}">"#)
+            } else {
+                let filename = release_ref.procedural_cover_120_filename_unchecked();
+                format!(r#"<img aria-hidden="true" class="procedural" src="{root_prefix}{release_slug}/{filename}">"#)
+            };
+
+            formatdoc!(r#"
+                <div class="track" {r_media_session_attributes} data-duration="{duration_seconds}">
+                    <button class="track_playback" tabindex="-1">
+                        <span class="icon">
+                            {play_icon}
+                        </span>
+                        {r_cover_micro}
+                    </button>
+                    <div>
+                        <div>
+                            <span class="number">{playlist_position}</span>
+                            <a class="title" href="{root_prefix}{release_slug}/{track_number}{index_suffix}">{track_title_escaped}</a>
+                        </div>
+                        {track_artists}
+                        {r_waveform}
+                        <audio controls preload="none">
+                            {audio_sources}
+                        </audio>
+                    </div>
+                    <div>
+                        {r_queue_buttons}
+                        <span class="time">{track_duration_formatted}</span>
+                    </div>
+                </div>
+            "#)
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let playlist_title_escaped = html_escape_outside_attribute(&playlist.title);
+
+    let mut primary_actions = Vec::new();
+    let mut secondary_actions = Vec::new();
+
+    let t_listen = &translations.listen;
+    let listen_button = formatdoc!(r#"
+        <button class="emphasized listen">
+            <span class="icon">{play_icon}</span>
+            <span class="label">{t_listen}</span>
+        </button>
+    "#);
+
+    primary_actions.push(listen_button);
+
+    let r_more = match &playlist.more {
+        Some(html_and_stripped) => {
+            let more_label = match &playlist.more_label {
+                Some(label) => label,
+                None => *translations.more
+            };
+            let more_link = formatdoc!(r##"
+                <a class="more" href="#more">
+                    {more_icon} {more_label}
+                </a>
+            "##);
+
+            primary_actions.push(more_link);
+
+            let more_html = &html_and_stripped.html;
+            formatdoc!(r#"
+                <a class="scroll_target" id="more"></a>
+                <div class="page">
+                    <div class="page_center">
+                        <div class="page_more">
+                            <div class="release_info">
+                                <h1>{playlist_title_escaped}</h1>
+                            </div>
+                            <div class="text">{more_html}</div>
+                        </div>
+                    </div>
+                </div>
+            "#)
+        }
+        None => String::new()
+    };
+
+    let primary_actions_joined = primary_actions.join("");
+    let r_primary_actions = formatdoc!(r#"
+        <div class="actions primary">
+            {primary_actions_joined}
+        </div>
+    "#);
+
+    if playlist.copy_link {
+        layout.add_clipboard_script();
+
+        let (content_key, content_value) = match &build.base_url {
+            Some(base_url) => ("content", base_url.join_index(build, &playlist.permalink.slug)),
+            None => ("dynamic-url", String::new())
+        };
+
+        let r_copy_link = copy_button(content_key, &content_value, &translations.copy_link);
+        secondary_actions.push(r_copy_link);
+    }
+
+    if build.base_url.is_some() {
+        if playlist.m3u {
+            let stream_icon = icons::STREAM;
+
+            let t_m3u_playlist = &translations.m3u_playlist;
+            let m3u_playlist_link = formatdoc!(r#"
+                <a href="{M3U_PLAYLIST_FILENAME}">
+                    {stream_icon}
+                    <span>{t_m3u_playlist}</span>
+                </a>
+            "#);
+
+            secondary_actions.push(m3u_playlist_link);
+
+            let t_xspf_playlist = &translations.xspf_playlist;
+            let xspf_playlist_link = formatdoc!(r#"
+                <a href="{XSPF_PLAYLIST_FILENAME}">
+                    {stream_icon}
+                    <span>{t_xspf_playlist}</span>
+                </a>
+            "#);
+
+            secondary_actions.push(xspf_playlist_link);
+
+            let t_jspf_playlist = &translations.jspf_playlist;
+            let jspf_playlist_link = formatdoc!(r#"
+                <a href="{JSPF_PLAYLIST_FILENAME}">
+                    {stream_icon}
+                    <span>{t_jspf_playlist}</span>
+                </a>
+            "#);
+
+            secondary_actions.push(jspf_playlist_link);
+        }
+
+        if playlist.embedding {
+            let t_embed = &translations.embed;
+            let embed_icon = icons::embed(t_embed);
+
+            let embed_link = formatdoc!(r#"
+                <a href="embed{index_suffix}">
+                    {embed_icon}
+                    <span>{t_embed}</span>
+                </a>
+            "#);
+
+            secondary_actions.push(embed_link);
+        }
+    }
+
+    for link in &playlist.links {
+        let r_link = link_action(link, translations);
+        secondary_actions.push(r_link);
+    }
+
+    let r_secondary_actions = if secondary_actions.is_empty() {
+        String::new()
+    } else {
+        let joined = secondary_actions.join("");
+
+        formatdoc!(r#"
+            <div class="actions">
+                {joined}
+            </div>
+        "#)
+    };
+
+    let relative_waveforms = if playlist.theme.relative_waveforms { "" } else { "data-disable-relative-waveforms " };
+
+    let playlist_title_with_unlisted_badge = if playlist.unlisted {
+        format!("{playlist_title_escaped} {}", unlisted_badge(build))
+    } else {
+        playlist_title_escaped.clone()
+    };
+
+    let synopsis = match &playlist.synopsis {
+        Some(synopsis) => {
+            formatdoc!(r#"
+                <div style="margin-bottom: 1rem; margin-top: 1rem;">
+                    {synopsis}
+                </div>
+            "#)
+        }
+        None => String::new()
+    };
+
+    let compact_tall = if playlist.theme.waveforms { "tall" } else { "" };
+
+    let speed_controls = if playlist.speed_controls { SPEED_CONTROLS } else { "" };
+    let r_volume_controls = volume_controls(translations);
+
+    let next_track_icon = icons::next_track(&translations.next_track);
+    let body = formatdoc!(r##"
+        <div class="page">
+            <div class="page_center">
+                <div>
+                    <h1>{playlist_title_with_unlisted_badge}</h1>
+                    {r_primary_actions}
+                    {synopsis}
+                    {r_secondary_actions}
+                </div>
+            </div>
+        </div>
+        <div class="page">
+            <div class="page_center">
+                <div class="{compact_tall} tracks" data-longest-duration="{longest_track_duration}" {relative_waveforms}>
+                    {r_tracks}
+                </div>
+            </div>
+        </div>
+        {r_more}
+        <div class="docked_player tall">
+            <div class="timeline">
+                <input aria-label="{t_playback_position}" aria-valuetext="" autocomplete="off" max="" min="0" step="any" type="range" value="0">
+                <div class="base"></div>
+                <div class="progress" style="width: 0%;"></div>
+            </div>
+            <div class="elements">
+                <button class="playback">
+                    {play_icon}
+                </button>
+                <button class="next_track">
+                    {next_track_icon}
+                </button>
+                {speed_controls}
+                {r_volume_controls}
+                <span class="track_info">
+                    <span class="number"></span>
+                    <span class="title_wrapper"></span>
+                </span>
+                <span class="time">
+                    <span class="current"></span>
+                    <span>/</span>
+                    <span class="total"></span>
+                </span>
+            </div>
+        </div>
+        <div aria-label="" class="docked_player_status" role="status"></div>
+    "##);
+
+    if playlist.unlisted {
+        layout.no_indexing();
+    }
+
+    if catalog.opengraph {
+        if let Some(base_url) = &build.base_url {
+            let playlist_url = base_url.join_index(build, &playlist.permalink.slug);
+            let mut meta = OpenGraphMeta::new(playlist.title.clone(), playlist_url);
+
+            if let Some(synopsis) = &playlist.synopsis {
+                meta.description(synopsis);
+            }
+
+            layout.add_opengraph_meta(meta);
+        }
+    }
+
+    layout.render(
+        &body,
+        build,
+        catalog,
+        root_prefix,
+        &playlist.theme,
+        &playlist.title
+    )
+}
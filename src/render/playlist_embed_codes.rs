@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use indoc::formatdoc;
+
+use crate::{
+    Build,
+    Catalog,
+    Playlist,
+    SiteUrl
+};
+use crate::util::html_escape_outside_attribute;
+
+use super::Layout;
+use super::{copy_button, embed_code};
+
+/// Renders the page that lets the visitor copy embed codes for the playlist.
+pub fn playlist_embed_codes_html(
+    base_url: &SiteUrl,
+    build: &Build,
+    catalog: &Catalog,
+    playlist: &Playlist
+) -> String {
+    let index_suffix = build.index_suffix();
+    let root_prefix = "../../";
+    let translations = &build.locale.translations;
+
+    let mut layout = Layout::new();
+
+    layout.add_clipboard_script();
+    layout.no_indexing();
+
+    let playlist_link = format!("..{index_suffix}");
+
+    let playlist_title = &playlist.title;
+    let playlist_title_escaped = html_escape_outside_attribute(playlist_title);
+
+    let t_audio_player_widget_for_xxx =
+        translations.audio_player_widget_for_xxx(playlist_title);
+
+    let playlist_slug = &playlist.permalink.slug;
+
+    let embed_url = base_url.join_index(build, format!("{playlist_slug}/embed/all"));
+
+    let (embed_copy_code, embed_display_code) = embed_code(&embed_url, &t_audio_player_widget_for_xxx);
+
+    let r_copy_button = copy_button("content", &embed_copy_code, &translations.copy);
+
+    let t_embed = &translations.embed;
+    let t_embed_entire_playlist = &translations.embed_entire_playlist;
+    let body = formatdoc!(r#"
+        <div class="page">
+            <div class="page_center">
+                <div>
+                    <h1>{t_embed}</h1>
+                    <div style="font-size: 1.17rem;">
+                        <a href="{playlist_link}">{playlist_title_escaped}</a>
+                    </div>
+                    <div style="margin-top: 2rem;">
+                        <div class="embed_split">
+                            <span>{t_embed_entire_playlist}</span>
+                            {r_copy_button}
+                        </div>
+                        {embed_display_code}
+                    </div>
+                </div>
+            </div>
+        </div>
+    "#);
+
+    layout.add_breadcrumb(format!(r#"<a href="{playlist_link}">{playlist_title_escaped}</a>"#));
+
+    let page_title = format!("{t_embed} – {playlist_title}");
+
+    layout.render(
+        &body,
+        build,
+        catalog,
+        root_prefix,
+        &playlist.theme,
+        &page_title
+    )
+}
@@ -0,0 +1,94 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use indoc::formatdoc;
+
+use crate::{
+    Build,
+    Catalog,
+    OpenGraphMeta,
+    Post
+};
+use crate::util::{html_escape_inside_attribute, html_escape_outside_attribute};
+
+use super::Layout;
+
+/// A news/blog post, followed by the releases it features. Where a featured
+/// release offers embeds, its embed player is included right on the page,
+/// otherwise the release is simply linked to.
+pub fn post_html(build: &Build, catalog: &Catalog, post: &Post) -> String {
+    let index_suffix = build.index_suffix();
+    let root_prefix = "../";
+
+    let mut layout = Layout::new();
+
+    let date_formatted = post.date.format("%Y-%m-%d");
+    let post_text = &post.text.html;
+    let title_escaped = html_escape_outside_attribute(&post.title);
+
+    let r_releases = post.releases
+        .iter()
+        .map(|release| {
+            let release_ref = release.borrow();
+            let release_slug = &release_ref.permalink.slug;
+            let release_title_escaped = html_escape_outside_attribute(&release_ref.title);
+
+            // Embed pages are only generated when a base_url is set
+            if build.base_url.is_some() && release_ref.embedding {
+                let release_title_attribute = html_escape_inside_attribute(&release_ref.title);
+
+                formatdoc!(r#"
+                    <div class="post_release">
+                        <iframe loading="lazy" src="{root_prefix}{release_slug}/embed/all{index_suffix}" style="border: none; height: 49.6px; width: 100%;" title="{release_title_attribute}"></iframe>
+                        <a href="{root_prefix}{release_slug}{index_suffix}">{release_title_escaped}</a>
+                    </div>
+                "#)
+            } else {
+                formatdoc!(r#"
+                    <div class="post_release">
+                        <a href="{root_prefix}{release_slug}{index_suffix}">{release_title_escaped}</a>
+                    </div>
+                "#)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let body = formatdoc!(r#"
+        <div class="page">
+            <div class="page_center">
+                <article class="page_more">
+                    <h1>{title_escaped}</h1>
+                    <time class="post_date" datetime="{date_formatted}">{date_formatted}</time>
+                    <div class="text">{post_text}</div>
+                    {r_releases}
+                </article>
+            </div>
+        </div>
+    "#);
+
+    if post.unlisted {
+        layout.no_indexing();
+    }
+
+    if catalog.opengraph {
+        if let Some(base_url) = &build.base_url {
+            let post_url = base_url.join_index(build, &post.permalink.slug);
+
+            let mut meta = OpenGraphMeta::new(post.title.clone(), post_url);
+
+            meta.description(&post.text.stripped);
+
+            layout.add_opengraph_meta(meta);
+        }
+    }
+
+    layout.render(
+        &body,
+        build,
+        catalog,
+        root_prefix,
+        &post.theme,
+        &post.title
+    )
+}
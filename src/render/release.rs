@@ -3,31 +3,42 @@
 
 use std::hash::Hash;
 
-use chrono::Datelike;
+use chrono::{Datelike, NaiveTime};
 use indoc::formatdoc;
 
-use crate::{M3U_PLAYLIST_FILENAME, TRACK_NUMBERS};
+use crate::{JSPF_PLAYLIST_FILENAME, M3U_PLAYLIST_FILENAME, TRACK_NUMBERS, XSPF_PLAYLIST_FILENAME};
 use crate::{
     Build,
     Catalog,
     DownloadAccess,
+    ImgAttributes,
+    OpenGraphImage,
     OpenGraphMeta,
     Release
 };
 use crate::icons;
-use crate::util::{format_time, html_escape_outside_attribute};
+use crate::util::{format_time, html_escape_inside_attribute, html_escape_outside_attribute};
+use crate::waveform::{WAVEFORM_IMAGE_HEIGHT, WAVEFORM_IMAGE_WIDTH, WAVEFORM_PNG_FILENAME};
 
 use super::SPEED_CONTROLS;
 use super::{Layout, Truncation};
 use super::{
+    chapter_markers,
+    chapters,
     copy_button,
+    license_badge,
     link_action,
     list_release_artists,
     list_track_artists,
+    media_session_attributes,
+    offline_button,
+    products,
+    queue_buttons,
     release_cover_image,
     unlisted_badge,
     volume_controls,
-    waveform
+    waveform,
+    wrap_undescribed_image
 };
 
 use crate::render::track;
@@ -138,43 +149,107 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
 
     let varying_track_artists = release.varying_track_artists();
 
+    let continuous_stream = release.continuous_source().is_some();
+
+    let r_queue_buttons = if catalog.soft_navigation {
+        queue_buttons(translations)
+    } else {
+        String::new()
+    };
+
     let t_playback_position = &translations.playback_position;
     let r_tracks = release.tracks
         .iter()
         .zip(TRACK_NUMBERS)
         .map(|(track, track_number)| {
-            let audio_sources = track.streaming_quality
-                .formats()
-                .iter()
-                .map(|format| {
-                    let format_dir = format.asset_dirname();
-                    let format_extension = format.extension();
-
-                    let track_filename = format!(
-                        "{basename}{format_extension}",
-                        basename = track.asset_basename.as_ref().unwrap()
-                    );
-
-                    let track_hash = build.hash_with_salt(|hasher| {
-                        release.permalink.slug.hash(hasher);
-                        track_number.hash(hasher);
-                        format_dir.hash(hasher);
-                        track_filename.hash(hasher);
-                    });
-
-                    let track_filename_urlencoded = urlencoding::encode(&track_filename);
-                    let relative_path = format!("{format_dir}/{track_hash}/{track_filename_urlencoded}");
-                    let src = if let Some(cdn_url) = &build.cdn_url {
-                        cdn_url.join_file(format!("{}/{}/{}", release.permalink.slug, track_number, relative_path))
-                    } else {
-                        relative_path
-                    };
+            // Locked tracks (of an upcoming release) have no streaming
+            // assets and no track page, only their duration is revealed.
+            if release.track_locked(track) {
+                let duration_seconds = track.transcodes.borrow().source_meta.duration_seconds;
+                let track_duration_formatted = format_time(duration_seconds);
+                let track_number_formatted = release.track_numbering.format(track_number);
+                let t_available_with_full_release = &translations.available_with_full_release;
+                let lock_icon = icons::lock(t_available_with_full_release);
+
+                return formatdoc!(r#"
+                    <div class="locked track">
+                        <span class="track_playback">
+                            <span class="icon">
+                                {lock_icon}
+                            </span>
+                        </span>
+                        <div>
+                            <div>
+                                <span class="number">{track_number_formatted}</span>
+                                <span class="title">{t_available_with_full_release}</span>
+                            </div>
+                        </div>
+                        <div>
+                            <span class="time">{track_duration_formatted}</span>
+                        </div>
+                    </div>
+                "#);
+            }
 
-                    let source_type = format.source_type();
-                    format!(r#"<source src="{src}" type="{source_type}">"#)
-                })
-                .collect::<Vec<String>>()
-                .join("\n");
+            // Tracks split from a continuous recording stream that
+            // recording from their start on, which allows gapless playback
+            // across them (see player.js).
+            let segment_start = match continuous_stream {
+                true => track.transcodes.borrow().segment.as_ref().map(|segment| segment.start_seconds()),
+                false => None
+            };
+
+            let audio_sources = if segment_start.is_some() {
+                track.streaming_quality
+                    .formats()
+                    .iter()
+                    .map(|format| {
+                        let relative_path = release.continuous_stream_path(build, *format);
+                        let src = if let Some(cdn_url) = &build.cdn_url {
+                            cdn_url.join_file(format!("{}/{}", release.permalink.slug, relative_path))
+                        } else {
+                            relative_path
+                        };
+
+                        let source_type = format.source_type();
+                        format!(r#"<source src="{src}" type="{source_type}">"#)
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            } else {
+                track.streaming_quality
+                    .formats()
+                    .iter()
+                    .map(|format| {
+                        let format_dir = format.asset_dirname();
+                        let format_extension = format.extension();
+
+                        let track_filename = format!(
+                            "{basename}{format_extension}",
+                            basename = track.asset_basename.as_ref().unwrap()
+                        );
+
+                        let track_hash = build.hash_with_salt(|hasher| {
+                            release.permalink.slug.hash(hasher);
+                            track_number.hash(hasher);
+                            format_dir.hash(hasher);
+                            track_filename.hash(hasher);
+                        });
+
+                        let track_filename_urlencoded = urlencoding::encode(&track_filename);
+                        let relative_path = format!("{format_dir}/{track_hash}/{track_filename_urlencoded}");
+                        let src = if let Some(cdn_url) = &build.cdn_url {
+                            cdn_url.join_file(format!("{}/{}/{}", release.permalink.slug, track_number, relative_path))
+                        } else {
+                            relative_path
+                        };
+
+                        let source_type = format.source_type();
+                        format!(r#"<source src="{src}" type="{source_type}">"#)
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            };
 
             let duration_seconds = track.transcodes.borrow().source_meta.duration_seconds;
             let track_title = track.title();
@@ -184,11 +259,13 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
             let track_title_escaped = html_escape_outside_attribute(&track_title);
 
             let r_waveform = if release.theme.waveforms {
+                let r_chapter_markers = chapter_markers(track);
                 let waveform_svg = waveform(track);
 
                 formatdoc!(r#"
                     <div class="waveform">
                         {waveform_svg}
+                        {r_chapter_markers}
                         <input aria-label="{t_playback_position}" aria-valuetext="" autocomplete="off" max="{duration_seconds}" min="0" step="any" type="range" value="0">
                         <div class="decoration"></div>
                     </div>
@@ -197,6 +274,13 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
                 String::new()
             };
 
+            let r_chapters = chapters(build, track, Some(track_number));
+
+            let segment_start_attribute = match segment_start {
+                Some(start_seconds) => format!(r#" data-segment-start="{start_seconds}""#),
+                None => String::new()
+            };
+
             let track_artists = match varying_track_artists {
                 true => {
                     let artists_truncation = Truncation::Truncate {
@@ -209,6 +293,8 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
                 false => String::new()
             };
 
+            let r_media_session_attributes = media_session_attributes(release, "", track, &format!("{track_number}/"));
+
             let r_cover_micro = if let Some(filename) = track.cover_160_filename() {
                 format!(r#"<img aria-hidden="true" src="{track_number}/{filename}">"#)
             } else if let Some(filename) = release.cover_160_filename() {
@@ -233,7 +319,7 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
                 .unwrap_or_default();
 
             formatdoc!(r#"
-                <div class="track" data-duration="{duration_seconds}">
+                <div class="track" {r_media_session_attributes} data-duration="{duration_seconds}"{segment_start_attribute}>
                     <button class="track_playback" tabindex="-1">
                         <span class="icon">
                             {play_icon}
@@ -250,8 +336,10 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
                         <audio controls preload="none">
                             {audio_sources}
                         </audio>
+                        {r_chapters}
                     </div>
                     <div>
+                        {r_queue_buttons}
                         {r_more} <span class="time">{track_duration_formatted}</span>
                     </div>
                     {t_download}
@@ -355,6 +443,10 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
         secondary_actions.push(r_copy_link);
     }
 
+    if catalog.web_app && release.tracks.iter().any(|track| !release.track_locked(track)) {
+        secondary_actions.push(offline_button(translations));
+    }
+
     if build.base_url.is_some() {
         if release.m3u  {
             let t_m3u_playlist = &translations.m3u_playlist;
@@ -368,6 +460,26 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
             "#);
 
             secondary_actions.push(m3u_playlist_link);
+
+            let t_xspf_playlist = &translations.xspf_playlist;
+            let xspf_playlist_link = formatdoc!(r#"
+                <a href="{XSPF_PLAYLIST_FILENAME}">
+                    {stream_icon}
+                    <span>{t_xspf_playlist}</span>
+                </a>
+            "#);
+
+            secondary_actions.push(xspf_playlist_link);
+
+            let t_jspf_playlist = &translations.jspf_playlist;
+            let jspf_playlist_link = formatdoc!(r#"
+                <a href="{JSPF_PLAYLIST_FILENAME}">
+                    {stream_icon}
+                    <span>{t_jspf_playlist}</span>
+                </a>
+            "#);
+
+            secondary_actions.push(jspf_playlist_link);
         }
 
         if release.embedding {
@@ -410,7 +522,47 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
         release_title_escaped.clone()
     };
 
+    let r_upcoming = match &release.upcoming {
+        Some(publish_at) => {
+            let publish_at_formatted = if publish_at.time() == NaiveTime::MIN {
+                publish_at.format("%Y-%m-%d").to_string()
+            } else {
+                publish_at.format("%Y-%m-%d %H:%M UTC").to_string()
+            };
+            let t_full_release_on_xxx = translations.full_release_on_xxx(&publish_at_formatted);
+            format!(r#"<div class="upcoming">{t_full_release_on_xxx}</div>"#)
+        }
+        None => String::new()
+    };
+
     let cover = release_cover_image(build, release, "", root_prefix);
+    let r_gallery = release_gallery(build, release, root_prefix);
+
+    let r_products = if release.products.is_empty() {
+        String::new()
+    } else {
+        let (r_products, json_ld) = products(
+            build,
+            &release.permalink.slug,
+            &release.title,
+            &release.products,
+            root_prefix
+        );
+
+        layout.add_json_ld(json_ld);
+
+        let t_buy_physical = &translations.buy_physical;
+        formatdoc!(r#"
+            <div class="page">
+                <div class="page_center">
+                    <section class="merch">
+                        <h2>{t_buy_physical}</h2>
+                        {r_products}
+                    </section>
+                </div>
+            </div>
+        "#)
+    };
 
     let synopsis = match &release.synopsis {
         Some(synopsis) => {
@@ -423,6 +575,14 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
         None => String::new()
     };
 
+    let r_license = match &release.license {
+        Some(license) => {
+            layout.add_license_link(&license.url);
+            license_badge(build, license)
+        }
+        None => String::new()
+    };
+
     let tall = if varying_track_artists { "tall" } else { "" };
 
     let compact_tall = match (release.theme.waveforms, varying_track_artists) {
@@ -442,8 +602,10 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
                 <div class="abstract">
                     <h1>{release_title_with_unlisted_badge}</h1>
                     <div class="release_artists">{artists_truncated}</div>
+                    {r_upcoming}
                     {r_primary_actions}
                     {synopsis}
+                    {r_license}
                     {r_secondary_actions}
                 </div>
             </div>
@@ -455,6 +617,8 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
                 </div>
             </div>
         </div>
+        {r_gallery}
+        {r_products}
         {r_more}
         <div class="docked_player {tall}">
             <div class="timeline">
@@ -510,6 +674,14 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
                 if let Some(description) = &described_image.description {
                     meta.image_alt(description);
                 }
+            } else if catalog.waveform_images && release.has_waveform_image() {
+                let opengraph_image = OpenGraphImage {
+                    height: WAVEFORM_IMAGE_HEIGHT,
+                    url: base_url.join_file(format!("{release_slug}/{WAVEFORM_PNG_FILENAME}")),
+                    width: WAVEFORM_IMAGE_WIDTH
+                };
+
+                meta.image(opengraph_image);
             }
             // TODO: Should(n't) we also provide a procedural cover as a
             // fallback here? (also applies for the same spot in the track
@@ -528,3 +700,143 @@ pub fn release_html(build: &Build, catalog: &Catalog, release: &Release) -> Stri
         &release.title
     )
 }
+
+/// Renders the thumbnails of all gallery images of a release, together
+/// with a dialog in which the full size versions can be viewed (and
+/// browsed through) when a thumbnail is opened. Returns an empty string
+/// if the release has no gallery images.
+fn release_gallery(build: &Build, release: &Release, root_prefix: &str) -> String {
+    if release.gallery.is_empty() {
+        return String::new();
+    }
+
+    let translations = &build.locale.translations;
+
+    let mut r_thumbnails = Vec::with_capacity(release.gallery.len());
+    let mut r_full_images = Vec::with_capacity(release.gallery.len());
+
+    for (described_image, number) in release.gallery.iter().zip(1..) {
+        let image_ref = described_image.borrow();
+        let gallery_assets = image_ref.gallery_assets_unchecked();
+        let hash = image_ref.hash.as_url_safe_base64();
+
+        let alt = match &described_image.description {
+            Some(description) => format!(r#"alt="{}""#, html_escape_inside_attribute(description)),
+            None => String::new()
+        };
+
+        let thumbnail_img_attributes = gallery_assets.thumbnail_img_attributes(&hash, number, "");
+        let thumbnail_sizes = "10rem";
+        let thumbnail_sources = thumbnail_img_attributes.picture_sources(None, thumbnail_sizes);
+        let ImgAttributes { src: thumbnail_src, srcset: thumbnail_srcset, .. } = thumbnail_img_attributes;
+        let thumbnail_edge_size = gallery_assets.thumbnail.width;
+
+        let full_img_attributes = gallery_assets.full_img_attributes(&hash, number, "");
+        let full_sizes = "calc(100vmin - 4rem)";
+        let full_sources = full_img_attributes.picture_sources(None, full_sizes);
+        let ImgAttributes { src: full_src, srcset: full_srcset, .. } = full_img_attributes;
+        let full_height = gallery_assets.full.height;
+        let full_width = gallery_assets.full.width;
+
+        let index = number - 1;
+        let thumbnail = formatdoc!(r#"
+            <a class="gallery_image" data-index="{index}" href="{full_src}" target="_blank">
+                <picture>
+                    {thumbnail_sources}
+                    <img
+                        {alt}
+                        height="{thumbnail_edge_size}"
+                        loading="lazy"
+                        sizes="{thumbnail_sizes}"
+                        src="{thumbnail_src}"
+                        srcset="{thumbnail_srcset}"
+                        width="{thumbnail_edge_size}">
+                </picture>
+            </a>
+        "#);
+
+        if described_image.description.is_some() {
+            r_thumbnails.push(thumbnail);
+        } else {
+            r_thumbnails.push(wrap_undescribed_image(build, root_prefix, &thumbnail, "", ""));
+        }
+
+        r_full_images.push(formatdoc!(r#"
+            <picture hidden>
+                {full_sources}
+                <img
+                    {alt}
+                    height="{full_height}"
+                    loading="lazy"
+                    sizes="{full_sizes}"
+                    src="{full_src}"
+                    srcset="{full_srcset}"
+                    width="{full_width}">
+            </picture>
+        "#));
+    }
+
+    let r_thumbnails = r_thumbnails.join("\n");
+    let r_full_images = r_full_images.join("\n");
+
+    let t_close = &translations.close;
+    let t_gallery = &translations.gallery;
+    let close_icon = icons::failure(t_close);
+    let next_image_icon = icons::next_track(&translations.next_image);
+    let previous_image_icon = icons::previous_track(&translations.previous_image);
+
+    formatdoc!(r#"
+        <div class="page">
+            <div class="page_center">
+                <section aria-label="{t_gallery}" class="gallery">
+                    {r_thumbnails}
+                </section>
+            </div>
+        </div>
+        <dialog aria-label="{t_gallery}" id="gallery_overlay">
+            <button class="close">
+                {close_icon}
+            </button>
+            <button class="previous">
+                {previous_image_icon}
+            </button>
+            <div class="images">
+                {r_full_images}
+            </div>
+            <button class="next">
+                {next_image_icon}
+            </button>
+        </dialog>
+        <script>
+            const galleryOverlay = document.querySelector('dialog#gallery_overlay');
+            const galleryImages = galleryOverlay.querySelectorAll('.images > picture');
+            let galleryIndex = 0;
+
+            const showGalleryImage = index => {{
+                galleryImages[galleryIndex].hidden = true;
+                galleryIndex = (index + galleryImages.length) % galleryImages.length;
+                galleryImages[galleryIndex].hidden = false;
+            }};
+
+            for (const thumbnail of document.querySelectorAll('a.gallery_image')) {{
+                thumbnail.addEventListener('click', event => {{
+                    showGalleryImage(parseInt(thumbnail.dataset.index));
+                    galleryOverlay.showModal();
+                    event.preventDefault();
+                }});
+            }}
+
+            galleryOverlay.querySelector('button.close').addEventListener('click', () => galleryOverlay.close());
+            galleryOverlay.querySelector('button.next').addEventListener('click', () => showGalleryImage(galleryIndex + 1));
+            galleryOverlay.querySelector('button.previous').addEventListener('click', () => showGalleryImage(galleryIndex - 1));
+
+            galleryOverlay.addEventListener('keydown', event => {{
+                if (event.key === 'ArrowLeft') {{
+                    showGalleryImage(galleryIndex - 1);
+                }} else if (event.key === 'ArrowRight') {{
+                    showGalleryImage(galleryIndex + 1);
+                }}
+            }});
+        </script>
+    "#)
+}
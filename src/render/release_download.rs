@@ -13,10 +13,11 @@ use crate::{
 };
 use crate::util::html_escape_outside_attribute;
 
+use super::DOWNLOAD_STATS_SCRIPT;
 use super::Layout;
 use super::{compact_release_identifier, download_entry};
 
-/// The download page itself, providing direct links to the (zip) archive
+/// The download page itself, providing direct links to the archive
 /// files and/or individual tracks download links.
 pub fn release_download_html(
     build: &Build,
@@ -68,7 +69,11 @@ pub fn release_download_html(
             .map(|download_format| {
                 let release_slug = &release.permalink.slug;
 
-                let archive_filename = format!("{}.zip", release.asset_basename.as_ref().unwrap());
+                let archive_filename = format!(
+                    "{}.{}",
+                    release.asset_basename.as_ref().unwrap(),
+                    release.archive_format.extension()
+                );
 
                 let archive_hash = build.hash_with_salt(|hasher| {
                     release_slug.hash(hasher);
@@ -81,11 +86,28 @@ pub fn release_download_html(
                 let archives = release.archives.as_ref().unwrap();
                 let format_dir = download_format.as_audio_format().asset_dirname().to_string();
 
-                download_entry(
+                let archive_entry = download_entry(
                     format!("{release_prefix}{format_dir}/{archive_hash}/{archive_filename_urlencoded}"),
                     download_format.user_label(),
                     archives.borrow().get_unchecked(*download_format).asset.filesize_bytes
-                )
+                );
+
+                if release.archive_checksums {
+                    // The checksum file contains the hex-encoded SHA-256 hash
+                    // (64 characters), two spaces, the filename and a newline.
+                    let checksum_filesize = (64 + 2 + archive_filename.len() + 1) as u64;
+                    let checksum_label = format!("{} (SHA-256)", download_format.user_label());
+
+                    let checksum_entry = download_entry(
+                        format!("{release_prefix}{format_dir}/{archive_hash}/{archive_filename_urlencoded}.sha256"),
+                        &checksum_label,
+                        checksum_filesize
+                    );
+
+                    format!("{archive_entry}{checksum_entry}")
+                } else {
+                    archive_entry
+                }
             })
             .collect::<Vec<String>>()
             .join("");
@@ -150,6 +172,8 @@ pub fn release_download_html(
         String::new()
     };
 
+    let r_download_stats_script = if catalog.stats_endpoint.is_some() { DOWNLOAD_STATS_SCRIPT } else { "" };
+
     let t_downloads = &build.locale.translations.downloads;
     let body = formatdoc!(
         r##"
@@ -168,6 +192,7 @@ pub fn release_download_html(
                     </div>
                 </div>
             </div>
+            {r_download_stats_script}
         "##
     );
 
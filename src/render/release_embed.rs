@@ -35,6 +35,7 @@ pub fn release_embed_html(
     let tracks_rendered = release.tracks
         .iter()
         .zip(TRACK_NUMBERS)
+        .filter(|(track, _track_number)| !release.track_locked(track))
         .map(|(track, track_number)| {
             let audio_sources = track.streaming_quality
                 .formats()
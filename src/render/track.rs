@@ -10,19 +10,25 @@ use crate::{
     Build,
     Catalog,
     DownloadAccess,
+    OpenGraphImage,
     OpenGraphMeta,
     Release,
     Track
 };
 use crate::icons;
 use crate::util::{format_time, html_escape_outside_attribute};
+use crate::waveform::{WAVEFORM_IMAGE_HEIGHT, WAVEFORM_IMAGE_WIDTH, WAVEFORM_PNG_FILENAME};
 
 use super::SPEED_CONTROLS;
 use super::{Layout, Truncation};
 use super::{
+    chapter_markers,
+    chapters,
     copy_button,
+    license_badge,
     link_action,
     list_track_artists,
+    media_session_attributes,
     release_cover_image,
     track_cover_image,
     volume_controls,
@@ -195,12 +201,14 @@ pub fn track_html(
     let r_waveform;
     let t_playback_position = &translations.playback_position;
     if release.theme.waveforms {
+        let r_chapter_markers = chapter_markers(track);
         let waveform_svg = waveform(track);
 
         compact = "";
         r_waveform = formatdoc!(r#"
             <div class="waveform">
                 {waveform_svg}
+                {r_chapter_markers}
                 <input aria-label="{t_playback_position}" aria-valuetext="" autocomplete="off" max="{duration_seconds}" min="0" step="any" type="range" value="0">
                 <div class="decoration"></div>
             </div>
@@ -210,6 +218,8 @@ pub fn track_html(
         r_waveform = String::new();
     };
 
+    let r_chapters = chapters(build, track, None);
+
     let r_cover_micro = if let Some(filename) = track.cover_160_filename() {
         format!(r#"<img aria-hidden="true" src="{filename}">"#)
     } else if let Some(filename) = release.cover_160_filename() {
@@ -219,9 +229,11 @@ pub fn track_html(
         format!(r#"<img aria-hidden="true" class="procedural" src="../{filename}">"#)
     };
 
+    let r_media_session_attributes = media_session_attributes(release, "../", track, "");
+
     let play_icon = icons::play(&translations.play);
     let r_track = formatdoc!(r#"
-        <div class="track" data-duration="{duration_seconds}">
+        <div class="track" {r_media_session_attributes} data-duration="{duration_seconds}">
             <button class="track_playback" tabindex="-1">
                 <span class="icon">
                     {play_icon}
@@ -236,6 +248,7 @@ pub fn track_html(
                 <audio controls preload="none">
                     {audio_sources}
                 </audio>
+                {r_chapters}
             </div>
             </span>
             <div>
@@ -401,6 +414,14 @@ pub fn track_html(
         None => String::new()
     };
 
+    let r_license = match &track.license {
+        Some(license) => {
+            layout.add_license_link(&license.url);
+            license_badge(build, license)
+        }
+        None => String::new()
+    };
+
     let track_number_formatted = release.track_numbering.format(track_number);
 
     let speed_controls = if track.speed_controls { SPEED_CONTROLS } else { "" };
@@ -419,6 +440,7 @@ pub fn track_html(
                     <div class="release_artists">{artists_truncated}</div>
                     {r_primary_actions}
                     {synopsis}
+                    {r_license}
                     {r_secondary_actions}
                 </div>
             </div>
@@ -496,6 +518,14 @@ pub fn track_html(
                 if let Some(description) = &described_image.description {
                     meta.image_alt(description);
                 }
+            } else if catalog.waveform_images {
+                let opengraph_image = OpenGraphImage {
+                    height: WAVEFORM_IMAGE_HEIGHT,
+                    url: base_url.join_file(format!("{release_slug}/{track_number}/{WAVEFORM_PNG_FILENAME}")),
+                    width: WAVEFORM_IMAGE_WIDTH
+                };
+
+                meta.image(opengraph_image);
             }
             // TODO: Should(n't) we also provide a procedural cover as a
             // fallback here? (also applies for the same spot in the release
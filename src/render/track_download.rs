@@ -15,6 +15,7 @@ use crate::{
 };
 use crate::util::{generic_hash, html_escape_outside_attribute};
 
+use super::DOWNLOAD_STATS_SCRIPT;
 use super::Layout;
 use super::{compact_track_identifier, download_entry};
 
@@ -188,6 +189,8 @@ pub fn track_download_html(
         String::new()
     };
 
+    let r_download_stats_script = if catalog.stats_endpoint.is_some() { DOWNLOAD_STATS_SCRIPT } else { "" };
+
     let t_downloads = &build.locale.translations.downloads;
     let body = formatdoc!(r##"
         <div class="page">
@@ -205,6 +208,7 @@ pub fn track_download_html(
                 </div>
             </div>
         </div>
+        {r_download_stats_script}
     "##);
 
     let release_link = format!("../../..{index_suffix}");
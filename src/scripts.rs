@@ -6,16 +6,26 @@ use crate::{Build, Catalog};
 mod browser;
 mod clipboard;
 mod embeds;
+mod navigation;
+mod offline;
 mod player;
 
 pub fn generate(build: &mut Build, catalog: &Catalog) {
     browser::generate_browser_js(build, catalog);
     clipboard::generate_clipboard_js(build);
-    player::generate_player_js(build);
+    player::generate_player_js(build, catalog);
 
     if build.embeds_requested {
         embeds::generate_embeds_js(build);
     }
+
+    if catalog.soft_navigation {
+        navigation::generate_navigation_js(build);
+    }
+
+    if catalog.web_app {
+        offline::generate_offline_js(build);
+    }
 }
 
 /// Escapes `'` as `\'` and `\` as `\\`
@@ -88,6 +88,7 @@ pub fn generate_browser_js(build: &mut Build, catalog: &Catalog) {
             let tracks = release_ref.tracks
                 .iter()
                 .zip(TRACK_NUMBERS)
+                .filter(|(track, _track_number)| !release_ref.track_locked(track))
                 .map(|(track, track_number)| {
                     let mut track_props = Vec::new();
 
@@ -0,0 +1,18 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::fs;
+
+use crate::Build;
+
+const NAVIGATION_JS: &str = include_str!(env!("FAIRCAMP_NAVIGATION_JS"));
+const NAVIGATION_JS_FILENAME: &str = "navigation.js";
+
+pub fn generate_navigation_js(build: &mut Build) {
+    fs::write(
+        build.build_dir.join(NAVIGATION_JS_FILENAME),
+        NAVIGATION_JS
+    ).unwrap();
+
+    build.reserve_filename(NAVIGATION_JS_FILENAME);
+}
@@ -0,0 +1,18 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::fs;
+
+use crate::Build;
+
+const OFFLINE_JS: &str = include_str!(env!("FAIRCAMP_OFFLINE_JS"));
+const OFFLINE_JS_FILENAME: &str = "offline.js";
+
+pub fn generate_offline_js(build: &mut Build) {
+    fs::write(
+        build.build_dir.join(OFFLINE_JS_FILENAME),
+        OFFLINE_JS
+    ).unwrap();
+
+    build.reserve_filename(OFFLINE_JS_FILENAME);
+}
@@ -5,7 +5,7 @@ use std::fs;
 
 use indoc::formatdoc;
 
-use crate::Build;
+use crate::{Build, Catalog};
 use crate::util::url_safe_hash_base64;
 
 use super::js_escape_inside_single_quoted_string;
@@ -13,7 +13,7 @@ use super::js_escape_inside_single_quoted_string;
 const PLAYER_JS: &str = include_str!(env!("FAIRCAMP_PLAYER_JS"));
 const PLAYER_JS_FILENAME: &str = "player.js";
 
-pub fn generate_player_js(build: &mut Build) {
+pub fn generate_player_js(build: &mut Build, catalog: &Catalog) {
     let t_listen = &build.locale.translations.listen;
     let t_mute = &build.locale.translations.mute;
     let t_pause = &build.locale.translations.pause;
@@ -26,6 +26,8 @@ pub fn generate_player_js(build: &mut Build) {
     let t_xxx_hours = &build.locale.translations.xxx_hours;
     let t_xxx_minutes = &build.locale.translations.xxx_minutes;
     let t_xxx_seconds = &build.locale.translations.xxx_seconds;
+
+    let keyboard_shortcuts = catalog.keyboard_shortcuts;
     let mut js = formatdoc!("
         const PLAYER_JS_T = {{
             listen: '{t_listen}',
@@ -41,6 +43,7 @@ pub fn generate_player_js(build: &mut Build) {
             xxxMinutes: minutes => '{t_xxx_minutes}'.replace('{{xxx}}', minutes),
             xxxSeconds: seconds => '{t_xxx_seconds}'.replace('{{xxx}}', seconds)
         }};
+        const PLAYER_JS_KEYBOARD_SHORTCUTS = {keyboard_shortcuts};
     ");
 
     js.push_str(PLAYER_JS);
@@ -3,24 +3,75 @@
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
 use actix_files::Files;
-use actix_web::{App, HttpServer};
+use actix_web::{App, HttpRequest, HttpResponse, HttpServer, web};
+use sha2::{Digest, Sha256};
+use std::fs;
 use std::net::{IpAddr, Ipv4Addr};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::activitypub::{ACTIVITYPUB_DIR, INBOX_FILENAME};
+use crate::shared_cache::{TRANSCODES_DIR, valid_artifact_name};
+use crate::stats::{Stats, StatsEvent, StatsPaths};
+use crate::util::uid;
 
 const DEFAULT_PREVIEW_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
 const DEFAULT_PREVIEW_PORT: u16 = 8080;
 
+/// Transcodes of long recordings can get large, uploads to the shared cache
+/// server may therefore be up to 1GiB in size (the default limit for request
+/// bodies is 256KiB).
+const MAX_SHARED_CACHE_UPLOAD_BYTES: usize = 1024 * 1024 * 1024;
+
+/// The shared cache server is usually long-running next to other services,
+/// hence it gets a fixed default port that does not collide with the preview.
+const DEFAULT_SHARED_CACHE_PORT: u16 = 8090;
+
+/// Like the shared cache server, the stats server is meant to be kept
+/// running, so it also gets its own fixed default port.
+const DEFAULT_STATS_PORT: u16 = 8091;
+
+/// How often the stats server writes the counts it received to the stats
+/// file (and checks whether the stats paths file has changed).
+const STATS_FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+
 /// When DEFAULT_PREVIEW_PORT is unavailable, we try DEFAULT_PREVIEW_PORT + 1,
 /// then DEFAULT_PREVIEW_PORT + 2, etc., but after MAX_PORT_ATTEMPTS
 /// iterations we stop, as probably something is else is wrong then, rather
 /// than all ports taken.
 const MAX_PORT_ATTEMPTS: u16 = 10;
 
+/// The secret that authorizes uploads to the shared cache server.
+struct SharedCacheToken(String);
+
+/// Everything the stats server keeps in memory. Counts are recorded here
+/// and only written to the stats file periodically (see
+/// STATS_FLUSH_INTERVAL) and on shutdown.
+struct StatsServerState {
+    paths: StatsPaths,
+    stats: Stats,
+    unsaved_changes: bool
+}
+
+/// A stand-in for an ActivityPub inbox, so that the follow flow (e.g. a local
+/// fediverse test instance sending a Follow activity) can be observed during
+/// preview. Deployed (static) sites have no inbox, so this merely prints
+/// incoming activities and acknowledges them without further processing.
+async fn activitypub_inbox(body: String) -> HttpResponse {
+    info!("Received ActivityPub activity at the preview inbox: {}", body);
+    HttpResponse::Accepted().finish()
+}
+
 #[actix_web::main]
 pub async fn serve_preview(build_dir: &Path, ip_requested: Option<IpAddr>, port_requested: Option<u16>) {
     let bind_server = |build_dir_moving: PathBuf, ip: IpAddr, port: u16| {
         HttpServer::new(move || {
             App::new()
+                .route(
+                    &format!("/{ACTIVITYPUB_DIR}/{INBOX_FILENAME}"),
+                    web::post().to(activitypub_inbox)
+                )
                 .service(
                     Files::new("/", &build_dir_moving)
                         .redirect_to_slash_directory()
@@ -77,3 +128,258 @@ pub async fn serve_preview(build_dir: &Path, ip_requested: Option<IpAddr>, port_
 
     tokio::join!(server.run(), open_browser()).0.unwrap();
 }
+
+#[actix_web::main]
+pub async fn serve_shared_cache(
+    shared_cache_dir: &Path,
+    ip_requested: Option<IpAddr>,
+    port_requested: Option<u16>,
+    token: Option<String>
+) {
+    let ip = ip_requested.unwrap_or(DEFAULT_PREVIEW_IP);
+    let port = port_requested.unwrap_or(DEFAULT_SHARED_CACHE_PORT);
+
+    let transcodes_dir = shared_cache_dir.join(TRANSCODES_DIR);
+
+    if let Err(err) = fs::create_dir_all(&transcodes_dir) {
+        error!("Could not create shared cache directory {:?} ({})", transcodes_dir, err);
+        return
+    }
+
+    let read_only = token.is_none();
+    let shared_cache_dir_moving = shared_cache_dir.to_owned();
+    let server = HttpServer::new(move || {
+        let mut app = App::new();
+
+        // Without a token there is no way to authorize uploads, so the
+        // route for them is not even set up and the server is read-only.
+        if let Some(token) = &token {
+            app = app
+                .app_data(web::Data::new(shared_cache_dir_moving.clone()))
+                .app_data(web::Data::new(SharedCacheToken(token.clone())))
+                .route(
+                    &format!("/{TRANSCODES_DIR}/{{name}}"),
+                    web::put().to(shared_cache_put)
+                );
+        }
+
+        app.service(Files::new(&format!("/{TRANSCODES_DIR}"), shared_cache_dir_moving.join(TRANSCODES_DIR)))
+    })
+        .bind((ip, port));
+
+    let server = match server {
+        Ok(server) => server,
+        Err(err) => {
+            error!("Could not bind shared cache server to {}:{} ({})", ip, port, err);
+            return
+        }
+    };
+
+    println!("Serving the shared cache in {shared_cache_dir:?} at http://{ip}:{port}/ (pass this address to --shared-cache on other machines)");
+    if read_only {
+        println!("No --token was given, the shared cache is served read-only");
+    }
+    println!("Press Ctrl+C to shut down the shared cache server");
+
+    server.run().await.unwrap();
+}
+
+#[actix_web::main]
+pub async fn serve_stats(
+    stats_file: &Path,
+    paths_file: &Path,
+    ip_requested: Option<IpAddr>,
+    port_requested: Option<u16>
+) -> bool {
+    let ip = ip_requested.unwrap_or(DEFAULT_PREVIEW_IP);
+    let port = port_requested.unwrap_or(DEFAULT_STATS_PORT);
+
+    let stats = match Stats::read(stats_file) {
+        Ok(stats) => stats,
+        Err(err) => {
+            error!("{}", err);
+            return false
+        }
+    };
+
+    let paths = match StatsPaths::read(paths_file) {
+        Ok(paths) => paths,
+        Err(err) => {
+            error!("{}", err);
+            return false
+        }
+    };
+
+    let state = web::Data::new(Mutex::new(StatsServerState {
+        paths,
+        stats,
+        unsaved_changes: false
+    }));
+
+    let state_moving = state.clone();
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(state_moving.clone())
+            // Reported paths are short, anything much larger is not from
+            // a faircamp site and is not even read.
+            .app_data(web::PayloadConfig::new(4096))
+            .route("/{event}", web::post().to(stats_record))
+    })
+        .bind((ip, port));
+
+    let server = match server {
+        Ok(server) => server,
+        Err(err) => {
+            error!("Could not bind stats server to {}:{} ({})", ip, port, err);
+            return false
+        }
+    };
+
+    println!("Receiving listening statistics at http://{ip}:{port}/ and storing them in {stats_file:?} (set the public address of this server as stats_endpoint in your catalog.eno)");
+    println!("Press Ctrl+C to shut down the stats server");
+
+    let state_flushing = state.clone();
+    let stats_file_flushing = stats_file.to_owned();
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(STATS_FLUSH_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let state = state_flushing.clone();
+            let stats_file = stats_file_flushing.clone();
+            let _ = web::block(move || stats_flush(&state, &stats_file)).await;
+        }
+    });
+
+    server.run().await.unwrap();
+
+    // The server has shut down, whatever was counted since the last
+    // flush is written now.
+    stats_flush(&state, stats_file);
+
+    true
+}
+
+/// Stores an artifact uploaded by a faircamp build using this server as its
+/// shared cache. The token is checked before the body is read, so that
+/// unauthorized requests can not make the server buffer large uploads.
+/// Artifacts are written to a temporary file first and then renamed, so
+/// they never become visible in an incomplete state.
+async fn shared_cache_put(
+    name: web::Path<String>,
+    payload: web::Payload,
+    request: HttpRequest,
+    shared_cache_dir: web::Data<PathBuf>,
+    token: web::Data<SharedCacheToken>
+) -> HttpResponse {
+    let authorized = request.headers()
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|given| token.matches(given));
+
+    if !authorized {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    if !valid_artifact_name(&name) {
+        return HttpResponse::BadRequest().finish();
+    }
+
+    let body = match payload.to_bytes_limited(MAX_SHARED_CACHE_UPLOAD_BYTES).await {
+        Ok(Ok(body)) => body,
+        Ok(Err(_)) => return HttpResponse::PayloadTooLarge().finish(),
+        Err(_) => return HttpResponse::BadRequest().finish()
+    };
+
+    let transcodes_dir = shared_cache_dir.join(TRANSCODES_DIR);
+    let tmp_path = transcodes_dir.join(format!(".{}.tmp", uid()));
+
+    let result = fs::write(&tmp_path, &body)
+        .and_then(|_| fs::rename(&tmp_path, transcodes_dir.join(name.as_str())));
+
+    match result {
+        Ok(()) => HttpResponse::Created().finish(),
+        Err(err) => {
+            error!("Could not store uploaded artifact {} ({})", name, err);
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
+
+/// Writes the counts to the stats file if anything was counted since the
+/// last flush, and re-reads the stats paths file if it has changed (i.e.
+/// the site was deployed anew). Files are only read and written outside
+/// of the lock, so that incoming counts are never held up by disk access.
+fn stats_flush(state: &Mutex<StatsServerState>, stats_file: &Path) {
+    let (paths_file, paths_modified, unsaved_stats) = {
+        let mut state = state.lock().unwrap();
+        let unsaved_stats = if state.unsaved_changes {
+            state.unsaved_changes = false;
+            Some(state.stats.clone())
+        } else {
+            None
+        };
+
+        (state.paths.path.clone(), state.paths.modified, unsaved_stats)
+    };
+
+    if let Some(stats) = unsaved_stats {
+        if let Err(err) = stats.write(stats_file) {
+            error!("{}", err);
+            state.lock().unwrap().unsaved_changes = true;
+        }
+    }
+
+    let modified = fs::metadata(&paths_file)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+
+    if modified != paths_modified {
+        match StatsPaths::read(&paths_file) {
+            Ok(paths) => state.lock().unwrap().paths = paths,
+            Err(err) => error!("{}", err)
+        }
+    }
+}
+
+/// Counts a play or download reported by a site with the stats_endpoint
+/// option set. The request body is the path of the track page that was
+/// played, or of the file that was downloaded, only paths listed in the
+/// stats paths file of the site are accepted. As the counts are all that
+/// is kept, no cookies are set and no address or other information about
+/// the visitor is ever looked at.
+async fn stats_record(
+    body: String,
+    event: web::Path<String>,
+    state: web::Data<Mutex<StatsServerState>>
+) -> HttpResponse {
+    let event = match StatsEvent::from_key(&event) {
+        Some(event) => event,
+        None => return HttpResponse::NotFound().finish()
+    };
+
+    let mut state = state.lock().unwrap();
+
+    if !state.paths.contains(&body) || !state.stats.record(event, &body) {
+        return HttpResponse::BadRequest().finish();
+    }
+
+    state.unsaved_changes = true;
+
+    // Beacons are sent cross-origin, allowing any origin lets the
+    // browser treat the (empty) response as successful.
+    HttpResponse::NoContent()
+        .insert_header(("Access-Control-Allow-Origin", "*"))
+        .finish()
+}
+
+impl SharedCacheToken {
+    /// Compares digests instead of the tokens themselves, so that the time
+    /// the comparison takes does not reveal how much of a guessed token
+    /// is correct.
+    fn matches(&self, given: &str) -> bool {
+        Sha256::digest(self.0.as_bytes()) == Sha256::digest(given.as_bytes())
+    }
+}
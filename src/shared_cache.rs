@@ -0,0 +1,228 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! A content-addressed cache that can be shared between several machines
+//! building the same catalog (or different catalogs using the same audio
+//! files). Whereas the local cache (see [crate::Cache]) associates assets
+//! with source files by their path and metadata, artifacts in the shared
+//! cache are addressed purely by the content hash of the source file plus
+//! all parameters that went into computing the artifact. The shared cache
+//! is consulted before a transcode is computed locally, and newly computed
+//! transcodes are stored to it afterwards.
+//!
+//! Layout (identical for both directory and http backends):
+//!
+//! ```text
+//! transcodes/{key}         (the artifact itself)
+//! transcodes/{key}.sha256  (SHA-256 checksum of the artifact, hex encoded)
+//! ```
+//!
+//! The checksum file is always written after the artifact, so its presence
+//! signals a complete artifact. Fetched artifacts are only accepted if their
+//! content matches the checksum.
+
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use crate::{AudioFormat, SourceHash, TagMapping};
+use crate::util::uid;
+
+/// Increase when the layout of the shared cache or the way keys are computed
+/// changes, so that artifacts computed by different faircamp versions can
+/// not be confused with one another.
+const SHARED_CACHE_VERSION: usize = 3;
+
+/// The directory (or url path segment) in which transcodes are stored
+pub const TRANSCODES_DIR: &str = "transcodes";
+
+#[derive(Clone, Debug)]
+pub enum SharedCache {
+    /// A directory on disk, usually a network share mounted on each machine
+    Directory(PathBuf),
+    /// An http server that serves artifacts with GET and accepts new ones
+    /// with PUT. The base url is always stored with a trailing slash, the
+    /// token (if given) is sent as bearer token with each PUT request.
+    Http {
+        base_url: String,
+        token: Option<String>
+    }
+}
+
+/// Returns the response body for a successful GET request, None on any
+/// error (including a 404 response, which simply means a cache miss).
+fn http_get(url: &str) -> Option<Vec<u8>> {
+    match ureq::get(url).call() {
+        Ok(response) => {
+            let mut bytes = Vec::new();
+            response.into_reader().read_to_end(&mut bytes).ok()?;
+            Some(bytes)
+        }
+        Err(ureq::Error::Status(404, _)) => None,
+        Err(err) => {
+            warn!("Could not reach the shared cache at {} ({})", url, err);
+            None
+        }
+    }
+}
+
+fn http_put(url: &str, token: Option<&str>, bytes: &[u8]) -> bool {
+    let mut request = ureq::put(url);
+
+    if let Some(token) = token {
+        request = request.set("Authorization", &format!("Bearer {token}"));
+    }
+
+    match request.send_bytes(bytes) {
+        Ok(_) => true,
+        Err(ureq::Error::Status(401 | 403, _)) => {
+            warn!("The shared cache at {} refused to store an artifact, it requires a valid token to be passed with --shared-cache-token", url);
+            false
+        }
+        Err(_) => false
+    }
+}
+
+/// SHA-256 checksum of the given bytes in lowercase hex notation
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Artifact names accepted by the shared cache server, i.e. a key (hex
+/// encoded SHA-256) optionally followed by the ".sha256" suffix of the
+/// checksum file. Everything else is rejected to keep requests from
+/// writing outside of the shared cache directory.
+pub fn valid_artifact_name(name: &str) -> bool {
+    let key = name.strip_suffix(".sha256").unwrap_or(name);
+    key.len() == 64 && key.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+impl SharedCache {
+    /// Retrieves the artifact stored under the given key and writes it to
+    /// `target_path`, but only if its checksum can be verified. Returns
+    /// whether the artifact was retrieved.
+    pub fn fetch(&self, dir: &str, key: &str, target_path: &Path) -> bool {
+        let (artifact, checksum) = match self {
+            SharedCache::Directory(path) => {
+                let artifact_path = path.join(dir).join(key);
+                let checksum_path = path.join(dir).join(format!("{key}.sha256"));
+
+                // Checksum first: If it's there, the artifact is complete
+                match (fs::read_to_string(checksum_path), fs::read(artifact_path)) {
+                    (Ok(checksum), Ok(artifact)) => (artifact, checksum),
+                    _ => return false
+                }
+            }
+            SharedCache::Http { base_url, .. } => {
+                let checksum = match http_get(&format!("{base_url}{dir}/{key}.sha256")) {
+                    Some(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                    None => return false
+                };
+
+                match http_get(&format!("{base_url}{dir}/{key}")) {
+                    Some(artifact) => (artifact, checksum),
+                    None => return false
+                }
+            }
+        };
+
+        if sha256_hex(&artifact) != checksum.trim() {
+            warn!("Ignoring artifact {}/{} in the shared cache because it does not match its checksum (it might be corrupted or incompletely stored)", dir, key);
+            return false;
+        }
+
+        match fs::write(target_path, artifact) {
+            Ok(()) => true,
+            Err(err) => {
+                error!("Could not write artifact retrieved from the shared cache to {:?} ({})", target_path, err);
+                false
+            }
+        }
+    }
+
+    /// A shared cache is specified on the command line either as a
+    /// directory path, or as an http(s) url, in the latter case optionally
+    /// accompanied by a token that authorizes storing artifacts.
+    pub fn from_arg(value: &str, token: Option<String>) -> SharedCache {
+        if value.starts_with("http://") || value.starts_with("https://") {
+            let base_url = if value.ends_with('/') {
+                value.to_string()
+            } else {
+                format!("{value}/")
+            };
+
+            SharedCache::Http { base_url, token }
+        } else {
+            SharedCache::Directory(PathBuf::from(value))
+        }
+    }
+
+    /// Stores the artifact at `source_path` under the given key. Failure to
+    /// store is reported but not fatal, the build continues either way.
+    pub fn store(&self, dir: &str, key: &str, source_path: &Path) {
+        let artifact = match fs::read(source_path) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                error!("Could not read {:?} for storing it in the shared cache ({})", source_path, err);
+                return;
+            }
+        };
+
+        let checksum = sha256_hex(&artifact);
+
+        match self {
+            SharedCache::Directory(path) => {
+                let artifact_dir = path.join(dir);
+
+                if let Err(err) = fs::create_dir_all(&artifact_dir) {
+                    error!("Could not create directory {:?} in the shared cache ({})", artifact_dir, err);
+                    return;
+                }
+
+                // Write to a temporary file first and then rename it, so other
+                // machines never get to see a partially written artifact.
+                let write_atomically = |filename: String, bytes: &[u8]| -> Result<(), std::io::Error> {
+                    let tmp_path = artifact_dir.join(format!(".{}.tmp", uid()));
+                    fs::write(&tmp_path, bytes)?;
+                    fs::rename(&tmp_path, artifact_dir.join(filename))
+                };
+
+                let result = write_atomically(key.to_string(), &artifact)
+                    .and_then(|_| write_atomically(format!("{key}.sha256"), checksum.as_bytes()));
+
+                if let Err(err) = result {
+                    error!("Could not store artifact {}/{} in the shared cache ({})", dir, key, err);
+                }
+            }
+            SharedCache::Http { base_url, token } => {
+                let token = token.as_deref();
+                let stored = http_put(&format!("{base_url}{dir}/{key}"), token, &artifact) &&
+                    http_put(&format!("{base_url}{dir}/{key}.sha256"), token, checksum.as_bytes());
+
+                if !stored {
+                    error!("Could not store artifact {}/{} in the shared cache at {}", dir, key, base_url);
+                }
+            }
+        }
+    }
+
+    /// Computes the key under which a transcode is stored in the shared
+    /// cache, derived from the content of the source file and all
+    /// parameters that determine the transcoded result. The tag mapping
+    /// enters the key through its bincode serialization rather than
+    /// through [std::hash::Hash], whose output may differ between builds
+    /// of faircamp made with different Rust versions.
+    pub fn transcode_key(hash: &SourceHash, format: AudioFormat, tag_mapping: &TagMapping) -> String {
+        let tag_mapping_serialized = bincode::serialize(tag_mapping).unwrap();
+        let descriptor = format!(
+            "{SHARED_CACHE_VERSION}:{}:{}:{format:?}:{}",
+            hash.version(),
+            hash.value,
+            sha256_hex(&tag_mapping_serialized)
+        );
+
+        sha256_hex(descriptor.as_bytes())
+    }
+}
@@ -8,8 +8,8 @@ use std::time::SystemTime;
 
 use serde_derive::{Deserialize, Serialize};
 
-use crate::Build;
-use crate::util::url_safe_hash_base64;
+use crate::{Build, Segment};
+use crate::util::{generic_hash, url_safe_hash_base64};
 
 /// This stores relevant metadata for checking whether files we are processing
 /// in the current build match files we were processing in a previous build.
@@ -57,6 +57,31 @@ impl SourceHash {
         url_safe_hash_base64(&self.value)
     }
 
+    /// Derives the hash that identifies a segment of the hashed file (see
+    /// [crate::CueSheet]), so that segments are cached (and shared) apart
+    /// from the entire file and from each other.
+    pub fn for_segment(&self, segment: &Segment) -> SourceHash {
+        let mut bytes = Vec::with_capacity(24);
+
+        bytes.extend_from_slice(&self.value.to_le_bytes());
+        bytes.extend_from_slice(&segment.start_ms.to_le_bytes());
+        bytes.extend_from_slice(&segment.end_ms.unwrap_or(u64::MAX).to_le_bytes());
+
+        SourceHash {
+            value: seahash::hash(&bytes),
+            version: self.version
+        }
+    }
+
+    /// Stands in for the actual hash when the catalog is only validated
+    /// (see [Build::validate_only]) and the file content is never read.
+    pub fn from_file_meta(file_meta: &FileMeta) -> SourceHash {
+        SourceHash {
+            value: generic_hash(file_meta),
+            version: SourceHash::HASHING_ALGORITHM_VERSION
+        }
+    }
+
     pub fn incompatible_version(&self) -> bool {
         self.version != SourceHash::HASHING_ALGORITHM_VERSION
     }
@@ -74,4 +99,8 @@ impl SourceHash {
             version: SourceHash::HASHING_ALGORITHM_VERSION
         }
     }
+
+    pub fn version(&self) -> usize {
+        self.version
+    }
 }
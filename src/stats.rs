@@ -0,0 +1,261 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use url::Url;
+
+use crate::Build;
+use crate::util::uid;
+
+/// No page or file of a faircamp site has a path anywhere near this long,
+/// anything longer that is sent to the stats server is rejected.
+const MAX_PATH_LENGTH: usize = 1024;
+
+/// The stats server is publicly reachable, so anyone could send it made-up
+/// paths. To keep the stats file from growing without bounds, new paths are
+/// not accepted anymore once this many are known (counts for paths already
+/// known are still recorded though).
+const MAX_PATHS: usize = 100_000;
+
+/// Written to the root of the build directory when the stats_endpoint option
+/// is set, lists (the digests of) all paths the stats server should accept.
+pub const STATS_PATHS_FILENAME: &str = "stats_paths.txt";
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct Counts {
+    pub downloads: u64,
+    pub plays: u64
+}
+
+/// Play and download counts aggregated by the stats server. Only the
+/// path of the track page (for plays) or of the downloaded file (for
+/// downloads) is recorded, nothing about the visitors themselves.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Stats {
+    pub counts: BTreeMap<String, Counts>,
+    /// When the stats file was started
+    pub since: DateTime<Utc>
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum StatsEvent {
+    Download,
+    Play
+}
+
+/// The paths of a deployed site for which the stats server accepts counts,
+/// read from the stats_paths.txt file written by the build. The file only
+/// contains SHA-256 digests of the paths, as it is publicly reachable on the
+/// site and must not reveal e.g. the paths of downloads that are only
+/// accessible with an unlock code.
+#[derive(Debug)]
+pub struct StatsPaths {
+    digests: HashSet<String>,
+    /// When the file was last modified at the time it was read, used for
+    /// picking up changes when the site is deployed anew.
+    pub modified: Option<SystemTime>,
+    pub path: PathBuf
+}
+
+/// Adds the digests of all files in `dir` (and of all directories that have
+/// an index.html, as they are linked with a trailing slash when clean urls
+/// are used) to `digests`, recursively. `url_path` is the path under which
+/// `dir` is reached on the site, always ending with a slash.
+fn collect_path_digests(dir: &Path, url_path: &str, digests: &mut Vec<String>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            error!("Could not read {:?} while writing the stats paths ({})", dir, err);
+            return
+        }
+    };
+
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => {
+                collect_path_digests(&entry.path(), &format!("{url_path}{name}/"), digests);
+            }
+            Ok(_) => {
+                if name == "index.html" {
+                    digests.push(path_digest(url_path));
+                }
+
+                digests.push(path_digest(&format!("{url_path}{name}")));
+            }
+            Err(_) => ()
+        }
+    }
+}
+
+/// SHA-256 digest (in lowercase hex notation) of a path as it is reported
+/// by a site, i.e. with percent-encoding already decoded.
+fn path_digest(path: &str) -> String {
+    format!("{:x}", Sha256::digest(path.as_bytes()))
+}
+
+/// Writes the digests of all paths in the build directory to
+/// STATS_PATHS_FILENAME, so that the stats server can reject paths that
+/// do not exist on the site. Must be called after everything that can be
+/// played or downloaded has been written to the build directory.
+pub fn write_paths_file(build: &mut Build) {
+    // Paths are reported without the domain, but including the path
+    // under which the site is served (e.g. "/music/" for a base_url of
+    // "https://example.com/music/").
+    let site_path = match &build.base_url {
+        Some(base_url) => match Url::parse(base_url.prefix()) {
+            Ok(url) => url.path().to_string(),
+            Err(_) => String::from("/")
+        },
+        None => String::from("/")
+    };
+
+    let mut digests = Vec::new();
+
+    collect_path_digests(&build.build_dir, &site_path, &mut digests);
+
+    digests.sort();
+
+    let content = digests.join("\n");
+    let paths_file = build.build_dir.join(STATS_PATHS_FILENAME);
+
+    if let Err(err) = fs::write(&paths_file, content) {
+        error!("Could not write {:?} ({})", paths_file, err);
+    }
+
+    build.reserve_filename(STATS_PATHS_FILENAME);
+}
+
+impl Stats {
+    fn new() -> Stats {
+        Stats {
+            counts: BTreeMap::new(),
+            since: Utc::now()
+        }
+    }
+
+    /// Reads the stats file at the given path, or starts out with empty
+    /// stats if there is no file yet.
+    pub fn read(path: &Path) -> Result<Stats, String> {
+        match fs::read(path) {
+            Ok(bytes) => bincode::deserialize::<Stats>(&bytes)
+                .map_err(|err| format!("The stats file {path:?} could not be read, it might be corrupted or stem from an incompatible version of faircamp ({err})")),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Stats::new()),
+            Err(err) => Err(format!("The stats file {path:?} could not be read ({err})"))
+        }
+    }
+
+    /// Returns false if the path was rejected (see MAX_PATH_LENGTH and
+    /// MAX_PATHS), in which case nothing was recorded.
+    pub fn record(&mut self, event: StatsEvent, path: &str) -> bool {
+        if !path.starts_with('/') || path.len() > MAX_PATH_LENGTH {
+            return false;
+        }
+
+        let counts = match self.counts.get_mut(path) {
+            Some(counts) => counts,
+            None => {
+                if self.counts.len() >= MAX_PATHS {
+                    return false;
+                }
+
+                self.counts.entry(path.to_string()).or_default()
+            }
+        };
+
+        match event {
+            StatsEvent::Download => counts.downloads += 1,
+            StatsEvent::Play => counts.plays += 1
+        }
+
+        true
+    }
+
+    /// Prints the most played tracks and most downloaded files (each list
+    /// ordered by count) to stdout.
+    pub fn report(&self) {
+        println!("Counts recorded since {}", self.since.format("%Y-%m-%d %H:%M UTC"));
+        println!();
+
+        let sections: [(&str, fn(&Counts) -> u64); 2] = [
+            ("Plays", |counts| counts.plays),
+            ("Downloads", |counts| counts.downloads)
+        ];
+
+        for (heading, count) in sections {
+            let mut entries: Vec<(&String, u64)> = self.counts
+                .iter()
+                .map(|(path, counts)| (path, count(counts)))
+                .filter(|(_path, count)| *count > 0)
+                .collect();
+
+            entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+            let total: u64 = entries.iter().map(|(_path, count)| count).sum();
+
+            println!("{heading} ({total} in total)");
+
+            for (path, count) in entries {
+                println!("  {count:>8} | {path}");
+            }
+
+            println!();
+        }
+    }
+
+    /// The stats are written to a temporary file first and then renamed, so
+    /// an interruption can never leave behind a half-written stats file.
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        let serialized = bincode::serialize(self).unwrap();
+        let tmp_path = path.with_extension(format!("{}.tmp", uid()));
+
+        fs::write(&tmp_path, serialized)
+            .and_then(|_| fs::rename(&tmp_path, path))
+            .map_err(|err| format!("The stats file {path:?} could not be written ({err})"))
+    }
+}
+
+impl StatsEvent {
+    /// The event as it appears in the url the site reports it to, e.g.
+    /// "https://stats.example.com/play".
+    pub fn from_key(key: &str) -> Option<StatsEvent> {
+        match key {
+            "download" => Some(StatsEvent::Download),
+            "play" => Some(StatsEvent::Play),
+            _ => None
+        }
+    }
+}
+
+impl StatsPaths {
+    /// Whether the given path (as reported by a site, i.e. possibly
+    /// percent-encoded) is one of the paths of the site.
+    pub fn contains(&self, path: &str) -> bool {
+        match urlencoding::decode(path) {
+            Ok(decoded) => self.digests.contains(&path_digest(&decoded)),
+            Err(_) => false
+        }
+    }
+
+    pub fn read(path: &Path) -> Result<StatsPaths, String> {
+        let modified = fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(StatsPaths {
+                digests: content.lines().map(|line| line.to_string()).collect(),
+                modified,
+                path: path.to_path_buf()
+            }),
+            Err(err) => Err(format!("The stats paths file {path:?} could not be read ({err})"))
+        }
+    }
+}
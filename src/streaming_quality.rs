@@ -14,6 +14,8 @@ pub enum StreamingQuality {
 }
 
 impl StreamingQuality {
+    pub const ALL_KEYS: [&'static str; 3] = ["frugal", "hybrid", "standard"];
+
     /// Returns both streaming formats (we always render two) for iteration.
     /// [0] is the primary format (opus) which we preferentially offer for
     /// streaming through the website. [1] is the secondary format(mp3) which
@@ -42,6 +42,18 @@ pub fn generate(build: &mut Build, catalog: &Catalog) {
         generate_theme_css(build, &artist.borrow().theme);
     }
 
+    for page in &catalog.pages {
+        generate_theme_css(build, &page.theme);
+    }
+
+    for playlist in &catalog.playlists {
+        generate_theme_css(build, &playlist.theme);
+    }
+
+    for post in &catalog.posts {
+        generate_theme_css(build, &post.theme);
+    }
+
     for release in &catalog.releases {
         let release_ref = release.borrow();
 
@@ -147,6 +159,25 @@ fn generate_theme_css(build: &mut Build, theme: &Theme) {
         let filename = &image_ref.background_asset.as_ref().unwrap().filename;
         let hashed_filename = format!("background-{}.jpg", url_safe_hash_base64(filename));
 
+        // Browsers that support image-set() with type() pick the first
+        // format they support, others fall back to the plain jpeg url.
+        let background_image_set = if image_ref.background_variants.is_empty() {
+            String::new()
+        } else {
+            let candidates = image_ref.background_variants
+                .iter()
+                .map(|variant| {
+                    let variant_filename = variant.target_filename(&hashed_filename);
+                    let mime_type = variant.format.mime_type();
+                    format!(r#"url({variant_filename}) type("{mime_type}")"#)
+                })
+                .chain(std::iter::once(format!(r#"url({hashed_filename}) type("image/jpeg")"#)))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            format!("background: linear-gradient(var(--bg-overlay), var(--bg-overlay)), image-set({candidates}) center / cover;")
+        };
+
         // We are using a pseudo-element floating behind all other page content
         // to display the background image. A more straight-forward way would
         // be to use "fixed" background positioning on body itself, but Apple
@@ -156,6 +187,7 @@ fn generate_theme_css(build: &mut Build, theme: &Theme) {
         let background_override = formatdoc!("
             body::before {{
                 background: linear-gradient(var(--bg-overlay), var(--bg-overlay)), url({hashed_filename}) center / cover;
+                {background_image_set}
                 content: '';
                 display: block;
                 height: 100vh;
@@ -5,6 +5,8 @@ use serde_derive::{Deserialize, Serialize};
 
 use crate::{
     ArtistRc,
+    Chapter,
+    ImageCrop,
     Release,
     SourceHash,
     Track
@@ -12,11 +14,16 @@ use crate::{
 
 /// This is the final mapping of a cover image to be embedded into an output audio file.
 /// It only stores a source hash without any path information because faircamp anyway
-/// knows from where to get the cover image (= from the release struct).
+/// knows from where to get the cover image (= from the release struct). The crop of
+/// the cover is part of it as well, so that transcodes of differently cropped covers
+/// are never mistaken for one another (in the cache and the shared cache).
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
 pub enum ImageEmbed {
     Copy,
-    Write(SourceHash)
+    Write {
+        crop: Option<ImageCrop>,
+        hash: SourceHash
+    }
 }
 
 /// Set behavior for a single tag:
@@ -58,6 +65,14 @@ pub enum TagMapping {
         album: Option<String>,
         album_artist: Option<String>,
         artist: Option<String>,
+        /// Chapter markers of the track as an ffmpeg metadata file (see
+        /// [crate::Chapter::ffmetadata]), None if the track has no chapters
+        chapters: Option<String>,
+        /// Copyright notice, currently only written when a license is
+        /// specified for the track, as its SPDX identifier and url (e.g.
+        /// "CC-BY-4.0 (https://...)"), so that it reads the same in any
+        /// language.
+        copyright: Option<String>,
         image: Option<ImageEmbed>,
         title: Option<String>,
         /// Track number
@@ -67,6 +82,8 @@ pub enum TagMapping {
 }
 
 impl TagAction {
+    pub const ALL_KEYS: [&'static str; 3] = ["copy", "remove", "rewrite"];
+
     pub fn from_key(key: &str) -> Result<TagAction, String> {
         match key {
             "copy" => Ok(TagAction::Copy),
@@ -81,6 +98,10 @@ impl TagAction {
 }
 
 impl TagAgenda {
+    /// The presets that can be given as plain value to the tags option
+    /// (instead of configuring each tag separately).
+    pub const ALL_MANIFEST_KEYS: [&'static str; 3] = ["copy", "normalize", "remove"];
+
     pub fn normalize() -> TagAgenda {
         TagAgenda::Custom {
             album: TagAction::Rewrite,
@@ -225,13 +246,13 @@ impl TagMapping {
                     TagAction::Copy => Some(ImageEmbed::Copy),
                     TagAction::Remove => None,
                     TagAction::Rewrite => {
-                        if let Some(described_image) = &track.cover {
-                            Some(ImageEmbed::Write(described_image.borrow().hash.clone()))
-                        } else if let Some(described_image) = &release.cover {
-                            Some(ImageEmbed::Write(described_image.borrow().hash.clone()))
-                        } else {
-                            None
-                        }
+                        track.cover
+                            .as_ref()
+                            .or(release.cover.as_ref())
+                            .map(|described_image| ImageEmbed::Write {
+                                crop: described_image.crop,
+                                hash: described_image.borrow().hash.clone()
+                            })
                     }
                 };
 
@@ -260,10 +281,24 @@ impl TagMapping {
                     TagAction::Rewrite => Some(track_number)
                 };
 
+                let chapters_mapped = match track.chapters.is_empty() {
+                    true => None,
+                    false => {
+                        let duration_seconds = track.transcodes.borrow().source_meta.duration_seconds;
+                        Some(Chapter::ffmetadata(&track.chapters, duration_seconds))
+                    }
+                };
+
+                let copyright_mapped = track.license
+                    .as_ref()
+                    .map(|license| format!("{} ({})", license.identifier, license.url));
+
                 TagMapping::Custom {
                     album: album_mapped,
                     album_artist: album_artist_mapped,
                     artist: artist_mapped,
+                    chapters: chapters_mapped,
+                    copyright: copyright_mapped,
                     image: image_mapped,
                     title: title_mapped,
                     track: track_mapped
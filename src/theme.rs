@@ -10,7 +10,7 @@ use std::path::PathBuf;
 
 use indoc::formatdoc;
 
-use crate::{CoverGenerator, ImageRcView};
+use crate::{CoverGenerator, ImageRcView, WaveformColor, WaveformStyle};
 use crate::util::url_safe_hash_base64;
 
 mod dark;
@@ -34,6 +34,8 @@ pub struct Theme {
     pub font: ThemeFont,
     pub relative_waveforms: bool,
     pub round_corners: bool,
+    pub waveform_color: WaveformColor,
+    pub waveform_style: WaveformStyle,
     pub waveforms: bool
 }
 
@@ -75,6 +77,8 @@ pub struct ThemeVarsOklch {
 }
 
 impl Theme {
+    pub const ALL_WAVEFORMS_MANIFEST_KEYS: [&'static str; 3] = ["absolute", "disabled", "relative"];
+
     pub fn new() -> Theme {
         Theme {
             accent_brightening: 50,
@@ -90,10 +94,50 @@ impl Theme {
             font: ThemeFont::Default,
             relative_waveforms: true,
             round_corners: false,
+            waveform_color: WaveformColor::Theme,
+            waveform_style: WaveformStyle::Line,
             waveforms: true
         }
     }
 
+    /// The primary background color (--bg-1 in the theme stylesheet) as a
+    /// hex sRGB color, for places outside of css that don't necessarily
+    /// understand oklch (e.g. the web app manifest).
+    pub fn background_hex(&self) -> String {
+        let range = self.base.vars().background_1_lightness_range;
+        let lightness = range.start + (self.dynamic_range as f32 / 100.0) * (range.end - range.start);
+        let chroma = self.base_chroma as f32 * ThemeVarsOklch::chroma_attenuator(lightness);
+
+        // OKLCH -> OKLab (in css, a chroma of 100% corresponds to 0.4)
+        let l = lightness as f64 / 100.0;
+        let c = chroma as f64 / 100.0 * 0.4;
+        let h = (self.base_hue as f64).to_radians();
+        let (a, b) = (c * h.cos(), c * h.sin());
+
+        // OKLab -> linear sRGB, see https://bottosson.github.io/posts/oklab/
+        let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s_ = (l - 0.0894841775 * a - 1.291485548 * b).powi(3);
+
+        let linear = [
+            4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_,
+            -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_,
+            -0.0041960863 * l_ - 0.7034186147 * m_ + 1.707614701 * s_
+        ];
+
+        let [red, green, blue] = linear.map(|value| {
+            let gamma_encoded = if value <= 0.0031308 {
+                12.92 * value
+            } else {
+                1.055 * value.powf(1.0 / 2.4) - 0.055
+            };
+
+            (gamma_encoded.clamp(0.0, 1.0) * 255.0).round() as u8
+        });
+
+        format!("#{red:02x}{green:02x}{blue:02x}")
+    }
+
     pub fn print_vars(&self) -> String {
         let oklch = |l: f32, c: f32, h: u16| format!("oklch({l}% {c}% {h})");
         let oklcha = |l: f32, c: f32, h: u16, a: f32| format!("oklch({l}% {c}% {h} / {a}%)");
@@ -190,6 +234,8 @@ impl Theme {
 }
 
 impl ThemeBase {
+    pub const ALL_MANIFEST_KEYS: [&'static str; 2] = ["dark", "light"];
+
     pub fn from_manifest_key(key: &str) -> Option<ThemeBase> {
         match key {
             "dark" => Some(ThemeBase::Dark),
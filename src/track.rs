@@ -6,27 +6,24 @@ use std::path::PathBuf;
 
 use crate::{
     ArtistRc,
-    Asset,
     AssetIntent,
     AudioFormat,
     Build,
+    Chapter,
     DescribedImage,
     DownloadAccess,
     DownloadFormat,
     Extra,
-    ffmpeg,
     HeuristicAudioMeta,
     HtmlAndStripped,
+    License,
     Link,
     StreamingQuality,
     TagAgenda,
     TagMapping,
     Theme,
-    Transcode,
-    TranscodesRcView,
-    util
+    TranscodesRcView
 };
-use crate::util::generic_hash;
 
 #[derive(Debug)]
 pub struct Track {
@@ -39,6 +36,14 @@ pub struct Track {
     /// Generated when we gathered all artist and title metadata.
     /// Used to compute the download/stream asset filenames.
     pub asset_basename: Option<String>,
+    /// Labeled points in time within the track (e.g. a DJ mix tracklist),
+    /// sorted by their start
+    pub chapters: Vec<Chapter>,
+    /// For tracks split from a continuous recording along a CUE sheet, the
+    /// transcodes of the entire recording (whereas `transcodes` then only
+    /// cover the track's segment of it). The release page streams the
+    /// entire recording, which enables gapless playback across the tracks.
+    pub continuous_source: Option<TranscodesRcView>,
     pub copy_link: bool,
     pub cover: Option<DescribedImage>,
     pub download_access: DownloadAccess,
@@ -48,12 +53,17 @@ pub struct Track {
     pub extras: Vec<Extra>,
     // TODO: Re-check if we need this post-creation (if not we don't need to store it on Track)
     pub heuristic_audio_meta: Option<HeuristicAudioMeta>,
+    /// The license under which the track is published (if specified)
+    pub license: Option<License>,
     pub links: Vec<Link>,
     pub more: Option<HtmlAndStripped>,
     /// Optional custom label for the button that (by default) says "More" on the
     /// track page and points to additional long-form content for the track.
     /// For tracks this label is also displayed in the track list on a release page.
     pub more_label: Option<String>,
+    /// Whether this track can already be streamed while its release is
+    /// upcoming (i.e. published as a teaser ahead of its publish_at date).
+    pub preview: bool,
     /// Whether players should offer speed controls for this track
     pub speed_controls: bool,
     pub streaming_quality: StreamingQuality,
@@ -87,6 +97,7 @@ impl Track {
 
     pub fn new(
         artists_to_map: Vec<String>,
+        chapters: Vec<Chapter>,
         copy_link: bool,
         cover: Option<DescribedImage>,
         download_access: DownloadAccess,
@@ -94,9 +105,11 @@ impl Track {
         embedding: bool,
         extra_downloads: bool,
         extras: Vec<Extra>,
+        license: Option<License>,
         links: Vec<Link>,
         more: Option<HtmlAndStripped>,
         more_label: Option<String>,
+        preview: bool,
         speed_controls: bool,
         streaming_quality: StreamingQuality,
         synopsis: Option<String>,
@@ -109,6 +122,8 @@ impl Track {
             artists: Vec::new(),
             artists_to_map,
             asset_basename: None,
+            chapters,
+            continuous_source: None,
             copy_link,
             cover,
             download_access,
@@ -117,9 +132,11 @@ impl Track {
             extra_downloads,
             extras,
             heuristic_audio_meta: None,
+            license,
             links,
             more,
             more_label,
+            preview,
             speed_controls,
             streaming_quality,
             synopsis,
@@ -158,27 +175,6 @@ impl Track {
         tag_mapping: &TagMapping,
         cover_path: Option<&PathBuf>
     ) {
-        let mut transcodes_mut = self.transcodes.borrow_mut();
-
-        if let Some(transcode) = transcodes_mut.get_mut(target_format, generic_hash(tag_mapping)) {
-            if asset_intent == AssetIntent::Deliverable {
-                transcode.asset.unmark_stale();
-            }
-        } else {
-            let target_filename = format!("{}{}", util::uid(), target_format.extension());
-
-            info_transcoding!("{:?} to {}", self.transcodes.file_meta.path, target_format);
-            ffmpeg::transcode(
-                cover_path,
-                &build.catalog_dir.join(&self.transcodes.file_meta.path),
-                &build.cache_dir.join(&target_filename),
-                transcodes_mut.source_meta.format_family,
-                target_format,
-                tag_mapping
-            ).unwrap();
-
-            let asset = Asset::new(build, target_filename, asset_intent);
-            transcodes_mut.formats.push(Transcode::new(asset, target_format, generic_hash(tag_mapping)));
-        }
+        self.transcodes.transcode_as(target_format, build, asset_intent, tag_mapping, cover_path);
     }
 }
@@ -14,6 +14,17 @@ pub enum TrackNumbering {
 }
 
 impl TrackNumbering {
+    pub const ALL_MANIFEST_KEYS: [&'static str; 8] = [
+        "arabic",
+        "arabic-dotted",
+        "arabic-padded",
+        "disabled",
+        "hexadecimal",
+        "hexadecimal-padded",
+        "roman",
+        "roman-dotted"
+    ];
+
     pub fn format(&self, number: usize) -> String {
         match self {
             TrackNumbering::Arabic => number.to_string(),
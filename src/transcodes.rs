@@ -11,13 +11,21 @@ use serde_derive::{Serialize, Deserialize};
 
 use crate::{
     Asset,
+    AssetIntent,
     AudioFormat,
     AudioMeta,
+    Build,
+    ffmpeg,
     FileMeta,
+    Segment,
+    SharedCache,
     SourceHash,
+    TagMapping,
+    util,
     View
 };
-use crate::util::url_safe_base64;
+use crate::shared_cache::TRANSCODES_DIR;
+use crate::util::{format_time, generic_hash, url_safe_base64};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Transcode {
@@ -36,6 +44,10 @@ pub struct Transcode {
 pub struct Transcodes {
     pub formats: Vec<Transcode>,
     pub hash: SourceHash,
+    /// If set, these transcodes are not of the entire source file, but
+    /// only of a segment of it (see [crate::CueSheet]). Source meta and
+    /// hash then describe only the segment as well.
+    pub segment: Option<Segment>,
     pub source_meta: AudioMeta,
     pub views: Vec<View>
 }
@@ -70,7 +82,7 @@ impl Transcodes {
     /// (or underlying structs that are contained within). This automatically
     /// informs the cache not to try to deserialize manifests that hold old,
     /// incompatible data.
-    pub const CACHE_SERIALIZATION_KEY: &'static str = "transcodes3";
+    pub const CACHE_SERIALIZATION_KEY: &'static str = "transcodes5";
 
     pub fn deserialize_cached(path: &Path) -> Option<Transcodes> {
         match fs::read(path) {
@@ -120,11 +132,13 @@ impl Transcodes {
     pub fn new(
         file_meta: FileMeta,
         hash: SourceHash,
+        segment: Option<Segment>,
         source_meta: AudioMeta
     ) -> Transcodes {
         Transcodes {
             formats: Vec::new(),
             hash,
+            segment,
             source_meta,
             views: vec![View::new(file_meta)]
         }
@@ -153,8 +167,13 @@ impl TranscodesRc {
         self.transcodes.borrow().hash == *hash
     }
 
-    pub fn new(file_meta: FileMeta, hash: SourceHash, source_meta: AudioMeta) -> TranscodesRc {
-        let transcodes = Transcodes::new(file_meta, hash, source_meta);
+    pub fn new(
+        file_meta: FileMeta,
+        hash: SourceHash,
+        segment: Option<Segment>,
+        source_meta: AudioMeta
+    ) -> TranscodesRc {
+        let transcodes = Transcodes::new(file_meta, hash, segment, source_meta);
 
         TranscodesRc {
             transcodes: Rc::new(RefCell::new(transcodes))
@@ -194,4 +213,66 @@ impl TranscodesRcView {
             transcodes
         }
     }
+
+    pub fn transcode_as(
+        &self,
+        target_format: AudioFormat,
+        build: &Build,
+        asset_intent: AssetIntent,
+        tag_mapping: &TagMapping,
+        cover_path: Option<&PathBuf>
+    ) {
+        let mut transcodes_mut = self.transcodes.borrow_mut();
+
+        if let Some(transcode) = transcodes_mut.get_mut(target_format, generic_hash(tag_mapping)) {
+            if asset_intent == AssetIntent::Deliverable {
+                transcode.asset.unmark_stale();
+            }
+        } else {
+            let target_filename = format!("{}{}", util::uid(), target_format.extension());
+            let target_path = build.cache_dir.join(&target_filename);
+
+            let shared_cache_key = SharedCache::transcode_key(
+                &transcodes_mut.hash,
+                target_format,
+                tag_mapping
+            );
+
+            let fetched_from_shared_cache = match &build.shared_cache {
+                Some(shared_cache) => shared_cache.fetch(TRANSCODES_DIR, &shared_cache_key, &target_path),
+                None => false
+            };
+
+            if fetched_from_shared_cache {
+                info_cache!("Retrieved {:?} as {} from the shared cache", self.file_meta.path, target_format);
+            } else {
+                match &transcodes_mut.segment {
+                    Some(segment) => {
+                        let start_formatted = format_time(segment.start_seconds());
+                        info_transcoding!("{:?} (segment starting at {}) to {}", self.file_meta.path, start_formatted, target_format);
+                    }
+                    None => {
+                        info_transcoding!("{:?} to {}", self.file_meta.path, target_format);
+                    }
+                }
+
+                ffmpeg::transcode(
+                    cover_path,
+                    &build.catalog_dir.join(&self.file_meta.path),
+                    transcodes_mut.segment.as_ref(),
+                    &target_path,
+                    transcodes_mut.source_meta.format_family,
+                    target_format,
+                    tag_mapping
+                ).unwrap();
+
+                if let Some(shared_cache) = &build.shared_cache {
+                    shared_cache.store(TRANSCODES_DIR, &shared_cache_key, &target_path);
+                }
+            }
+
+            let asset = Asset::new(build, target_filename, asset_intent);
+            transcodes_mut.formats.push(Transcode::new(asset, target_format, generic_hash(tag_mapping)));
+        }
+    }
 }
@@ -149,6 +149,56 @@ pub fn html_escape_outside_attribute(string: &str) -> String {
           .replace('>', "&gt;")
 }
 
+/// Escape e.g. "say \"hi\"" so it can be rendered inside a double-quoted
+/// JSON string, e.g. as {"name":"say \"hi\""}
+pub fn json_escape_inside_string(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+
+    for character in string.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character if (character as u32) < 0x20 => {
+                escaped.push_str(&format!("\\u{:04x}", character as u32));
+            }
+            character => escaped.push(character)
+        }
+    }
+
+    escaped
+}
+
+/// Parses a human-readable size such as "500MB", "1.5GB" or "20 GB" (units
+/// are case-insensitive and - like in [format_bytes] - based on 1024, a
+/// number without unit is taken as bytes). Negative numbers and the
+/// non-finite values that f64 parsing would otherwise let through ("inf",
+/// "infinity", "NaN") are rejected.
+pub fn parse_bytes(value: &str) -> Result<u64, String> {
+    let normalized = value.trim().to_uppercase();
+
+    let (number, unit_bytes) = if let Some(number) = normalized.strip_suffix("TB") {
+        (number, 1024 * BYTES_GB)
+    } else if let Some(number) = normalized.strip_suffix("GB") {
+        (number, BYTES_GB)
+    } else if let Some(number) = normalized.strip_suffix("MB") {
+        (number, BYTES_MB)
+    } else if let Some(number) = normalized.strip_suffix("KB") {
+        (number, BYTES_KB)
+    } else if let Some(number) = normalized.strip_suffix('B') {
+        (number, 1)
+    } else {
+        (normalized.as_str(), 1)
+    };
+
+    match number.trim().parse::<f64>() {
+        Ok(number) if number.is_finite() && number.is_sign_positive() => Ok((number * unit_bytes as f64) as u64),
+        _ => Err(format!("'{value}' is not a valid size (examples of valid sizes are '500MB', '1.5GB' or '20GB')"))
+    }
+}
+
 /// Efficient, reusable implementation of the annoying OsString to String conversion
 pub fn string_from_os(os_string: OsString) -> String {
     match os_string.into_string() {
@@ -0,0 +1,555 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! A more detailed waveform analysis than the simplified peaks stored in
+//! [crate::AudioMeta], computed from the decoded samples at the same time.
+//! It keeps the left and right channel apart, stores an RMS level alongside
+//! each peak, and estimates the spectral brightness of each window. This is
+//! what the mirrored and stereo waveform styles, the spectral waveform
+//! color, and the static waveform images (SVG/PNG) are drawn from.
+
+use std::fs;
+use std::path::Path;
+
+use serde_derive::{Serialize, Deserialize};
+use tiny_skia::{
+    Color,
+    FillRule,
+    GradientStop,
+    LinearGradient,
+    LineCap,
+    Paint,
+    PathBuilder,
+    Pixmap,
+    Point,
+    SpreadMode,
+    Stroke,
+    Transform
+};
+
+use crate::{Theme, ThemeBase};
+use crate::decode::DecodeResult;
+
+/// Dominant frequencies (in Hz) at or below this are considered fully dark
+const BRIGHTNESS_FREQUENCY_MIN: f32 = 50.0;
+/// Dominant frequencies (in Hz) at or above this are considered fully bright
+const BRIGHTNESS_FREQUENCY_MAX: f32 = 5000.0;
+
+/// Windows with an RMS level below this are treated as silence, for which
+/// the zero crossing rate (picking up dither and noise) is meaningless.
+const SILENCE_RMS: f32 = 0.001;
+
+/// The spectral color gradient of static images is limited to this many
+/// stops, which is plenty to convey the color changes over time.
+const SPECTRAL_GRADIENT_STOPS: usize = 64;
+
+/// Open Graph previews are most commonly displayed at 1.91:1
+pub const WAVEFORM_IMAGE_HEIGHT: u32 = 630;
+pub const WAVEFORM_IMAGE_WIDTH: u32 = 1200;
+pub const WAVEFORM_PNG_FILENAME: &str = "waveform.png";
+pub const WAVEFORM_SVG_FILENAME: &str = "waveform.svg";
+
+const IMAGE_PADDING_X: f32 = 60.0;
+const IMAGE_PADDING_Y: f32 = 90.0;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WaveformAnalysis {
+    /// Per window, the estimated spectral brightness in the range 0-1,
+    /// derived from the zero crossing rate (0 = bass heavy/dark sound,
+    /// 1 = treble heavy/bright sound).
+    pub brightness: Vec<f32>,
+    /// Per window, the largest absolute amplitude in the left channel,
+    /// normalized so that the loudest window of both channels is 1.0
+    pub peaks_left: Vec<f32>,
+    /// Like `peaks_left`, identical to it for mono audio
+    pub peaks_right: Vec<f32>,
+    /// Per window, the RMS level of the left channel, normalized with the
+    /// same factor as the peaks (so the two can be layered)
+    pub rms_left: Vec<f32>,
+    /// Like `rms_left`, identical to it for mono audio
+    pub rms_right: Vec<f32>
+}
+
+/// How the waveform is colored on the site and in static images
+#[derive(Clone, Debug, Hash)]
+pub enum WaveformColor {
+    /// Color varies along the waveform, following its spectral brightness
+    Spectral,
+    /// The foreground color of the theme
+    Theme
+}
+
+/// How the waveform is drawn on the site and in static images
+#[derive(Clone, Debug, Hash)]
+pub enum WaveformStyle {
+    /// A single line tracing the peaks (faircamp's original look)
+    Line,
+    /// Peaks and RMS level as filled layers, mirrored around the center
+    Mirrored,
+    /// Like mirrored, but with the left channel above and the right
+    /// channel below the center
+    Stereo
+}
+
+/// Geometry for drawing into a static image
+struct ImageLayout {
+    amplitude: f32,
+    center_y: f32,
+    width: f32,
+    x_offset: f32
+}
+
+/// Returns the points of a shape that traces the upper values from left to
+/// right above the center and then the lower values from right to left
+/// below the center.
+fn filled_outline(layout: &ImageLayout, upper: &[f32], lower: &[f32]) -> Vec<(f32, f32)> {
+    let mut points = Vec::with_capacity(upper.len() + lower.len());
+
+    for (index, value) in upper.iter().enumerate() {
+        points.push((layout.x(index, upper.len()), layout.center_y - value * layout.amplitude));
+    }
+
+    for (index, value) in lower.iter().enumerate().rev() {
+        points.push((layout.x(index, lower.len()), layout.center_y + value * layout.amplitude));
+    }
+
+    points
+}
+
+/// h in degrees (0-360), s and l in the range 0-1, returns r, g, b in the
+/// range 0-1.
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h_sector = (h % 360.0) / 60.0;
+    let x = c * (1.0 - (h_sector % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+
+    let (r, g, b) = match h_sector as u8 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x)
+    };
+
+    (r + m, g + m, b + m)
+}
+
+/// Resamples a sequence of values to the given length by picking the
+/// nearest value.
+fn resample(values: &[f32], length: usize) -> Vec<f32> {
+    if values.is_empty() {
+        return vec![0.0; length];
+    }
+
+    (0..length)
+        .map(|index| {
+            let factor = if length > 1 { index as f32 / (length - 1) as f32 } else { 0.0 };
+            values[(factor * (values.len() - 1) as f32).round() as usize]
+        })
+        .collect()
+}
+
+fn rgb_hex((r, g, b): (f32, f32, f32)) -> String {
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        (r * 255.0).round() as u8,
+        (g * 255.0).round() as u8,
+        (b * 255.0).round() as u8
+    )
+}
+
+/// Maps a brightness value (0-1) to a color: Dark sounds are warm (red),
+/// bright sounds are cool (blue). The same mapping is implemented in
+/// player.js for the waveforms on the site.
+pub fn spectral_rgb(brightness: f32) -> (f32, f32, f32) {
+    hsl_to_rgb(15.0 + brightness * 240.0, 0.7, 0.55)
+}
+
+/// Points as svg path data
+fn svg_path_data(points: &[(f32, f32)], close: bool) -> String {
+    let mut d = points
+        .iter()
+        .enumerate()
+        .map(|(index, (x, y))| {
+            let command = if index == 0 { 'M' } else { 'L' };
+            format!("{command}{x:.1},{y:.1}")
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    if close {
+        d.push_str(" Z");
+    }
+
+    d
+}
+
+fn tiny_skia_path(points: &[(f32, f32)], close: bool) -> Option<tiny_skia::Path> {
+    let mut path_builder = PathBuilder::new();
+
+    for (index, (x, y)) in points.iter().enumerate() {
+        if index == 0 {
+            path_builder.move_to(*x, *y);
+        } else {
+            path_builder.line_to(*x, *y);
+        }
+    }
+
+    if close {
+        path_builder.close();
+    }
+
+    path_builder.finish()
+}
+
+impl ImageLayout {
+    fn new() -> ImageLayout {
+        let height = WAVEFORM_IMAGE_HEIGHT as f32;
+
+        ImageLayout {
+            amplitude: (height - IMAGE_PADDING_Y * 2.0) / 2.0,
+            center_y: height / 2.0,
+            width: WAVEFORM_IMAGE_WIDTH as f32 - IMAGE_PADDING_X * 2.0,
+            x_offset: IMAGE_PADDING_X
+        }
+    }
+
+    fn x(&self, index: usize, count: usize) -> f32 {
+        let factor = if count > 1 { index as f32 / (count - 1) as f32 } else { 0.0 };
+        self.x_offset + factor * self.width
+    }
+}
+
+impl WaveformAnalysis {
+    /// The brightness and per-channel peaks/RMS are computed over [points]
+    /// windows of equal length (the last window may be shorter).
+    pub fn compute(decode_result: &DecodeResult, points: u32) -> WaveformAnalysis {
+        let channels = decode_result.channels.max(1) as usize;
+        let frame_count = decode_result.samples.len() / channels;
+        let frames_per_window = (frame_count / points as usize).max(1);
+
+        let mut analysis = WaveformAnalysis {
+            brightness: Vec::with_capacity(points as usize + 1),
+            peaks_left: Vec::with_capacity(points as usize + 1),
+            peaks_right: Vec::with_capacity(points as usize + 1),
+            rms_left: Vec::with_capacity(points as usize + 1),
+            rms_right: Vec::with_capacity(points as usize + 1)
+        };
+
+        let mut previous_mix: f32 = 0.0;
+
+        for window in decode_result.samples.chunks(frames_per_window * channels) {
+            let mut peak_left: f32 = 0.0;
+            let mut peak_right: f32 = 0.0;
+            let mut squares_left = 0.0;
+            let mut squares_right = 0.0;
+            let mut zero_crossings = 0;
+
+            for frame in window.chunks(channels) {
+                let left = frame[0];
+                let right = frame.get(1).copied().unwrap_or(left);
+
+                peak_left = peak_left.max(left.abs());
+                peak_right = peak_right.max(right.abs());
+                squares_left += left * left;
+                squares_right += right * right;
+
+                let mix = (left + right) / 2.0;
+                if mix.is_sign_positive() != previous_mix.is_sign_positive() {
+                    zero_crossings += 1;
+                }
+                previous_mix = mix;
+            }
+
+            let window_frames = window.len().div_ceil(channels) as f32;
+            let rms_left = (squares_left / window_frames).sqrt();
+            let rms_right = (squares_right / window_frames).sqrt();
+
+            // A sine wave crosses zero twice per period, so the zero crossing
+            // rate gives a rough estimate of the dominant frequency.
+            let brightness = if rms_left.max(rms_right) < SILENCE_RMS {
+                0.0
+            } else {
+                let frequency = zero_crossings as f32 / window_frames * decode_result.sample_rate as f32 / 2.0;
+                if frequency <= BRIGHTNESS_FREQUENCY_MIN {
+                    0.0
+                } else {
+                    let octaves = (frequency / BRIGHTNESS_FREQUENCY_MIN).log2();
+                    let octave_range = (BRIGHTNESS_FREQUENCY_MAX / BRIGHTNESS_FREQUENCY_MIN).log2();
+                    (octaves / octave_range).min(1.0)
+                }
+            };
+
+            analysis.brightness.push(brightness);
+            analysis.peaks_left.push(peak_left);
+            analysis.peaks_right.push(peak_right);
+            analysis.rms_left.push(rms_left);
+            analysis.rms_right.push(rms_right);
+        }
+
+        let peak_max = analysis.peaks_left
+            .iter()
+            .chain(analysis.peaks_right.iter())
+            .fold(0.0, |max: f32, peak| max.max(*peak));
+
+        if peak_max > 0.0 {
+            // Some decoders overshoot slightly beyond 1.0, so we also limit
+            // (and not only scale) here.
+            for values in [
+                &mut analysis.peaks_left,
+                &mut analysis.peaks_right,
+                &mut analysis.rms_left,
+                &mut analysis.rms_right
+            ] {
+                for value in values.iter_mut() {
+                    *value = (*value / peak_max).min(1.0);
+                }
+            }
+        }
+
+        analysis
+    }
+
+    /// Concatenates the analyses of several tracks into one (used for the
+    /// waveform image of a whole release). Each analysis is given a share
+    /// of the [points] proportional to its duration in seconds.
+    pub fn concatenate(analyses: &[(&WaveformAnalysis, f32)], points: usize) -> WaveformAnalysis {
+        let total_duration: f32 = analyses.iter().map(|(_, duration)| duration).sum();
+
+        let mut concatenated = WaveformAnalysis {
+            brightness: Vec::with_capacity(points),
+            peaks_left: Vec::with_capacity(points),
+            peaks_right: Vec::with_capacity(points),
+            rms_left: Vec::with_capacity(points),
+            rms_right: Vec::with_capacity(points)
+        };
+
+        for (analysis, duration) in analyses {
+            let share = if total_duration > 0.0 {
+                ((duration / total_duration) * points as f32).round().max(1.0) as usize
+            } else {
+                (points / analyses.len()).max(1)
+            };
+
+            concatenated.brightness.extend(resample(&analysis.brightness, share));
+            concatenated.peaks_left.extend(resample(&analysis.peaks_left, share));
+            concatenated.peaks_right.extend(resample(&analysis.peaks_right, share));
+            concatenated.rms_left.extend(resample(&analysis.rms_left, share));
+            concatenated.rms_right.extend(resample(&analysis.rms_right, share));
+        }
+
+        concatenated
+    }
+
+    /// Returns the (upper peaks, upper rms, lower peaks, lower rms) lanes
+    /// for the given style. For the line style only the upper peaks are
+    /// relevant.
+    pub fn lanes(&self, style: &WaveformStyle) -> (Vec<f32>, Vec<f32>, Vec<f32>, Vec<f32>) {
+        match style {
+            WaveformStyle::Line |
+            WaveformStyle::Mirrored => {
+                let peaks: Vec<f32> = self.peaks_left
+                    .iter()
+                    .zip(self.peaks_right.iter())
+                    .map(|(left, right)| left.max(*right))
+                    .collect();
+
+                let rms: Vec<f32> = self.rms_left
+                    .iter()
+                    .zip(self.rms_right.iter())
+                    .map(|(left, right)| ((left * left + right * right) / 2.0).sqrt())
+                    .collect();
+
+                (peaks.clone(), rms.clone(), peaks, rms)
+            }
+            WaveformStyle::Stereo => (
+                self.peaks_left.clone(),
+                self.rms_left.clone(),
+                self.peaks_right.clone(),
+                self.rms_right.clone()
+            )
+        }
+    }
+
+    /// Writes waveform.svg and waveform.png into the given directory, drawn
+    /// in the style and colors configured in the theme.
+    pub fn write_images(&self, dir: &Path, theme: &Theme) {
+        let (background, foreground) = match theme.base {
+            ThemeBase::Dark => ((0.09, 0.09, 0.09), (0.93, 0.93, 0.93)),
+            ThemeBase::Light => ((0.96, 0.96, 0.96), (0.12, 0.12, 0.12))
+        };
+
+        let layout = ImageLayout::new();
+        let (upper_peaks, upper_rms, lower_peaks, lower_rms) = self.lanes(&theme.waveform_style);
+
+        // (points, closed, opacity) for each layer, from back to front
+        let layers: Vec<(Vec<(f32, f32)>, bool, f32)> = match theme.waveform_style {
+            WaveformStyle::Line => {
+                let points = upper_peaks
+                    .iter()
+                    .enumerate()
+                    .map(|(index, peak)| {
+                        let y = layout.center_y + layout.amplitude - peak * layout.amplitude * 2.0;
+                        (layout.x(index, upper_peaks.len()), y)
+                    })
+                    .collect();
+
+                vec![(points, false, 1.0)]
+            }
+            WaveformStyle::Mirrored |
+            WaveformStyle::Stereo => vec![
+                (filled_outline(&layout, &upper_peaks, &lower_peaks), true, 0.45),
+                (filled_outline(&layout, &upper_rms, &lower_rms), true, 1.0)
+            ]
+        };
+
+        let gradient_stops: Option<Vec<(f32, (f32, f32, f32))>> = match theme.waveform_color {
+            WaveformColor::Spectral => {
+                let stop_count = self.brightness.len().clamp(2, SPECTRAL_GRADIENT_STOPS);
+                let stops = resample(&self.brightness, stop_count)
+                    .iter()
+                    .enumerate()
+                    .map(|(index, brightness)| (index as f32 / (stop_count - 1) as f32, spectral_rgb(*brightness)))
+                    .collect();
+                Some(stops)
+            }
+            WaveformColor::Theme => None
+        };
+
+        let stroke_width = 3.0;
+
+        // SVG
+        {
+            let (defs, paint) = match &gradient_stops {
+                Some(stops) => {
+                    let r_stops = stops
+                        .iter()
+                        .map(|(offset, rgb)| format!(r#"<stop offset="{offset:.4}" stop-color="{}"/>"#, rgb_hex(*rgb)))
+                        .collect::<Vec<String>>()
+                        .join("");
+
+                    let x1 = layout.x_offset;
+                    let x2 = layout.x_offset + layout.width;
+                    let defs = format!(r#"<defs><linearGradient id="spectral" gradientUnits="userSpaceOnUse" x1="{x1}" x2="{x2}" y1="0" y2="0">{r_stops}</linearGradient></defs>"#);
+
+                    (defs, String::from("url(#spectral)"))
+                }
+                None => (String::new(), rgb_hex(foreground))
+            };
+
+            let r_paths = layers
+                .iter()
+                .map(|(points, closed, opacity)| {
+                    let d = svg_path_data(points, *closed);
+                    if *closed {
+                        format!(r#"<path d="{d}" fill="{paint}" fill-opacity="{opacity}"/>"#)
+                    } else {
+                        format!(r#"<path d="{d}" fill="none" stroke="{paint}" stroke-linecap="round" stroke-linejoin="round" stroke-width="{stroke_width}"/>"#)
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("");
+
+            let svg = format!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WAVEFORM_IMAGE_WIDTH}" height="{WAVEFORM_IMAGE_HEIGHT}" viewBox="0 0 {WAVEFORM_IMAGE_WIDTH} {WAVEFORM_IMAGE_HEIGHT}">{defs}<rect width="100%" height="100%" fill="{}"/>{r_paths}</svg>"#,
+                rgb_hex(background)
+            );
+
+            let svg_path = dir.join(WAVEFORM_SVG_FILENAME);
+            if let Err(err) = fs::write(&svg_path, svg) {
+                error!("Could not write waveform image {:?} ({})", svg_path, err);
+            }
+        }
+
+        // PNG
+        {
+            let mut pixmap = Pixmap::new(WAVEFORM_IMAGE_WIDTH, WAVEFORM_IMAGE_HEIGHT).unwrap();
+            pixmap.fill(Color::from_rgba(background.0, background.1, background.2, 1.0).unwrap());
+
+            let mut stroke = Stroke::default();
+            stroke.line_cap = LineCap::Round;
+            stroke.width = stroke_width;
+
+            for (points, closed, opacity) in &layers {
+                let Some(path) = tiny_skia_path(points, *closed) else { continue };
+
+                let mut paint = Paint::default();
+                paint.anti_alias = true;
+
+                match &gradient_stops {
+                    Some(stops) => {
+                        let gradient_stops = stops
+                            .iter()
+                            .map(|(offset, (r, g, b))| GradientStop::new(*offset, Color::from_rgba(*r, *g, *b, *opacity).unwrap()))
+                            .collect();
+
+                        let shader = LinearGradient::new(
+                            Point::from_xy(layout.x_offset, 0.0),
+                            Point::from_xy(layout.x_offset + layout.width, 0.0),
+                            gradient_stops,
+                            SpreadMode::Pad,
+                            Transform::identity()
+                        );
+
+                        match shader {
+                            Some(shader) => paint.shader = shader,
+                            None => continue
+                        }
+                    }
+                    None => {
+                        let (r, g, b) = foreground;
+                        paint.set_color(Color::from_rgba(r, g, b, *opacity).unwrap());
+                    }
+                }
+
+                if *closed {
+                    pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+                } else {
+                    pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+                }
+            }
+
+            let png_path = dir.join(WAVEFORM_PNG_FILENAME);
+            if let Err(err) = pixmap.save_png(&png_path) {
+                error!("Could not write waveform image {:?} ({})", png_path, err);
+            }
+        }
+    }
+}
+
+impl WaveformColor {
+    pub const ALL_MANIFEST_KEYS: [&'static str; 2] = ["spectral", "theme"];
+
+    pub fn from_manifest_key(key: &str) -> Option<WaveformColor> {
+        match key {
+            "spectral" => Some(WaveformColor::Spectral),
+            "theme" => Some(WaveformColor::Theme),
+            _ => None
+        }
+    }
+}
+
+impl WaveformStyle {
+    pub const ALL_MANIFEST_KEYS: [&'static str; 3] = ["line", "mirrored", "stereo"];
+
+    pub fn from_manifest_key(key: &str) -> Option<WaveformStyle> {
+        match key {
+            "line" => Some(WaveformStyle::Line),
+            "mirrored" => Some(WaveformStyle::Mirrored),
+            "stereo" => Some(WaveformStyle::Stereo),
+            _ => None
+        }
+    }
+
+    /// Used as the value of the data-style attribute on the waveform svg,
+    /// which player.js reads to determine how to draw it.
+    pub fn to_key(&self) -> &'static str {
+        match self {
+            WaveformStyle::Line => "line",
+            WaveformStyle::Mirrored => "mirrored",
+            WaveformStyle::Stereo => "stereo"
+        }
+    }
+}
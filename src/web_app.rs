@@ -0,0 +1,118 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! When web_app is enabled, faircamp generates a web app manifest (which
+//! makes the site installable) and a service worker (which keeps the site
+//! usable offline, see src/assets/service_worker.js).
+
+use std::fs;
+
+use indoc::formatdoc;
+
+use crate::{AssetHashes, Build, Catalog};
+use crate::util::{json_escape_inside_string, url_safe_hash_base64};
+
+const SERVICE_WORKER_JS: &str = include_str!(env!("FAIRCAMP_SERVICE_WORKER_JS"));
+const SERVICE_WORKER_FILENAME: &str = "service_worker.js";
+pub const WEB_APP_MANIFEST_FILENAME: &str = "manifest.webmanifest";
+
+/// Must be called after all scripts, styles and the favicon were written,
+/// as the service worker precaches them (by their hashed urls).
+pub fn generate(build: &mut Build, catalog: &Catalog) {
+    write_manifest(build, catalog);
+    write_service_worker(build, catalog);
+}
+
+/// The urls (relative to the site root) of the homepage and of all assets
+/// that every page needs. These are cached when the service worker is
+/// installed, so that the site's shell is available offline right away.
+fn precache_urls(build: &Build, catalog: &Catalog) -> Vec<String> {
+    let index_url = if build.clean_urls { String::from("./") } else { String::from("index.html") };
+
+    let mut urls = vec![
+        index_url,
+        format!("browser.js?{}", build.asset_hashes.browser_js.as_ref().unwrap()),
+        format!("clipboard.js?{}", AssetHashes::CLIPBOARD_JS),
+        format!("offline.js?{}", AssetHashes::OFFLINE_JS),
+        format!("player.js?{}", build.asset_hashes.player_js.as_ref().unwrap()),
+        format!("site.css?{}", build.asset_hashes.site_css.as_ref().unwrap())
+    ];
+
+    if catalog.soft_navigation {
+        urls.push(format!("navigation.js?{}", AssetHashes::NAVIGATION_JS));
+    }
+
+    let mut theme_css_urls: Vec<String> = build.asset_hashes.theme_css
+        .iter()
+        .map(|(filename, hash)| format!("{filename}?{hash}"))
+        .collect();
+
+    theme_css_urls.sort();
+    urls.append(&mut theme_css_urls);
+
+    for (icon_url, _mime_type, _sizes) in catalog.favicon.web_app_icons(build) {
+        urls.push(icon_url);
+    }
+
+    urls
+}
+
+fn write_manifest(build: &mut Build, catalog: &Catalog) {
+    let background_color = catalog.theme.background_hex();
+    let name = json_escape_inside_string(&catalog.title());
+    let start_url = if build.clean_urls { "./" } else { "index.html" };
+
+    let icons = catalog.favicon.web_app_icons(build)
+        .iter()
+        .map(|(icon_url, mime_type, sizes)| match sizes {
+            Some(sizes) => format!(r#"{{ "sizes": "{sizes}", "src": "{icon_url}", "type": "{mime_type}" }}"#),
+            None => format!(r#"{{ "src": "{icon_url}", "type": "{mime_type}" }}"#)
+        })
+        .collect::<Vec<String>>()
+        .join(",\n    ");
+
+    let manifest = formatdoc!(r#"
+        {{
+          "background_color": "{background_color}",
+          "display": "standalone",
+          "icons": [
+            {icons}
+          ],
+          "name": "{name}",
+          "scope": "./",
+          "short_name": "{name}",
+          "start_url": "{start_url}",
+          "theme_color": "{background_color}"
+        }}
+    "#);
+
+    fs::write(build.build_dir.join(WEB_APP_MANIFEST_FILENAME), manifest).unwrap();
+    build.reserve_filename(WEB_APP_MANIFEST_FILENAME);
+}
+
+fn write_service_worker(build: &mut Build, catalog: &Catalog) {
+    let precache_urls = precache_urls(build, catalog);
+
+    // The cache name changes whenever any precached asset changes, which
+    // (through the changed service worker script) triggers browsers to
+    // install the new service worker and drop the outdated cache.
+    let cache_name = format!("faircamp-shell-{}", url_safe_hash_base64(&precache_urls));
+
+    let precache = precache_urls
+        .iter()
+        .map(|url| format!("'{url}'"))
+        .collect::<Vec<String>>()
+        .join(",\n    ");
+
+    let mut js = formatdoc!("
+        const SERVICE_WORKER_CACHE = '{cache_name}';
+        const SERVICE_WORKER_PRECACHE = [
+            {precache}
+        ];
+    ");
+
+    js.push_str(SERVICE_WORKER_JS);
+
+    fs::write(build.build_dir.join(SERVICE_WORKER_FILENAME), js).unwrap();
+    build.reserve_filename(SERVICE_WORKER_FILENAME);
+}
@@ -0,0 +1,229 @@
+// SPDX-FileCopyrightText: 2025 Simon Repp
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+/// XSPF format reference:
+/// - https://www.xspf.org/spec
+/// - https://en.wikipedia.org/wiki/XML_Shareable_Playlist_Format
+
+use indoc::formatdoc;
+
+use crate::{
+    Artist,
+    Build,
+    Catalog,
+    GENERATOR_INFO,
+    Playlist,
+    Release,
+    SiteUrl,
+    Track,
+    TRACK_NUMBERS
+};
+use crate::util::html_escape_outside_attribute;
+
+pub const XSPF_PLAYLIST_FILENAME: &str = "playlist.xspf";
+
+/// Generate complete content of an XSPF playlist for all (public) releases
+/// of an artist.
+pub fn generate_for_artist(
+    artist: &Artist,
+    base_url: &SiteUrl,
+    build: &Build
+) -> String {
+    let r_tracks = artist.public_releases()
+        .iter()
+        .map(|release| generate_tracks(base_url, build, &release.borrow()))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let artist_slug = &artist.permalink.slug;
+
+    let image_url = artist.image.as_ref().map(|described_image| {
+        let image_ref = described_image.borrow();
+        let file_name = image_ref.artist_assets.as_ref().unwrap().playlist_image();
+        let hash = image_ref.hash.as_url_safe_base64();
+
+        base_url.join_file(format!("{artist_slug}/{file_name}?{hash}"))
+    });
+
+    let info_url = build.base_url
+        .as_ref()
+        .map(|page_base_url| page_base_url.join_index(build, artist_slug));
+
+    generate_playlist(image_url, info_url, &artist.name, &r_tracks)
+}
+
+/// Generate complete content of an XSPF playlist for all (public) releases
+/// of the catalog.
+pub fn generate_for_catalog(build: &Build, catalog: &Catalog) -> String {
+    let base_url = build.cdn_url_unchecked();
+
+    let r_tracks = catalog.public_releases()
+        .iter()
+        .map(|release| generate_tracks(base_url, build, &release.borrow()))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let image_url = catalog.home_image.as_ref().map(|described_image| {
+        let image_ref = described_image.borrow();
+        let file_name = image_ref.artist_assets.as_ref().unwrap().playlist_image();
+        let hash = image_ref.hash.as_url_safe_base64();
+
+        base_url.join_file(format!("{file_name}?{hash}"))
+    });
+
+    let info_url = build.base_url
+        .as_ref()
+        .map(|page_base_url| page_base_url.index(build));
+
+    generate_playlist(image_url, info_url, &catalog.title(), &r_tracks)
+}
+
+/// Generate complete content of an XSPF playlist for a curated playlist,
+/// whose tracks may stem from any number of releases.
+pub fn generate_for_playlist(
+    base_url: &SiteUrl,
+    build: &Build,
+    playlist: &Playlist
+) -> String {
+    let r_tracks = playlist.entries
+        .iter()
+        .map(|entry| {
+            let release_ref = entry.release.borrow();
+            let track = &release_ref.tracks[entry.track_number - 1];
+
+            generate_track(
+                base_url,
+                build,
+                &release_ref,
+                track,
+                entry.track_number
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let info_url = build.base_url
+        .as_ref()
+        .map(|page_base_url| page_base_url.join_index(build, &playlist.permalink.slug));
+
+    generate_playlist(None, info_url, &playlist.title, &r_tracks)
+}
+
+/// Generate complete content of an XSPF playlist for a release
+pub fn generate_for_release(
+    base_url: &SiteUrl,
+    build: &Build,
+    release: &Release
+) -> String {
+    let r_tracks = generate_tracks(base_url, build, release);
+
+    let image_url = release.playlist_image_url(base_url);
+
+    let info_url = build.base_url
+        .as_ref()
+        .map(|page_base_url| page_base_url.join_index(build, &release.permalink.slug));
+
+    generate_playlist(Some(image_url), info_url, &release.title, &r_tracks)
+}
+
+/// Wraps the given (already rendered) <track> elements in a complete XSPF
+/// document. The info url points to the page the playlist stems from, if
+/// that page can be addressed (i.e. when a base_url is set).
+fn generate_playlist(
+    image_url: Option<String>,
+    info_url: Option<String>,
+    title: &str,
+    r_tracks: &str
+) -> String {
+    let image = match image_url {
+        Some(url) => format!("<image>{}</image>", html_escape_outside_attribute(&url)),
+        None => String::new()
+    };
+
+    let info = match info_url {
+        Some(url) => format!("<info>{}</info>", html_escape_outside_attribute(&url)),
+        None => String::new()
+    };
+
+    let title_escaped = html_escape_outside_attribute(title);
+
+    formatdoc!(r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <playlist version="1" xmlns="http://xspf.org/ns/0/">
+            <creator>{GENERATOR_INFO}</creator>
+            {image}
+            {info}
+            <title>{title_escaped}</title>
+            <trackList>
+                {r_tracks}
+            </trackList>
+        </playlist>
+    "#)
+}
+
+/// Generate a single XSPF <track> element, including the release it stems
+/// from as album, its cover as image and its page as info.
+fn generate_track(
+    base_url: &SiteUrl,
+    build: &Build,
+    release: &Release,
+    track: &Track,
+    track_number: usize
+) -> String {
+    let release_slug = &release.permalink.slug;
+
+    let album_escaped = html_escape_outside_attribute(&release.title);
+
+    let artists = track.artists
+        .iter()
+        .map(|artist| artist.borrow().name.clone())
+        .collect::<Vec<String>>()
+        .join(", ");
+    let creator_escaped = html_escape_outside_attribute(&artists);
+
+    let duration_milliseconds = (track.transcodes.borrow().source_meta.duration_seconds * 1000.0) as usize;
+
+    let image_url = release.playlist_image_url(base_url);
+    let image_url_escaped = html_escape_outside_attribute(&image_url);
+
+    let info = match &build.base_url {
+        Some(page_base_url) => {
+            let track_url = page_base_url.join_index(build, format!("{release_slug}/{track_number}"));
+            format!("<info>{}</info>", html_escape_outside_attribute(&track_url))
+        }
+        None => String::new()
+    };
+
+    let location = release.track_stream_url(base_url, build, track, track_number);
+    let location_escaped = html_escape_outside_attribute(&location);
+
+    let title_escaped = html_escape_outside_attribute(&track.title());
+
+    formatdoc!(r#"
+        <track>
+            <album>{album_escaped}</album>
+            <creator>{creator_escaped}</creator>
+            <duration>{duration_milliseconds}</duration>
+            <image>{image_url_escaped}</image>
+            {info}
+            <location>{location_escaped}</location>
+            <title>{title_escaped}</title>
+            <trackNum>{track_number}</trackNum>
+        </track>
+    "#)
+}
+
+/// Generate the <track> elements for all (unlocked) tracks of a release
+fn generate_tracks(
+    base_url: &SiteUrl,
+    build: &Build,
+    release: &Release
+) -> String {
+    release.tracks
+        .iter()
+        .zip(TRACK_NUMBERS)
+        .filter(|(track, _track_number)| !release.track_locked(track))
+        .map(|(track, track_number)| generate_track(base_url, build, release, track, track_number))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
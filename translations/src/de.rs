@@ -6,6 +6,7 @@
 use super::{Reviewed, Translations};
 
 pub const DE: Translations = Translations {
+    add_to_queue: Reviewed("Zur Warteschlange hinzufügen"),
     audio_format_alac: Reviewed("Verlustfrei komprimiert, wenn du nur Apple Produkte verwendest wähle dies hier statt FLAC"),
     audio_format_average: Reviewed("Durchschnittliche Komprimierung, sinnvoll wenn dein Player keine besseren Formate unterstützt"),
     audio_format_flac: Reviewed("Verlustfrei komprimiert, beste Wahl für Archivierung"),
@@ -17,8 +18,11 @@ pub const DE: Translations = Translations {
     audio_player_widget_for_xxx: Reviewed(r#"Audio Player Widget für "{title}""#),
     auto_generated_cover: Reviewed("Automatisch generiertes Cover"),
     available_formats: Reviewed("Verfügbare Formate:"),
+    available_with_full_release: Reviewed("Verfügbar mit der vollständigen Veröffentlichung"),
     browse: Reviewed("Stöbern"),
     buy: Reviewed("Kaufen"),
+    buy_physical: Reviewed("Physisch kaufen"),
+    chapters: Reviewed("Kapitel"),
     close: Reviewed("Schließen"),
     copied: Reviewed("Kopiert"),
     copy: Reviewed("Kopieren"),
@@ -32,12 +36,15 @@ pub const DE: Translations = Translations {
     downloads: Reviewed("Downloads"),
     downloads_permalink: Reviewed("downloads"),
     embed: Reviewed("Einbetten"),
+    embed_entire_playlist: Reviewed("Gesamte Playlist einbetten"),
     embed_entire_release: Reviewed("Den gesamten Release einbetten"),
     enter_code_here: Reviewed("Code hier eingeben"),
     external_link: Reviewed("Externer Link"),
     extras: Reviewed("Extras"),
     failed: Reviewed("Fehler"),
     feed: Reviewed("Feed"),
+    full_release_on_xxx: Reviewed("Vollständige Veröffentlichung am {xxx}"),
+    gallery: Reviewed("Galerie"),
     generic_rss: Reviewed("Generisches RSS"),
     image_descriptions: Reviewed("Bildbeschreibungen"),
     image_descriptions_guide: Reviewed("\
@@ -57,35 +64,53 @@ Hier ein paar Tipps zum Schreiben guter Bildbeschreibungen:<br>\
 - Beschreib was da ist und wichtig fürs Verständnis, aber analysiere und interpretiere darüber hinaus nicht.<br>\
 - Du kannst Farbbeschreibungen verwenden wo es Sinn macht - viele Menschen verlieren ihre Sehkraft erst spät im Leben und verstehen und schätzen Farben."),
     image_descriptions_permalink: Reviewed("bildbeschreibungen"),
+    in_stock: Reviewed("Auf Lager"),
     javascript_is_disabled_listen_at_xxx: Reviewed("JavaScript ist deaktiviert – Anhören auf {link}"),
     javascript_is_disabled_text: Reviewed("JavaScript ist deaktiviert – Manche Features sind nicht verfügbar"),
+    jspf_playlist: Reviewed("JSPF-Playlist"),
+    license: Reviewed("Lizenz"),
     listen: Reviewed("Anhören"),
     loading: Reviewed("Lädt"),
+    low_stock: Reviewed("Nur noch wenige verfügbar"),
     m3u_playlist: Reviewed("M3U Playlist"),
     made_or_arranged_payment: Reviewed("Ich habe die Bezahlung durchgeführt oder arrangiert"),
+    merch: Reviewed("Merch"),
+    merch_permalink: Reviewed("merch"),
     missing_image_description_note: Reviewed("Fehlende Bildbeschreibung<br>Klick für mehr Info"),
     more: Reviewed("Mehr"),
     mute: Reviewed("Stummschalten"),
     name_your_price: Reviewed("Nenne einen Preis"),
+    news: Reviewed("Neuigkeiten"),
+    news_permalink: Reviewed("neuigkeiten"),
+    next_image: Reviewed("Nächstes Bild"),
     next_track: Reviewed("Nächster Track"),
     nothing_found_for_xxx: Reviewed("Nichts für '{query}' gefunden"),
+    past_events: Reviewed("Vergangene Termine"),
     pause: Reviewed("Pausieren"),
     play: Reviewed("Abspielen"),
+    play_next: Reviewed("Als Nächstes abspielen"),
     playback_position: Reviewed("Wiedergabeposition"),
     player_closed: Reviewed("Player geschlossen"),
     player_open_playing_xxx: Reviewed("Player offen, spielt {title}"),
     player_open_with_xxx: Reviewed("Player offen mit {title}"),
+    preorder: Reviewed("Vorbestellung"),
+    previous_image: Reviewed("Vorheriges Bild"),
     previous_track: Reviewed("Vorheriger Track"),
     price: Reviewed("Preis:"),
     purchase_downloads: Reviewed("Downloads bezahlen"),
     purchase_permalink: Reviewed("bezahlen"),
     recommended_format: Reviewed("Empfohlenes Format"),
+    remove_offline_copy: Reviewed("Offline-Kopie entfernen"),
+    save_for_offline: Reviewed("Offline speichern"),
     search: Reviewed("Suchen"),
     showing_featured_items: Reviewed("Gefeaturete Einträge werden angezeigt"),
     showing_xxx_results_for_xxx: Reviewed("{count} Ergebnisse für '{query}' werden angezeigt"),
     skip_to_main_content: Reviewed("Zum Hauptinhalt springen"),
+    sold_out: Reviewed("Ausverkauft"),
     subscribe: Reviewed("Abonnieren"),
     subscribe_permalink: Reviewed("abonnieren"),
+    subscribe_to_calendar: Reviewed("Kalender abonnieren"),
+    tickets: Reviewed("Tickets"),
     unlisted: Reviewed("Ungelistet"),
     unlock: Reviewed("Freischalten"),
     unlock_downloads: Reviewed("Downloads freischalten"),
@@ -104,10 +129,13 @@ mit /{downloads_permalink}/[dein-downloadcode]{index_suffix} und drücke dann En
     unlock_permalink: Reviewed("freischalten"),
     unmute: Reviewed("Lautschalten"),
     up_to_xxx: Reviewed("Bis zu {xxx}"),
+    upcoming_events: Reviewed("Kommende Termine"),
     visual_impairment: Reviewed("Visuelle Beeinträchtigung"),
     volume: Reviewed("Lautstärke"),
+    xspf_playlist: Reviewed("XSPF-Playlist"),
     xxx_and_others: Reviewed(r#"{xxx} und <a href="{others_link}">Weitere</a>"#),
     xxx_hours: Reviewed("{xxx} Stunden"),
+    xxx_is_licensed_under_xxx: Reviewed("„{title}“ steht unter der Lizenz {license}."),
     xxx_minutes: Reviewed("{xxx} Minuten"),
     xxx_or_more: Reviewed("{xxx} oder mehr"),
     xxx_seconds: Reviewed("{xxx} Sekunden")
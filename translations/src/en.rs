@@ -5,6 +5,7 @@
 use super::{Reviewed, Translations};
 
 pub const EN: Translations = Translations {
+    add_to_queue: Reviewed("Add to queue"),
     audio_format_alac: Reviewed("Lossless and compressed, if you exclusively use Apple products choose this over FLAC"),
     audio_format_average: Reviewed("Average compression, appropriate if your player does not support better formats"),
     audio_format_flac: Reviewed("Lossless and compressed, best choice for archival"),
@@ -16,8 +17,11 @@ pub const EN: Translations = Translations {
     audio_player_widget_for_xxx: Reviewed(r#"Audio player widget for "{title}""#),
     auto_generated_cover: Reviewed("Automatically generated cover"),
     available_formats: Reviewed("Available formats:"),
+    available_with_full_release: Reviewed("Available with the full release"),
     browse: Reviewed("Browse"),
     buy: Reviewed("Buy"),
+    buy_physical: Reviewed("Buy physical"),
+    chapters: Reviewed("Chapters"),
     close: Reviewed("Close"),
     copied: Reviewed("Copied"),
     copy: Reviewed("Copy"),
@@ -31,12 +35,15 @@ pub const EN: Translations = Translations {
     downloads: Reviewed("Downloads"),
     downloads_permalink: Reviewed("downloads"),
     embed: Reviewed("Embed"),
+    embed_entire_playlist: Reviewed("Embed entire playlist"),
     embed_entire_release: Reviewed("Embed the entire release"),
     enter_code_here: Reviewed("Enter code here"),
     external_link: Reviewed("External Link"),
     extras: Reviewed("Extras"),
     failed: Reviewed("Failed"),
     feed: Reviewed("Feed"),
+    full_release_on_xxx: Reviewed("Full release on {xxx}"),
+    gallery: Reviewed("Gallery"),
     generic_rss: Reviewed("Generic RSS"),
     image_descriptions: Reviewed("Image Descriptions"),
     image_descriptions_guide: Reviewed("\
@@ -56,35 +63,53 @@ Here are some tips for writing good image descriptions:<br>\
 - Don't interpret. Describe what is there and relevant for its understanding, don't analyze beyond that.<br>\
 - You can use colors where it makes sense - many people only lost their sight later on and understand and appreciate colors."),
     image_descriptions_permalink: Reviewed("image-descriptions"),
+    in_stock: Reviewed("In stock"),
     javascript_is_disabled_listen_at_xxx: Reviewed("JavaScript is disabled – Listen at {link}"),
     javascript_is_disabled_text: Reviewed("JavaScript is disabled – Some features are not available"),
+    jspf_playlist: Reviewed("JSPF Playlist"),
+    license: Reviewed("License"),
     listen: Reviewed("Listen"),
     loading: Reviewed("Loading"),
+    low_stock: Reviewed("Only a few left"),
     m3u_playlist: Reviewed("M3U Playlist"),
     made_or_arranged_payment: Reviewed("I have made or arranged the payment"),
+    merch: Reviewed("Merch"),
+    merch_permalink: Reviewed("merch"),
     missing_image_description_note: Reviewed("Missing image description<br>Click to learn more"),
     more: Reviewed("More"),
     mute: Reviewed("Mute"),
     name_your_price: Reviewed("Name your price"),
+    news: Reviewed("News"),
+    news_permalink: Reviewed("news"),
+    next_image: Reviewed("Next Image"),
     next_track: Reviewed("Next Track"),
     nothing_found_for_xxx: Reviewed("Nothing found for '{query}'"),
+    past_events: Reviewed("Past events"),
     pause: Reviewed("Pause"),
     play: Reviewed("Play"),
+    play_next: Reviewed("Play next"),
     playback_position: Reviewed("Playback position"),
     player_closed: Reviewed("Player closed"),
     player_open_playing_xxx: Reviewed("Player open, playing {title}"),
     player_open_with_xxx: Reviewed("Player open with {title}"),
+    preorder: Reviewed("Pre-order"),
+    previous_image: Reviewed("Previous Image"),
     previous_track: Reviewed("Previous Track"),
     price: Reviewed("Price:"),
     purchase_downloads: Reviewed("Purchase downloads"),
     purchase_permalink: Reviewed("purchase"),
     recommended_format: Reviewed("Recommended Format"),
+    remove_offline_copy: Reviewed("Remove offline copy"),
+    save_for_offline: Reviewed("Save for offline"),
     search: Reviewed("Search"),
     showing_featured_items: Reviewed("Showing featured items"),
     showing_xxx_results_for_xxx: Reviewed("Showing {count} results for '{query}'"),
     skip_to_main_content: Reviewed("Skip to main content"),
+    sold_out: Reviewed("Sold out"),
     subscribe: Reviewed("Subscribe"),
     subscribe_permalink: Reviewed("subscribe"),
+    subscribe_to_calendar: Reviewed("Subscribe to calendar"),
+    tickets: Reviewed("Tickets"),
     unlisted: Reviewed("Unlisted"),
     unlock: Reviewed("Unlock"),
     unlock_downloads: Reviewed("Unlock downloads"),
@@ -101,10 +126,13 @@ with /{downloads_permalink}/[your-download-code]{index_suffix} and then press En
     unlock_permalink: Reviewed("unlock"),
     unmute: Reviewed("Unmute"),
     up_to_xxx: Reviewed("Up to {xxx}"),
+    upcoming_events: Reviewed("Upcoming events"),
     visual_impairment: Reviewed("Visual Impairment"),
     volume: Reviewed("Volume"),
+    xspf_playlist: Reviewed("XSPF Playlist"),
     xxx_and_others: Reviewed(r#"{xxx} and <a href="{others_link}">others</a>"#),
     xxx_hours: Reviewed("{xxx} hours"),
+    xxx_is_licensed_under_xxx: Reviewed(r#""{title}" is licensed under {license}."#),
     xxx_minutes: Reviewed("{xxx} minutes"),
     xxx_or_more: Reviewed("{xxx} or more"),
     xxx_seconds: Reviewed("{xxx} seconds")
@@ -163,6 +163,7 @@ impl std::fmt::Display for Translation {
 /// a method of the same name - these are translations that need to be called
 /// as a function because they interpolate some parameter into the translation.
 pub struct Translations {
+    pub add_to_queue: Translation,
     pub audio_format_alac: Translation,
     pub audio_format_average: Translation,
     pub audio_format_flac: Translation,
@@ -174,8 +175,11 @@ pub struct Translations {
     pub audio_player_widget_for_xxx: Translation,
     pub auto_generated_cover: Translation,
     pub available_formats: Translation,
+    pub available_with_full_release: Translation,
     pub browse: Translation,
     pub buy: Translation,
+    pub buy_physical: Translation,
+    pub chapters: Translation,
     pub close: Translation,
     pub copied: Translation,
     pub copy: Translation,
@@ -190,6 +194,7 @@ pub struct Translations {
     /// Must be unique and only contain url-safe characters
     pub downloads_permalink: Translation,
     pub embed: Translation,
+    pub embed_entire_playlist: Translation,
     pub embed_entire_release: Translation,
     pub enter_code_here: Translation,
     pub external_link: Translation,
@@ -199,41 +204,61 @@ pub struct Translations {
     pub failed: Translation,
     pub feed: Translation,
     /// Must be unique and only contain url-safe characters
+    pub full_release_on_xxx: Translation,
+    pub gallery: Translation,
     pub generic_rss: Translation,
     pub image_descriptions: Translation,
     pub image_descriptions_guide: Translation,
     /// Must be unique and only contain url-safe characters
     pub image_descriptions_permalink: Translation,
+    pub in_stock: Translation,
     pub javascript_is_disabled_listen_at_xxx: Translation,
     pub javascript_is_disabled_text: Translation,
+    pub jspf_playlist: Translation,
+    pub license: Translation,
     pub listen: Translation,
     pub loading: Translation,
+    pub low_stock: Translation,
     pub m3u_playlist: Translation,
     pub made_or_arranged_payment: Translation,
+    pub merch: Translation,
+    pub merch_permalink: Translation,
     pub missing_image_description_note: Translation,
     pub more: Translation,
     pub mute: Translation,
     pub name_your_price: Translation,
+    pub news: Translation,
+    pub news_permalink: Translation,
+    pub next_image: Translation,
     pub next_track: Translation,
     pub nothing_found_for_xxx: Translation,
+    pub past_events: Translation,
     pub pause: Translation,
     pub play: Translation,
+    pub play_next: Translation,
     pub playback_position: Translation,
     pub player_closed: Translation,
     pub player_open_playing_xxx: Translation,
     pub player_open_with_xxx: Translation,
+    pub preorder: Translation,
+    pub previous_image: Translation,
     pub previous_track: Translation,
     pub price: Translation,
     pub purchase_downloads: Translation,
     /// Must be unique and only contain url-safe characters
     pub purchase_permalink: Translation,
     pub recommended_format: Translation,
+    pub remove_offline_copy: Translation,
+    pub save_for_offline: Translation,
     pub search: Translation,
     pub showing_featured_items: Translation,
     pub showing_xxx_results_for_xxx: Translation,
     pub skip_to_main_content: Translation,
+    pub sold_out: Translation,
     pub subscribe: Translation,
     pub subscribe_permalink: Translation,
+    pub subscribe_to_calendar: Translation,
+    pub tickets: Translation,
     pub unlisted: Translation,
     pub unlock: Translation,
     pub unlock_downloads: Translation,
@@ -242,10 +267,13 @@ pub struct Translations {
     pub unlock_permalink: Translation,
     pub unmute: Translation,
     pub up_to_xxx: Translation,
+    pub upcoming_events: Translation,
     pub visual_impairment: Translation,
     pub volume: Translation,
+    pub xspf_playlist: Translation,
     pub xxx_and_others: Translation,
     pub xxx_hours: Translation,
+    pub xxx_is_licensed_under_xxx: Translation,
     pub xxx_minutes: Translation,
     pub xxx_or_more: Translation,
     pub xxx_seconds: Translation
@@ -253,6 +281,7 @@ pub struct Translations {
 
 impl Translations {
     pub const KEYS: Translations = Translations {
+        add_to_queue: Reviewed("add_to_queue"),
         audio_format_alac: Reviewed("audio_format_alac"),
         audio_format_average: Reviewed("audio_format_average"),
         audio_format_flac: Reviewed("audio_format_flac"),
@@ -264,8 +293,11 @@ impl Translations {
         audio_player_widget_for_xxx: Reviewed("audio_player_widget_for_xxx"),
         auto_generated_cover: Reviewed("auto_generated_cover"),
         available_formats: Reviewed("available_formats"),
+        available_with_full_release: Reviewed("available_with_full_release"),
         browse: Reviewed("browse"),
         buy: Reviewed("buy"),
+        buy_physical: Reviewed("buy_physical"),
+        chapters: Reviewed("chapters"),
         close: Reviewed("close"),
         copied: Reviewed("copied"),
         copy: Reviewed("copy"),
@@ -279,45 +311,66 @@ impl Translations {
         downloads: Reviewed("downloads"),
         downloads_permalink: Reviewed("downloads_permalink"),
         embed: Reviewed("embed"),
+        embed_entire_playlist: Reviewed("embed_entire_playlist"),
         embed_entire_release: Reviewed("embed_entire_release"),
         enter_code_here: Reviewed("enter_code_here"),
         external_link: Reviewed("external_link"),
         extras: Reviewed("extras"),
         failed: Reviewed("failed"),
         feed: Reviewed("feed"),
+        full_release_on_xxx: Reviewed("full_release_on_xxx"),
+        gallery: Reviewed("gallery"),
         generic_rss: Reviewed("generic_rss"),
         image_descriptions: Reviewed("image_descriptions"),
         image_descriptions_guide: Reviewed("image_descriptions_guide"),
         image_descriptions_permalink: Reviewed("image_descriptions_permalink"),
+        in_stock: Reviewed("in_stock"),
         javascript_is_disabled_listen_at_xxx: Reviewed("javascript_is_disabled_listen_at_xxx"),
         javascript_is_disabled_text: Reviewed("javascript_is_disabled_text"),
+        jspf_playlist: Reviewed("jspf_playlist"),
+        license: Reviewed("license"),
         listen: Reviewed("listen"),
         loading: Reviewed("loading"),
+        low_stock: Reviewed("low_stock"),
         m3u_playlist: Reviewed("m3u_playlist"),
         made_or_arranged_payment: Reviewed("made_or_arranged_payment"),
+        merch: Reviewed("merch"),
+        merch_permalink: Reviewed("merch_permalink"),
         missing_image_description_note: Reviewed("missing_image_description_note"),
         more: Reviewed("more"),
         mute: Reviewed("mute"),
         name_your_price: Reviewed("name_your_price"),
+        news: Reviewed("news"),
+        news_permalink: Reviewed("news_permalink"),
+        next_image: Reviewed("next_image"),
         next_track: Reviewed("next_track"),
         nothing_found_for_xxx: Reviewed("next_track"),
+        past_events: Reviewed("past_events"),
         pause: Reviewed("pause"),
         play: Reviewed("play"),
+        play_next: Reviewed("play_next"),
         player_closed: Reviewed("player_closed"),
         playback_position: Reviewed("playback_position"),
         player_open_playing_xxx: Reviewed("player_open_playing_xxx"),
         player_open_with_xxx: Reviewed("player_open_with_xxx"),
+        preorder: Reviewed("preorder"),
+        previous_image: Reviewed("previous_image"),
         previous_track: Reviewed("previous_track"),
         price: Reviewed("price"),
         purchase_downloads: Reviewed("purchase_downloads"),
         purchase_permalink: Reviewed("purchase_permalink"),
         recommended_format: Reviewed("recommended_format"),
+        remove_offline_copy: Reviewed("remove_offline_copy"),
+        save_for_offline: Reviewed("save_for_offline"),
         search: Reviewed("search"),
         showing_featured_items: Reviewed("showing_featured_items"),
         showing_xxx_results_for_xxx: Reviewed("showing_xxx_results_for_xxx"),
         skip_to_main_content: Reviewed("skip_to_main_content"),
+        sold_out: Reviewed("sold_out"),
         subscribe: Reviewed("subscribe"),
         subscribe_permalink: Reviewed("subscribe_permalink"),
+        subscribe_to_calendar: Reviewed("subscribe_to_calendar"),
+        tickets: Reviewed("tickets"),
         unlisted: Reviewed("unlisted"),
         unlock: Reviewed("unlock"),
         unlock_downloads: Reviewed("unlock_downloads"),
@@ -325,16 +378,20 @@ impl Translations {
         unlock_permalink: Reviewed("unlock_permalink"),
         unmute: Reviewed("unmute"),
         up_to_xxx: Reviewed("up_to_xxx"),
+        upcoming_events: Reviewed("upcoming_events"),
         visual_impairment: Reviewed("visual_impairment"),
         volume: Reviewed("volume"),
+        xspf_playlist: Reviewed("xspf_playlist"),
         xxx_and_others: Reviewed("xxx_and_others"),
         xxx_hours: Reviewed("xxx_hours"),
+        xxx_is_licensed_under_xxx: Reviewed("xxx_is_licensed_under_xxx"),
         xxx_minutes: Reviewed("xxx_minutes"),
         xxx_or_more: Reviewed("xxx_or_more"),
         xxx_seconds: Reviewed("xxx_seconds")
     };
 
     pub const UNTRANSLATED: Translations = Translations {
+        add_to_queue: EN.add_to_queue.as_untranslated(),
         audio_format_alac: EN.audio_format_alac.as_untranslated(),
         audio_format_average: EN.audio_format_average.as_untranslated(),
         audio_format_flac: EN.audio_format_flac.as_untranslated(),
@@ -346,8 +403,11 @@ impl Translations {
         audio_player_widget_for_xxx: EN.audio_player_widget_for_xxx.as_untranslated(),
         auto_generated_cover: EN.auto_generated_cover.as_untranslated(),
         available_formats: EN.available_formats.as_untranslated(),
+        available_with_full_release: EN.available_with_full_release.as_untranslated(),
         browse: EN.browse.as_untranslated(),
         buy: EN.buy.as_untranslated(),
+        buy_physical: EN.buy_physical.as_untranslated(),
+        chapters: EN.chapters.as_untranslated(),
         close: EN.close.as_untranslated(),
         confirm: EN.confirm.as_untranslated(),
         r#continue: EN.r#continue.as_untranslated(),
@@ -361,45 +421,66 @@ impl Translations {
         downloads: EN.downloads.as_untranslated(),
         downloads_permalink: EN.downloads_permalink.as_untranslated(),
         embed: EN.embed.as_untranslated(),
+        embed_entire_playlist: EN.embed_entire_playlist.as_untranslated(),
         embed_entire_release: EN.embed_entire_release.as_untranslated(),
         enter_code_here: EN.enter_code_here.as_untranslated(),
         external_link: EN.external_link.as_untranslated(),
         extras: EN.extras.as_untranslated(),
         failed: EN.failed.as_untranslated(),
         feed: EN.feed.as_untranslated(),
+        full_release_on_xxx: EN.full_release_on_xxx.as_untranslated(),
+        gallery: EN.gallery.as_untranslated(),
         generic_rss: EN.generic_rss.as_untranslated(),
         image_descriptions: EN.image_descriptions.as_untranslated(),
         image_descriptions_guide: EN.image_descriptions_guide.as_untranslated(),
         image_descriptions_permalink: EN.image_descriptions_permalink.as_untranslated(),
+        in_stock: EN.in_stock.as_untranslated(),
         javascript_is_disabled_listen_at_xxx: EN.javascript_is_disabled_listen_at_xxx.as_untranslated(),
         javascript_is_disabled_text: EN.javascript_is_disabled_text.as_untranslated(),
+        jspf_playlist: EN.jspf_playlist.as_untranslated(),
+        license: EN.license.as_untranslated(),
         listen: EN.listen.as_untranslated(),
         loading: EN.loading.as_untranslated(),
+        low_stock: EN.low_stock.as_untranslated(),
         m3u_playlist: EN.m3u_playlist.as_untranslated(),
         made_or_arranged_payment: EN.made_or_arranged_payment.as_untranslated(),
+        merch: EN.merch.as_untranslated(),
+        merch_permalink: EN.merch_permalink.as_untranslated(),
         missing_image_description_note: EN.missing_image_description_note.as_untranslated(),
         more: EN.more.as_untranslated(),
         mute: EN.mute.as_untranslated(),
         name_your_price: EN.name_your_price.as_untranslated(),
+        news: EN.news.as_untranslated(),
+        news_permalink: EN.news_permalink.as_untranslated(),
+        next_image: EN.next_image.as_untranslated(),
         next_track: EN.next_track.as_untranslated(),
         nothing_found_for_xxx: EN.nothing_found_for_xxx.as_untranslated(),
+        past_events: EN.past_events.as_untranslated(),
         pause: EN.pause.as_untranslated(),
         play: EN.play.as_untranslated(),
+        play_next: EN.play_next.as_untranslated(),
         playback_position: EN.playback_position.as_untranslated(),
         player_closed: EN.player_closed.as_untranslated(),
         player_open_playing_xxx: EN.player_open_playing_xxx.as_untranslated(),
         player_open_with_xxx: EN.player_open_with_xxx.as_untranslated(),
+        preorder: EN.preorder.as_untranslated(),
+        previous_image: EN.previous_image.as_untranslated(),
         previous_track: EN.previous_track.as_untranslated(),
         price: EN.price.as_untranslated(),
         purchase_downloads: EN.purchase_downloads.as_untranslated(),
         purchase_permalink: EN.purchase_permalink.as_untranslated(),
         recommended_format: EN.recommended_format.as_untranslated(),
+        remove_offline_copy: EN.remove_offline_copy.as_untranslated(),
+        save_for_offline: EN.save_for_offline.as_untranslated(),
         search: EN.search.as_untranslated(),
         showing_featured_items: EN.showing_featured_items.as_untranslated(),
         showing_xxx_results_for_xxx: EN.showing_xxx_results_for_xxx.as_untranslated(),
         skip_to_main_content: EN.skip_to_main_content.as_untranslated(),
+        sold_out: EN.sold_out.as_untranslated(),
         subscribe: EN.subscribe.as_untranslated(),
         subscribe_permalink: EN.subscribe_permalink.as_untranslated(),
+        subscribe_to_calendar: EN.subscribe_to_calendar.as_untranslated(),
+        tickets: EN.tickets.as_untranslated(),
         unlisted: EN.unlisted.as_untranslated(),
         unlock: EN.unlock.as_untranslated(),
         unlock_downloads: EN.unlock_downloads.as_untranslated(),
@@ -407,10 +488,13 @@ impl Translations {
         unlock_permalink: EN.unlock_permalink.as_untranslated(),
         unmute: EN.unmute.as_untranslated(),
         up_to_xxx: EN.up_to_xxx.as_untranslated(),
+        upcoming_events: EN.upcoming_events.as_untranslated(),
         visual_impairment: EN.visual_impairment.as_untranslated(),
         volume: EN.volume.as_untranslated(),
+        xspf_playlist: EN.xspf_playlist.as_untranslated(),
         xxx_and_others: EN.xxx_and_others.as_untranslated(),
         xxx_hours: EN.xxx_hours.as_untranslated(),
+        xxx_is_licensed_under_xxx: EN.xxx_is_licensed_under_xxx.as_untranslated(),
         xxx_minutes: EN.xxx_minutes.as_untranslated(),
         xxx_or_more: EN.xxx_or_more.as_untranslated(),
         xxx_seconds: EN.xxx_seconds.as_untranslated()
@@ -419,6 +503,7 @@ impl Translations {
     /// (key, value, is_multiline)
     pub fn all_strings(&self) -> Vec<(&'static str, &Translation, bool)> {
         vec![
+            ("add_to_queue", &self.add_to_queue, false),
             ("audio_format_alac", &self.audio_format_alac, false),
             ("audio_format_average", &self.audio_format_average, false),
             ("audio_format_flac", &self.audio_format_flac, false),
@@ -430,8 +515,11 @@ impl Translations {
             ("audio_player_widget_for_xxx", &self.audio_player_widget_for_xxx, false),
             ("auto_generated_cover", &self.auto_generated_cover, false),
             ("available_formats", &self.available_formats, false),
+            ("available_with_full_release", &self.available_with_full_release, false),
             ("browse", &self.browse, false),
             ("buy", &self.buy, false),
+            ("buy_physical", &self.buy_physical, false),
+            ("chapters", &self.chapters, false),
             ("close", &self.close, false),
             ("copied", &self.copied, false),
             ("copy", &self.copy, false),
@@ -445,45 +533,66 @@ impl Translations {
             ("downloads", &self.downloads, false),
             ("downloads_permalink", &self.downloads_permalink, false),
             ("embed", &self.embed, false),
+            ("embed_entire_playlist", &self.embed_entire_playlist, false),
             ("embed_entire_release", &self.embed_entire_release, false),
             ("enter_code_here", &self.enter_code_here, false),
             ("external_link", &self.external_link, false),
             ("extras", &self.extras, false),
             ("failed", &self.failed, false),
             ("feed", &self.feed, false),
+            ("full_release_on_xxx", &self.full_release_on_xxx, false),
+            ("gallery", &self.gallery, false),
             ("generic_rss", &self.generic_rss, false),
             ("image_descriptions", &self.image_descriptions, false),
             ("image_descriptions_guide", &self.image_descriptions_guide, true),
             ("image_descriptions_permalink", &self.image_descriptions_permalink, false),
+            ("in_stock", &self.in_stock, false),
             ("javascript_is_disabled_listen_at_xxx", &self.javascript_is_disabled_listen_at_xxx, false),
             ("javascript_is_disabled_text", &self.javascript_is_disabled_text, false),
+            ("jspf_playlist", &self.jspf_playlist, false),
+            ("license", &self.license, false),
             ("listen", &self.listen, false),
             ("loading", &self.loading, false),
+            ("low_stock", &self.low_stock, false),
             ("m3u_playlist", &self.m3u_playlist, false),
             ("made_or_arranged_payment", &self.made_or_arranged_payment, false),
+            ("merch", &self.merch, false),
+            ("merch_permalink", &self.merch_permalink, false),
             ("missing_image_description_note", &self.missing_image_description_note, false),
             ("more", &self.more, false),
             ("mute", &self.mute, false),
             ("name_your_price", &self.name_your_price, false),
+            ("news", &self.news, false),
+            ("news_permalink", &self.news_permalink, false),
+            ("next_image", &self.next_image, false),
             ("next_track", &self.next_track, false),
             ("nothing_found_for_xxx", &self.nothing_found_for_xxx, false),
+            ("past_events", &self.past_events, false),
             ("pause", &self.pause, false),
             ("play", &self.play, false),
+            ("play_next", &self.play_next, false),
             ("playback_position", &self.playback_position, false),
             ("player_closed", &self.player_closed, false),
             ("player_open_playing_xxx", &self.player_open_playing_xxx, false),
             ("player_open_with_xxx", &self.player_open_with_xxx, false),
+            ("preorder", &self.preorder, false),
+            ("previous_image", &self.previous_image, false),
             ("previous_track", &self.previous_track, false),
             ("price", &self.price, false),
             ("purchase_downloads", &self.purchase_downloads, false),
             ("purchase_permalink", &self.purchase_permalink, false),
             ("recommended_format", &self.recommended_format, false),
+            ("remove_offline_copy", &self.remove_offline_copy, false),
+            ("save_for_offline", &self.save_for_offline, false),
             ("search", &self.search, false),
             ("showing_featured_items", &self.showing_featured_items, false),
             ("showing_xxx_results_for_xxx", &self.showing_xxx_results_for_xxx, false),
             ("skip_to_main_content", &self.skip_to_main_content, false),
+            ("sold_out", &self.sold_out, false),
             ("subscribe", &self.subscribe, false),
             ("subscribe_permalink", &self.subscribe_permalink, false),
+            ("subscribe_to_calendar", &self.subscribe_to_calendar, false),
+            ("tickets", &self.tickets, false),
             ("unlisted", &self.unlisted, false),
             ("unlock", &self.unlock, false),
             ("unlock_downloads", &self.unlock_downloads, false),
@@ -491,10 +600,13 @@ impl Translations {
             ("unlock_permalink", &self.unlock_permalink, false),
             ("unmute", &self.unmute, false),
             ("up_to_xxx", &self.up_to_xxx, false),
+            ("upcoming_events", &self.upcoming_events, false),
             ("visual_impairment", &self.visual_impairment, false),
             ("volume", &self.volume, false),
+            ("xspf_playlist", &self.xspf_playlist, false),
             ("xxx_and_others", &self.xxx_and_others, false),
             ("xxx_hours", &self.xxx_hours, false),
+            ("xxx_is_licensed_under_xxx", &self.xxx_is_licensed_under_xxx, false),
             ("xxx_minutes", &self.xxx_minutes, false),
             ("xxx_or_more", &self.xxx_or_more, false),
             ("xxx_seconds", &self.xxx_seconds, false)
@@ -519,6 +631,10 @@ impl Translations {
             .count()
     }
 
+    pub fn full_release_on_xxx(&self, xxx: &str) -> String {
+        self.full_release_on_xxx.replace("{xxx}", xxx)
+    }
+
     pub fn javascript_is_disabled_listen_at_xxx(&self, link: &str) -> String {
         self.javascript_is_disabled_listen_at_xxx.replace("{link}", link)
     }
@@ -579,6 +695,12 @@ impl Translations {
             .replace("{others_link}", others_link)
     }
 
+    pub fn xxx_is_licensed_under_xxx(&self, title: &str, license: &str) -> String {
+        self.xxx_is_licensed_under_xxx
+            .replace("{title}", title)
+            .replace("{license}", license)
+    }
+
     pub fn xxx_minutes(&self, xxx: &str) -> String {
         self.xxx_minutes.replace("{xxx}", xxx)
     }
@@ -617,6 +739,7 @@ fn check_translations() {
 
     for translations in LOCALES {
         assert!(&translations.audio_player_widget_for_xxx.contains("{title}"));
+        assert!(&translations.full_release_on_xxx.contains("{xxx}"));
         assert!(&translations.javascript_is_disabled_listen_at_xxx.contains("{link}"));
         assert!(&translations.nothing_found_for_xxx.contains("{query}"));
         assert!(&translations.player_open_playing_xxx.contains("{title}"));
@@ -639,6 +762,8 @@ fn check_translations() {
 
         assert!(!&translations.downloads_permalink.contains(disallowed_char));
         assert!(!&translations.image_descriptions_permalink.contains(disallowed_char));
+        assert!(!&translations.merch_permalink.contains(disallowed_char));
+        assert!(!&translations.news_permalink.contains(disallowed_char));
         assert!(!&translations.purchase_permalink.contains(disallowed_char));
         assert!(!&translations.subscribe_permalink.contains(disallowed_char));
         assert!(!&translations.unlock_permalink.contains(disallowed_char));